@@ -7,7 +7,7 @@ use std::sync::Arc;
 use mtgsim::cards::registry::CardRegistry;
 use mtgsim::objects::card_data::CardData;
 use mtgsim::state::game::{Game, GameResult};
-use mtgsim::state::game_config::GameConfig;
+use mtgsim::state::game_config::{GameConfig, MulliganRule};
 use mtgsim::ui::cli::CliDecisionProvider;
 use mtgsim::ui::decision::DispatchDecisionProvider;
 use mtgsim::ui::random::RandomDecisionProvider;
@@ -37,6 +37,12 @@ fn build_test_deck(registry: &CardRegistry) -> Vec<Arc<CardData>> {
         deck.push(registry.create("Lightning Bolt").unwrap());
     }
 
+    // Equipment
+    for _ in 0..2 {
+        deck.push(registry.create("Bonesplitter").unwrap());
+    }
+    deck.push(registry.create("Sword of Fire and Ice").unwrap());
+
     // Pad to 40 with more lands
     while deck.len() < 40 {
         deck.push(registry.create("Mountain").unwrap());
@@ -51,7 +57,8 @@ fn main() {
     println!();
 
     let registry = CardRegistry::default_registry();
-    let config = GameConfig::test();
+    let mut config = GameConfig::test();
+    config.mulligan_rule = MulliganRule::London;
 
     let deck0 = build_test_deck(&registry);
     let deck1 = build_test_deck(&registry);
@@ -76,6 +83,13 @@ fn main() {
                     println!("\n*** You lost. Player {} wins. ***", pid);
                 }
             }
+            GameResult::TeamWinner(team) => {
+                if team.contains(&0) {
+                    println!("\n*** YOUR TEAM WINS! ***");
+                } else {
+                    println!("\n*** Your team lost. Players {:?} win. ***", team);
+                }
+            }
             GameResult::Draw => println!("\n*** DRAW ***"),
         },
         Err(e) => println!("\nGame error: {}", e),
@@ -3,6 +3,8 @@
 // Usage: cargo run --bin fuzz_games -- --games 100 --max-turns 200 --verbose
 //        cargo run --bin fuzz_games -- --games 10 --dump-events events.log
 //        cargo run --bin fuzz_games -- --seed 12345 --games 1 --verbose
+//        cargo run --bin fuzz_games -- --players 4 --games 20
+//        cargo run --bin fuzz_games -- --players 4 --team-size 2 --games 20
 
 use std::collections::HashMap;
 use std::panic;
@@ -17,7 +19,7 @@ use mtgsim::cards::registry::CardRegistry;
 use mtgsim::events::event::GameEvent;
 use mtgsim::objects::card_data::CardData;
 use mtgsim::state::game::Game;
-use mtgsim::state::game_config::GameConfig;
+use mtgsim::state::game_config::{GameConfig, MulliganRule};
 use mtgsim::types::card_types::CardType;
 use mtgsim::types::colors::Color;
 use mtgsim::types::mana::ManaSymbol;
@@ -32,6 +34,10 @@ struct Args {
     dump_events: Option<String>,
     /// If set, use this seed for reproducibility.
     seed: Option<u64>,
+    /// Number of players per game (free-for-all when more than two).
+    players: usize,
+    /// Players per team (e.g. 2 with 4 players for Two-Headed Giant).
+    team_size: Option<usize>,
 }
 
 fn parse_args() -> Args {
@@ -42,6 +48,8 @@ fn parse_args() -> Args {
         verbose: false,
         dump_events: None,
         seed: None,
+        players: 2,
+        team_size: None,
     };
 
     let mut i = 1;
@@ -74,6 +82,18 @@ fn parse_args() -> Args {
                     result.seed = Some(args[i].parse().unwrap_or(0));
                 }
             }
+            "--players" | "-p" => {
+                i += 1;
+                if i < args.len() {
+                    result.players = args[i].parse().unwrap_or(2).max(2);
+                }
+            }
+            "--team-size" => {
+                i += 1;
+                if i < args.len() {
+                    result.team_size = args[i].parse().ok();
+                }
+            }
             _ => {
                 eprintln!("Unknown argument: {}", args[i]);
             }
@@ -306,8 +326,8 @@ fn main() {
 
     println!("=== MTG Simulator Fuzz Harness ===");
     println!(
-        "Running {} games, max {} turns each",
-        args.games, args.max_turns
+        "Running {} {}-player games, max {} turns each",
+        args.games, args.players, args.max_turns
     );
     println!("Master seed: {} (reproduce with --seed {})", master_seed, master_seed);
     println!();
@@ -329,13 +349,16 @@ fn main() {
         let game_seed = master_seed.wrapping_add(game_num as u64);
         let mut deck_rng = StdRng::seed_from_u64(game_seed);
 
-        let deck1 = random_deck(&registry, &mut deck_rng);
-        let deck2 = random_deck(&registry, &mut deck_rng);
+        let decks: Vec<_> = (0..args.players)
+            .map(|_| random_deck(&registry, &mut deck_rng))
+            .collect();
 
         let result = panic::catch_unwind(panic::AssertUnwindSafe(|| {
-            let config = GameConfig::test();
+            let mut config = GameConfig::test();
+            config.team_size = args.team_size;
+            config.mulligan_rule = MulliganRule::London;
             let mut game =
-                Game::new(config, vec![deck1, deck2]).expect("Failed to create game");
+                Game::new(config, decks).expect("Failed to create game");
             let dp = RandomDecisionProvider::new();
             game.setup(&dp).expect("Failed to setup game");
 
@@ -381,6 +404,11 @@ fn main() {
                         *winner_counts.entry(key.clone()).or_insert(0) += 1;
                         key
                     }
+                    Some(mtgsim::state::game::GameResult::TeamWinner(team)) => {
+                        let key = format!("Team {:?} wins", team);
+                        *winner_counts.entry(key.clone()).or_insert(0) += 1;
+                        key
+                    }
                     Some(mtgsim::state::game::GameResult::Draw) => {
                         *winner_counts.entry("Draw".to_string()).or_insert(0) += 1;
                         "Draw".to_string()
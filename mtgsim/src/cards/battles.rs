@@ -0,0 +1,82 @@
+//! Battle card definitions.
+//!
+//! Sieges enter with defense counters and a protector chosen by their
+//! controller (rules 310.4b, 310.8a), and are cast transformed once defeated
+//! (rule 310.11b), so each is built with `transforms_into` its back face.
+//! Their front-face enters abilities are triggered abilities, which the
+//! engine doesn't queue yet; they're omitted and noted on each card, as are
+//! the back faces not yet filled in.
+//!
+//! Card origins:
+//! - Invasion of Regatha, Invasion of Zendikar — March of the Machine (2023)
+//! - Invasion of Emberfall — made-up, {1}{R} Siege whose back face is a
+//!   3/2 haste creature
+
+use std::sync::Arc;
+
+use crate::objects::card_data::{CardData, CardDataBuilder};
+use crate::types::card_types::{BattleType, CardType, CreatureType, Subtype};
+use crate::types::colors::Color;
+use crate::types::keywords::KeywordAbility;
+use crate::types::mana::{ManaCost, ManaType};
+
+/// Invasion of Regatha — {2}{R}
+/// Battle — Siege
+/// Defense 5
+/// When Invasion of Regatha enters, it deals 4 damage to up to one other
+/// target creature. (omitted — ETB trigger)
+/// Back face: Disciples of the Inferno (omitted)
+pub fn invasion_of_regatha() -> Arc<CardData> {
+    CardDataBuilder::new("Invasion of Regatha")
+        .mana_cost(ManaCost::build(&[ManaType::Red], 2))
+        .color(Color::Red)
+        .card_type(CardType::Battle)
+        .subtype(Subtype::Battle(BattleType::Siege))
+        .defense(5)
+        .build()
+}
+
+/// Invasion of Zendikar — {3}{G}
+/// Battle — Siege
+/// Defense 3
+/// When Invasion of Zendikar enters, search your library for up to two basic
+/// land cards, put them onto the battlefield tapped, then shuffle.
+/// (omitted — ETB trigger)
+/// Back face: Awakened Skyclave (omitted)
+pub fn invasion_of_zendikar() -> Arc<CardData> {
+    CardDataBuilder::new("Invasion of Zendikar")
+        .mana_cost(ManaCost::build(&[ManaType::Green], 3))
+        .color(Color::Green)
+        .card_type(CardType::Battle)
+        .subtype(Subtype::Battle(BattleType::Siege))
+        .defense(3)
+        .build()
+}
+
+/// Invasion of Emberfall (made-up) — {1}{R}
+/// Battle — Siege
+/// Defense 2
+///
+/// Emberfall Raider — color indicator {R}
+/// Creature — Elemental
+/// 3/2
+/// Haste
+pub fn invasion_of_emberfall() -> Arc<CardData> {
+    let emberfall_raider = CardDataBuilder::new("Emberfall Raider")
+        .card_type(CardType::Creature)
+        .subtype(Subtype::Creature(CreatureType::Elemental))
+        .color(Color::Red)
+        .color_indicator(vec![Color::Red])
+        .power_toughness(3, 2)
+        .keyword(KeywordAbility::Haste)
+        .build();
+
+    CardDataBuilder::new("Invasion of Emberfall")
+        .mana_cost(ManaCost::build(&[ManaType::Red], 1))
+        .color(Color::Red)
+        .card_type(CardType::Battle)
+        .subtype(Subtype::Battle(BattleType::Siege))
+        .defense(2)
+        .transforms_into(emberfall_raider)
+        .build()
+}
@@ -0,0 +1,68 @@
+//! Cards that use the command zone beyond commanders: the monarch (rule
+//! 724), the initiative (rule 725) and venturing into the dungeon (rule
+//! 701.49). Emblems come from planeswalker ultimates (see planeswalkers.rs).
+//!
+//! Card origins:
+//! - Claim the Throne — (made-up) {1}{W} sorcery
+//! - Seize the Initiative — (made-up) {2}{B} sorcery
+//! - Delve the Depths — (made-up) {U} sorcery
+
+use std::sync::Arc;
+
+use crate::objects::card_data::{AbilityDef, AbilityType, CardData, CardDataBuilder};
+use crate::types::card_types::CardType;
+use crate::types::colors::Color;
+use crate::types::effects::*;
+use crate::types::ids::new_ability_id;
+use crate::types::mana::{ManaCost, ManaType};
+
+/// Claim the Throne — {1}{W}
+/// Sorcery
+/// You become the monarch.
+pub fn claim_the_throne() -> Arc<CardData> {
+    CardDataBuilder::new("Claim the Throne")
+        .card_type(CardType::Sorcery)
+        .color(Color::White)
+        .mana_cost(ManaCost::build(&[ManaType::White], 1))
+        .ability(AbilityDef {
+            id: new_ability_id(),
+            ability_type: AbilityType::Spell,
+            costs: Vec::new(),
+            effect: Effect::Atom(Primitive::BecomeMonarch, EffectRecipient::Controller),
+        })
+        .build()
+}
+
+/// Seize the Initiative — {2}{B}
+/// Sorcery
+/// You take the initiative.
+pub fn seize_the_initiative() -> Arc<CardData> {
+    CardDataBuilder::new("Seize the Initiative")
+        .card_type(CardType::Sorcery)
+        .color(Color::Black)
+        .mana_cost(ManaCost::build(&[ManaType::Black], 2))
+        .ability(AbilityDef {
+            id: new_ability_id(),
+            ability_type: AbilityType::Spell,
+            costs: Vec::new(),
+            effect: Effect::Atom(Primitive::TakeInitiative, EffectRecipient::Controller),
+        })
+        .build()
+}
+
+/// Delve the Depths — {U}
+/// Sorcery
+/// Venture into the dungeon.
+pub fn delve_the_depths() -> Arc<CardData> {
+    CardDataBuilder::new("Delve the Depths")
+        .card_type(CardType::Sorcery)
+        .color(Color::Blue)
+        .mana_cost(ManaCost::build(&[ManaType::Blue], 0))
+        .ability(AbilityDef {
+            id: new_ability_id(),
+            ability_type: AbilityType::Spell,
+            costs: Vec::new(),
+            effect: Effect::Atom(Primitive::Venture, EffectRecipient::Controller),
+        })
+        .build()
+}
@@ -0,0 +1,105 @@
+//! Cards that copy spells on the stack (rule 707.10): by targeting one,
+//! or with storm, replicate or casualty, which copy the spell they're on as
+//! it's cast (rules 702.40, 702.56, 702.153).
+//!
+//! Card origins:
+//! - Twincast — Saviors of Kamigawa (2005), {U}{U} instant
+//! - Grapeshot — Scourge (2003), {1}{R} sorcery with storm
+//! - Pyromatics — Guildpact (2006), {1}{R} instant with replicate {1}{R}
+//! - Ruthless Volley — made-up, {1}{R} instant with casualty 1
+
+use std::sync::Arc;
+
+use crate::objects::card_data::{AbilityDef, AbilityType, CardData, CardDataBuilder};
+use crate::types::card_types::CardType;
+use crate::types::colors::Color;
+use crate::types::costs::{AdditionalCost, Cost};
+use crate::types::effects::*;
+use crate::types::ids::new_ability_id;
+use crate::types::keywords::KeywordAbility;
+use crate::types::mana::{ManaCost, ManaType};
+
+/// Twincast — {U}{U}
+/// Instant
+/// Copy target instant or sorcery spell. You may choose new targets for
+/// the copy.
+pub fn twincast() -> Arc<CardData> {
+    CardDataBuilder::new("Twincast")
+        .card_type(CardType::Instant)
+        .color(Color::Blue)
+        .mana_cost(ManaCost::build(&[ManaType::Blue, ManaType::Blue], 0))
+        .ability(AbilityDef {
+            id: new_ability_id(),
+            ability_type: AbilityType::Spell,
+            costs: Vec::new(),
+            effect: Effect::Atom(
+                Primitive::CopySpell(AmountExpr::Fixed(1)),
+                EffectRecipient::Target(SelectionFilter::InstantOrSorcerySpell, TargetCount::Exactly(1)),
+            ),
+        })
+        .build()
+}
+
+/// Grapeshot — {1}{R}
+/// Sorcery
+/// Grapeshot deals 1 damage to any target.
+/// Storm
+pub fn grapeshot() -> Arc<CardData> {
+    CardDataBuilder::new("Grapeshot")
+        .card_type(CardType::Sorcery)
+        .color(Color::Red)
+        .mana_cost(ManaCost::build(&[ManaType::Red], 1))
+        .keyword(KeywordAbility::Storm)
+        .ability(one_damage_to_any_target())
+        .build()
+}
+
+/// Pyromatics — {1}{R}
+/// Instant
+/// Replicate {1}{R}
+/// Pyromatics deals 1 damage to any target.
+pub fn pyromatics() -> Arc<CardData> {
+    CardDataBuilder::new("Pyromatics")
+        .card_type(CardType::Instant)
+        .color(Color::Red)
+        .mana_cost(ManaCost::build(&[ManaType::Red], 1))
+        .additional_cost(AdditionalCost::Replicate(vec![
+            Cost::Mana(ManaCost::build(&[ManaType::Red], 1)),
+        ]))
+        .ability(one_damage_to_any_target())
+        .build()
+}
+
+/// Ruthless Volley (made-up) — {1}{R}
+/// Instant
+/// Casualty 1
+/// Ruthless Volley deals 2 damage to any target.
+pub fn ruthless_volley() -> Arc<CardData> {
+    CardDataBuilder::new("Ruthless Volley")
+        .card_type(CardType::Instant)
+        .color(Color::Red)
+        .mana_cost(ManaCost::build(&[ManaType::Red], 1))
+        .additional_cost(AdditionalCost::Casualty(1))
+        .ability(AbilityDef {
+            id: new_ability_id(),
+            ability_type: AbilityType::Spell,
+            costs: Vec::new(),
+            effect: Effect::Atom(
+                Primitive::DealDamage(AmountExpr::Fixed(2)),
+                EffectRecipient::Target(SelectionFilter::Any, TargetCount::Exactly(1)),
+            ),
+        })
+        .build()
+}
+
+fn one_damage_to_any_target() -> AbilityDef {
+    AbilityDef {
+        id: new_ability_id(),
+        ability_type: AbilityType::Spell,
+        costs: Vec::new(),
+        effect: Effect::Atom(
+            Primitive::DealDamage(AmountExpr::Fixed(1)),
+            EffectRecipient::Target(SelectionFilter::Any, TargetCount::Exactly(1)),
+        ),
+    }
+}
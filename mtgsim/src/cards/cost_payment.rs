@@ -0,0 +1,119 @@
+//! Cards whose mana can be paid other than with mana: convoke, delve and
+//! improvise (rules 702.51, 702.66, 702.126), and emerge, an alternative
+//! cost reduced by the creature sacrificed for it (rule 702.119).
+//!
+//! Card origins:
+//! - Stoke the Flames — Magic 2015 (2014), {2}{R}{R} instant with convoke
+//! - Treasure Cruise — Khans of Tarkir (2014), {7}{U} sorcery with delve
+//! - Reverse Engineer — Aether Revolt (2017), {3}{U}{U} sorcery with improvise
+//! - Wretched Gryff — Eldritch Moon (2016), {7}{U} 3/4 creature with emerge
+//! - Gorging Larva — made-up, {5}{U} 3/3 creature with emerge and casualty 1
+
+use std::sync::Arc;
+
+use crate::objects::card_data::{AbilityDef, AbilityType, CardData, CardDataBuilder};
+use crate::types::card_types::{CardType, CreatureType, Subtype};
+use crate::types::colors::Color;
+use crate::types::costs::{AdditionalCost, AlternativeCost, Cost};
+use crate::types::effects::*;
+use crate::types::ids::new_ability_id;
+use crate::types::keywords::KeywordAbility;
+use crate::types::mana::{ManaCost, ManaType};
+
+/// Stoke the Flames — {2}{R}{R}
+/// Instant
+/// Convoke
+/// Stoke the Flames deals 4 damage to any target.
+pub fn stoke_the_flames() -> Arc<CardData> {
+    CardDataBuilder::new("Stoke the Flames")
+        .card_type(CardType::Instant)
+        .color(Color::Red)
+        .mana_cost(ManaCost::build(&[ManaType::Red, ManaType::Red], 2))
+        .keyword(KeywordAbility::Convoke)
+        .ability(AbilityDef {
+            id: new_ability_id(),
+            ability_type: AbilityType::Spell,
+            costs: Vec::new(),
+            effect: Effect::Atom(
+                Primitive::DealDamage(AmountExpr::Fixed(4)),
+                EffectRecipient::Target(SelectionFilter::Any, TargetCount::Exactly(1)),
+            ),
+        })
+        .build()
+}
+
+/// Treasure Cruise — {7}{U}
+/// Sorcery
+/// Delve
+/// Draw three cards.
+pub fn treasure_cruise() -> Arc<CardData> {
+    CardDataBuilder::new("Treasure Cruise")
+        .card_type(CardType::Sorcery)
+        .color(Color::Blue)
+        .mana_cost(ManaCost::build(&[ManaType::Blue], 7))
+        .keyword(KeywordAbility::Delve)
+        .ability(draw_three())
+        .build()
+}
+
+/// Reverse Engineer — {3}{U}{U}
+/// Sorcery
+/// Improvise
+/// Draw three cards.
+pub fn reverse_engineer() -> Arc<CardData> {
+    CardDataBuilder::new("Reverse Engineer")
+        .card_type(CardType::Sorcery)
+        .color(Color::Blue)
+        .mana_cost(ManaCost::build(&[ManaType::Blue, ManaType::Blue], 3))
+        .keyword(KeywordAbility::Improvise)
+        .ability(draw_three())
+        .build()
+}
+
+/// Wretched Gryff — {7}{U}
+/// Creature — Eldrazi Hippogriff
+/// 3/4
+/// Emerge {5}{U}
+/// When you cast this spell, draw a card.
+/// Flying
+///
+/// The cast trigger isn't modelled: there's no "when you cast this spell"
+/// trigger for a spell's own casting yet.
+pub fn wretched_gryff() -> Arc<CardData> {
+    CardDataBuilder::new("Wretched Gryff")
+        .card_type(CardType::Creature)
+        .subtype(Subtype::Creature(CreatureType::Eldrazi))
+        .subtype(Subtype::Creature(CreatureType::Hippogriff))
+        .color(Color::Blue)
+        .mana_cost(ManaCost::build(&[ManaType::Blue], 7))
+        .power_toughness(3, 4)
+        .alternative_cost(AlternativeCost::Emerge(vec![Cost::Mana(ManaCost::build(&[ManaType::Blue], 5))]))
+        .keyword(KeywordAbility::Flying)
+        .build()
+}
+
+/// Gorging Larva (made-up) — {5}{U}
+/// Creature — Eldrazi
+/// 3/3
+/// Emerge {3}{U}
+/// Casualty 1
+pub fn gorging_larva() -> Arc<CardData> {
+    CardDataBuilder::new("Gorging Larva")
+        .card_type(CardType::Creature)
+        .subtype(Subtype::Creature(CreatureType::Eldrazi))
+        .color(Color::Blue)
+        .mana_cost(ManaCost::build(&[ManaType::Blue], 5))
+        .power_toughness(3, 3)
+        .alternative_cost(AlternativeCost::Emerge(vec![Cost::Mana(ManaCost::build(&[ManaType::Blue], 3))]))
+        .additional_cost(AdditionalCost::Casualty(1))
+        .build()
+}
+
+fn draw_three() -> AbilityDef {
+    AbilityDef {
+        id: new_ability_id(),
+        ability_type: AbilityType::Spell,
+        costs: Vec::new(),
+        effect: Effect::Atom(Primitive::DrawCards(AmountExpr::Fixed(3)), EffectRecipient::Controller),
+    }
+}
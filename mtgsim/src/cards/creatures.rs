@@ -1,17 +1,19 @@
 //! Phase 3 vanilla creature card definitions.
 //!
-//! These creatures have no abilities — they resolve to the battlefield as
-//! permanents. The combat system uses their printed power/toughness.
+//! These creatures have no abilities, but for Llanowar Elves' mana ability —
+//! they resolve to the battlefield as permanents. The combat system uses
+//! their printed power/toughness.
 //!
 //! Card origins:
 //! - Grizzly Bears — Alpha (1993), {1}{G} 2/2
 //! - Hill Giant — Alpha (1993), {3}{R} 3/3
 //! - Savannah Lions — Alpha (1993), {W} 2/1
+//! - Llanowar Elves — Alpha (1993), {G} 1/1 that taps for {G}
 
 use std::sync::Arc;
 
 use crate::objects::card_data::{CardData, CardDataBuilder};
-use crate::types::card_types::CardType;
+use crate::types::card_types::{CardType, CreatureType, Subtype};
 use crate::types::colors::Color;
 use crate::types::mana::{ManaCost, ManaType};
 
@@ -63,6 +65,22 @@ pub fn earth_elemental() -> Arc<CardData> {
         .build()
 }
 
+/// Llanowar Elves — {G}
+/// Creature — Elf Druid
+/// {T}: Add {G}.
+/// 1/1
+pub fn llanowar_elves() -> Arc<CardData> {
+    CardDataBuilder::new("Llanowar Elves")
+        .card_type(CardType::Creature)
+        .subtype(Subtype::Creature(CreatureType::Elf))
+        .subtype(Subtype::Creature(CreatureType::Druid))
+        .color(Color::Green)
+        .mana_cost(ManaCost::build(&[ManaType::Green], 0))
+        .power_toughness(1, 1)
+        .mana_ability_single(ManaType::Green)
+        .build()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
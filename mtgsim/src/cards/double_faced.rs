@@ -0,0 +1,212 @@
+//! Double-faced card definitions (rule 712).
+//!
+//! Each factory builds the back face first and hands it to the front face's
+//! builder with `transforms_into` (a transforming DFC) or `modal_back_face`
+//! (a modal DFC). The registry knows a DFC by its front face's name.
+//!
+//! Card origins:
+//! - Delver of Secrets // Insectile Aberration — Innistrad (2011)
+//! - Moonmist — Innistrad (2011), {1}{G} instant
+//! - Outland Liberator // Frenzied Trapbreaker — Innistrad: Midnight Hunt
+//!   (2021), daybound/nightbound
+//! - Spikefield Hazard // Spikefield Cave — Zendikar Rising (2020), modal
+//!   spell/land
+//! - Brightclimb Pathway // Grimclimb Pathway — Zendikar Rising (2020),
+//!   modal land/land
+//! - Halvar, God of Battle // Sword of the Realms — Kaldheim (2021), modal
+//!   creature/Equipment
+
+use std::sync::Arc;
+
+use crate::objects::card_data::{AbilityDef, AbilityType, CardData, CardDataBuilder};
+use crate::types::card_types::{ArtifactType, CardType, CreatureType, Subtype, Supertype};
+use crate::types::colors::Color;
+use crate::types::effects::*;
+use crate::types::ids::new_ability_id;
+use crate::types::keywords::KeywordAbility;
+use crate::types::mana::{ManaCost, ManaType};
+
+use super::equipment::{equipped_creature_gets, equipped_creature_has};
+
+/// Delver of Secrets — {U}
+/// Creature — Human Wizard
+/// 1/1
+/// At the beginning of your upkeep, look at the top card of your library.
+/// You may reveal that card. If an instant or sorcery card is revealed this
+/// way, transform Delver of Secrets. (omitted — needs upkeep triggers and
+/// reveal)
+///
+/// Insectile Aberration — color indicator {U}
+/// Creature — Human Insect
+/// 3/2
+/// Flying
+pub fn delver_of_secrets() -> Arc<CardData> {
+    let insectile_aberration = CardDataBuilder::new("Insectile Aberration")
+        .card_type(CardType::Creature)
+        .subtype(Subtype::Creature(CreatureType::Human))
+        .subtype(Subtype::Creature(CreatureType::Insect))
+        .color(Color::Blue)
+        .color_indicator(vec![Color::Blue])
+        .power_toughness(3, 2)
+        .keyword(KeywordAbility::Flying)
+        .build();
+
+    CardDataBuilder::new("Delver of Secrets")
+        .card_type(CardType::Creature)
+        .subtype(Subtype::Creature(CreatureType::Human))
+        .subtype(Subtype::Creature(CreatureType::Wizard))
+        .color(Color::Blue)
+        .mana_cost(ManaCost::build(&[ManaType::Blue], 0))
+        .power_toughness(1, 1)
+        .transforms_into(insectile_aberration)
+        .build()
+}
+
+/// Moonmist — {1}{G}
+/// Instant
+/// Transform all Humans.
+/// Prevent all combat damage that would be dealt this turn by creatures
+/// other than Werewolves and Wolves. (omitted — needs prevention effects)
+pub fn moonmist() -> Arc<CardData> {
+    CardDataBuilder::new("Moonmist")
+        .card_type(CardType::Instant)
+        .color(Color::Green)
+        .mana_cost(ManaCost::build(&[ManaType::Green], 1))
+        .ability(AbilityDef {
+            id: new_ability_id(),
+            ability_type: AbilityType::Spell,
+            costs: Vec::new(),
+            effect: Effect::Atom(
+                Primitive::Transform,
+                EffectRecipient::FilteredPermanents(PermanentFilter::BySubtype(
+                    Subtype::Creature(CreatureType::Human),
+                )),
+            ),
+        })
+        .build()
+}
+
+/// Outland Liberator — {1}{G}
+/// Creature — Human Werewolf
+/// 2/2
+/// {1}, Sacrifice Outland Liberator: Destroy target artifact or enchantment.
+/// (omitted — needs sacrifice-self costs)
+/// Daybound
+///
+/// Frenzied Trapbreaker — color indicator {G}
+/// Creature — Werewolf
+/// 3/3
+/// Whenever Frenzied Trapbreaker attacks, destroy target artifact or
+/// enchantment defending player controls. (omitted — needs attack triggers)
+/// Nightbound
+pub fn outland_liberator() -> Arc<CardData> {
+    let frenzied_trapbreaker = CardDataBuilder::new("Frenzied Trapbreaker")
+        .card_type(CardType::Creature)
+        .subtype(Subtype::Creature(CreatureType::Werewolf))
+        .color(Color::Green)
+        .color_indicator(vec![Color::Green])
+        .power_toughness(3, 3)
+        .keyword(KeywordAbility::Nightbound)
+        .build();
+
+    CardDataBuilder::new("Outland Liberator")
+        .card_type(CardType::Creature)
+        .subtype(Subtype::Creature(CreatureType::Human))
+        .subtype(Subtype::Creature(CreatureType::Werewolf))
+        .color(Color::Green)
+        .mana_cost(ManaCost::build(&[ManaType::Green], 1))
+        .power_toughness(2, 2)
+        .keyword(KeywordAbility::Daybound)
+        .transforms_into(frenzied_trapbreaker)
+        .build()
+}
+
+/// Spikefield Hazard — {R}
+/// Instant
+/// Spikefield Hazard deals 1 damage to any target.
+///
+/// Spikefield Cave
+/// Land
+/// Spikefield Cave enters the battlefield tapped. (omitted — needs
+/// enters-tapped replacement effects)
+/// {T}: Add {R}.
+pub fn spikefield_hazard() -> Arc<CardData> {
+    let spikefield_cave = CardDataBuilder::new("Spikefield Cave")
+        .card_type(CardType::Land)
+        .mana_ability_single(ManaType::Red)
+        .build();
+
+    CardDataBuilder::new("Spikefield Hazard")
+        .card_type(CardType::Instant)
+        .color(Color::Red)
+        .mana_cost(ManaCost::build(&[ManaType::Red], 0))
+        .ability(AbilityDef {
+            id: new_ability_id(),
+            ability_type: AbilityType::Spell,
+            costs: Vec::new(),
+            effect: Effect::Atom(
+                Primitive::DealDamage(AmountExpr::Fixed(1)),
+                EffectRecipient::Target(SelectionFilter::Any, TargetCount::Exactly(1)),
+            ),
+        })
+        .modal_back_face(spikefield_cave)
+        .build()
+}
+
+/// Brightclimb Pathway
+/// Land
+/// {T}: Add {W}.
+///
+/// Grimclimb Pathway
+/// Land
+/// {T}: Add {B}.
+pub fn brightclimb_pathway() -> Arc<CardData> {
+    let grimclimb_pathway = CardDataBuilder::new("Grimclimb Pathway")
+        .card_type(CardType::Land)
+        .mana_ability_single(ManaType::Black)
+        .build();
+
+    CardDataBuilder::new("Brightclimb Pathway")
+        .card_type(CardType::Land)
+        .mana_ability_single(ManaType::White)
+        .modal_back_face(grimclimb_pathway)
+        .build()
+}
+
+/// Halvar, God of Battle — {2}{W}{W}
+/// Legendary Creature — God
+/// 4/4
+/// Creatures you control that are enchanted or equipped have double strike.
+/// At the beginning of each combat, you may attach target Aura or Equipment
+/// attached to a creature you control to target creature you control.
+/// (both omitted — need "enchanted or equipped" filters and beginning of
+/// combat triggers)
+///
+/// Sword of the Realms — {1}{W}
+/// Legendary Artifact — Equipment
+/// Equipped creature gets +2/+0 and has vigilance.
+/// Whenever equipped creature dies, return it to its owner's hand. (omitted
+/// — needs death triggers)
+/// Equip {W}
+pub fn halvar_god_of_battle() -> Arc<CardData> {
+    let sword_of_the_realms = CardDataBuilder::new("Sword of the Realms")
+        .card_type(CardType::Artifact)
+        .supertype(Supertype::Legendary)
+        .subtype(Subtype::Artifact(ArtifactType::Equipment))
+        .color(Color::White)
+        .mana_cost(ManaCost::build(&[ManaType::White], 1))
+        .ability(equipped_creature_gets(2, 0))
+        .ability(equipped_creature_has(KeywordAbility::Vigilance))
+        .equip(ManaCost::build(&[ManaType::White], 0))
+        .build();
+
+    CardDataBuilder::new("Halvar, God of Battle")
+        .card_type(CardType::Creature)
+        .supertype(Supertype::Legendary)
+        .subtype(Subtype::Creature(CreatureType::God))
+        .color(Color::White)
+        .mana_cost(ManaCost::build(&[ManaType::White, ManaType::White], 2))
+        .power_toughness(4, 4)
+        .modal_back_face(sword_of_the_realms)
+        .build()
+}
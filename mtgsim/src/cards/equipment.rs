@@ -0,0 +1,133 @@
+//! Equipment card definitions.
+//!
+//! Equip and reconfigure are added with `CardDataBuilder::equip` /
+//! `reconfigure`. "Equipped creature gets/has ..." is a static ability whose
+//! recipient is `EffectRecipient::AttachedPermanent`, so the effect follows
+//! the Equipment from host to host. Abilities that need engine features we
+//! don't have yet (triggers, parameterized protection) are omitted and noted
+//! on each card.
+
+use std::sync::Arc;
+
+use crate::objects::card_data::{AbilityDef, AbilityType, CardData, CardDataBuilder};
+use crate::types::card_types::{ArtifactType, CardType, CreatureType, Subtype};
+use crate::types::colors::Color;
+use crate::types::effects::{AmountExpr, Duration, Effect, EffectRecipient, Primitive};
+use crate::types::ids::new_ability_id;
+use crate::types::keywords::KeywordAbility;
+use crate::types::mana::{ManaCost, ManaType};
+
+/// Static "equipped creature gets +P/+T".
+pub(crate) fn equipped_creature_gets(power: u64, toughness: u64) -> AbilityDef {
+    AbilityDef {
+        id: new_ability_id(),
+        ability_type: AbilityType::Static,
+        costs: Vec::new(),
+        effect: Effect::Atom(
+            Primitive::ModifyPowerToughness(
+                AmountExpr::Fixed(power),
+                AmountExpr::Fixed(toughness),
+                Duration::WhileEquipped,
+            ),
+            EffectRecipient::AttachedPermanent,
+        ),
+    }
+}
+
+/// Static "equipped creature has [keyword]".
+pub(crate) fn equipped_creature_has(keyword: KeywordAbility) -> AbilityDef {
+    AbilityDef {
+        id: new_ability_id(),
+        ability_type: AbilityType::Static,
+        costs: Vec::new(),
+        effect: Effect::Atom(
+            Primitive::GrantKeyword(keyword, Duration::WhileEquipped),
+            EffectRecipient::AttachedPermanent,
+        ),
+    }
+}
+
+/// Bonesplitter — {1}
+/// Artifact — Equipment
+/// Equipped creature gets +2/+0.
+/// Equip {1}
+pub fn bonesplitter() -> Arc<CardData> {
+    CardDataBuilder::new("Bonesplitter")
+        .mana_cost(ManaCost::build(&[], 1))
+        .card_type(CardType::Artifact)
+        .subtype(Subtype::Artifact(ArtifactType::Equipment))
+        .ability(equipped_creature_gets(2, 0))
+        .equip(ManaCost::build(&[], 1))
+        .build()
+}
+
+/// Loxodon Warhammer — {3}
+/// Artifact — Equipment
+/// Equipped creature gets +3/+0 and has trample and lifelink.
+/// Equip {3}
+pub fn loxodon_warhammer() -> Arc<CardData> {
+    CardDataBuilder::new("Loxodon Warhammer")
+        .mana_cost(ManaCost::build(&[], 3))
+        .card_type(CardType::Artifact)
+        .subtype(Subtype::Artifact(ArtifactType::Equipment))
+        .ability(equipped_creature_gets(3, 0))
+        .ability(equipped_creature_has(KeywordAbility::Trample))
+        .ability(equipped_creature_has(KeywordAbility::Lifelink))
+        .equip(ManaCost::build(&[], 3))
+        .build()
+}
+
+/// Sword of Fire and Ice — {3}
+/// Artifact — Equipment
+/// Equipped creature gets +2/+2 and has protection from red and from blue.
+/// (protection omitted — `Protection` isn't parameterized yet)
+/// Whenever equipped creature deals combat damage to a player, ...
+/// (omitted — triggered ability)
+/// Equip {2}
+pub fn sword_of_fire_and_ice() -> Arc<CardData> {
+    CardDataBuilder::new("Sword of Fire and Ice")
+        .mana_cost(ManaCost::build(&[], 3))
+        .card_type(CardType::Artifact)
+        .subtype(Subtype::Artifact(ArtifactType::Equipment))
+        .ability(equipped_creature_gets(2, 2))
+        .equip(ManaCost::build(&[], 2))
+        .build()
+}
+
+/// Sword of Feast and Famine — {3}
+/// Artifact — Equipment
+/// Equipped creature gets +2/+2 and has protection from black and from green.
+/// (protection omitted — `Protection` isn't parameterized yet)
+/// Whenever equipped creature deals combat damage to a player, ...
+/// (omitted — triggered ability)
+/// Equip {2}
+pub fn sword_of_feast_and_famine() -> Arc<CardData> {
+    CardDataBuilder::new("Sword of Feast and Famine")
+        .mana_cost(ManaCost::build(&[], 3))
+        .card_type(CardType::Artifact)
+        .subtype(Subtype::Artifact(ArtifactType::Equipment))
+        .ability(equipped_creature_gets(2, 2))
+        .equip(ManaCost::build(&[], 2))
+        .build()
+}
+
+/// Lizard Blades — {1}{R}
+/// Artifact Creature — Equipment Lizard
+/// 1/1
+/// Double strike
+/// Equipped creature has double strike.
+/// Reconfigure {2}
+pub fn lizard_blades() -> Arc<CardData> {
+    CardDataBuilder::new("Lizard Blades")
+        .mana_cost(ManaCost::build(&[ManaType::Red], 1))
+        .color(Color::Red)
+        .card_type(CardType::Artifact)
+        .card_type(CardType::Creature)
+        .subtype(Subtype::Artifact(ArtifactType::Equipment))
+        .subtype(Subtype::Creature(CreatureType::Lizard))
+        .power_toughness(1, 1)
+        .keyword(KeywordAbility::DoubleStrike)
+        .ability(equipped_creature_has(KeywordAbility::DoubleStrike))
+        .reconfigure(ManaCost::build(&[], 2))
+        .build()
+}
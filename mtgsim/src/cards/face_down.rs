@@ -0,0 +1,113 @@
+//! Face-down card definitions (rule 708): morph, megamorph and disguise
+//! creatures, and spells that manifest or cloak.
+//!
+//! Card origins:
+//! - Exalted Angel — Onslaught (2002), morph
+//! - Den Protector — Dragons of Tarkir (2015), megamorph
+//! - Masked Vagrant — made-up, {2}{B} 3/2 with disguise {1}{B}
+//! - Soul Summons — Fate Reforged (2015), manifest
+//! - Shrouded Secret — made-up, {1}{U} sorcery that cloaks
+
+use std::sync::Arc;
+
+use crate::objects::card_data::{AbilityDef, AbilityType, CardData, CardDataBuilder};
+use crate::types::card_types::{CardType, CreatureType, Subtype};
+use crate::types::colors::Color;
+use crate::types::effects::*;
+use crate::types::ids::new_ability_id;
+use crate::types::keywords::KeywordAbility;
+use crate::types::mana::{ManaCost, ManaType};
+
+/// Exalted Angel — {4}{W}{W}
+/// Creature — Angel
+/// 4/5
+/// Flying, lifelink
+/// Morph {2}{W}{W}
+pub fn exalted_angel() -> Arc<CardData> {
+    CardDataBuilder::new("Exalted Angel")
+        .card_type(CardType::Creature)
+        .subtype(Subtype::Creature(CreatureType::Angel))
+        .color(Color::White)
+        .mana_cost(ManaCost::build(&[ManaType::White, ManaType::White], 4))
+        .power_toughness(4, 5)
+        .keyword(KeywordAbility::Flying)
+        .keyword(KeywordAbility::Lifelink)
+        .morph(ManaCost::build(&[ManaType::White, ManaType::White], 2))
+        .build()
+}
+
+/// Den Protector — {1}{G}
+/// Creature — Human Rogue
+/// 2/1
+/// Creatures with power less than Den Protector's power can't block it.
+/// (omitted — needs blocking restrictions)
+/// Megamorph {1}{G}
+/// When Den Protector is turned face up, return target card from your
+/// graveyard to your hand. (omitted — needs turned-face-up triggers)
+pub fn den_protector() -> Arc<CardData> {
+    CardDataBuilder::new("Den Protector")
+        .card_type(CardType::Creature)
+        .subtype(Subtype::Creature(CreatureType::Human))
+        .subtype(Subtype::Creature(CreatureType::Rogue))
+        .color(Color::Green)
+        .mana_cost(ManaCost::build(&[ManaType::Green], 1))
+        .power_toughness(2, 1)
+        .megamorph(ManaCost::build(&[ManaType::Green], 1))
+        .build()
+}
+
+/// Masked Vagrant (made-up) — {2}{B}
+/// Creature — Human Rogue
+/// 3/2
+/// Disguise {1}{B}
+pub fn masked_vagrant() -> Arc<CardData> {
+    CardDataBuilder::new("Masked Vagrant")
+        .card_type(CardType::Creature)
+        .subtype(Subtype::Creature(CreatureType::Human))
+        .subtype(Subtype::Creature(CreatureType::Rogue))
+        .color(Color::Black)
+        .mana_cost(ManaCost::build(&[ManaType::Black], 2))
+        .power_toughness(3, 2)
+        .disguise(ManaCost::build(&[ManaType::Black], 1))
+        .build()
+}
+
+/// Soul Summons — {1}{W}
+/// Sorcery
+/// Manifest the top card of your library.
+pub fn soul_summons() -> Arc<CardData> {
+    CardDataBuilder::new("Soul Summons")
+        .card_type(CardType::Sorcery)
+        .color(Color::White)
+        .mana_cost(ManaCost::build(&[ManaType::White], 1))
+        .ability(AbilityDef {
+            id: new_ability_id(),
+            ability_type: AbilityType::Spell,
+            costs: Vec::new(),
+            effect: Effect::Atom(
+                Primitive::Manifest(AmountExpr::Fixed(1)),
+                EffectRecipient::Controller,
+            ),
+        })
+        .build()
+}
+
+/// Shrouded Secret (made-up) — {1}{U}
+/// Sorcery
+/// Cloak the top card of your library.
+pub fn shrouded_secret() -> Arc<CardData> {
+    CardDataBuilder::new("Shrouded Secret")
+        .card_type(CardType::Sorcery)
+        .color(Color::Blue)
+        .mana_cost(ManaCost::build(&[ManaType::Blue], 1))
+        .ability(AbilityDef {
+            id: new_ability_id(),
+            ability_type: AbilityType::Spell,
+            costs: Vec::new(),
+            effect: Effect::Atom(
+                Primitive::Cloak(AmountExpr::Fixed(1)),
+                EffectRecipient::Controller,
+            ),
+        })
+        .build()
+}
@@ -0,0 +1,72 @@
+//! Cards that let spells be cast without paying their mana costs (rule
+//! 118.9): cascade and discover, which cast a card exiled from the top of
+//! the library (rules 702.85, 701.57), and Omniscience's static ability.
+//!
+//! Card origins:
+//! - Bloodbraid Elf — Alara Reborn (2009), {2}{R}{G} 3/2 creature with
+//!   haste and cascade
+//! - Omniscience — Magic 2013 (2012), {7}{U}{U}{U} enchantment
+//! - Uncharted Depths — made-up, {2}{R} sorcery that discovers 3
+
+use std::sync::Arc;
+
+use crate::objects::card_data::{AbilityDef, AbilityType, CardData, CardDataBuilder};
+use crate::types::card_types::{CardType, CreatureType, Subtype};
+use crate::types::colors::Color;
+use crate::types::effects::*;
+use crate::types::ids::new_ability_id;
+use crate::types::keywords::KeywordAbility;
+use crate::types::mana::{ManaCost, ManaType};
+
+/// Bloodbraid Elf — {2}{R}{G}
+/// Creature — Elf Berserker
+/// 3/2
+/// Haste
+/// Cascade
+pub fn bloodbraid_elf() -> Arc<CardData> {
+    CardDataBuilder::new("Bloodbraid Elf")
+        .card_type(CardType::Creature)
+        .subtype(Subtype::Creature(CreatureType::Elf))
+        .subtype(Subtype::Creature(CreatureType::Berserker))
+        .color(Color::Red)
+        .color(Color::Green)
+        .mana_cost(ManaCost::build(&[ManaType::Red, ManaType::Green], 2))
+        .power_toughness(3, 2)
+        .keyword(KeywordAbility::Haste)
+        .keyword(KeywordAbility::Cascade)
+        .build()
+}
+
+/// Omniscience — {7}{U}{U}{U}
+/// Enchantment
+/// You may cast spells from your hand without paying their mana costs.
+pub fn omniscience() -> Arc<CardData> {
+    CardDataBuilder::new("Omniscience")
+        .card_type(CardType::Enchantment)
+        .color(Color::Blue)
+        .mana_cost(ManaCost::build(&[ManaType::Blue, ManaType::Blue, ManaType::Blue], 7))
+        .ability(AbilityDef {
+            id: new_ability_id(),
+            ability_type: AbilityType::Static,
+            costs: Vec::new(),
+            effect: Effect::Atom(Primitive::MayCastFromHandWithoutPaying, EffectRecipient::Controller),
+        })
+        .build()
+}
+
+/// Uncharted Depths (made-up) — {2}{R}
+/// Sorcery
+/// Discover 3.
+pub fn uncharted_depths() -> Arc<CardData> {
+    CardDataBuilder::new("Uncharted Depths")
+        .card_type(CardType::Sorcery)
+        .color(Color::Red)
+        .mana_cost(ManaCost::build(&[ManaType::Red], 2))
+        .ability(AbilityDef {
+            id: new_ability_id(),
+            ability_type: AbilityType::Spell,
+            costs: Vec::new(),
+            effect: Effect::Atom(Primitive::Discover(AmountExpr::Fixed(3)), EffectRecipient::Controller),
+        })
+        .build()
+}
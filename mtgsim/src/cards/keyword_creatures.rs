@@ -15,6 +15,9 @@
 //! - Rhox War Monk — Shards of Alara (2008), {G}{W}{U} 3/4 Lifelink
 //! - Giant Spider — Alpha (1993), {3}{G} 2/4 Reach
 //! - Vampire Nighthawk — Zendikar (2009), {1}{B}{B} 2/3 Flying, Lifelink, Deathtouch
+//! - Glistener Elf — New Phyrexia (2011), {G} 1/1 Infect
+//! - Sickle Ripper — Shadowmoor (2008), {1}{B} 2/1 Wither
+//! - Bilious Skulldweller — Phyrexia: All Will Be One (2023), {B} 1/1 Deathtouch, Toxic 1
 
 use std::sync::Arc;
 
@@ -178,6 +181,46 @@ pub fn vampire_nighthawk() -> Arc<CardData> {
         .build()
 }
 
+/// Glistener Elf — {G}
+/// Creature — Phyrexian Elf Warrior
+/// 1/1 Infect
+pub fn glistener_elf() -> Arc<CardData> {
+    CardDataBuilder::new("Glistener Elf")
+        .card_type(CardType::Creature)
+        .color(Color::Green)
+        .mana_cost(ManaCost::build(&[ManaType::Green], 0))
+        .power_toughness(1, 1)
+        .keyword(KeywordAbility::Infect)
+        .build()
+}
+
+/// Sickle Ripper — {1}{B}
+/// Creature — Elemental Warrior
+/// 2/1 Wither
+pub fn sickle_ripper() -> Arc<CardData> {
+    CardDataBuilder::new("Sickle Ripper")
+        .card_type(CardType::Creature)
+        .color(Color::Black)
+        .mana_cost(ManaCost::build(&[ManaType::Black], 1))
+        .power_toughness(2, 1)
+        .keyword(KeywordAbility::Wither)
+        .build()
+}
+
+/// Bilious Skulldweller — {B}
+/// Creature — Phyrexian Insect
+/// 1/1 Deathtouch, Toxic 1
+pub fn bilious_skulldweller() -> Arc<CardData> {
+    CardDataBuilder::new("Bilious Skulldweller")
+        .card_type(CardType::Creature)
+        .color(Color::Black)
+        .mana_cost(ManaCost::build(&[ManaType::Black], 0))
+        .power_toughness(1, 1)
+        .keyword(KeywordAbility::Deathtouch)
+        .keyword(KeywordAbility::Toxic(1))
+        .build()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -297,4 +340,23 @@ mod tests {
         assert!(card.keywords.contains(&KeywordAbility::Deathtouch));
         assert_eq!(card.mana_cost.as_ref().unwrap().mana_value(), 3);
     }
+
+    #[test]
+    fn test_glistener_elf() {
+        let card = glistener_elf();
+        assert_eq!(card.name, "Glistener Elf");
+        assert_eq!(card.power, Some(1));
+        assert_eq!(card.toughness, Some(1));
+        assert!(card.keywords.contains(&KeywordAbility::Infect));
+        assert_eq!(card.mana_cost.as_ref().unwrap().mana_value(), 1);
+    }
+
+    #[test]
+    fn test_bilious_skulldweller() {
+        let card = bilious_skulldweller();
+        assert_eq!(card.name, "Bilious Skulldweller");
+        assert!(card.keywords.contains(&KeywordAbility::Deathtouch));
+        assert!(card.keywords.contains(&KeywordAbility::Toxic(1)));
+        assert_eq!(card.mana_cost.as_ref().unwrap().mana_value(), 1);
+    }
 }
@@ -0,0 +1,60 @@
+//! Cards that refer back to an object after it's left the battlefield, and
+//! so read its last known information (rule 608.2h): "its power", "its
+//! controller", and a dies trigger that looks back in time (rule 603.10a).
+//!
+//! Card origins:
+//! - Swords to Plowshares — Alpha (1993), {W} instant
+//! - Perilous Myr — New Phyrexia (2011), {2} 1/1 artifact creature
+
+use std::sync::Arc;
+
+use crate::objects::card_data::{AbilityDef, AbilityType, CardData, CardDataBuilder, TriggerEvent};
+use crate::types::card_types::{CardType, CreatureType, Subtype};
+use crate::types::colors::Color;
+use crate::types::effects::*;
+use crate::types::ids::new_ability_id;
+use crate::types::mana::{ManaCost, ManaType};
+
+/// Swords to Plowshares — {W}
+/// Instant
+/// Exile target creature. Its controller gains life equal to its power.
+pub fn swords_to_plowshares() -> Arc<CardData> {
+    CardDataBuilder::new("Swords to Plowshares")
+        .card_type(CardType::Instant)
+        .color(Color::White)
+        .mana_cost(ManaCost::build(&[ManaType::White], 0))
+        .ability(AbilityDef {
+            id: new_ability_id(),
+            ability_type: AbilityType::Spell,
+            costs: Vec::new(),
+            effect: Effect::Sequence(vec![
+                Effect::Atom(
+                    Primitive::Exile,
+                    EffectRecipient::Target(SelectionFilter::Creature, TargetCount::Exactly(1)),
+                ),
+                Effect::Atom(
+                    Primitive::GainLife(AmountExpr::TargetPower),
+                    EffectRecipient::TargetController,
+                ),
+            ]),
+        })
+        .build()
+}
+
+/// Perilous Myr — {2}
+/// Artifact Creature — Myr
+/// 1/1
+/// When Perilous Myr dies, it deals 2 damage to any target.
+pub fn perilous_myr() -> Arc<CardData> {
+    CardDataBuilder::new("Perilous Myr")
+        .card_type(CardType::Artifact)
+        .card_type(CardType::Creature)
+        .subtype(Subtype::Creature(CreatureType::Myr))
+        .mana_cost(ManaCost::build(&[], 2))
+        .power_toughness(1, 1)
+        .triggered_on(TriggerEvent::Dies, Effect::Atom(
+            Primitive::DealDamage(AmountExpr::Fixed(2)),
+            EffectRecipient::Target(SelectionFilter::Any, TargetCount::Exactly(1)),
+        ))
+        .build()
+}
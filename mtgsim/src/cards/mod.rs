@@ -3,6 +3,23 @@ pub mod basic_lands;
 pub mod alpha;
 pub mod creatures;
 pub mod keyword_creatures;
+pub mod planeswalkers;
+pub mod battles;
+pub mod equipment;
+pub mod regeneration;
+pub mod phasing;
+pub mod double_faced;
+pub mod multipart;
+pub mod face_down;
+pub mod sagas;
+pub mod command_zone;
+pub mod last_known;
+pub mod copies;
+pub mod redirection;
+pub mod free_spells;
+pub mod cost_payment;
+pub mod pregame;
+pub mod turn_structure;
 pub mod phase5_pre_cards;
 pub mod phase_lc_cards;
 pub mod phase_ld_cards;
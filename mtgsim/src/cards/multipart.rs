@@ -0,0 +1,176 @@
+//! Split card (rule 709) and adventurer card (rule 715) definitions.
+//!
+//! Each factory builds the second half first and hands it to the first
+//! half's builder with `split_with`, `fuse_with`, `aftermath` or
+//! `adventure`. The registry knows a split card by both halves' names and
+//! an adventurer by its creature's name.
+//!
+//! Card origins:
+//! - Dead // Gone — Planar Chaos (2007), split instants
+//! - Wear // Tear — Dragon's Maze (2013), fuse
+//! - Onward // Victory — Amonkhet (2017), aftermath
+//! - Bonecrusher Giant // Stomp — Throne of Eldraine (2019), adventurer
+
+use std::sync::Arc;
+
+use crate::objects::card_data::{AbilityDef, AbilityType, CardData, CardDataBuilder};
+use crate::types::card_types::{CardType, CreatureType, SpellType, Subtype};
+use crate::types::colors::Color;
+use crate::types::effects::*;
+use crate::types::ids::new_ability_id;
+use crate::types::keywords::KeywordAbility;
+use crate::types::mana::{ManaCost, ManaType};
+
+/// A spell ability that does `effect`.
+fn spell(effect: Effect) -> AbilityDef {
+    AbilityDef {
+        id: new_ability_id(),
+        ability_type: AbilityType::Spell,
+        costs: Vec::new(),
+        effect,
+    }
+}
+
+/// "target [filter]" — exactly one.
+fn target(filter: SelectionFilter) -> EffectRecipient {
+    EffectRecipient::Target(filter, TargetCount::Exactly(1))
+}
+
+/// Dead — {R}
+/// Instant
+/// Dead deals 2 damage to target creature.
+///
+/// Gone — {2}{R}
+/// Instant
+/// Return target creature you don't control to its owner's hand.
+pub fn dead_gone() -> Arc<CardData> {
+    let gone = CardDataBuilder::new("Gone")
+        .card_type(CardType::Instant)
+        .color(Color::Red)
+        .mana_cost(ManaCost::build(&[ManaType::Red], 2))
+        .ability(spell(Effect::Atom(
+            Primitive::ReturnToHand,
+            target(SelectionFilter::Permanent(PermanentFilter::And(
+                Box::new(PermanentFilter::ByType(CardType::Creature)),
+                Box::new(PermanentFilter::Not(Box::new(PermanentFilter::ByController(PlayerRef::You)))),
+            ))),
+        )))
+        .build();
+
+    CardDataBuilder::new("Dead")
+        .card_type(CardType::Instant)
+        .color(Color::Red)
+        .mana_cost(ManaCost::build(&[ManaType::Red], 0))
+        .ability(spell(Effect::Atom(
+            Primitive::DealDamage(AmountExpr::Fixed(2)),
+            target(SelectionFilter::Creature),
+        )))
+        .split_with(gone)
+        .build()
+}
+
+/// Wear — {1}{R}
+/// Instant
+/// Destroy target artifact.
+///
+/// Tear — {W}
+/// Instant
+/// Destroy target enchantment.
+///
+/// Fuse
+pub fn wear_tear() -> Arc<CardData> {
+    let tear = CardDataBuilder::new("Tear")
+        .card_type(CardType::Instant)
+        .color(Color::White)
+        .mana_cost(ManaCost::build(&[ManaType::White], 0))
+        .ability(spell(Effect::Atom(
+            Primitive::Destroy,
+            target(SelectionFilter::Permanent(PermanentFilter::ByType(CardType::Enchantment))),
+        )))
+        .build();
+
+    CardDataBuilder::new("Wear")
+        .card_type(CardType::Instant)
+        .color(Color::Red)
+        .mana_cost(ManaCost::build(&[ManaType::Red], 1))
+        .ability(spell(Effect::Atom(
+            Primitive::Destroy,
+            target(SelectionFilter::Permanent(PermanentFilter::ByType(CardType::Artifact))),
+        )))
+        .fuse_with(tear)
+        .build()
+}
+
+/// Onward — {2}{R}
+/// Sorcery
+/// Target creature gets +X/+0 until end of turn, where X is its power. It
+/// gains first strike until end of turn.
+///
+/// Victory — {2}{W}
+/// Sorcery
+/// Aftermath
+/// Target creature gains double strike until end of turn.
+pub fn onward_victory() -> Arc<CardData> {
+    let victory = CardDataBuilder::new("Victory")
+        .card_type(CardType::Sorcery)
+        .color(Color::White)
+        .mana_cost(ManaCost::build(&[ManaType::White], 2))
+        .ability(spell(Effect::Atom(
+            Primitive::GrantKeyword(KeywordAbility::DoubleStrike, Duration::UntilEndOfTurn),
+            target(SelectionFilter::Creature),
+        )))
+        .build();
+
+    CardDataBuilder::new("Onward")
+        .card_type(CardType::Sorcery)
+        .color(Color::Red)
+        .mana_cost(ManaCost::build(&[ManaType::Red], 2))
+        .ability(spell(Effect::Sequence(vec![
+            Effect::Atom(
+                Primitive::ModifyPowerToughness(
+                    AmountExpr::TargetPower, AmountExpr::Fixed(0), Duration::UntilEndOfTurn,
+                ),
+                target(SelectionFilter::Creature),
+            ),
+            Effect::Atom(
+                Primitive::GrantKeyword(KeywordAbility::FirstStrike, Duration::UntilEndOfTurn),
+                target(SelectionFilter::Creature),
+            ),
+        ])))
+        .aftermath(victory)
+        .build()
+}
+
+/// Bonecrusher Giant — {2}{R}
+/// Creature — Giant
+/// 4/3
+/// Whenever Bonecrusher Giant becomes the target of a spell, Bonecrusher
+/// Giant deals 2 damage to that spell's controller. (omitted — needs
+/// becomes-the-target triggers)
+///
+/// Stomp — {1}{R}
+/// Instant — Adventure
+/// Damage can't be prevented this turn. (omitted — needs prevention
+/// effects)
+/// Stomp deals 2 damage to any target.
+pub fn bonecrusher_giant() -> Arc<CardData> {
+    let stomp = CardDataBuilder::new("Stomp")
+        .card_type(CardType::Instant)
+        .subtype(Subtype::Spell(SpellType::Adventure))
+        .color(Color::Red)
+        .mana_cost(ManaCost::build(&[ManaType::Red], 1))
+        .ability(spell(Effect::Atom(
+            Primitive::DealDamage(AmountExpr::Fixed(2)),
+            target(SelectionFilter::Any),
+        )))
+        .build();
+
+    CardDataBuilder::new("Bonecrusher Giant")
+        .card_type(CardType::Creature)
+        .subtype(Subtype::Creature(CreatureType::Giant))
+        .color(Color::Red)
+        .mana_cost(ManaCost::build(&[ManaType::Red], 2))
+        .power_toughness(4, 3)
+        .adventure(stomp)
+        .build()
+}
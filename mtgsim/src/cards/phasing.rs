@@ -0,0 +1,55 @@
+//! Phasing card definitions (rule 702.26).
+//!
+//! Card origins:
+//! - Breezekeeper — Mirage (1996), {3}{U} 4/4 Djinn with phasing
+//! - Teferi's Protection — Commander 2017, {2}{W} instant
+
+use std::sync::Arc;
+
+use crate::objects::card_data::{AbilityDef, AbilityType, CardData, CardDataBuilder};
+use crate::types::card_types::{CardType, CreatureType, Subtype};
+use crate::types::colors::Color;
+use crate::types::effects::*;
+use crate::types::ids::new_ability_id;
+use crate::types::keywords::KeywordAbility;
+use crate::types::mana::{ManaCost, ManaType};
+
+/// Breezekeeper — {3}{U}
+/// Creature — Djinn
+/// 4/4
+/// Phasing
+pub fn breezekeeper() -> Arc<CardData> {
+    CardDataBuilder::new("Breezekeeper")
+        .card_type(CardType::Creature)
+        .subtype(Subtype::Creature(CreatureType::Djinn))
+        .color(Color::Blue)
+        .mana_cost(ManaCost::build(&[ManaType::Blue], 3))
+        .power_toughness(4, 4)
+        .keyword(KeywordAbility::Phasing)
+        .build()
+}
+
+/// Teferi's Protection — {2}{W}
+/// Instant
+/// Until your next turn, your life total can't change and you gain
+/// protection from everything. (omitted — needs replacement effects and
+/// player protection)
+/// All permanents you control phase out. (They phase in before you untap
+/// during your next untap step.)
+/// Exile Teferi's Protection. (omitted — the spell goes to the graveyard)
+pub fn teferis_protection() -> Arc<CardData> {
+    CardDataBuilder::new("Teferi's Protection")
+        .card_type(CardType::Instant)
+        .color(Color::White)
+        .mana_cost(ManaCost::build(&[ManaType::White], 2))
+        .ability(AbilityDef {
+            id: new_ability_id(),
+            ability_type: AbilityType::Spell,
+            costs: Vec::new(),
+            effect: Effect::Atom(
+                Primitive::PhaseOut,
+                EffectRecipient::FilteredPermanents(PermanentFilter::ByController(PlayerRef::You)),
+            ),
+        })
+        .build()
+}
@@ -0,0 +1,113 @@
+//! Planeswalker card definitions.
+//!
+//! Loyalty abilities are built with `CardDataBuilder::loyalty_ability`, which
+//! encodes the printed +N / −N as a `Cost::AddCounters` / `RemoveCounters`
+//! on loyalty counters (rule 606.4). Abilities that need engine features we
+//! don't have yet (tokens, keyword grants) are omitted and noted on
+//! each card.
+
+use std::sync::Arc;
+
+use crate::objects::card_data::{AbilityDef, AbilityType, CardData, CardDataBuilder, TriggerEvent};
+use crate::types::card_types::{CardType, PlaneswalkerType, Subtype, Supertype};
+use crate::types::colors::Color;
+use crate::types::effects::{
+    AmountExpr, Effect, EffectRecipient, ManaOutput, PermanentFilter, Primitive, SelectionFilter,
+    TargetCount,
+};
+use crate::types::ids::new_ability_id;
+use crate::types::mana::{ManaCost, ManaType};
+
+/// Ob Nixilis Reignited — {3}{B}{B}
+/// Legendary Planeswalker — Nixilis
+/// Loyalty 5
+/// +1: You draw a card and you lose 1 life.
+/// −3: Destroy target creature.
+/// −8: (omitted — emblem)
+pub fn ob_nixilis_reignited() -> Arc<CardData> {
+    CardDataBuilder::new("Ob Nixilis Reignited")
+        .mana_cost(ManaCost::build(&[ManaType::Black, ManaType::Black], 3))
+        .color(Color::Black)
+        .supertype(Supertype::Legendary)
+        .card_type(CardType::Planeswalker)
+        .subtype(Subtype::Planeswalker(PlaneswalkerType::Nixilis))
+        .loyalty(5)
+        .loyalty_ability(1, Effect::Sequence(vec![
+            Effect::Atom(Primitive::DrawCards(AmountExpr::Fixed(1)), EffectRecipient::Controller),
+            Effect::Atom(Primitive::LoseLife(AmountExpr::Fixed(1)), EffectRecipient::Controller),
+        ]))
+        .loyalty_ability(-3, Effect::Atom(
+            Primitive::Destroy,
+            EffectRecipient::Target(SelectionFilter::Creature, TargetCount::Exactly(1)),
+        ))
+        .build()
+}
+
+/// Chandra, Torch of Defiance — {2}{R}{R}
+/// Legendary Planeswalker — Chandra
+/// Loyalty 4
+/// +1: (omitted — exile top card, may cast it)
+/// +1: Add {R}{R}.
+/// −3: Chandra deals 4 damage to target creature.
+/// −7: You get an emblem with "Whenever you cast a spell, this emblem deals
+/// 5 damage to any target."
+pub fn chandra_torch_of_defiance() -> Arc<CardData> {
+    CardDataBuilder::new("Chandra, Torch of Defiance")
+        .mana_cost(ManaCost::build(&[ManaType::Red, ManaType::Red], 2))
+        .color(Color::Red)
+        .supertype(Supertype::Legendary)
+        .card_type(CardType::Planeswalker)
+        .subtype(Subtype::Planeswalker(PlaneswalkerType::Chandra))
+        .loyalty(4)
+        .loyalty_ability(1, Effect::Atom(
+            Primitive::ProduceMana(ManaOutput {
+                mana: vec![(ManaType::Red, AmountExpr::Fixed(2))],
+                special: vec![],
+            }),
+            EffectRecipient::Controller,
+        ))
+        .loyalty_ability(-3, Effect::Atom(
+            Primitive::DealDamage(AmountExpr::Fixed(4)),
+            EffectRecipient::Target(SelectionFilter::Creature, TargetCount::Exactly(1)),
+        ))
+        .loyalty_ability(-7, Effect::Atom(
+            Primitive::CreateEmblem(vec![AbilityDef {
+                id: new_ability_id(),
+                ability_type: AbilityType::TriggeredOn(TriggerEvent::YouCastSpell),
+                costs: Vec::new(),
+                effect: Effect::Atom(
+                    Primitive::DealDamage(AmountExpr::Fixed(5)),
+                    EffectRecipient::Target(SelectionFilter::Any, TargetCount::Exactly(1)),
+                ),
+            }]),
+            EffectRecipient::Controller,
+        ))
+        .build()
+}
+
+/// Garruk Wildspeaker — {2}{G}{G}
+/// Legendary Planeswalker — Garruk
+/// Loyalty 3
+/// +1: Untap two target lands.
+/// −1: (omitted — 3/3 Beast token)
+/// −4: (omitted — Overrun)
+///
+/// "Two target lands" is modelled as up to two so the ability stays usable
+/// with a single land out.
+pub fn garruk_wildspeaker() -> Arc<CardData> {
+    CardDataBuilder::new("Garruk Wildspeaker")
+        .mana_cost(ManaCost::build(&[ManaType::Green, ManaType::Green], 2))
+        .color(Color::Green)
+        .supertype(Supertype::Legendary)
+        .card_type(CardType::Planeswalker)
+        .subtype(Subtype::Planeswalker(PlaneswalkerType::Garruk))
+        .loyalty(3)
+        .loyalty_ability(1, Effect::Atom(
+            Primitive::Untap,
+            EffectRecipient::Target(
+                SelectionFilter::Permanent(PermanentFilter::ByType(CardType::Land)),
+                TargetCount::UpTo(2),
+            ),
+        ))
+        .build()
+}
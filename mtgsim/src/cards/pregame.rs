@@ -0,0 +1,45 @@
+//! Cards with opening-hand actions (rule 103.6).
+//!
+//! Card origins:
+//! - Leyline of Vitality — Magic 2011 (2010), {2}{G}{G} enchantment
+
+use std::sync::Arc;
+
+use crate::objects::card_data::{AbilityDef, AbilityType, CardData, CardDataBuilder, OpeningHandAction};
+use crate::types::card_types::CardType;
+use crate::types::colors::Color;
+use crate::types::effects::*;
+use crate::types::ids::new_ability_id;
+use crate::types::mana::{ManaCost, ManaType};
+
+/// Leyline of Vitality — {2}{G}{G}
+/// Enchantment
+/// If Leyline of Vitality is in your opening hand, you may begin the game
+/// with it on the battlefield.
+/// Creatures you control get +0/+1.
+/// Whenever a creature you control enters, you may gain 1 life.
+/// (omitted — triggered ability)
+pub fn leyline_of_vitality() -> Arc<CardData> {
+    CardDataBuilder::new("Leyline of Vitality")
+        .mana_cost(ManaCost::build(&[ManaType::Green, ManaType::Green], 2))
+        .color(Color::Green)
+        .card_type(CardType::Enchantment)
+        .opening_hand_action(OpeningHandAction::BeginOnBattlefield)
+        .ability(AbilityDef {
+            id: new_ability_id(),
+            ability_type: AbilityType::Static,
+            costs: Vec::new(),
+            effect: Effect::Atom(
+                Primitive::ModifyPowerToughness(
+                    AmountExpr::Fixed(0),
+                    AmountExpr::Fixed(1),
+                    Duration::WhileSourceOnBattlefield,
+                ),
+                EffectRecipient::FilteredPermanents(PermanentFilter::And(
+                    Box::new(PermanentFilter::ByType(CardType::Creature)),
+                    Box::new(PermanentFilter::ByController(PlayerRef::You)),
+                )),
+            ),
+        })
+        .build()
+}
@@ -0,0 +1,103 @@
+//! Cards that change the targets of spells and abilities (rule 115.7).
+//!
+//! Card origins:
+//! - Deflection — Ice Age (1995), {3}{U} instant
+//! - Misdirection — Mercadian Masques (2000), {3}{U}{U} instant
+//! - Spellskite — New Phyrexia (2011), {2} 0/4 artifact creature
+//! - Second Guess — made-up, {U} instant that lets you choose new targets
+
+use std::sync::Arc;
+
+use crate::objects::card_data::{AbilityDef, AbilityType, CardData, CardDataBuilder};
+use crate::types::card_types::{CardType, CreatureType, Subtype};
+use crate::types::colors::Color;
+use crate::types::costs::Cost;
+use crate::types::effects::*;
+use crate::types::ids::new_ability_id;
+use crate::types::mana::{ManaCost, ManaType};
+
+/// Deflection — {3}{U}
+/// Instant
+/// Change the target of target spell with a single target.
+pub fn deflection() -> Arc<CardData> {
+    CardDataBuilder::new("Deflection")
+        .card_type(CardType::Instant)
+        .color(Color::Blue)
+        .mana_cost(ManaCost::build(&[ManaType::Blue], 3))
+        .ability(change_target_of_single_target_spell())
+        .build()
+}
+
+/// Misdirection — {3}{U}{U}
+/// Instant
+/// You may exile a blue card from your hand rather than pay this spell's
+/// mana cost.
+/// Change the target of target spell with a single target.
+///
+/// Only the mana cost is modelled: exiling a card from hand isn't a cost
+/// the engine can pay yet.
+pub fn misdirection() -> Arc<CardData> {
+    CardDataBuilder::new("Misdirection")
+        .card_type(CardType::Instant)
+        .color(Color::Blue)
+        .mana_cost(ManaCost::build(&[ManaType::Blue, ManaType::Blue], 3))
+        .ability(change_target_of_single_target_spell())
+        .build()
+}
+
+/// Spellskite — {2}
+/// Artifact Creature — Horror
+/// 0/4
+/// {U/P}: Change the target of target spell or ability to Spellskite.
+///
+/// The ability costs {U}: Phyrexian mana can't be paid with life yet.
+pub fn spellskite() -> Arc<CardData> {
+    CardDataBuilder::new("Spellskite")
+        .card_type(CardType::Artifact)
+        .card_type(CardType::Creature)
+        .subtype(Subtype::Creature(CreatureType::Horror))
+        .mana_cost(ManaCost::build(&[], 2))
+        .power_toughness(0, 4)
+        .ability(AbilityDef {
+            id: new_ability_id(),
+            ability_type: AbilityType::Activated,
+            costs: vec![Cost::Mana(ManaCost::build(&[ManaType::Blue], 0))],
+            effect: Effect::Atom(
+                Primitive::ChangeTargetToSource,
+                EffectRecipient::Target(SelectionFilter::Spell, TargetCount::Exactly(1)),
+            ),
+        })
+        .build()
+}
+
+/// Second Guess (made-up) — {U}
+/// Instant
+/// You may choose new targets for target spell.
+pub fn second_guess() -> Arc<CardData> {
+    CardDataBuilder::new("Second Guess")
+        .card_type(CardType::Instant)
+        .color(Color::Blue)
+        .mana_cost(ManaCost::build(&[ManaType::Blue], 0))
+        .ability(AbilityDef {
+            id: new_ability_id(),
+            ability_type: AbilityType::Spell,
+            costs: Vec::new(),
+            effect: Effect::Atom(
+                Primitive::ChooseNewTargets,
+                EffectRecipient::Target(SelectionFilter::Spell, TargetCount::Exactly(1)),
+            ),
+        })
+        .build()
+}
+
+fn change_target_of_single_target_spell() -> AbilityDef {
+    AbilityDef {
+        id: new_ability_id(),
+        ability_type: AbilityType::Spell,
+        costs: Vec::new(),
+        effect: Effect::Atom(
+            Primitive::ChangeTarget,
+            EffectRecipient::Target(SelectionFilter::SpellWithSingleTarget, TargetCount::Exactly(1)),
+        ),
+    }
+}
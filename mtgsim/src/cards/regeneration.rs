@@ -0,0 +1,85 @@
+//! Regeneration and indestructible card definitions.
+//!
+//! Card origins:
+//! - Drudge Skeletons — Alpha (1993), {1}{B} 1/1, {B}: Regenerate
+//! - Terror — Alpha (1993), {1}{B} instant, destroy; can't be regenerated
+//! - Darksteel Myr — Darksteel (2004), {3} 0/1 artifact creature, indestructible
+
+use std::sync::Arc;
+
+use crate::objects::card_data::{AbilityDef, AbilityType, CardData, CardDataBuilder};
+use crate::types::card_types::{CardType, CreatureType, Subtype};
+use crate::types::colors::Color;
+use crate::types::costs::Cost;
+use crate::types::effects::*;
+use crate::types::ids::new_ability_id;
+use crate::types::keywords::KeywordAbility;
+use crate::types::mana::{ManaCost, ManaType};
+
+/// Drudge Skeletons — {1}{B}
+/// Creature — Skeleton
+/// 1/1
+/// {B}: Regenerate Drudge Skeletons.
+pub fn drudge_skeletons() -> Arc<CardData> {
+    CardDataBuilder::new("Drudge Skeletons")
+        .card_type(CardType::Creature)
+        .subtype(Subtype::Creature(CreatureType::Skeleton))
+        .color(Color::Black)
+        .mana_cost(ManaCost::build(&[ManaType::Black], 1))
+        .power_toughness(1, 1)
+        .ability(AbilityDef {
+            id: new_ability_id(),
+            ability_type: AbilityType::Activated,
+            costs: vec![Cost::Mana(ManaCost::build(&[ManaType::Black], 0))],
+            effect: Effect::Atom(Primitive::Regenerate, EffectRecipient::Implicit),
+        })
+        .build()
+}
+
+/// Terror — {1}{B}
+/// Instant
+/// Destroy target nonartifact, nonblack creature. It can't be regenerated.
+pub fn terror() -> Arc<CardData> {
+    CardDataBuilder::new("Terror")
+        .card_type(CardType::Instant)
+        .color(Color::Black)
+        .mana_cost(ManaCost::build(&[ManaType::Black], 1))
+        .ability(AbilityDef {
+            id: new_ability_id(),
+            ability_type: AbilityType::Spell,
+            costs: Vec::new(),
+            effect: Effect::Atom(
+                Primitive::DestroyNoRegeneration,
+                EffectRecipient::Target(
+                    SelectionFilter::Permanent(PermanentFilter::And(
+                        Box::new(PermanentFilter::ByType(CardType::Creature)),
+                        Box::new(PermanentFilter::And(
+                            Box::new(PermanentFilter::Not(Box::new(
+                                PermanentFilter::ByType(CardType::Artifact),
+                            ))),
+                            Box::new(PermanentFilter::Not(Box::new(
+                                PermanentFilter::ByColor(Color::Black),
+                            ))),
+                        )),
+                    )),
+                    TargetCount::Exactly(1),
+                ),
+            ),
+        })
+        .build()
+}
+
+/// Darksteel Myr — {3}
+/// Artifact Creature — Myr
+/// 0/1
+/// Indestructible
+pub fn darksteel_myr() -> Arc<CardData> {
+    CardDataBuilder::new("Darksteel Myr")
+        .card_type(CardType::Artifact)
+        .card_type(CardType::Creature)
+        .subtype(Subtype::Creature(CreatureType::Myr))
+        .mana_cost(ManaCost::build(&[], 3))
+        .power_toughness(0, 1)
+        .keyword(KeywordAbility::Indestructible)
+        .build()
+}
@@ -4,10 +4,27 @@ use std::sync::Arc;
 use crate::objects::card_data::CardData;
 
 use super::basic_lands;
+use super::battles;
 use super::alpha;
 use super::creatures;
+use super::double_faced;
+use super::equipment;
+use super::face_down;
+use super::free_spells;
+use super::sagas;
+use super::command_zone;
+use super::copies;
+use super::cost_payment;
 use super::keyword_creatures;
+use super::last_known;
+use super::multipart;
 use super::phase5_pre_cards;
+use super::phasing;
+use super::planeswalkers;
+use super::pregame;
+use super::redirection;
+use super::regeneration;
+use super::turn_structure;
 
 /// Card registry: maps card names to factory functions that produce CardData.
 ///
@@ -68,6 +85,7 @@ impl CardRegistry {
         registry.register("Hill Giant", creatures::hill_giant);
         registry.register("Savannah Lions", creatures::savannah_lions);
         registry.register("Earth Elemental", creatures::earth_elemental);
+        registry.register("Llanowar Elves", creatures::llanowar_elves);
 
         // Keyword creatures (Phase 4)
         registry.register("Serra Angel", keyword_creatures::serra_angel);
@@ -81,6 +99,9 @@ impl CardRegistry {
         registry.register("Rhox War Monk", keyword_creatures::rhox_war_monk);
         registry.register("Giant Spider", keyword_creatures::giant_spider);
         registry.register("Vampire Nighthawk", keyword_creatures::vampire_nighthawk);
+        registry.register("Glistener Elf", keyword_creatures::glistener_elf);
+        registry.register("Sickle Ripper", keyword_creatures::sickle_ripper);
+        registry.register("Bilious Skulldweller", keyword_creatures::bilious_skulldweller);
 
         // Phase 5 pre cards
         registry.register("Isamaru, Hound of Konda", phase5_pre_cards::isamaru_hound_of_konda);
@@ -90,6 +111,99 @@ impl CardRegistry {
         registry.register("Dark Ritual", phase5_pre_cards::dark_ritual);
         registry.register("Glorious Anthem", phase5_pre_cards::glorious_anthem);
 
+        // Planeswalkers
+        registry.register("Ob Nixilis Reignited", planeswalkers::ob_nixilis_reignited);
+        registry.register("Chandra, Torch of Defiance", planeswalkers::chandra_torch_of_defiance);
+        registry.register("Garruk Wildspeaker", planeswalkers::garruk_wildspeaker);
+
+        // Battles
+        registry.register("Invasion of Regatha", battles::invasion_of_regatha);
+        registry.register("Invasion of Zendikar", battles::invasion_of_zendikar);
+        registry.register("Invasion of Emberfall", battles::invasion_of_emberfall);
+
+        // Equipment
+        registry.register("Bonesplitter", equipment::bonesplitter);
+        registry.register("Loxodon Warhammer", equipment::loxodon_warhammer);
+        registry.register("Sword of Fire and Ice", equipment::sword_of_fire_and_ice);
+        registry.register("Sword of Feast and Famine", equipment::sword_of_feast_and_famine);
+        registry.register("Lizard Blades", equipment::lizard_blades);
+
+        // Regeneration and indestructible
+        registry.register("Drudge Skeletons", regeneration::drudge_skeletons);
+        registry.register("Terror", regeneration::terror);
+        registry.register("Darksteel Myr", regeneration::darksteel_myr);
+
+        // Phasing (rule 702.26)
+        registry.register("Breezekeeper", phasing::breezekeeper);
+        registry.register("Teferi's Protection", phasing::teferis_protection);
+
+        // Double-faced cards (rule 712), registered by front face
+        registry.register("Delver of Secrets", double_faced::delver_of_secrets);
+        registry.register("Moonmist", double_faced::moonmist);
+        registry.register("Outland Liberator", double_faced::outland_liberator);
+        registry.register("Spikefield Hazard", double_faced::spikefield_hazard);
+        registry.register("Brightclimb Pathway", double_faced::brightclimb_pathway);
+        registry.register("Halvar, God of Battle", double_faced::halvar_god_of_battle);
+
+        // Split cards (rule 709) and adventurers (rule 715)
+        registry.register("Dead // Gone", multipart::dead_gone);
+        registry.register("Wear // Tear", multipart::wear_tear);
+        registry.register("Onward // Victory", multipart::onward_victory);
+        registry.register("Bonecrusher Giant", multipart::bonecrusher_giant);
+
+        // Face-down permanents (rule 708)
+        registry.register("Exalted Angel", face_down::exalted_angel);
+        registry.register("Den Protector", face_down::den_protector);
+        registry.register("Masked Vagrant", face_down::masked_vagrant);
+        registry.register("Soul Summons", face_down::soul_summons);
+        registry.register("Shrouded Secret", face_down::shrouded_secret);
+
+        // Sagas (rule 714)
+        registry.register("Chronicle of Embers", sagas::chronicle_of_embers);
+
+        // Monarch, initiative and dungeons (rules 701.49, 724, 725)
+        registry.register("Claim the Throne", command_zone::claim_the_throne);
+        registry.register("Seize the Initiative", command_zone::seize_the_initiative);
+        registry.register("Delve the Depths", command_zone::delve_the_depths);
+
+        // Last known information (rules 603.10a, 608.2h)
+        registry.register("Swords to Plowshares", last_known::swords_to_plowshares);
+        registry.register("Perilous Myr", last_known::perilous_myr);
+
+        // Copying spells (rule 707.10)
+        registry.register("Twincast", copies::twincast);
+        registry.register("Grapeshot", copies::grapeshot);
+        registry.register("Pyromatics", copies::pyromatics);
+        registry.register("Ruthless Volley", copies::ruthless_volley);
+
+        // Changing targets (rule 115.7)
+        registry.register("Deflection", redirection::deflection);
+        registry.register("Misdirection", redirection::misdirection);
+        registry.register("Spellskite", redirection::spellskite);
+        registry.register("Second Guess", redirection::second_guess);
+
+        // Casting without paying the mana cost (rule 118.9)
+        registry.register("Bloodbraid Elf", free_spells::bloodbraid_elf);
+        registry.register("Omniscience", free_spells::omniscience);
+        registry.register("Uncharted Depths", free_spells::uncharted_depths);
+
+        // Paying mana costs without mana, and emerge (rules 702.51, 702.66,
+        // 702.119, 702.126)
+        registry.register("Stoke the Flames", cost_payment::stoke_the_flames);
+        registry.register("Treasure Cruise", cost_payment::treasure_cruise);
+        registry.register("Reverse Engineer", cost_payment::reverse_engineer);
+        registry.register("Wretched Gryff", cost_payment::wretched_gryff);
+        registry.register("Gorging Larva", cost_payment::gorging_larva);
+
+        // Opening-hand actions (rule 103.6)
+        registry.register("Leyline of Vitality", pregame::leyline_of_vitality);
+
+        // Turn structure (rules 500.7, 500.8, 614.10, 723)
+        registry.register("Time Walk", turn_structure::time_walk);
+        registry.register("Relentless Assault", turn_structure::relentless_assault);
+        registry.register("Fatigue", turn_structure::fatigue);
+        registry.register("Time Stop", turn_structure::time_stop);
+
         registry
     }
 }
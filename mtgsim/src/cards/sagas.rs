@@ -0,0 +1,35 @@
+//! Saga card definitions (rule 714).
+//!
+//! Card origins:
+//! - Chronicle of Embers — made-up, {2}{R} three-chapter Saga
+
+use std::sync::Arc;
+
+use crate::objects::card_data::{CardData, CardDataBuilder};
+use crate::types::card_types::{CardType, EnchantmentType, Subtype};
+use crate::types::colors::Color;
+use crate::types::effects::*;
+use crate::types::mana::{ManaCost, ManaType};
+
+/// Chronicle of Embers (made-up) — {2}{R}
+/// Enchantment — Saga
+/// I, II — Chronicle of Embers deals 2 damage to any target.
+/// III — Draw a card.
+pub fn chronicle_of_embers() -> Arc<CardData> {
+    let burn = || Effect::Atom(
+        Primitive::DealDamage(AmountExpr::Fixed(2)),
+        EffectRecipient::Target(SelectionFilter::Any, TargetCount::Exactly(1)),
+    );
+    CardDataBuilder::new("Chronicle of Embers")
+        .card_type(CardType::Enchantment)
+        .subtype(Subtype::Enchantment(EnchantmentType::Saga))
+        .color(Color::Red)
+        .mana_cost(ManaCost::build(&[ManaType::Red], 2))
+        .chapter(1, burn())
+        .chapter(2, burn())
+        .chapter(3, Effect::Atom(
+            Primitive::DrawCards(AmountExpr::Fixed(1)),
+            EffectRecipient::Controller,
+        ))
+        .build()
+}
@@ -0,0 +1,94 @@
+//! Cards that change the turn plan: extra turns (rule 500.7), additional
+//! phases (rule 500.8), skips (rule 614.10) and ending the turn (rule 723).
+//!
+//! Card origins:
+//! - Time Walk — Alpha (1993), {1}{U} sorcery
+//! - Relentless Assault — Visions (1997), {2}{R}{R} sorcery
+//! - Fatigue — Stronghold (1998), {1}{U} sorcery
+//! - Time Stop — Champions of Kamigawa (2004), {4}{U}{U} instant
+
+use std::sync::Arc;
+
+use crate::objects::card_data::{AbilityDef, AbilityType, CardData, CardDataBuilder};
+use crate::types::card_types::CardType;
+use crate::types::colors::Color;
+use crate::types::effects::*;
+use crate::types::ids::new_ability_id;
+use crate::types::mana::{ManaCost, ManaType};
+
+/// Time Walk — {1}{U}
+/// Sorcery
+/// Take an extra turn after this one.
+pub fn time_walk() -> Arc<CardData> {
+    CardDataBuilder::new("Time Walk")
+        .card_type(CardType::Sorcery)
+        .color(Color::Blue)
+        .mana_cost(ManaCost::build(&[ManaType::Blue], 1))
+        .ability(AbilityDef {
+            id: new_ability_id(),
+            ability_type: AbilityType::Spell,
+            costs: Vec::new(),
+            effect: Effect::Atom(Primitive::ExtraTurn, EffectRecipient::Controller),
+        })
+        .build()
+}
+
+/// Relentless Assault — {2}{R}{R}
+/// Sorcery
+/// Untap all creatures that attacked this turn. (omitted — attackers
+/// aren't remembered after combat)
+/// After this main phase, there is an additional combat phase followed by
+/// an additional main phase.
+pub fn relentless_assault() -> Arc<CardData> {
+    CardDataBuilder::new("Relentless Assault")
+        .card_type(CardType::Sorcery)
+        .color(Color::Red)
+        .mana_cost(ManaCost::build(&[ManaType::Red, ManaType::Red], 2))
+        .ability(AbilityDef {
+            id: new_ability_id(),
+            ability_type: AbilityType::Spell,
+            costs: Vec::new(),
+            effect: Effect::Atom(
+                Primitive::AdditionalCombatPhase { followed_by_main: true },
+                EffectRecipient::Implicit,
+            ),
+        })
+        .build()
+}
+
+/// Fatigue — {1}{U}
+/// Sorcery
+/// Target player skips their next draw step.
+pub fn fatigue() -> Arc<CardData> {
+    CardDataBuilder::new("Fatigue")
+        .card_type(CardType::Sorcery)
+        .color(Color::Blue)
+        .mana_cost(ManaCost::build(&[ManaType::Blue], 1))
+        .ability(AbilityDef {
+            id: new_ability_id(),
+            ability_type: AbilityType::Spell,
+            costs: Vec::new(),
+            effect: Effect::Atom(
+                Primitive::Skip(SkipTarget::DrawStep),
+                EffectRecipient::Target(SelectionFilter::Player, TargetCount::Exactly(1)),
+            ),
+        })
+        .build()
+}
+
+/// Time Stop — {4}{U}{U}
+/// Instant
+/// End the turn.
+pub fn time_stop() -> Arc<CardData> {
+    CardDataBuilder::new("Time Stop")
+        .card_type(CardType::Instant)
+        .color(Color::Blue)
+        .mana_cost(ManaCost::build(&[ManaType::Blue, ManaType::Blue], 4))
+        .ability(AbilityDef {
+            id: new_ability_id(),
+            ability_type: AbilityType::Spell,
+            costs: Vec::new(),
+            effect: Effect::Atom(Primitive::EndTheTurn, EffectRecipient::Implicit),
+        })
+        .build()
+}
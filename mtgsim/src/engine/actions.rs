@@ -1,7 +1,11 @@
-use crate::engine::keywords::{apply_deathtouch_flag, apply_lifelink};
+use crate::engine::keywords::{
+    apply_damage_results, apply_deathtouch_flag, apply_lifelink, apply_toxic,
+};
 use crate::events::event::{DamageTarget, GameEvent};
+use crate::oracle::characteristics::{has_keyword, is_creature};
 use crate::state::game_state::GameState;
 use crate::types::ids::{ObjectId, PlayerId};
+use crate::types::keywords::KeywordAbility;
 use crate::types::zones::Zone;
 
 /// A game action that is *about to happen*.
@@ -61,6 +65,31 @@ pub enum GameAction {
         object: ObjectId,
     },
 
+    /// Attach a permanent to another permanent (rule 701.3a).
+    Attach {
+        attachment: ObjectId,
+        host: ObjectId,
+    },
+
+    /// Unattach a permanent from whatever it's attached to (rule 701.3d).
+    Unattach {
+        attachment: ObjectId,
+    },
+
+    /// Destroy a permanent (rule 701.8a). Does nothing to an indestructible
+    /// permanent (rule 702.12b). A regeneration shield replaces the
+    /// destruction unless `can_regenerate` is false — "it can't be
+    /// regenerated" (rule 701.19c).
+    Destroy {
+        object: ObjectId,
+        can_regenerate: bool,
+    },
+
+    /// Give a permanent a regeneration shield (rule 701.19a).
+    Regenerate {
+        object: ObjectId,
+    },
+
     // === Phase 3+ actions — add variants here as primitives are implemented ===
     // Sacrifice { object: ObjectId },
     // Exile { object: ObjectId },
@@ -97,9 +126,12 @@ impl GameState {
     /// `draw_card` and `play_land` still call `move_object` directly — they
     /// live inside `engine/zones.rs` and go through the same chokepoint
     /// transitively via `execute_action`'s ZoneChange arm.
-    pub fn change_zone(&mut self, object: ObjectId, to: Zone) -> Result<(), String> {
+    ///
+    /// Returns the new object it became (rule 400.7).
+    pub fn change_zone(&mut self, object: ObjectId, to: Zone) -> Result<ObjectId, String> {
         let from = self.get_object(object)?.zone;
-        self.execute_action(GameAction::ZoneChange { object, from, to })
+        self.execute_action(GameAction::ZoneChange { object, from, to })?;
+        Ok(self.current_identity(object))
     }
 
     /// Perform the actual state mutation and emit the event.
@@ -114,24 +146,12 @@ impl GameState {
                     return Ok(());
                 }
 
-                match &target {
-                    DamageTarget::Object(id) => {
-                        if let Some(entry) = self.battlefield.get_mut(id) {
-                            entry.damage_marked += amount as u32;
-                        } else {
-                            return Err(format!(
-                                "Target object {} not on battlefield", id
-                            ));
-                        }
-                    }
-                    DamageTarget::Player(pid) => {
-                        let player = self.get_player_mut(*pid)?;
-                        player.life_total -= amount as i64;
-                    }
-                }
+                // Rule 120.3: life loss, poison, counters or marked damage,
+                // depending on the recipient and the source's keywords.
+                let result_event = apply_damage_results(self, source, &target, amount)?;
 
                 // Keyword hooks (delegated to engine/keywords.rs)
-                apply_deathtouch_flag(self, source, &target);
+                apply_deathtouch_flag(self, source, &target, amount);
                 apply_lifelink(self, source, amount)?;
 
                 // Rule 903.10a — if a commander deals combat damage to a
@@ -143,12 +163,18 @@ impl GameState {
                             .map(|o| o.is_commander)
                             .unwrap_or(false);
                         if is_cmdr {
+                            // Keyed by the card, whatever zones it's
+                            // been through since (rule 400.7).
+                            let commander = self.original_identity(source);
                             let entry = self.get_player_mut(*pid)?
                                 .commander_damage_taken
-                                .entry(source)
+                                .entry(commander)
                                 .or_insert(0);
                             *entry = entry.saturating_add(amount as u32);
                         }
+                        // Rules 724.2, 725.2 — combat damage to the monarch
+                        // or the player with the initiative steals it.
+                        self.designation_combat_damage_triggers(source, *pid);
                     }
                 }
 
@@ -158,16 +184,11 @@ impl GameState {
                     amount,
                 });
 
-                // Emit LifeChanged for player damage
-                if let DamageTarget::Player(pid) = &target {
-                    let new_life = self.get_player(*pid)?.life_total;
-                    self.events.emit(GameEvent::LifeChanged {
-                        player_id: *pid,
-                        old: new_life + amount as i64,
-                        new: new_life,
-                        source: Some(source),
-                    });
+                // Emit LifeChanged / PoisonCountersAdded for player damage
+                if let Some(event) = result_event {
+                    self.events.emit(event);
                 }
+                apply_toxic(self, source, &target, is_combat)?;
 
                 Ok(())
             }
@@ -188,6 +209,7 @@ impl GameState {
                 let p = self.get_player_mut(player)?;
                 p.life_total += amount as i64;
                 let new_life = p.life_total;
+                self.sync_team_life(player);
 
                 self.events.emit(GameEvent::LifeChanged {
                     player_id: player,
@@ -207,6 +229,7 @@ impl GameState {
                 let p = self.get_player_mut(player)?;
                 p.life_total -= amount as i64;
                 let new_life = p.life_total;
+                self.sync_team_life(player);
 
                 self.events.emit(GameEvent::LifeChanged {
                     player_id: player,
@@ -225,6 +248,43 @@ impl GameState {
                 Ok(())
             }
 
+            GameAction::Destroy { object, can_regenerate } => {
+                let Some(entry) = self.battlefield.get(&object) else {
+                    // Rule 701.8b: only permanents on the battlefield can be destroyed.
+                    return Ok(());
+                };
+                if has_keyword(self, object, KeywordAbility::Indestructible) {
+                    return Ok(());
+                }
+                if can_regenerate && entry.regeneration_shields > 0 {
+                    // Rule 701.19a: the next time it would be destroyed,
+                    // instead tap it, remove all damage from it, and remove
+                    // it from combat.
+                    let entry = self.battlefield.get_mut(&object).unwrap();
+                    entry.regeneration_shields -= 1;
+                    entry.tapped = true;
+                    entry.damage_marked = 0;
+                    entry.damaged_by_deathtouch = false;
+                    self.remove_from_combat(object);
+                    self.events.emit(GameEvent::Regenerated { object_id: object });
+                    return Ok(());
+                }
+                let was_creature = is_creature(self, object);
+                let owner = self.get_object(object)?.owner;
+                self.move_object(object, Zone::Graveyard)?;
+                if was_creature {
+                    self.events.emit(GameEvent::CreatureDied { creature_id: object, owner });
+                }
+                Ok(())
+            }
+
+            GameAction::Regenerate { object } => {
+                if let Some(entry) = self.battlefield.get_mut(&object) {
+                    entry.regeneration_shields += 1;
+                }
+                Ok(())
+            }
+
             GameAction::Untap { object } => {
                 if let Some(entry) = self.battlefield.get_mut(&object) {
                     entry.tapped = false;
@@ -240,7 +300,63 @@ impl GameState {
                 }
                 Ok(())
             }
+
+            GameAction::Attach { attachment, host } => {
+                // Rule 701.3b: attaching to the object it's already attached
+                // to, or to itself, does nothing.
+                if attachment == host
+                    || !self.battlefield.contains_key(&host)
+                    || self.battlefield.get(&attachment)
+                        .is_none_or(|e| e.attached_to == Some(host))
+                {
+                    return Ok(());
+                }
+                self.detach_from_host(attachment);
+                // Rule 701.3c: moving to a new object gives a new timestamp.
+                let ts = self.allocate_timestamp();
+                let entry = self.battlefield.get_mut(&attachment).unwrap();
+                entry.attach_to(host);
+                entry.timestamp = ts;
+                self.battlefield.get_mut(&host).unwrap().attached_by.push(attachment);
+                self.events.emit(GameEvent::Attached { attachment, host });
+                Ok(())
+            }
+
+            GameAction::Unattach { attachment } => {
+                if let Some(former_host) = self.detach_from_host(attachment) {
+                    self.events.emit(GameEvent::Unattached { attachment, former_host });
+                }
+                Ok(())
+            }
+        }
+    }
+
+    /// Remove a creature from combat (rule 506.4): it stops attacking or
+    /// blocking, and creatures it was blocking or blocked by forget it.
+    /// An attacker that was blocked stays blocked (rule 509.1h).
+    pub(crate) fn remove_from_combat(&mut self, id: ObjectId) {
+        let Some(entry) = self.battlefield.get_mut(&id) else {
+            return;
+        };
+        entry.clear_combat_state();
+        for other in self.battlefield.values_mut() {
+            if let Some(attacking) = other.attacking.as_mut() {
+                attacking.blocked_by.retain(|&b| b != id);
+            }
+            if let Some(blocking) = other.blocking.as_mut() {
+                blocking.blocking.retain(|&a| a != id);
+            }
+        }
+    }
+
+    /// Break `attachment`'s link to its host on both sides. Returns the
+    /// former host, or None if it wasn't attached.
+    fn detach_from_host(&mut self, attachment: ObjectId) -> Option<ObjectId> {
+        let host = self.battlefield.get_mut(&attachment)?.attached_to.take()?;
+        if let Some(host_entry) = self.battlefield.get_mut(&host) {
+            host_entry.attached_by.retain(|&id| id != attachment);
         }
+        Some(host)
     }
 }
 
@@ -0,0 +1,93 @@
+//! Battles (rule 310).
+//!
+//! A battle enters with defense counters (310.4b) and a protector chosen by
+//! its controller (310.8a). Damage dealt to it removes defense counters
+//! (120.3h, handled in `actions.rs`), creatures whose controller is an
+//! opponent of the protector may attack it (508.1b, `legal_attack_targets`),
+//! and its protector is the defending player for those attacks (506.2).
+//!
+//! Siege is the only battle type printed so far (310.11). Its protector must
+//! be an opponent of its controller, and it has the defeat ability
+//! "When the last defense counter is removed from this battle, exile it, then
+//! you may cast it transformed without paying its mana cost" (310.11b).
+
+use crate::engine::cast::CastPermission;
+use crate::events::event::GameEvent;
+use crate::oracle::characteristics::has_subtype;
+use crate::state::battlefield::AttackTarget;
+use crate::state::game_state::GameState;
+use crate::types::card_types::{BattleType, Subtype};
+use crate::types::ids::{ObjectId, PlayerId};
+use crate::types::zones::Zone;
+use crate::ui::ask::ask_choose_protector;
+use crate::ui::decision::DecisionProvider;
+
+impl GameState {
+    /// Players eligible to protect battle `id` under `controller`, in turn
+    /// order starting after the controller (rule 310.8a). A Siege must be
+    /// protected by an opponent (rule 310.11a); other battle types may be
+    /// protected by anyone, the controller last.
+    pub fn battle_protector_candidates(&self, id: ObjectId, controller: PlayerId) -> Vec<PlayerId> {
+        let n = self.num_players();
+        let is_siege = self.is_siege(id);
+        (1..=n)
+            .map(|offset| (controller + offset) % n)
+            .filter(|&pid| !self.player_lost[pid])
+            .filter(|&pid| !(is_siege && self.same_team(pid, controller)))
+            .collect()
+    }
+
+    /// Have the controller of battle `id` choose its protector (rule 310.8a).
+    ///
+    /// Returns false without changing anything if there is no eligible
+    /// player (e.g. every opponent of a Siege's controller has left the
+    /// game); the caller decides what that means (SBA 704.5w/x put the
+    /// battle into its owner's graveyard).
+    pub fn choose_battle_protector(
+        &mut self,
+        id: ObjectId,
+        decisions: &dyn DecisionProvider,
+    ) -> Result<bool, String> {
+        let controller = self.battlefield.get(&id)
+            .ok_or_else(|| format!("Battle {} not on battlefield", id))?
+            .controller;
+        let candidates = self.battle_protector_candidates(id, controller);
+        if candidates.is_empty() {
+            return Ok(false);
+        }
+        let protector = ask_choose_protector(decisions, self, controller, id, &candidates);
+        self.battlefield.get_mut(&id).unwrap().protector = Some(protector);
+        self.events.emit(GameEvent::ProtectorChosen { battle_id: id, protector });
+        Ok(true)
+    }
+
+    /// Whether any creature is currently attacking battle `id`.
+    pub fn battle_is_attacked(&self, id: ObjectId) -> bool {
+        self.battlefield.values().any(|e| {
+            e.attacking.as_ref().is_some_and(|a| a.target == AttackTarget::Battle(id))
+        })
+    }
+
+    /// Whether `id` is a Siege (rule 310.11).
+    pub fn is_siege(&self, id: ObjectId) -> bool {
+        has_subtype(self, id, &Subtype::Battle(BattleType::Siege))
+    }
+
+    /// Resolve a Siege's defeat ability (rule 310.11b): exile it, then its
+    /// controller may cast it transformed without paying its mana cost.
+    ///
+    /// The ability is a triggered ability in the rules. There is no trigger
+    /// queue yet, so SBA 704.5v calls this in place of putting the Siege into
+    /// the graveyard — the outcome is the same as the trigger resolving with
+    /// no responses. A Siege whose back face can't be cast stays in exile.
+    pub(crate) fn defeat_siege(&mut self, id: ObjectId, decisions: &dyn DecisionProvider) -> Result<(), String> {
+        let controller = self.battlefield.get(&id)
+            .ok_or_else(|| format!("Siege {} not on battlefield", id))?
+            .controller;
+        let exiled = self.change_zone(id, Zone::Exile)?;
+        self.events.emit(GameEvent::SiegeDefeated { object_id: exiled, controller });
+        let permission = CastPermission { transformed: true, ..CastPermission::free_from(Zone::Exile) };
+        self.offer_free_cast(controller, exiled, permission, decisions);
+        Ok(())
+    }
+}
@@ -1,30 +1,78 @@
 use std::collections::HashMap;
 
-use crate::engine::costs::assemble_total_cost;
+use crate::engine::costs::{assemble_total_cost, combine_mana_costs, reduce_generic};
 use crate::events::event::GameEvent;
-use crate::objects::card_data::AbilityType;
-use crate::types::costs::Cost;
+use crate::engine::resolve::ResolvedTarget;
+use crate::objects::card_data::{AbilityType, CardData, CardFace, DoubleFacedKind, TriggerEvent};
+use crate::types::costs::{AdditionalCost, AlternativeCost, Cost};
 use crate::objects::object::GameObject;
-use crate::state::game_state::{GameState, PhaseType, StackEntry};
+use crate::state::game_state::{FusedHalf, GameState, PhaseType, StackEntry};
 use crate::types::card_types::CardType;
-use crate::types::effects::EffectRecipient;
+use crate::types::effects::{Effect, EffectRecipient};
 use crate::types::ids::{AbilityId, ObjectId, PlayerId};
 use crate::types::keywords::KeywordAbility;
 use crate::types::mana::ManaCost;
 use crate::types::zones::Zone;
+use crate::oracle::board::permanents_controlled_by;
+use crate::oracle::characteristics::{get_effective_power, get_mana_value, has_keyword, has_type};
 use crate::oracle::legality::enumerate_legal_selections;
 use crate::oracle::mana_helpers::{
     enumerate_activatable_mana_abilities, remaining_cost_after_pool,
 };
 use crate::ui::ask::{
     ask_activate_mana_ability,
-    ask_choose_alternative_cost, ask_choose_additional_costs,
-    ask_choose_x_value, ask_select_recipients, ask_choose_generic_mana_allocation,
+    ask_choose_alternative_cost, ask_choose_additional_costs, ask_choose_replicate_count,
+    ask_choose_sacrifice, ask_choose_x_value, ask_select_recipients, ask_choose_generic_mana_allocation,
 };
 use crate::ui::decision::DecisionProvider;
 
+/// What lets a spell be cast beyond the normal permissions of a player with
+/// priority — "you may cast it without paying its mana cost" from cascade,
+/// discover and similar effects (rule 601.3).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct CastPermission {
+    /// The card may be cast from this zone, as well as from where it
+    /// normally could be.
+    pub from_zone: Option<Zone>,
+    /// It's cast without paying its mana cost — an alternative cost, so no
+    /// other one can be chosen (rule 118.9a), and X is 0 (rule 107.3b).
+    pub without_paying_mana_cost: bool,
+    /// It's cast while another spell or ability resolves. Timing
+    /// permissions based on card type are for a player with priority (rule
+    /// 117.1a), so a sorcery can be cast then too.
+    pub during_resolution: bool,
+    /// It's cast transformed — a transforming double-faced card back face
+    /// up, as a defeated Siege is (rule 310.11b). No other face can be.
+    pub transformed: bool,
+}
+
+impl CastPermission {
+    /// Cast from `zone` without paying its mana cost while an effect
+    /// resolves, as cascade and discover do.
+    pub fn free_from(zone: Zone) -> Self {
+        CastPermission {
+            from_zone: Some(zone),
+            without_paying_mana_cost: true,
+            during_resolution: true,
+            transformed: false,
+        }
+    }
+}
+
 impl GameState {
-    /// Cast a spell from hand onto the stack (rule 601.2).
+    /// Cast a spell from hand — or a commander from the command zone
+    /// (rule 903.8) — onto the stack (rule 601.2).
+    ///
+    /// A modal double-faced card is cast as whichever of its faces its
+    /// controller chooses (rule 712.12), and the spell has only that face's
+    /// characteristics (rule 712.11). Split and adventurer cards are cast
+    /// the same way as one of their halves (rules 709.3, 715.3) — or, for a
+    /// fuse card cast from hand, both (rule 702.102a) — from whichever zone
+    /// `faces_castable_from_zone` allows.
+    ///
+    /// The spell is a new object (rule 400.7); its id on the stack is
+    /// returned. If casting it turns out to be illegal, it goes back as the
+    /// card it was (rule 732.1b).
     ///
     /// Steps follow CR 601.2a–i:
     /// 1. Pre-proposal legality check (rule 601.3)
@@ -42,63 +90,95 @@ impl GameState {
         player_id: PlayerId,
         card_id: ObjectId,
         decisions: &dyn DecisionProvider,
-    ) -> Result<(), String> {
+    ) -> Result<ObjectId, String> {
+        self.cast_spell_with(player_id, card_id, CastPermission::default(), decisions)
+    }
+
+    /// Cast a spell as `cast_spell` does, with what `permission` allows
+    /// besides: from another zone, without paying its mana cost, or in the
+    /// middle of resolving another spell or ability.
+    pub fn cast_spell_with(
+        &mut self,
+        player_id: PlayerId,
+        card_id: ObjectId,
+        permission: CastPermission,
+        decisions: &dyn DecisionProvider,
+    ) -> Result<ObjectId, String> {
         // --- Pre-proposal legality check (rule 601.3) ---
-        self.check_cast_legality(player_id, card_id)?;
-
-        // Snapshot data we need before moving the card
-        let card_data = self.get_object(card_id)?.card_data.clone();
-
-        // Find the spell ability on the card.
-        // Permanent spells (creatures, enchantments, artifacts, planeswalkers)
-        // may not have a spell ability — they resolve by entering the
-        // battlefield. Use an empty Sequence as a no-op effect.
-        let (effect, recipient) = if let Some(spell_ability) = card_data.abilities.iter()
-            .find(|a| a.ability_type == AbilityType::Spell)
-        {
-            let effect = spell_ability.effect.clone();
-            let recipient = match &effect {
-                crate::types::effects::Effect::Atom(_, ts) => ts.clone(),
-                crate::types::effects::Effect::Sequence(effects) => {
-                    // For sequence effects, use the target spec from the first atom
-                    effects.iter().find_map(|e| {
-                        if let crate::types::effects::Effect::Atom(_, ts) = e {
-                            Some(ts.clone())
-                        } else {
-                            None
-                        }
-                    }).unwrap_or(EffectRecipient::Implicit)
-                }
-                _ => EffectRecipient::Implicit,
-            };
-            (effect, recipient)
-        } else if card_data.types.iter().any(|t| t.is_permanent()) {
-            // Permanent spell with no spell ability — resolves by ETB alone
-            (crate::types::effects::Effect::Sequence(Vec::new()), EffectRecipient::Implicit)
+        // Each face it could be cast as is checked before the player chooses
+        // one, so a cast that can't be legal asks nothing.
+        let mut faces = Vec::new();
+        let mut illegal = None;
+        for face in self.faces_castable_with(card_id, &permission) {
+            match self.check_cast_legality(player_id, card_id, face, &permission) {
+                Ok(()) => faces.push(face),
+                Err(e) => { illegal.get_or_insert(e); }
+            }
+        }
+        if faces.is_empty() {
+            return Err(match illegal {
+                Some(e) => e,
+                None => self.check_cast_legality(player_id, card_id, CardFace::Front, &permission)
+                    .err().unwrap_or_else(|| "Card has no face that can be cast".to_string()),
+            });
+        }
+
+        // --- Choose the face to cast (rules 712.12, 709.3) ---
+        let face = self.choose_face(player_id, card_id, &faces, decisions);
+
+        // Snapshot data we need before moving the card. Rollbacks return it
+        // to the zone it was cast from; the mana cost includes commander tax
+        // when that's the command zone.
+        let card_data = self.get_object(card_id)?.face(face).clone();
+        let cast_from = self.get_object(card_id)?.zone;
+        let base_mana_cost = self.mana_cost_to_cast(card_id, face)?;
+        let commander_tax = if cast_from == Zone::Command { self.commander_tax(card_id) } else { 0 };
+
+        // A fused split spell follows its left half's instructions, then its
+        // right half's (rule 702.102d); every other spell has one set.
+        let (effect, recipient, fused_right) = if face == CardFace::Combined {
+            let obj = self.get_object(card_id)?;
+            let (left, right) = (obj.face(CardFace::Front).clone(), obj.face(CardFace::Back).clone());
+            let (effect, recipient) = spell_effect(&left, player_id)?;
+            (effect, recipient, Some(spell_effect(&right, player_id)?))
         } else {
-            return Err(format!("Card '{}' has no spell ability", card_data.name));
+            let (effect, recipient) = spell_effect(&card_data, player_id)?;
+            (effect, recipient, None)
         };
 
         // --- 601.2a: Move to stack ---
-        self.change_zone(card_id, Zone::Stack)?;
+        let original_id = card_id;
+        let card_id = self.change_zone(card_id, Zone::Stack)?;
 
         // --- 601.2b: Choose alternative cost, additional costs, X value ---
-        let chosen_alt_cost_idx = if !card_data.alternative_costs.is_empty() {
-            ask_choose_alternative_cost(decisions, self, player_id, &card_data.alternative_costs)
+        // An effect that casts the spell without paying its mana cost has
+        // chosen its alternative cost already (rule 118.9a); Omniscience
+        // offers that one alongside the card's own.
+        let mut alternative_costs = card_data.alternative_costs.clone();
+        if cast_from == Zone::Hand && self.may_cast_from_hand_without_paying(player_id) {
+            alternative_costs.push(AlternativeCost::WithoutPayingManaCost);
+        }
+        let chosen_alt_cost_idx = if permission.without_paying_mana_cost {
+            alternative_costs = vec![AlternativeCost::WithoutPayingManaCost];
+            Some(0)
+        } else if !alternative_costs.is_empty() {
+            ask_choose_alternative_cost(decisions, self, player_id, &alternative_costs)
         } else {
             None
         };
 
         // Validate alt cost index is in range
         if let Some(idx) = chosen_alt_cost_idx {
-            if idx >= card_data.alternative_costs.len() {
-                self.change_zone(card_id, Zone::Hand)?;
+            if idx >= alternative_costs.len() {
+                self.undo_zone_change(card_id, cast_from, original_id)?;
                 return Err(format!(
                     "Alternative cost index {} out of range (card has {})",
-                    idx, card_data.alternative_costs.len()
+                    idx, alternative_costs.len()
                 ));
             }
         }
+        let chosen_alt = chosen_alt_cost_idx.map(|idx| alternative_costs[idx].clone());
+        let without_paying = chosen_alt == Some(AlternativeCost::WithoutPayingManaCost);
 
         let chosen_additional_cost_indices = if !card_data.additional_costs.is_empty() {
             ask_choose_additional_costs(decisions, self, player_id, &card_data.additional_costs)
@@ -109,7 +189,7 @@ impl GameState {
         // Validate additional cost indices are in range
         for &idx in &chosen_additional_cost_indices {
             if idx >= card_data.additional_costs.len() {
-                self.change_zone(card_id, Zone::Hand)?;
+                self.undo_zone_change(card_id, cast_from, original_id)?;
                 return Err(format!(
                     "Additional cost index {} out of range (card has {})",
                     idx, card_data.additional_costs.len()
@@ -117,44 +197,56 @@ impl GameState {
             }
         }
 
-        // Choose X value if the cost has X symbols (rule 107.3a)
-        let base_mana_cost = card_data.mana_cost.clone()
-            .unwrap_or_else(ManaCost::zero);
+        // Replicate is paid as many times as its controller announces, each
+        // payment its own additional cost (rules 601.2b, 702.56a).
+        let mut chosen_additional = Vec::new();
+        for &idx in &chosen_additional_cost_indices {
+            let cost = &card_data.additional_costs[idx];
+            let times = match cost {
+                AdditionalCost::Replicate(_) => ask_choose_replicate_count(decisions, self, player_id, card_id),
+                _ => 1,
+            };
+            chosen_additional.extend(std::iter::repeat_n(cost.clone(), times as usize));
+        }
+        let casualty = chosen_additional.iter().find_map(|cost| match cost {
+            AdditionalCost::Casualty(n) => Some(*n),
+            _ => None,
+        });
+
+        // Choose X value if the cost has X symbols (rule 107.3a). Without
+        // paying the mana cost, the only legal choice is 0 (rule 107.3b).
         let x_count = base_mana_cost.x_count();
-        let x_value = if x_count > 0 {
+        let x_value = if x_count > 0 && !without_paying {
             ask_choose_x_value(decisions, self, player_id, card_id, x_count as u64)
         } else {
             0
         };
 
         // --- 601.2c: Choose targets ---
-        let targets = if let EffectRecipient::Target(filter, count) | EffectRecipient::Choose(filter, count) = &recipient {
-            let legal = enumerate_legal_selections(self, filter, Some(card_id));
-            let (min_sel, max_sel) = match count {
-                crate::types::effects::TargetCount::Exactly(n) => (*n as usize, *n as usize),
-                crate::types::effects::TargetCount::UpTo(n) => (0, *n as usize),
-            };
-            let chosen = ask_select_recipients(
-                decisions, self, player_id, &recipient, card_id,
-                &legal, min_sel, max_sel,
-            );
-            if let Err(e) = self.validate_targets(&recipient, &chosen) {
-                self.change_zone(card_id, Zone::Hand)?;
+        // A fused split spell's halves each choose their own (rule 702.102d).
+        let targets = match self.choose_spell_targets(player_id, card_id, &recipient, decisions) {
+            Ok(targets) => targets,
+            Err(e) => {
+                self.undo_zone_change(card_id, cast_from, original_id)?;
                 return Err(e);
             }
-            chosen
-        } else {
-            Vec::new()
+        };
+        let fused_half = match fused_right {
+            Some((effect, recipient)) => {
+                match self.choose_spell_targets(player_id, card_id, &recipient, decisions) {
+                    Ok(chosen_targets) => Some(FusedHalf { effect, chosen_targets }),
+                    Err(e) => {
+                        self.undo_zone_change(card_id, cast_from, original_id)?;
+                        return Err(e);
+                    }
+                }
+            }
+            None => None,
         };
 
         // --- 601.2d: Distribution placeholder (T18c) ---
 
         // --- Create StackEntry with all proposal data ---
-        let chosen_alt = chosen_alt_cost_idx.map(|idx| card_data.alternative_costs[idx].clone());
-        let chosen_additional: Vec<_> = chosen_additional_cost_indices.iter()
-            .map(|&idx| card_data.additional_costs[idx].clone())
-            .collect();
-
         let entry = StackEntry {
             object_id: card_id,
             controller: player_id,
@@ -163,14 +255,19 @@ impl GameState {
             x_value: if x_count > 0 { Some(x_value) } else { None },
             effect,
             is_spell: true,
+            ability_source: None,
             chosen_alternative_cost: chosen_alt.clone(),
             additional_costs_paid: chosen_additional.clone(),
+            cast_face: face,
+            fused_half,
+            is_copy: false,
+            is_triggered: false,
         };
         self.stack_entries.insert(card_id, entry);
 
         // --- 601.2e: Post-proposal legality check ---
         // At this point the only mutations are: card moved to stack + StackEntry created.
-        // No costs paid yet. If the proposal is illegal, rollback via move_object(Hand)
+        // No costs paid yet. If the proposal is illegal, rollback via undo_zone_change
         // which also cleans up the StackEntry.
         //
         // Currently a no-op (the pre-proposal check is sufficient for the cards we
@@ -178,13 +275,58 @@ impl GameState {
         // proposal choices are made, and that the assembled cost is payable.
 
         // --- 601.2f: Assemble total cost ---
+        // Without paying the mana cost, commander tax is still paid (rule
+        // 903.8): it's all that's left of it.
+        let (mana_cost, alt_cost) = if without_paying {
+            (ManaCost::build(&[], commander_tax as u8), None)
+        } else {
+            (base_mana_cost, chosen_alt.as_ref())
+        };
         let additional_refs: Vec<_> = chosen_additional.iter().collect();
-        let total_costs = assemble_total_cost(
-            &base_mana_cost,
-            chosen_alt.as_ref(),
+        let mut total_costs = combine_mana_costs(assemble_total_cost(
+            &mana_cost,
+            alt_cost,
             &additional_refs,
             x_value,
-        );
+        ));
+
+        // Emerge's total cost is reduced by the mana value of the creature
+        // sacrificed for it (rule 702.119a), which is chosen now.
+        let emerge_sacrifice = if matches!(chosen_alt, Some(AlternativeCost::Emerge(_))) {
+            let creatures: Vec<ObjectId> = permanents_controlled_by(self, player_id).into_iter()
+                .filter(|&id| has_type(self, id, CardType::Creature))
+                .collect();
+            if creatures.is_empty() {
+                self.undo_zone_change(card_id, cast_from, original_id)?;
+                return Err("No creature to sacrifice for emerge".to_string());
+            }
+            let creature = ask_choose_sacrifice(decisions, self, player_id, card_id, &creatures);
+            let reduction = get_mana_value(self, creature) as usize;
+            for cost in &mut total_costs {
+                if let Cost::Mana(mana) = cost {
+                    *mana = reduce_generic(mana, reduction);
+                }
+            }
+            Some(creature)
+        } else {
+            None
+        };
+
+        // Convoke, improvise and delve pay for part of the locked-in total
+        // cost before any mana abilities are activated (rules 702.51a,
+        // 702.126a, 702.66a). The permanents are tapped now, so none of them
+        // can also be tapped for mana.
+        let (substitutes, total_costs) = match self.choose_mana_substitutes(player_id, card_id, total_costs, decisions) {
+            Ok(chosen) => chosen,
+            Err(e) => {
+                self.undo_zone_change(card_id, cast_from, original_id)?;
+                return Err(e);
+            }
+        };
+        if let Err(e) = self.tap_substitutes(&substitutes) {
+            self.undo_zone_change(card_id, cast_from, original_id)?;
+            return Err(e);
+        }
 
         // --- 601.2g: Mana ability window ---
         // Rule 601.2g / 605.1a: the player activates mana abilities to pay
@@ -205,41 +347,103 @@ impl GameState {
         // --- 601.2h: Pay total cost ---
         // Pre-check: can we pay? If not, roll back.
         if let Err(e) = self.can_pay_costs(&total_costs, player_id, card_id) {
-            // Rollback: move card back to hand. The zone-change chokepoint
+            // Rollback: move card back where it came from. The zone-change chokepoint
             // cleans up stack_entries via `remove_from_zone_collection(Stack)`.
-            self.change_zone(card_id, Zone::Hand)?;
+            self.untap_substitutes(&substitutes);
+            self.undo_zone_change(card_id, cast_from, original_id)?;
             return Err(e);
         }
 
-        // Find the mana cost component for generic allocation
-        let mana_cost_for_alloc = total_costs.iter().find_map(|c| {
-            if let Cost::Mana(mc) = c { Some(mc.clone()) } else { None }
-        }).unwrap_or_else(ManaCost::zero);
-
-        let generic_allocation = if mana_cost_for_alloc.generic_count() > 0 {
-            let mut available: Vec<(crate::types::mana::ManaType, u64)> = self.players[player_id]
-                .mana_pool.available().iter()
-                .filter(|(_, amt)| **amt > 0)
-                .map(|(mt, amt)| (*mt, *amt))
-                .collect();
-            available.sort_by_key(|(mt, _)| *mt as u8);
-            ask_choose_generic_mana_allocation(
-                decisions, self, player_id, &mana_cost_for_alloc,
-                &available, mana_cost_for_alloc.generic_count() as u64,
-            )
-        } else {
-            HashMap::new()
-        };
+        // Casualty N needs a creature with power N or greater to sacrifice
+        // (rule 702.153a), other than the one sacrificed for emerge.
+        let casualty_candidates = casualty.map(|n| self.casualty_candidates(player_id, n, emerge_sacrifice));
+        if casualty_candidates.as_ref().is_some_and(|c| c.is_empty()) {
+            self.untap_substitutes(&substitutes);
+            self.undo_zone_change(card_id, cast_from, original_id)?;
+            return Err("No creature with enough power to sacrifice for casualty".to_string());
+        }
 
+        let generic_allocation = self.choose_generic_allocation(&total_costs, player_id, decisions);
         self.pay_costs(&total_costs, player_id, card_id, &generic_allocation)?;
+        self.exile_delved(&substitutes)?;
+        if let Some(creature) = emerge_sacrifice {
+            self.change_zone(creature, Zone::Graveyard)?;
+        }
+        if let Some(candidates) = &casualty_candidates {
+            let creature = ask_choose_sacrifice(decisions, self, player_id, card_id, candidates);
+            self.change_zone(creature, Zone::Graveyard)?;
+        }
+
+        if cast_from == Zone::Command {
+            self.record_commander_cast(card_id)?;
+        }
+        if self.is_active_player(player_id) {
+            self.active_player_spells_this_turn += 1;
+        }
+        let spells_before = self.spells_cast_this_turn;
+        self.spells_cast_this_turn += 1;
 
         // --- 601.2i: Emit SpellCast event ---
         self.events.emit(GameEvent::SpellCast {
             spell_id: card_id,
             caster: player_id,
         });
+        self.fire_triggers(TriggerEvent::YouCastSpell, player_id);
 
-        Ok(())
+        // Storm, replicate and casualty trigger as the spell is cast and
+        // copy it (rules 702.40a, 702.56a, 702.153a).
+        if has_keyword(self, card_id, KeywordAbility::Storm) {
+            self.queue_copy_trigger(player_id, card_id, spells_before as u64);
+        }
+        let replicated = chosen_additional.iter()
+            .filter(|cost| matches!(cost, AdditionalCost::Replicate(_)))
+            .count();
+        self.queue_copy_trigger(player_id, card_id, replicated as u64);
+        if casualty.is_some() {
+            self.queue_copy_trigger(player_id, card_id, 1);
+        }
+        // Cascade triggers as the spell is cast, too (rule 702.85a).
+        if has_keyword(self, card_id, KeywordAbility::Cascade) {
+            self.queue_cascade_trigger(player_id, card_id, get_mana_value(self, card_id));
+        }
+
+        Ok(card_id)
+    }
+
+    /// Creatures `player_id` controls that could be sacrificed for
+    /// casualty `n`: those with power `n` or greater (rule 702.153a), but
+    /// not `emerged`, the creature already being sacrificed for emerge.
+    fn casualty_candidates(&self, player_id: PlayerId, n: u32, emerged: Option<ObjectId>) -> Vec<ObjectId> {
+        permanents_controlled_by(self, player_id).into_iter()
+            .filter(|&id| Some(id) != emerged)
+            .filter(|&id| has_type(self, id, CardType::Creature))
+            .filter(|&id| get_effective_power(self, id).is_some_and(|p| p >= n as i32))
+            .collect()
+    }
+
+    /// Choose targets for a spell's instructions when they target or choose
+    /// (rule 601.2c). Empty when they don't; Err if the choice isn't legal.
+    pub(crate) fn choose_spell_targets(
+        &mut self,
+        player_id: PlayerId,
+        card_id: ObjectId,
+        recipient: &EffectRecipient,
+        decisions: &dyn DecisionProvider,
+    ) -> Result<Vec<ResolvedTarget>, String> {
+        let (EffectRecipient::Target(filter, count) | EffectRecipient::Choose(filter, count)) = recipient else {
+            return Ok(Vec::new());
+        };
+        let legal = enumerate_legal_selections(self, filter, Some(card_id));
+        let (min_sel, max_sel) = match count {
+            crate::types::effects::TargetCount::Exactly(n) => (*n as usize, *n as usize),
+            crate::types::effects::TargetCount::UpTo(n) => (0, *n as usize),
+        };
+        let chosen = ask_select_recipients(
+            decisions, self, player_id, recipient, card_id,
+            &legal, min_sel, max_sel,
+        );
+        self.validate_targets(recipient, &chosen)?;
+        Ok(chosen)
     }
 
     /// Activate a non-mana activated ability and put it on the stack (rule 602.2).
@@ -267,13 +471,13 @@ impl GameState {
     ) -> Result<(), String> {
         // Verify the source is on the battlefield and controlled by this player
         // (see doc comment for future zone-aware activation plan)
-        let entry = self.battlefield.get(&source_id)
+        let entry = self.permanent(source_id)
             .ok_or_else(|| format!("Permanent {} not on battlefield", source_id))?;
         if entry.controller != player_id {
             return Err("Can only activate abilities of permanents you control".to_string());
         }
 
-        let card_data = self.get_object(source_id)?.card_data.clone();
+        let card_data = self.get_object(source_id)?.face(self.face_up(source_id)).clone();
         // `ability_index` indexes the EFFECTIVE ability list — see the matching
         // comment in `oracle::mana_helpers::activatable_abilities`.
         let abilities = crate::oracle::characteristics::get_effective_abilities(self, source_id);
@@ -286,8 +490,11 @@ impl GameState {
         if ability.ability_type != AbilityType::Activated {
             return Err(format!("Ability at index {} is not an activated ability", ability_index));
         }
+        let is_loyalty = ability.is_loyalty_ability();
+        crate::oracle::legality::can_activate_ability(self, player_id, source_id, ability)?;
 
-        let effect = ability.effect.clone();
+        // "You" is fixed once the ability is on the stack (rule 109.5).
+        let effect = ability.effect.bind_controller(player_id);
         let ability_costs = ability.costs.clone();
         let recipient = match &effect {
             crate::types::effects::Effect::Atom(_, ts) => ts.clone(),
@@ -335,8 +542,13 @@ impl GameState {
             x_value: None,
             effect,
             is_spell: false,
+            ability_source: Some(source_id),
             chosen_alternative_cost: None,
             additional_costs_paid: Vec::new(),
+            cast_face: CardFace::Front,
+            fused_half: None,
+            is_copy: false,
+            is_triggered: false,
         };
         self.stack_entries.insert(ability_obj_id, stack_entry);
 
@@ -348,15 +560,54 @@ impl GameState {
         self.run_mana_ability_window(player_id, source_id, &ability_costs, decisions);
 
         // Pay ability costs
-        let generic_allocation = HashMap::new();
+        if let Err(e) = self.can_pay_costs(&ability_costs, player_id, source_id) {
+            self.rollback_ability_activation(ability_obj_id);
+            return Err(e);
+        }
+        let generic_allocation = self.choose_generic_allocation(&ability_costs, player_id, decisions);
         if let Err(e) = self.pay_costs(&ability_costs, player_id, source_id, &generic_allocation) {
             self.rollback_ability_activation(ability_obj_id);
             return Err(e);
         }
 
+        // Rule 606.3: record the activation so no further loyalty ability of
+        // this permanent can be activated this turn.
+        if is_loyalty && let Some(entry) = self.battlefield.get_mut(&source_id) {
+            entry.loyalty_activated_turn = Some(self.turn_number);
+        }
+
         Ok(())
     }
 
+    /// Ask the paying player which mana in their pool pays the generic part
+    /// of `costs`' mana component (rules 601.2h / 602.2b). Returns an empty
+    /// allocation when there's no generic mana to pay.
+    pub(crate) fn choose_generic_allocation(
+        &self,
+        costs: &[Cost],
+        player_id: PlayerId,
+        decisions: &dyn DecisionProvider,
+    ) -> HashMap<crate::types::mana::ManaType, u64> {
+        // Find the mana cost component for generic allocation
+        let mana_cost_for_alloc = costs.iter().find_map(|c| {
+            if let Cost::Mana(mc) = c { Some(mc.clone()) } else { None }
+        }).unwrap_or_else(ManaCost::zero);
+
+        if mana_cost_for_alloc.generic_count() == 0 {
+            return HashMap::new();
+        }
+        let mut available: Vec<(crate::types::mana::ManaType, u64)> = self.players[player_id]
+            .mana_pool.available().iter()
+            .filter(|(_, amt)| **amt > 0)
+            .map(|(mt, amt)| (*mt, *amt))
+            .collect();
+        available.sort_by_key(|(mt, _)| *mt as u8);
+        ask_choose_generic_mana_allocation(
+            decisions, self, player_id, &mana_cost_for_alloc,
+            &available, mana_cost_for_alloc.generic_count() as u64,
+        )
+    }
+
     /// Run the 601.2g / 602.1b mana-ability window for a pending spell or
     /// activated ability.
     ///
@@ -394,7 +645,7 @@ impl GameState {
     /// abilities). That is a DP-correctness concern — `RandomDecisionProvider`
     /// caps itself with an internal per-window counter; a future `AutoPayDP`
     /// will use a mana-bootstrap solver; a human CLI user self-polices.
-    fn run_mana_ability_window(
+    pub(crate) fn run_mana_ability_window(
         &mut self,
         player_id: PlayerId,
         spell_or_ability_id: ObjectId,
@@ -461,28 +712,55 @@ impl GameState {
         self.objects.remove(&ability_obj_id);
     }
 
+    /// The faces `card_id` can be cast as from where it is, with
+    /// `permission`: those `faces_castable_from_zone` allows, or — in the
+    /// zone the permission names — any of its faces but a fused split spell,
+    /// which is only cast from hand (rule 702.102a). Casting face down is an
+    /// alternative cost of its own, so not without paying the mana cost
+    /// (rule 118.9a). Cast transformed, it's the back face or nothing.
+    pub(crate) fn faces_castable_with(&self, card_id: ObjectId, permission: &CastPermission) -> Vec<CardFace> {
+        let Some(obj) = self.objects.get(&card_id) else {
+            return Vec::new();
+        };
+        if permission.transformed {
+            let castable = permission.from_zone == Some(obj.zone)
+                && obj.card_data.is_double_faced(DoubleFacedKind::Transforming)
+                && !obj.face(CardFace::Back).types.contains(&CardType::Land);
+            return if castable { vec![CardFace::Back] } else { Vec::new() };
+        }
+        let mut faces = self.faces_castable_from_zone(card_id);
+        if permission.from_zone == Some(obj.zone) {
+            for face in obj.castable_faces() {
+                if face != CardFace::Combined && !faces.contains(&face) {
+                    faces.push(face);
+                }
+            }
+        }
+        if permission.without_paying_mana_cost {
+            faces.retain(|face| !matches!(face, CardFace::FaceDown(_)));
+        }
+        faces
+    }
+
     /// Check whether a player can legally begin casting a spell (rule 601.3).
     ///
-    /// # Future extensibility
-    /// Currently hard-codes Zone::Hand as the only legal cast zone. This will
-    /// need to become a query against "cast permissions" once we implement:
-    /// - **Flashback** (cast from graveyard, rule 702.33)
-    /// - **Cascade / Impulse draw** (cast from exile)
-    /// - **Cycling-adjacent** cast-from-zone effects
-    ///
-    /// The planned approach: introduce a `CastPermission` enum or trait that
-    /// cards/effects register on the GameState (e.g. "player X may cast card Y
-    /// from zone Z this turn"). `check_cast_legality` would then check the
-    /// card's current zone against any active permissions, defaulting to Hand.
+    /// A spell is normally cast from hand, a commander from the command zone
+    /// (rule 903.8), and split and adventurer halves from where
+    /// `faces_castable_from_zone` allows. `permission` adds the zone an
+    /// effect lets it be cast from — cascade and discover cast from exile —
+    /// and lifts the timing restrictions while another spell or ability
+    /// resolves.
     fn check_cast_legality(
         &self,
         player_id: PlayerId,
         card_id: ObjectId,
+        face: CardFace,
+        permission: &CastPermission,
     ) -> Result<(), String> {
         let obj = self.get_object(card_id)?;
+        let data = obj.face(face);
 
-        // Card must be in hand (see doc comment for future zone-casting plan)
-        if obj.zone != Zone::Hand {
+        if !self.faces_castable_with(card_id, permission).contains(&face) {
             return Err(format!("Card is in {:?}, not in hand", obj.zone));
         }
 
@@ -494,12 +772,12 @@ impl GameState {
         // Timing check (rule 117.1a):
         // - Instants and spells with flash: anytime you have priority
         // - Everything else: main phase, stack empty, active player only
-        let is_instant = obj.card_data.types.contains(&CardType::Instant);
-        let has_flash = obj.card_data.keywords.contains(&KeywordAbility::Flash);
+        let is_instant = data.types.contains(&CardType::Instant);
+        let has_flash = data.keywords.contains(&KeywordAbility::Flash);
 
-        if !is_instant && !has_flash {
+        if !is_instant && !has_flash && !permission.during_resolution {
             // Sorcery-speed timing
-            if player_id != self.active_player {
+            if !self.is_active_player(player_id) {
                 return Err("Only the active player can cast sorcery-speed spells".to_string());
             }
             match self.phase.phase_type {
@@ -516,6 +794,40 @@ impl GameState {
 
 }
 
+/// The instructions a spell cast with `card_data`'s characteristics
+/// follows, with "you" bound to `player_id` (rule 109.5), and what they
+/// target or choose.
+///
+/// Permanent spells (creatures, enchantments, artifacts, planeswalkers)
+/// may not have a spell ability — they resolve by entering the
+/// battlefield. They get an empty Sequence as a no-op effect.
+pub(crate) fn spell_effect(card_data: &CardData, player_id: PlayerId) -> Result<(Effect, EffectRecipient), String> {
+    let Some(spell_ability) = card_data.abilities.iter()
+        .find(|a| a.ability_type == AbilityType::Spell)
+    else {
+        if card_data.types.iter().any(|t| t.is_permanent()) {
+            return Ok((Effect::Sequence(Vec::new()), EffectRecipient::Implicit));
+        }
+        return Err(format!("Card '{}' has no spell ability", card_data.name));
+    };
+    let effect = spell_ability.effect.bind_controller(player_id);
+    let recipient = match &effect {
+        Effect::Atom(_, ts) => ts.clone(),
+        Effect::Sequence(effects) => {
+            // For sequence effects, use the target spec from the first atom
+            effects.iter().find_map(|e| {
+                if let Effect::Atom(_, ts) = e {
+                    Some(ts.clone())
+                } else {
+                    None
+                }
+            }).unwrap_or(EffectRecipient::Implicit)
+        }
+        _ => EffectRecipient::Implicit,
+    };
+    Ok((effect, recipient))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -570,7 +882,7 @@ mod tests {
     #[test]
     fn test_cast_instant_spell() {
         let (mut game, card_id, decisions) = setup_for_casting();
-        game.cast_spell(0, card_id, &decisions).unwrap();
+        let card_id = game.cast_spell(0, card_id, &decisions).unwrap();
 
         // Card should be on the stack
         assert!(game.stack.contains(&card_id));
@@ -644,7 +956,7 @@ mod tests {
         let (mut game, card_id, decisions) = setup_for_casting();
         // Instants can be cast during any phase
         game.phase = crate::state::game_state::Phase::new(PhaseType::Combat);
-        game.cast_spell(0, card_id, &decisions).unwrap();
+        let card_id = game.cast_spell(0, card_id, &decisions).unwrap();
         assert!(game.stack.contains(&card_id));
     }
 
@@ -695,7 +1007,7 @@ mod tests {
             vec![3],
         );
 
-        game.cast_spell(0, card_id, &decisions).unwrap();
+        let card_id = game.cast_spell(0, card_id, &decisions).unwrap();
 
         // Card on stack
         assert!(game.stack.contains(&card_id));
@@ -727,7 +1039,7 @@ mod tests {
             spell_id: card_id,
         }, vec![1]);
 
-        game.cast_spell(0, card_id, &decisions).unwrap();
+        let card_id = game.cast_spell(0, card_id, &decisions).unwrap();
 
         let entry = game.stack_entries.get(&card_id).unwrap();
         assert_eq!(entry.x_value, Some(0));
@@ -801,7 +1113,7 @@ mod tests {
         // Options: [NormalCost, AlternativeCost(Custom(...))] — index 1 = first alt cost
         decisions.expect_pick_n(ChoiceKind::ChooseAlternativeCost, vec![1]);
 
-        game.cast_spell(0, card_id, &decisions).unwrap();
+        let card_id = game.cast_spell(0, card_id, &decisions).unwrap();
 
         // Card on stack
         assert!(game.stack.contains(&card_id));
@@ -845,7 +1157,7 @@ mod tests {
             vec![1],
         );
 
-        game.cast_spell(0, card_id, &decisions).unwrap();
+        let card_id = game.cast_spell(0, card_id, &decisions).unwrap();
 
         assert!(game.stack.contains(&card_id));
         let entry = game.stack_entries.get(&card_id).unwrap();
@@ -859,7 +1171,7 @@ mod tests {
     fn test_cast_normal_cost_no_x_no_alt() {
         // Verify the normal path still sets x_value=None and no alt/additional
         let (mut game, card_id, decisions) = setup_for_casting();
-        game.cast_spell(0, card_id, &decisions).unwrap();
+        let card_id = game.cast_spell(0, card_id, &decisions).unwrap();
 
         let entry = game.stack_entries.get(&card_id).unwrap();
         assert_eq!(entry.x_value, None);
@@ -59,7 +59,12 @@ pub fn assign_combat_damage(
             let has_trample = has_keyword(game, *id, KeywordAbility::Trample);
 
             if !attacking_info.is_blocked {
-                // Unblocked attacker: damage goes to attack target (rule 510.1b)
+                // Unblocked attacker: damage goes to attack target (rule 510.1b).
+                // A planeswalker that has left the battlefield is removed
+                // from combat (rule 506.4); its attackers deal no damage.
+                if game.defending_player_for(&attacking_info.target).is_none() {
+                    continue;
+                }
                 assignments.push(CombatDamageAssignment {
                     source: *id,
                     target: attack_target_to_damage_target(&attacking_info.target),
@@ -175,6 +180,13 @@ impl GameState {
         assignments: Vec<CombatDamageAssignment>,
     ) -> Result<(), String> {
         for assignment in assignments {
+            // A permanent removed from combat (e.g. an attacked planeswalker
+            // that left the battlefield) can't be dealt combat damage.
+            if let DamageTarget::Object(id) = assignment.target
+                && !self.battlefield.contains_key(&id)
+            {
+                continue;
+            }
             self.execute_action(GameAction::DealDamage {
                 source: assignment.source,
                 target: assignment.target,
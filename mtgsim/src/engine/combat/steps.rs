@@ -2,7 +2,7 @@
 // declare blockers, and combat damage steps.
 // See rules 508, 509, 510.
 
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 
 use crate::engine::combat::resolution::assign_combat_damage;
 use crate::engine::combat::validation::{
@@ -11,12 +11,15 @@ use crate::engine::combat::validation::{
 };
 use crate::events::event::GameEvent;
 use crate::oracle::characteristics::has_keyword;
-use crate::oracle::legality::{legal_attackers, legal_blockers};
+use crate::oracle::legality::{legal_attack_targets, legal_attackers, legal_blockers};
+use crate::oracle::mana_helpers::sources_to_pay;
 use crate::state::battlefield::{AttackTarget, AttackingInfo, BlockingInfo};
 use crate::state::game_state::GameState;
+use crate::types::costs::Cost;
 use crate::types::ids::{ObjectId, PlayerId};
 use crate::types::keywords::KeywordAbility;
-use crate::ui::ask::{ask_choose_attackers, ask_choose_blockers};
+use crate::types::mana::{ManaCost, ManaType};
+use crate::ui::ask::{ask_choose_attackers, ask_choose_blockers, ask_choose_generic_mana_allocation};
 use crate::ui::decision::DecisionProvider;
 
 impl GameState {
@@ -28,6 +31,24 @@ impl GameState {
     pub fn process_declare_attackers(
         &mut self,
         decisions: &dyn DecisionProvider,
+    ) -> Result<bool, String> {
+        // Phase 3: no constraints
+        self.process_declare_attackers_with(decisions, &AttackConstraints::none())
+    }
+
+    /// Declare attackers under the given restrictions, requirements, and
+    /// attack costs (rules 508.1c–h).
+    ///
+    /// The declaration must obey as many requirements as possible (508.1d).
+    /// Attack costs for the declared creatures are totalled and paid after
+    /// validation (508.1g–h); the player gets a mana-ability window first,
+    /// exactly as for casting a spell. If the pool and the player's mana
+    /// sources can't cover the total, the declaration is rejected before any
+    /// mana ability is activated and no state changes.
+    pub fn process_declare_attackers_with(
+        &mut self,
+        decisions: &dyn DecisionProvider,
+        constraints: &AttackConstraints,
     ) -> Result<bool, String> {
         let active = self.active_player;
         // Build legal attacker-target pairs (each legal attacker × each opponent)
@@ -35,14 +56,18 @@ impl GameState {
         // and battles as attack targets, even 2-player games can grow large. Consider a
         // two-step approach: (1) pick which creatures attack, (2) assign each a target.
         // This keeps options O(creatures + creatures) instead of O(creatures × targets).
-        let attacker_ids = legal_attackers(self, active);
+        //
+        // In Two-Headed Giant the active team declares attackers together
+        // (rule 810.7a): every active player's creatures are offered in one
+        // declaration, made by the active player.
+        let attacker_ids: Vec<ObjectId> = self.active_players()
+            .into_iter()
+            .flat_map(|pid| legal_attackers(self, pid))
+            .collect();
+        let targets = legal_attack_targets(self, active);
         let legal_pairs: Vec<(ObjectId, AttackTarget)> = attacker_ids
             .into_iter()
-            .flat_map(|id| {
-                (0..self.num_players())
-                    .filter(|&pid| pid != active)
-                    .map(move |pid| (id, AttackTarget::Player(pid)))
-            })
+            .flat_map(|id| targets.iter().map(move |t| (id, t.clone())))
             .collect();
         let proposed = ask_choose_attackers(decisions, self, active, &legal_pairs);
 
@@ -51,10 +76,32 @@ impl GameState {
         }
 
         // Validate the proposed attackers
-        // Phase 3: no constraints
-        validate_attackers(self, active, &proposed, &AttackConstraints::none())
+        validate_attackers(self, active, &proposed, constraints)
             .map_err(|e| format!("Invalid attackers: {}", e))?;
 
+        // Rules 508.1g–h: determine and pay the total cost to attack. Each
+        // player pays for the attackers they control. Nothing is paid until
+        // every payer is known to be able to afford their share.
+        let mut payments = Vec::new();
+        for payer in self.active_players() {
+            let declared: Vec<ObjectId> = proposed.iter()
+                .map(|(id, _)| *id)
+                .filter(|id| self.battlefield.get(id).is_some_and(|e| e.controller == payer))
+                .collect();
+            let attack_costs = constraints.total_cost_for(&declared);
+            if !attack_costs.is_empty() {
+                payments.push((payer, declared[0], attack_costs));
+            }
+        }
+        for (payer, reference, attack_costs) in &payments {
+            self.check_attack_costs_affordable(*payer, *reference, attack_costs)
+                .map_err(|e| format!("Invalid attackers: can't pay cost to attack: {}", e))?;
+        }
+        for (payer, reference, attack_costs) in &payments {
+            self.pay_attack_costs(*payer, *reference, attack_costs, decisions)
+                .map_err(|e| format!("Invalid attackers: {}", e))?;
+        }
+
         // Pre-collect vigilance set to avoid borrow-checker conflict
         // (has_keyword borrows self.objects, battlefield.get_mut borrows self.battlefield)
         let vigilance_set: HashSet<ObjectId> = proposed.iter()
@@ -87,6 +134,65 @@ impl GameState {
         Ok(true)
     }
 
+    /// Whether `player_id` could pay the cost to attack with the mana in
+    /// their pool and the mana sources they could tap, before any mana
+    /// ability is activated for it — the same check as `castable_spells`.
+    fn check_attack_costs_affordable(
+        &self,
+        player_id: PlayerId,
+        reference: ObjectId,
+        costs: &[Cost],
+    ) -> Result<(), String> {
+        let (mana_cost, other_costs) = split_attack_costs(costs);
+        if sources_to_pay(self, player_id, &mana_cost).is_none() {
+            return Err(format!("not enough mana for {}", mana_cost));
+        }
+        self.can_pay_costs(&other_costs, player_id, reference)
+    }
+
+    /// Pay the combined cost to attack (rules 508.1g–h).
+    ///
+    /// Mana components are merged into a single mana cost so the player sees
+    /// one mana-ability window and one generic allocation, as for a spell.
+    /// Attack costs have no source of their own; `reference` (the first
+    /// declared attacker) stands in for it in the mana-window prompt.
+    ///
+    /// The caller checks affordability first, but the player may still stop
+    /// activating mana abilities short of the cost. The declaration is then
+    /// rejected, and mana already made stays in their pool (rule 605.3b:
+    /// activating a mana ability can't be undone).
+    fn pay_attack_costs(
+        &mut self,
+        player_id: PlayerId,
+        reference: ObjectId,
+        costs: &[Cost],
+        decisions: &dyn DecisionProvider,
+    ) -> Result<(), String> {
+        let (mana_cost, other_costs) = split_attack_costs(costs);
+        let mut total = vec![Cost::Mana(mana_cost.clone())];
+        total.extend(other_costs);
+
+        self.run_mana_ability_window(player_id, reference, &total, decisions);
+        self.can_pay_costs(&total, player_id, reference)
+            .map_err(|e| format!("can't pay cost to attack: {}", e))?;
+
+        let generic_allocation = if mana_cost.generic_count() > 0 {
+            let mut available: Vec<(ManaType, u64)> = self.players[player_id]
+                .mana_pool.available().iter()
+                .filter(|(_, amt)| **amt > 0)
+                .map(|(mt, amt)| (*mt, *amt))
+                .collect();
+            available.sort_by_key(|(mt, _)| *mt as u8);
+            ask_choose_generic_mana_allocation(
+                decisions, self, player_id, &mana_cost,
+                &available, mana_cost.generic_count() as u64,
+            )
+        } else {
+            HashMap::new()
+        };
+        self.pay_costs(&total, player_id, reference, &generic_allocation)
+    }
+
     /// Declare blockers turn-based action (rule 509.1).
     ///
     /// For each defending player, asks them to choose blockers via
@@ -94,6 +200,18 @@ impl GameState {
     pub fn process_declare_blockers(
         &mut self,
         decisions: &dyn DecisionProvider,
+    ) -> Result<(), String> {
+        // Phase 3: no constraints
+        self.process_declare_blockers_with(decisions, &BlockConstraints::none())
+    }
+
+    /// Declare blockers under the given restrictions and requirements
+    /// (rules 509.1b–c). Proposals that break a restriction or obey fewer
+    /// requirements than possible go back to the defending player.
+    pub fn process_declare_blockers_with(
+        &mut self,
+        decisions: &dyn DecisionProvider,
+        constraints: &BlockConstraints,
     ) -> Result<(), String> {
         // Find defending players — each player being attacked
         let defending_players: Vec<PlayerId> = self.get_defending_players();
@@ -107,7 +225,14 @@ impl GameState {
             // uniqueness (CR 509.1) is *set-level* and is not pre-filterable
             // on individual pairs — it's enforced by `validate_blockers` and
             // the retry loop below.
-            let blocker_ids = legal_blockers(self, defender);
+            //
+            // In Two-Headed Giant the defending team blocks together (rule
+            // 810.7b): the whole team's creatures are offered to `defender`.
+            let blocker_ids: Vec<ObjectId> = self.team_members(defender)
+                .into_iter()
+                .filter(|&pid| self.is_in_game(pid))
+                .flat_map(|pid| legal_blockers(self, pid))
+                .collect();
             let attackers_in_combat: Vec<ObjectId> = self.battlefield.iter()
                 .filter_map(|(id, e)| e.attacking.as_ref().map(|_| *id))
                 .collect();
@@ -130,9 +255,7 @@ impl GameState {
                 let candidate = ask_choose_blockers(
                     decisions, self, defender, &legal_block_pairs,
                 );
-                match validate_blockers(
-                    self, defender, &candidate, &BlockConstraints::none(),
-                ) {
+                match validate_blockers(self, defender, &candidate, constraints) {
                     Ok(()) => break candidate,
                     Err(e) => {
                         if retries >= BLOCKER_RETRY_BUDGET {
@@ -242,19 +365,51 @@ impl GameState {
         Ok(())
     }
 
-    /// Get the list of defending players (players being attacked).
+    /// Get the list of defending players (rule 506.2): each player being
+    /// attacked, plus the controller of each planeswalker being attacked.
+    /// In a team game each defending team appears once, as its first
+    /// remaining player, who declares blockers for the team (rule 810.7b).
     fn get_defending_players(&self) -> Vec<PlayerId> {
         let mut defenders = Vec::new();
-        for (_id, entry) in &self.battlefield {
-            if let Some(ref info) = entry.attacking {
-                if let AttackTarget::Player(pid) = info.target {
-                    if !defenders.contains(&pid) {
-                        defenders.push(pid);
-                    }
-                }
+        for entry in self.battlefield.values() {
+            let Some(ref info) = entry.attacking else { continue };
+            let Some(pid) = self.defending_player_for(&info.target) else { continue };
+            let speaker = self.team_members(pid)
+                .into_iter()
+                .find(|&mate| self.is_in_game(mate))
+                .unwrap_or(pid);
+            if !defenders.contains(&speaker) {
+                defenders.push(speaker);
             }
         }
+        defenders.sort();
         defenders
     }
 
+    /// The player defending against an attack on `target` (rule 506.2):
+    /// the attacked player, the attacked planeswalker's controller, or the
+    /// attacked battle's protector. None if the permanent has left the
+    /// battlefield, the battle has no protector, or the attacked player has
+    /// left the game.
+    pub fn defending_player_for(&self, target: &AttackTarget) -> Option<PlayerId> {
+        match target {
+            AttackTarget::Player(pid) => Some(*pid).filter(|&pid| self.is_in_game(pid)),
+            AttackTarget::Planeswalker(id) => self.battlefield.get(id).map(|e| e.controller),
+            AttackTarget::Battle(id) => self.battlefield.get(id).and_then(|e| e.protector),
+        }
+    }
+
+}
+
+/// Split attack costs into one merged mana cost and everything else.
+fn split_attack_costs(costs: &[Cost]) -> (ManaCost, Vec<Cost>) {
+    let mut mana_symbols = Vec::new();
+    let mut other_costs = Vec::new();
+    for cost in costs {
+        match cost {
+            Cost::Mana(mc) => mana_symbols.extend(mc.symbols.iter().cloned()),
+            other => other_costs.push(other.clone()),
+        }
+    }
+    (ManaCost::from_symbols(mana_symbols), other_costs)
 }
@@ -3,10 +3,12 @@
 
 use std::collections::HashMap;
 
-use crate::oracle::characteristics::{has_keyword, is_creature};
-use crate::oracle::legality::can_attack;
+use crate::oracle::characteristics::{has_keyword, has_type, is_creature};
+use crate::oracle::legality::{can_attack, legal_attackers, legal_blockers};
 use crate::state::battlefield::AttackTarget;
 use crate::state::game_state::GameState;
+use crate::types::card_types::CardType;
+use crate::types::costs::Cost;
 use crate::types::ids::{ObjectId, PlayerId};
 use crate::types::keywords::KeywordAbility;
 
@@ -28,6 +30,11 @@ pub enum CombatError {
     HasDefender(ObjectId),
     CantBlockFlyer(ObjectId, ObjectId),
     ConstraintViolation(String),
+    /// The declaration obeys fewer requirements than the maximum possible
+    /// without violating a restriction (rules 508.1d / 509.1c).
+    /// Fields: (description of a dropped requirement, requirements obeyed,
+    /// maximum obeyable).
+    RequirementDropped(String, usize, usize),
 }
 
 impl std::fmt::Display for CombatError {
@@ -48,6 +55,11 @@ impl std::fmt::Display for CombatError {
                 write!(f, "Creature {} can't block flyer {} (no flying or reach)", blocker, attacker)
             }
             CombatError::ConstraintViolation(msg) => write!(f, "Constraint violation: {}", msg),
+            CombatError::RequirementDropped(req, obeyed, max) => write!(
+                f,
+                "Requirement dropped: {} (declaration obeys {} of a possible {} requirement(s))",
+                req, obeyed, max
+            ),
         }
     }
 }
@@ -64,6 +76,10 @@ impl std::fmt::Display for CombatError {
 pub struct AttackConstraints {
     pub restrictions: Vec<AttackRestriction>,
     pub requirements: Vec<AttackRequirement>,
+    /// Costs to attack (rule 508.1g), e.g. Propaganda. A creature with an
+    /// attack cost may only attack if its controller pays; the player is
+    /// never obliged to pay just to obey a requirement (rule 508.1d).
+    pub costs: Vec<AttackCost>,
 }
 
 /// An effect that prevents a creature from attacking.
@@ -84,14 +100,56 @@ pub enum AttackRequirement {
     MustAttackIfAble(ObjectId),
 }
 
+impl AttackRequirement {
+    /// The creature this requirement applies to.
+    pub fn creature(&self) -> ObjectId {
+        match self {
+            AttackRequirement::MustAttackIfAble(id) => *id,
+        }
+    }
+}
+
+impl std::fmt::Display for AttackRequirement {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AttackRequirement::MustAttackIfAble(id) => write!(f, "creature {} must attack if able", id),
+        }
+    }
+}
+
+/// A cost that must be paid for a creature to attack (rule 508.1g).
+#[derive(Debug, Clone)]
+pub struct AttackCost {
+    pub creature: ObjectId,
+    pub costs: Vec<Cost>,
+}
+
 impl AttackConstraints {
     /// No constraints — used in Phase 3 where no restriction/requirement effects exist.
     pub fn none() -> Self {
         AttackConstraints {
             restrictions: Vec::new(),
             requirements: Vec::new(),
+            costs: Vec::new(),
         }
     }
+
+    /// Whether any cost must be paid for this creature to attack.
+    pub fn has_attack_cost(&self, creature_id: ObjectId) -> bool {
+        self.costs.iter().any(|c| c.creature == creature_id && !c.costs.is_empty())
+    }
+
+    /// Every cost that must be paid for the given attackers to attack, in
+    /// declaration order (rule 508.1g: costs are locked in together).
+    pub fn total_cost_for(&self, attackers: &[ObjectId]) -> Vec<Cost> {
+        attackers.iter()
+            .flat_map(|id| {
+                self.costs.iter()
+                    .filter(move |c| c.creature == *id)
+                    .flat_map(|c| c.costs.iter().cloned())
+            })
+            .collect()
+    }
 }
 
 // ---------------------------------------------------------------------------
@@ -124,6 +182,10 @@ pub enum BlockRestriction {
 pub enum BlockRequirement {
     /// This creature blocks each combat if able
     MustBlockIfAble(ObjectId),
+    /// All creatures able to block this attacker do so (e.g. Lure).
+    /// Per rule 509.1c this is one requirement for *each* creature able to
+    /// block the attacker.
+    MustBeBlockedByAll(ObjectId),
 }
 
 impl BlockConstraints {
@@ -168,8 +230,9 @@ pub fn validate_attackers(
             return Err(CombatError::NotACreature(*creature_id));
         }
 
-        // 3. Must be controlled by the attacking player
-        if entry.controller != player_id {
+        // 3. Must be controlled by the attacking player (or their teammate,
+        // rule 810.7a)
+        if !game.same_team(entry.controller, player_id) {
             return Err(CombatError::NotControlledByPlayer(*creature_id, player_id));
         }
 
@@ -191,51 +254,64 @@ pub fn validate_attackers(
         // 7. Attack target must be valid
         match target {
             AttackTarget::Player(pid) => {
-                // Must be an opponent (not self, and within player range)
-                if *pid == player_id || *pid >= num_players {
+                // Must be an opponent still in the game (not self, and
+                // within player range)
+                if *pid >= num_players || !game.is_opponent(player_id, *pid) || !game.is_in_game(*pid) {
                     return Err(CombatError::InvalidAttackTarget(*creature_id));
                 }
             }
-            AttackTarget::Planeswalker(_) | AttackTarget::Battle(_) => {
-                // Phase 3: planeswalkers and battles not yet supported as attack targets
-                return Err(CombatError::InvalidAttackTarget(*creature_id));
+            AttackTarget::Planeswalker(pw) => {
+                // Rule 508.1b: a planeswalker an opponent controls
+                let valid = game.battlefield.get(pw)
+                    .is_some_and(|e| game.is_opponent(player_id, e.controller))
+                    && has_type(game, *pw, CardType::Planeswalker);
+                if !valid {
+                    return Err(CombatError::InvalidAttackTarget(*creature_id));
+                }
+            }
+            AttackTarget::Battle(battle) => {
+                // Rule 508.1b: a battle an opponent protects
+                let valid = game.battlefield.get(battle)
+                    .is_some_and(|e| e.protector.is_some_and(|p| game.is_opponent(player_id, p)))
+                    && has_type(game, *battle, CardType::Battle);
+                if !valid {
+                    return Err(CombatError::InvalidAttackTarget(*creature_id));
+                }
             }
         }
     }
 
     // Set-level constraint checks (rule 508.1c-d)
-    check_attack_set_constraints(proposed, constraints)?;
+    let proposed_ids: Vec<ObjectId> = proposed.iter().map(|(id, _)| *id).collect();
+    check_attack_restrictions(&proposed_ids, constraints)?;
+    check_attack_requirements(game, player_id, &proposed_ids, constraints)?;
 
     Ok(())
 }
 
-/// Check set-level attack constraints (restrictions and requirements).
-///
-/// Phase 3: this is a no-op when constraints is `AttackConstraints::none()`.
-/// Phase 4/5 will populate constraints from keywords and continuous effects.
-fn check_attack_set_constraints(
-    proposed: &[(ObjectId, AttackTarget)],
+/// Check set-level attack restrictions (rule 508.1c).
+fn check_attack_restrictions(
+    attackers: &[ObjectId],
     constraints: &AttackConstraints,
 ) -> Result<(), CombatError> {
-    // Check restrictions
     for restriction in &constraints.restrictions {
         match restriction {
             AttackRestriction::CantAttack(id) => {
-                if proposed.iter().any(|(cid, _)| cid == id) {
+                if attackers.contains(id) {
                     return Err(CombatError::ConstraintViolation(
                         format!("Creature {} can't attack", id),
                     ));
                 }
             }
             AttackRestriction::CantAttackAlone(id) => {
-                if proposed.len() == 1 && proposed[0].0 == *id {
+                if attackers.len() == 1 && attackers[0] == *id {
                     return Err(CombatError::ConstraintViolation(
                         format!("Creature {} can't attack alone", id),
                     ));
                 }
             }
             AttackRestriction::MaxAttackers(max) => {
-                if proposed.len() > *max {
+                if attackers.len() > *max {
                     return Err(CombatError::ConstraintViolation(
                         format!("At most {} creature(s) can attack", max),
                     ));
@@ -243,22 +319,117 @@ fn check_attack_set_constraints(
             }
         }
     }
+    Ok(())
+}
 
-    // Check requirements — Phase 4/5 will implement the requirement-maximizing logic.
-    // For now, just verify that required creatures are present if possible.
-    for requirement in &constraints.requirements {
-        match requirement {
-            AttackRequirement::MustAttackIfAble(id) => {
-                if !proposed.iter().any(|(cid, _)| cid == id) {
-                    return Err(CombatError::ConstraintViolation(
-                        format!("Creature {} must attack if able", id),
-                    ));
-                }
+/// Check that the declaration obeys the maximum possible number of attack
+/// requirements without violating a restriction (rule 508.1d).
+///
+/// A requirement on a creature that can't attack (tapped, summoning sick,
+/// restricted) is simply not counted. A creature with an unpaid attack cost
+/// is treated as unable to attack: the player may decline the cost even if
+/// paying would let them obey more requirements. Creatures whose cost the
+/// player *is* paying (i.e. that appear in the proposal) count normally.
+fn check_attack_requirements(
+    game: &GameState,
+    player_id: PlayerId,
+    attackers: &[ObjectId],
+    constraints: &AttackConstraints,
+) -> Result<(), CombatError> {
+    if constraints.requirements.is_empty() {
+        return Ok(());
+    }
+
+    // Creatures that could be added to the attack without the player
+    // having to pay anything they didn't already choose to pay.
+    let mut candidates: Vec<ObjectId> = legal_attackers(game, player_id)
+        .into_iter()
+        .filter(|id| !constraints.has_attack_cost(*id) || attackers.contains(id))
+        .filter(|id| {
+            !constraints.restrictions.iter()
+                .any(|r| matches!(r, AttackRestriction::CantAttack(c) if c == id))
+        })
+        .collect();
+    candidates.sort();
+
+    let weight = |id: &ObjectId| -> usize {
+        constraints.requirements.iter().filter(|r| r.creature() == *id).count()
+    };
+    let required: Vec<(ObjectId, usize)> = candidates.iter()
+        .map(|id| (*id, weight(id)))
+        .filter(|(_, w)| *w > 0)
+        .collect();
+
+    let obeyed: usize = attackers.iter().map(weight).sum();
+    let (maximum, best) = max_attack_requirements(&required, &candidates, constraints);
+    if obeyed >= maximum {
+        return Ok(());
+    }
+
+    let dropped = constraints.requirements.iter()
+        .find(|r| best.contains(&r.creature()) && !attackers.contains(&r.creature()))
+        .map(|r| r.to_string())
+        .unwrap_or_else(|| "attack requirement".to_string());
+    Err(CombatError::RequirementDropped(dropped, obeyed, maximum))
+}
+
+/// Find the largest number of attack requirements that can be obeyed
+/// simultaneously, returning it together with one set of required creatures
+/// that achieves it.
+///
+/// Branch-and-bound over subsets of `required` (creature, requirement count)
+/// pairs. Each subset is accepted if it passes the restrictions on its own
+/// or with a single non-required creature from `candidates` added — with the
+/// current restriction vocabulary (`CantAttack`, `CantAttackAlone`,
+/// `MaxAttackers`) a companion only ever matters for "can't attack alone",
+/// and one companion suffices.
+fn max_attack_requirements(
+    required: &[(ObjectId, usize)],
+    candidates: &[ObjectId],
+    constraints: &AttackConstraints,
+) -> (usize, Vec<ObjectId>) {
+    fn completable(set: &[ObjectId], candidates: &[ObjectId], constraints: &AttackConstraints) -> bool {
+        if check_attack_restrictions(set, constraints).is_ok() {
+            return true;
+        }
+        candidates.iter()
+            .filter(|c| !set.contains(c))
+            .any(|c| {
+                let mut with = set.to_vec();
+                with.push(*c);
+                check_attack_restrictions(&with, constraints).is_ok()
+            })
+    }
+
+    fn search(
+        idx: usize,
+        current: &mut Vec<ObjectId>,
+        current_weight: usize,
+        required: &[(ObjectId, usize)],
+        candidates: &[ObjectId],
+        constraints: &AttackConstraints,
+        best: &mut (usize, Vec<ObjectId>),
+    ) {
+        let remaining: usize = required[idx..].iter().map(|(_, w)| w).sum();
+        if current_weight + remaining <= best.0 {
+            return;
+        }
+        if idx == required.len() {
+            if completable(current, candidates, constraints) {
+                *best = (current_weight, current.clone());
             }
+            return;
         }
+        let (id, w) = required[idx];
+        current.push(id);
+        search(idx + 1, current, current_weight + w, required, candidates, constraints, best);
+        current.pop();
+        search(idx + 1, current, current_weight, required, candidates, constraints, best);
     }
 
-    Ok(())
+    let mut best = (0, Vec::new());
+    search(0, &mut Vec::new(), 0, required, candidates, constraints, &mut best);
+    best
 }
 
 // ---------------------------------------------------------------------------
@@ -290,23 +461,26 @@ pub fn can_block(
     if !is_creature(game, blocker_id) {
         return Err(CombatError::NotACreature(blocker_id));
     }
-    if entry.controller != defender {
+    // Teammates block together in Two-Headed Giant (rule 810.7b).
+    if !game.same_team(entry.controller, defender) {
         return Err(CombatError::NotControlledByPlayer(blocker_id, defender));
     }
     if entry.tapped {
         return Err(CombatError::CreatureIsTapped(blocker_id));
     }
 
-    // Attacker must be on the battlefield and attacking this defender.
+    // Attacker must be on the battlefield and attacking this defender (or,
+    // in a team game, this defender's team).
     let att_entry = game.battlefield.get(&attacker_id)
         .ok_or(CombatError::NotOnBattlefield(attacker_id))?;
     let attacking_info = att_entry.attacking.as_ref()
         .ok_or(CombatError::AttackerNotAttackingThisPlayer(blocker_id, attacker_id))?;
-    match &attacking_info.target {
-        AttackTarget::Player(pid) if *pid == defender => {}
-        _ => {
-            return Err(CombatError::AttackerNotAttackingThisPlayer(blocker_id, attacker_id));
-        }
+    // Rule 509.1a: the defending player may block creatures attacking them
+    // or a planeswalker they control.
+    if !game.defending_player_for(&attacking_info.target)
+        .is_some_and(|pid| game.same_team(pid, defender))
+    {
+        return Err(CombatError::AttackerNotAttackingThisPlayer(blocker_id, attacker_id));
     }
 
     // Flying evasion (rule 702.9b / 702.17b).
@@ -354,6 +528,7 @@ pub fn validate_blockers(
 
     // Set-level constraint checks
     check_block_set_constraints(proposed, constraints)?;
+    check_block_requirements(game, player_id, proposed, constraints)?;
 
     Ok(())
 }
@@ -384,19 +559,99 @@ fn check_block_set_constraints(
         }
     }
 
-    for requirement in &constraints.requirements {
-        match requirement {
-            BlockRequirement::MustBlockIfAble(id) => {
-                if !proposed.iter().any(|(bid, _)| bid == id) {
-                    return Err(CombatError::ConstraintViolation(
-                        format!("Creature {} must block if able", id),
-                    ));
-                }
-            }
+    Ok(())
+}
+
+/// Check that the declaration obeys the maximum possible number of block
+/// requirements without violating a restriction (rule 509.1c).
+///
+/// With the current requirement vocabulary every requirement concerns a
+/// single blocker ("X blocks if able", "X blocks the Lure creature if
+/// able") and every restriction is per-blocker, so blockers are independent:
+/// the global maximum is the sum of each blocker's best, and a declaration
+/// falls short exactly when some blocker falls short of its own best.
+fn check_block_requirements(
+    game: &GameState,
+    defender: PlayerId,
+    proposed: &[(ObjectId, ObjectId)],
+    constraints: &BlockConstraints,
+) -> Result<(), CombatError> {
+    if constraints.requirements.is_empty() {
+        return Ok(());
+    }
+
+    let attackers: Vec<ObjectId> = {
+        let mut ids: Vec<ObjectId> = game.battlefield.iter()
+            .filter_map(|(id, e)| e.attacking.as_ref().map(|_| *id))
+            .collect();
+        ids.sort();
+        ids
+    };
+    let mut blockers = legal_blockers(game, defender);
+    blockers.sort();
+
+    let lure_weight = |attacker: ObjectId| -> usize {
+        constraints.requirements.iter()
+            .filter(|r| matches!(r, BlockRequirement::MustBeBlockedByAll(a) if *a == attacker))
+            .count()
+    };
+
+    let mut total_obeyed = 0;
+    let mut total_max = 0;
+    let mut dropped: Option<String> = None;
+
+    for blocker in blockers {
+        let restricted = constraints.restrictions.iter().any(|r| match r {
+            BlockRestriction::CantBlock(id) | BlockRestriction::CantBlockUnless(id, _) => *id == blocker,
+        });
+        let blocked: Vec<ObjectId> = proposed.iter()
+            .filter(|(b, _)| *b == blocker)
+            .map(|(_, a)| *a)
+            .collect();
+        let must_block = constraints.requirements.iter()
+            .filter(|r| matches!(r, BlockRequirement::MustBlockIfAble(b) if *b == blocker))
+            .count();
+
+        let obeyed = blocked.iter().map(|a| lure_weight(*a)).sum::<usize>()
+            + if blocked.is_empty() { 0 } else { must_block };
+        total_obeyed += obeyed;
+
+        if restricted {
+            continue;
+        }
+        let mut blockable: Vec<(ObjectId, usize)> = attackers.iter()
+            .filter(|a| can_block(game, defender, blocker, **a).is_ok())
+            .map(|a| (*a, lure_weight(*a)))
+            .collect();
+        if blockable.is_empty() {
+            continue;
+        }
+        blockable.sort_by_key(|(_, w)| std::cmp::Reverse(*w));
+        let limit = constraints.max_blocks_for(blocker);
+        let best = blockable.iter().take(limit).map(|(_, w)| w).sum::<usize>()
+            + if limit > 0 { must_block } else { 0 };
+        total_max += best;
+
+        if obeyed < best && dropped.is_none() {
+            dropped = Some(if blocked.is_empty() && must_block > 0 {
+                format!("creature {} must block if able", blocker)
+            } else {
+                let lure = blockable.iter()
+                    .find(|(a, w)| *w > 0 && !blocked.contains(a))
+                    .map(|(a, _)| *a)
+                    .unwrap_or(blockable[0].0);
+                format!(
+                    "creature {} must block creature {} if able (all creatures able to block it do so)",
+                    blocker, lure
+                )
+            });
         }
     }
 
-    Ok(())
+    match dropped {
+        Some(req) => Err(CombatError::RequirementDropped(req, total_obeyed, total_max)),
+        None => Ok(()),
+    }
 }
 
 #[cfg(test)]
@@ -411,6 +666,7 @@ mod tests {
     use crate::types::mana::{ManaCost, ManaType};
     use crate::types::zones::Zone;
     use crate::types::colors::Color;
+    use crate::types::costs::Cost;
 
     fn make_bears(owner: PlayerId) -> (ObjectId, std::sync::Arc<crate::objects::card_data::CardData>) {
         let data = CardDataBuilder::new("Grizzly Bears")
@@ -1084,4 +1340,202 @@ mod tests {
         let att2_info = game.battlefield.get(&att2).unwrap().attacking.as_ref().unwrap();
         assert!(!att2_info.is_blocked);
     }
+
+    // --- Requirement maximization (CR 508.1d / 509.1c) ---
+
+    fn must_attack(ids: &[ObjectId]) -> AttackConstraints {
+        let mut c = AttackConstraints::none();
+        c.requirements = ids.iter().map(|id| AttackRequirement::MustAttackIfAble(*id)).collect();
+        c
+    }
+
+    #[test]
+    fn test_must_attack_obeyed() {
+        let mut game = GameState::new(2, 20);
+        let c = place_creature(&mut game, 0);
+        let result = validate_attackers(&game, 0, &[(c, AttackTarget::Player(1))], &must_attack(&[c]));
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_must_attack_dropped_rejected() {
+        let mut game = GameState::new(2, 20);
+        let c = place_creature(&mut game, 0);
+        let result = validate_attackers(&game, 0, &[], &must_attack(&[c]));
+        assert!(matches!(result, Err(CombatError::RequirementDropped(_, 0, 1))));
+    }
+
+    #[test]
+    fn test_must_attack_ignored_when_unable() {
+        let mut game = GameState::new(2, 20);
+        let tapped = place_creature(&mut game, 0);
+        game.battlefield.get_mut(&tapped).unwrap().tapped = true;
+        let sick = place_creature_sick(&mut game, 0);
+
+        let result = validate_attackers(&game, 0, &[], &must_attack(&[tapped, sick]));
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_attack_cost_may_be_declined() {
+        // Propaganda: the player isn't required to pay to obey a requirement.
+        let mut game = GameState::new(2, 20);
+        let c = place_creature(&mut game, 0);
+        let mut constraints = must_attack(&[c]);
+        constraints.costs.push(AttackCost {
+            creature: c,
+            costs: vec![Cost::Mana(ManaCost::build(&[], 2))],
+        });
+
+        assert!(validate_attackers(&game, 0, &[], &constraints).is_ok());
+        assert!(validate_attackers(&game, 0, &[(c, AttackTarget::Player(1))], &constraints).is_ok());
+    }
+
+    #[test]
+    fn test_must_attack_with_cant_attack_alone() {
+        let mut game = GameState::new(2, 20);
+        let loner = place_creature(&mut game, 0);
+        let mut constraints = must_attack(&[loner]);
+        constraints.restrictions.push(AttackRestriction::CantAttackAlone(loner));
+
+        // Only creature: can't attack alone, so the requirement can't be obeyed.
+        assert!(validate_attackers(&game, 0, &[], &constraints).is_ok());
+
+        // With a companion available the requirement becomes obeyable.
+        let buddy = place_creature(&mut game, 0);
+        assert!(matches!(
+            validate_attackers(&game, 0, &[], &constraints),
+            Err(CombatError::RequirementDropped(_, 0, 1)),
+        ));
+        assert!(validate_attackers(
+            &game, 0,
+            &[(loner, AttackTarget::Player(1)), (buddy, AttackTarget::Player(1))],
+            &constraints,
+        ).is_ok());
+    }
+
+    #[test]
+    fn test_must_attack_with_max_attackers() {
+        let mut game = GameState::new(2, 20);
+        let a = place_creature(&mut game, 0);
+        let b = place_creature(&mut game, 0);
+        let mut constraints = must_attack(&[a, b]);
+        constraints.restrictions.push(AttackRestriction::MaxAttackers(1));
+
+        assert!(validate_attackers(&game, 0, &[(b, AttackTarget::Player(1))], &constraints).is_ok());
+        assert!(matches!(
+            validate_attackers(&game, 0, &[], &constraints),
+            Err(CombatError::RequirementDropped(_, 0, 1)),
+        ));
+    }
+
+    #[test]
+    fn test_must_block_if_able() {
+        let mut game = GameState::new(2, 20);
+        let attacker = place_creature(&mut game, 0);
+        let blocker = place_creature(&mut game, 1);
+        set_attacking(&mut game, attacker, 1);
+        let mut constraints = BlockConstraints::none();
+        constraints.requirements.push(BlockRequirement::MustBlockIfAble(blocker));
+
+        assert!(matches!(
+            validate_blockers(&game, 1, &[], &constraints),
+            Err(CombatError::RequirementDropped(_, 0, 1)),
+        ));
+        assert!(validate_blockers(&game, 1, &[(blocker, attacker)], &constraints).is_ok());
+    }
+
+    #[test]
+    fn test_must_block_ignored_when_only_flyers_attack() {
+        let mut game = GameState::new(2, 20);
+        let flyer = place_creature_with_keywords(&mut game, 0, &[KeywordAbility::Flying], 2, 2);
+        let ground = place_creature(&mut game, 1);
+        set_attacking(&mut game, flyer, 1);
+        let mut constraints = BlockConstraints::none();
+        constraints.requirements.push(BlockRequirement::MustBlockIfAble(ground));
+
+        assert!(validate_blockers(&game, 1, &[], &constraints).is_ok());
+    }
+
+    #[test]
+    fn test_lure_must_be_blocked_by_all() {
+        let mut game = GameState::new(2, 20);
+        let lure = place_creature(&mut game, 0);
+        let other = place_creature(&mut game, 0);
+        let b1 = place_creature(&mut game, 1);
+        let b2 = place_creature(&mut game, 1);
+        set_attacking(&mut game, lure, 1);
+        set_attacking(&mut game, other, 1);
+        let mut constraints = BlockConstraints::none();
+        constraints.requirements.push(BlockRequirement::MustBeBlockedByAll(lure));
+
+        // One blocker peels off onto the other attacker: drops a requirement.
+        let result = validate_blockers(&game, 1, &[(b1, lure), (b2, other)], &constraints);
+        assert!(matches!(result, Err(CombatError::RequirementDropped(_, 1, 2))));
+
+        assert!(validate_blockers(&game, 1, &[(b1, lure), (b2, lure)], &constraints).is_ok());
+    }
+
+    #[test]
+    fn test_process_declare_attackers_pays_attack_cost() {
+        let mut game = GameState::new(2, 20);
+        let c = place_creature(&mut game, 0);
+        game.players[0].mana_pool.add(ManaType::Green, 2);
+        let mut constraints = AttackConstraints::none();
+        constraints.costs.push(AttackCost {
+            creature: c,
+            costs: vec![Cost::Mana(ManaCost::build(&[], 2))],
+        });
+
+        let scripted = crate::ui::decision::ScriptedDecisionProvider::new();
+        scripted.expect_pick_n(crate::ui::choice_types::ChoiceKind::DeclareAttackers, vec![0]);
+        scripted.expect_allocation(
+            crate::ui::choice_types::ChoiceKind::GenericManaAllocation {
+                mana_cost: ManaCost::build(&[], 2),
+            },
+            vec![2],
+        );
+        assert!(game.process_declare_attackers_with(&scripted, &constraints).unwrap());
+        assert_eq!(game.players[0].mana_pool.amount(ManaType::Green), 0);
+        assert!(game.battlefield.get(&c).unwrap().attacking.is_some());
+    }
+
+    #[test]
+    fn test_process_declare_attackers_unpaid_cost_rejected() {
+        let mut game = GameState::new(2, 20);
+        let c = place_creature(&mut game, 0);
+        let mut constraints = AttackConstraints::none();
+        constraints.costs.push(AttackCost {
+            creature: c,
+            costs: vec![Cost::Mana(ManaCost::build(&[], 2))],
+        });
+
+        let scripted = crate::ui::decision::ScriptedDecisionProvider::new();
+        scripted.expect_pick_n(crate::ui::choice_types::ChoiceKind::DeclareAttackers, vec![0]);
+        assert!(game.process_declare_attackers_with(&scripted, &constraints).is_err());
+        assert!(game.battlefield.get(&c).unwrap().attacking.is_none());
+    }
+
+    #[test]
+    fn test_unaffordable_attack_cost_taps_nothing() {
+        // One Forest can't pay {2}: no mana ability window is opened, so the
+        // Forest stays untapped and the pool empty.
+        let mut game = GameState::new(2, 20);
+        let c = place_creature(&mut game, 0);
+        let forest = game.add_object(GameObject::new(crate::cards::basic_lands::forest(), 0, Zone::Battlefield));
+        let ts = game.allocate_timestamp();
+        game.battlefield.insert(forest, BattlefieldEntity::new(forest, 0, ts, 0));
+        let mut constraints = AttackConstraints::none();
+        constraints.costs.push(AttackCost {
+            creature: c,
+            costs: vec![Cost::Mana(ManaCost::build(&[], 2))],
+        });
+
+        let scripted = crate::ui::decision::ScriptedDecisionProvider::new();
+        scripted.expect_pick_n(crate::ui::choice_types::ChoiceKind::DeclareAttackers, vec![0]);
+        assert!(game.process_declare_attackers_with(&scripted, &constraints).is_err());
+        assert!(!game.battlefield[&forest].tapped);
+        assert_eq!(game.players[0].mana_pool.total(), 0);
+        assert!(game.battlefield[&c].attacking.is_none());
+    }
 }
@@ -0,0 +1,246 @@
+//! Commander (rule 903).
+//!
+//! Each player's commander starts the game in the command zone (903.6) and
+//! may be cast from there (903.8). Each cast from the command zone costs
+//! {2} more for every previous time it was cast from there this game —
+//! commander tax — tracked per commander on its owner's `PlayerState`.
+//!
+//! A commander that would leave for somewhere else may go to the command
+//! zone instead (903.9):
+//! - into a hand or library, that's a replacement its owner chooses as the
+//!   move happens (903.9b) — `change_zone_with_commander_choice`;
+//! - into a graveyard or exile, it gets there first, and its owner may move
+//!   it the next time state-based actions are checked (903.9a, 704.6d) —
+//!   `return_commanders_to_command_zone`.
+//!
+//! Color identity and deck construction (903.4, 903.5) are oracle queries —
+//! see `oracle::color_identity` and `oracle::deck_validation`.
+
+use crate::objects::card_data::CardFace;
+use crate::state::game_state::GameState;
+use crate::types::ids::{ObjectId, PlayerId};
+use crate::types::mana::{ManaCost, ManaSymbol};
+use crate::types::zones::Zone;
+use crate::ui::ask::ask_commander_to_command_zone;
+use crate::ui::decision::DecisionProvider;
+
+impl GameState {
+    /// Designate `card_id` as `player`'s commander and put it into the
+    /// command zone (rules 903.3, 903.6). Returns its id there.
+    pub fn designate_commander(&mut self, player: PlayerId, card_id: ObjectId) -> Result<ObjectId, String> {
+        let obj = self.get_object_mut(card_id)?;
+        if obj.owner != player {
+            return Err(format!("Player {} doesn't own the commander {}", player, card_id));
+        }
+        obj.is_commander = true;
+        self.change_zone(card_id, Zone::Command)
+    }
+
+    /// Designate the card named `name` in `player`'s library as their
+    /// commander (see `designate_commander`). Used at setup, when the
+    /// commander is still part of the decklist.
+    pub fn designate_commander_by_name(&mut self, player: PlayerId, name: &str) -> Result<ObjectId, String> {
+        let card_id = self.players[player].library.iter()
+            .copied()
+            .find(|id| self.objects.get(id).is_some_and(|o| o.card_data.name == name))
+            .ok_or_else(|| format!("Commander '{}' isn't in player {}'s library", name, player))?;
+        self.designate_commander(player, card_id)
+    }
+
+    /// Whether `card_id` may be cast from the zone it's in: a hand, or the
+    /// command zone for a commander (rule 903.8).
+    pub fn in_castable_zone(&self, card_id: ObjectId) -> bool {
+        self.objects.get(&card_id).is_some_and(|obj| match obj.zone {
+            Zone::Hand => true,
+            Zone::Command => obj.is_commander,
+            _ => false,
+        })
+    }
+
+    /// Commander tax on `card_id`: {2} for each time it's been cast from the
+    /// command zone this game (rule 903.8). Zero for anything else.
+    pub fn commander_tax(&self, card_id: ObjectId) -> u32 {
+        let Some(obj) = self.objects.get(&card_id) else {
+            return 0;
+        };
+        let commander = self.original_identity(card_id);
+        let casts = self.players[obj.owner].commander_casts.get(&commander).copied().unwrap_or(0);
+        2 * casts
+    }
+
+    /// The mana cost to cast `card_id` as `face` from where it is: that
+    /// face's printed cost (rule 712.12) — or {3} face down (rule 702.37c)
+    /// — plus commander tax as generic mana when it's in the command zone
+    /// (rule 903.8, an additional cost added in 601.2f).
+    pub fn mana_cost_to_cast(&self, card_id: ObjectId, face: CardFace) -> Result<ManaCost, String> {
+        let obj = self.get_object(card_id)?;
+        let mut cost = match face {
+            CardFace::FaceDown(_) => ManaCost::build(&[], 3),
+            _ => obj.face(face).mana_cost.clone().unwrap_or_else(ManaCost::zero),
+        };
+        if obj.zone == Zone::Command {
+            let tax = self.commander_tax(card_id) as usize;
+            cost.symbols.extend(std::iter::repeat_n(ManaSymbol::Generic, tax));
+        }
+        Ok(cost)
+    }
+
+    /// Count a cast of commander `card_id` from the command zone toward its
+    /// tax (rule 903.8).
+    pub(crate) fn record_commander_cast(&mut self, card_id: ObjectId) -> Result<(), String> {
+        let owner = self.get_object(card_id)?.owner;
+        let commander = self.original_identity(card_id);
+        *self.players[owner].commander_casts.entry(commander).or_insert(0) += 1;
+        Ok(())
+    }
+
+    /// Note that `id` was just put into `to`. A commander put into a
+    /// graveyard or exile is remembered for the next state-based action
+    /// check (rule 903.9a).
+    pub(crate) fn note_commander_zone_change(&mut self, id: ObjectId, to: Zone) {
+        let is_commander = self.objects.get(&id).is_some_and(|o| o.is_commander);
+        if is_commander && matches!(to, Zone::Graveyard | Zone::Exile)
+            && !self.commanders_to_return.contains(&id)
+        {
+            self.commanders_to_return.push(id);
+        }
+    }
+
+    /// Move an object to `to`, giving its owner the commander replacement
+    /// when it's a commander headed for a hand or library: they may put it
+    /// into the command zone instead (rule 903.9b). Everything else is a
+    /// plain `change_zone`. Returns the new object it became.
+    pub fn change_zone_with_commander_choice(
+        &mut self,
+        id: ObjectId,
+        to: Zone,
+        decisions: &dyn DecisionProvider,
+    ) -> Result<ObjectId, String> {
+        let obj = self.get_object(id)?;
+        if obj.is_commander && matches!(to, Zone::Hand | Zone::Library) {
+            let owner = obj.owner;
+            if ask_commander_to_command_zone(decisions, self, owner, id, to) {
+                return self.change_zone(id, Zone::Command);
+            }
+        }
+        self.change_zone(id, to)
+    }
+
+    /// State-based action 704.6d: each commander put into a graveyard or
+    /// exile since the last check may be put into the command zone by its
+    /// owner (rule 903.9a). Owners decide in APNAP order. Returns whether any
+    /// commander moved.
+    pub fn return_commanders_to_command_zone(
+        &mut self,
+        decisions: &dyn DecisionProvider,
+    ) -> Result<bool, String> {
+        let mut pending = std::mem::take(&mut self.commanders_to_return);
+        let order = self.apnap_order();
+        pending.retain(|id| self.objects.get(id).is_some_and(|o| {
+            matches!(o.zone, Zone::Graveyard | Zone::Exile) && order.contains(&o.owner)
+        }));
+        pending.sort_by_key(|id| {
+            let owner = self.objects[id].owner;
+            order.iter().position(|&pid| pid == owner)
+        });
+
+        let mut moved = false;
+        for id in pending {
+            let obj = &self.objects[&id];
+            let (owner, zone) = (obj.owner, obj.zone);
+            if ask_commander_to_command_zone(decisions, self, owner, id, zone) {
+                self.change_zone(id, Zone::Command)?;
+                moved = true;
+            }
+        }
+        Ok(moved)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cards::creatures;
+    use crate::objects::object::GameObject;
+    use crate::ui::choice_types::ChoiceKind;
+    use crate::ui::decision::ScriptedDecisionProvider;
+
+    fn setup_with_commander() -> (GameState, ObjectId) {
+        let mut game = GameState::new(2, 40);
+        let obj = GameObject::in_library(creatures::grizzly_bears(), 0);
+        let id = obj.id;
+        game.add_object(obj);
+        game.players[0].library.push(id);
+        let id = game.designate_commander(0, id).unwrap();
+        (game, id)
+    }
+
+    #[test]
+    fn test_designate_commander_moves_to_command_zone() {
+        let (game, id) = setup_with_commander();
+        let obj = game.get_object(id).unwrap();
+        assert!(obj.is_commander);
+        assert_eq!(obj.zone, Zone::Command);
+        assert_eq!(game.command, vec![id]);
+        assert!(game.players[0].library.is_empty());
+        assert!(game.in_castable_zone(id));
+    }
+
+    #[test]
+    fn test_designate_commander_rejects_other_players_card() {
+        let (mut game, id) = setup_with_commander();
+        assert!(game.designate_commander(1, id).is_err());
+    }
+
+    #[test]
+    fn test_commander_tax_grows_by_two_per_cast() {
+        let (mut game, id) = setup_with_commander();
+        assert_eq!(game.commander_tax(id), 0);
+        assert_eq!(game.mana_cost_to_cast(id, CardFace::Front).unwrap().mana_value(), 2);
+
+        game.record_commander_cast(id).unwrap();
+        game.record_commander_cast(id).unwrap();
+        assert_eq!(game.commander_tax(id), 4);
+        assert_eq!(game.mana_cost_to_cast(id, CardFace::Front).unwrap().mana_value(), 6);
+    }
+
+    #[test]
+    fn test_no_tax_outside_command_zone() {
+        let (mut game, id) = setup_with_commander();
+        game.record_commander_cast(id).unwrap();
+        let id = game.change_zone(id, Zone::Hand).unwrap();
+        assert_eq!(game.mana_cost_to_cast(id, CardFace::Front).unwrap().mana_value(), 2);
+    }
+
+    #[test]
+    fn test_hand_replacement_to_command_zone() {
+        let (mut game, id) = setup_with_commander();
+        let id = game.change_zone(id, Zone::Battlefield).unwrap();
+        let dp = ScriptedDecisionProvider::new();
+        dp.expect_pick_n(
+            ChoiceKind::CommanderToCommandZone { commander_id: id, destination: Zone::Hand },
+            vec![0],
+        );
+        let id = game.change_zone_with_commander_choice(id, Zone::Hand, &dp).unwrap();
+        assert_eq!(game.get_object(id).unwrap().zone, Zone::Command);
+        assert!(game.players[0].hand.is_empty());
+    }
+
+    #[test]
+    fn test_graveyard_return_is_optional() {
+        let (mut game, id) = setup_with_commander();
+        let id = game.change_zone(id, Zone::Graveyard).unwrap();
+        assert_eq!(game.commanders_to_return, vec![id]);
+
+        let dp = ScriptedDecisionProvider::new();
+        dp.expect_pick_n(
+            ChoiceKind::CommanderToCommandZone { commander_id: id, destination: Zone::Graveyard },
+            vec![],
+        );
+        assert!(!game.return_commanders_to_command_zone(&dp).unwrap());
+        assert_eq!(game.get_object(id).unwrap().zone, Zone::Graveyard);
+        // Declined: not asked again until it changes zones.
+        assert!(game.commanders_to_return.is_empty());
+        assert!(!game.return_commanders_to_command_zone(&dp).unwrap());
+    }
+}
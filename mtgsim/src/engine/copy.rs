@@ -0,0 +1,152 @@
+//! Copies of spells and abilities on the stack (rule 707.10).
+//!
+//! A copy has the characteristics of what it copies and every choice made
+//! as that was cast or activated — modes, targets, X, the additional and
+//! alternative costs paid — but it wasn't cast or activated itself, so
+//! nothing that triggers on casting sees it. Its controller owns it. Fork
+//! and Twincast copy a target spell; storm, replicate and casualty copy the
+//! spell they're on as it's cast, by a trigger (rules 702.40a, 702.56a,
+//! 702.153a). Each lets the copy's controller choose new targets (rule
+//! 707.10c).
+//!
+//! A copy of a spell ceases to exist once it leaves the stack (SBA
+//! 704.5e), and a copy of a permanent spell becomes a token as it resolves
+//! (rule 111.12).
+
+use crate::engine::cast::spell_effect;
+use crate::events::event::GameEvent;
+use crate::objects::card_data::CardFace;
+use crate::objects::object::GameObject;
+use crate::state::game_state::GameState;
+use crate::types::effects::{AmountExpr, Effect, EffectRecipient, Primitive};
+use crate::types::ids::{ObjectId, PlayerId};
+use crate::types::zones::Zone;
+use crate::ui::decision::DecisionProvider;
+
+impl GameState {
+    /// Put a copy of the spell or ability `original` on the stack under
+    /// `controller`'s control, and return it. The controller may choose new
+    /// targets for it (rule 707.10c).
+    pub fn copy_stack_object(
+        &mut self,
+        original: ObjectId,
+        controller: PlayerId,
+        dp: &dyn DecisionProvider,
+    ) -> Result<ObjectId, String> {
+        let entry = self.stack_entries.get(&original).cloned()
+            .ok_or_else(|| format!("Object {} is not a spell or ability on the stack", original))?;
+        let source = self.get_object(original)?;
+        let mut copy = GameObject::new(source.card_data.clone(), controller, Zone::Stack);
+        copy.is_copy = true;
+        let copy_id = copy.id;
+
+        // "You" on the copy means its controller (rule 109.5), so a spell's
+        // instructions are bound afresh from the card. An ability's stay as
+        // they were put on the stack.
+        let (effect, fused_effect) = if !entry.is_spell {
+            (entry.effect.clone(), None)
+        } else if entry.cast_face == CardFace::Combined {
+            let left = spell_effect(source.face(CardFace::Front), controller)?.0;
+            let right = spell_effect(source.face(CardFace::Back), controller)?.0;
+            (left, Some(right))
+        } else {
+            (spell_effect(source.face(entry.cast_face), controller)?.0, None)
+        };
+        self.objects.insert(copy_id, copy);
+
+        let mut copy_entry = entry;
+        copy_entry.object_id = copy_id;
+        copy_entry.controller = controller;
+        copy_entry.is_copy = true;
+        copy_entry.is_triggered = false;
+        copy_entry.chosen_targets =
+            self.choose_new_targets(controller, copy_id, &effect, &copy_entry.chosen_targets, dp);
+        copy_entry.effect = effect;
+        if let (Some(half), Some(effect)) = (copy_entry.fused_half.as_mut(), fused_effect) {
+            half.chosen_targets = self.choose_new_targets(controller, copy_id, &effect, &half.chosen_targets, dp);
+            half.effect = effect;
+        }
+
+        self.stack.push(copy_id);
+        self.stack_entries.insert(copy_id, copy_entry);
+        self.events.emit(GameEvent::SpellCopied { original, copy: copy_id, controller });
+        Ok(copy_id)
+    }
+
+    /// Queue a "when you cast this spell, copy it" trigger of the spell
+    /// `spell` that copies it `times` times — storm, replicate, casualty.
+    pub(crate) fn queue_copy_trigger(&mut self, controller: PlayerId, spell: ObjectId, times: u64) {
+        if times == 0 {
+            return;
+        }
+        let effect = Effect::Atom(Primitive::CopySpell(AmountExpr::Fixed(times)), EffectRecipient::Implicit);
+        self.queue_trigger(controller, spell, effect);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::engine::resolve::ResolvedTarget;
+    use crate::objects::card_data::{AbilityDef, AbilityType, CardDataBuilder, CardFace};
+    use crate::objects::object::GameObject;
+    use crate::state::game_state::{GameState, StackEntry};
+    use crate::types::card_types::CardType;
+    use crate::types::effects::{AmountExpr, Effect, EffectRecipient, Primitive, SelectionFilter, TargetCount};
+    use crate::types::ids::new_ability_id;
+    use crate::types::zones::Zone;
+    use crate::ui::choice_types::ChoiceKind;
+    use crate::ui::decision::ScriptedDecisionProvider;
+
+    #[test]
+    fn test_copy_keeps_choices_and_can_change_targets() {
+        let mut game = GameState::new(2, 20);
+        let shock = CardDataBuilder::new("Shock")
+            .card_type(CardType::Instant)
+            .ability(AbilityDef {
+                id: new_ability_id(),
+                ability_type: AbilityType::Spell,
+                costs: Vec::new(),
+                effect: Effect::Atom(
+                    Primitive::DealDamage(AmountExpr::Fixed(2)),
+                    EffectRecipient::Target(SelectionFilter::Player, TargetCount::Exactly(1)),
+                ),
+            })
+            .build();
+        let id = game.add_object(GameObject::new(shock, 0, Zone::Stack));
+        game.stack.push(id);
+        game.stack_entries.insert(id, StackEntry {
+            object_id: id,
+            controller: 0,
+            chosen_targets: vec![ResolvedTarget::Player(1)],
+            chosen_modes: Vec::new(),
+            x_value: Some(3),
+            effect: Effect::Sequence(Vec::new()),
+            is_spell: true,
+            ability_source: None,
+            chosen_alternative_cost: None,
+            additional_costs_paid: Vec::new(),
+            cast_face: CardFace::Front,
+            fused_half: None,
+            is_copy: false,
+            is_triggered: false,
+        });
+
+        // Options: [Player(1) (current), Player(0)]
+        let dp = ScriptedDecisionProvider::new();
+        dp.expect_pick_n(
+            ChoiceKind::ChooseNewTargets { recipient: EffectRecipient::Implicit, spell_id: id },
+            vec![1],
+        );
+        let copy = game.copy_stack_object(id, 1, &dp).unwrap();
+
+        assert_eq!(game.stack.last(), Some(&copy));
+        let entry = &game.stack_entries[&copy];
+        assert!(entry.is_copy);
+        assert_eq!(entry.controller, 1);
+        assert_eq!(entry.x_value, Some(3));
+        assert_eq!(entry.chosen_targets, vec![ResolvedTarget::Player(0)]);
+        assert!(game.objects[&copy].is_copy);
+        assert_eq!(game.objects[&copy].owner, 1, "a copy is owned by its controller");
+        assert_eq!(game.stack_entries[&id].chosen_targets, vec![ResolvedTarget::Player(1)]);
+    }
+}
@@ -3,6 +3,7 @@ use std::collections::HashMap;
 use crate::types::costs::{AdditionalCost, AlternativeCost, Cost};
 use crate::oracle::characteristics::{has_summoning_sickness, is_creature};
 use crate::state::game_state::GameState;
+use crate::types::effects::CounterType;
 use crate::types::ids::{ObjectId, PlayerId};
 use crate::types::mana::{ManaCost, ManaSymbol, ManaType};
 
@@ -46,7 +47,7 @@ impl GameState {
     ) -> Result<(), String> {
         match cost {
             Cost::Tap => {
-                let entry = self.battlefield.get(&source_id)
+                let entry = self.permanent(source_id)
                     .ok_or_else(|| format!("Permanent {} not on battlefield", source_id))?;
                 if entry.tapped {
                     return Err("Permanent is already tapped".to_string());
@@ -59,7 +60,7 @@ impl GameState {
                 Ok(())
             }
             Cost::Untap => {
-                let entry = self.battlefield.get(&source_id)
+                let entry = self.permanent(source_id)
                     .ok_or_else(|| format!("Permanent {} not on battlefield", source_id))?;
                 if !entry.tapped {
                     return Err("Permanent is not tapped".to_string());
@@ -89,16 +90,34 @@ impl GameState {
                 Ok(())
             }
             Cost::SacrificeSelf => {
-                if !self.battlefield.contains_key(&source_id) {
+                if !self.is_phased_in(source_id) {
                     return Err(format!("Permanent {} not on battlefield", source_id));
                 }
                 Ok(())
             }
+            Cost::AddCounters(_, _) => {
+                if !self.is_phased_in(source_id) {
+                    return Err(format!("Permanent {} not on battlefield", source_id));
+                }
+                Ok(())
+            }
+            Cost::RemoveCounters(counter_type, n) => {
+                // Rule 606.6 (loyalty) / 118.3: can't remove counters that
+                // aren't there.
+                let entry = self.permanent(source_id)
+                    .ok_or_else(|| format!("Permanent {} not on battlefield", source_id))?;
+                let have = entry.counter_count(*counter_type);
+                if have < *n {
+                    return Err(format!(
+                        "Cannot remove {} {:?} counter(s), only {} present",
+                        n, counter_type, have
+                    ));
+                }
+                Ok(())
+            }
             Cost::Sacrifice(_, _)
             | Cost::Discard(_, _)
-            | Cost::ExileFromGraveyard(_, _)
-            | Cost::RemoveCounters(_, _)
-            | Cost::AddCounters(_, _) => {
+            | Cost::ExileFromGraveyard(_, _) => {
                 Err(format!("Cost {:?} validation not yet implemented", cost))
             }
         }
@@ -135,7 +154,7 @@ impl GameState {
     ) -> Result<(), String> {
         match cost {
             Cost::Tap => {
-                let entry = self.battlefield.get(&source_id)
+                let entry = self.permanent(source_id)
                     .ok_or_else(|| format!("Permanent {} not on battlefield", source_id))?;
                 if entry.tapped {
                     return Err("Permanent is already tapped".to_string());
@@ -150,7 +169,7 @@ impl GameState {
                 Ok(())
             }
             Cost::Untap => {
-                let entry = self.battlefield.get(&source_id)
+                let entry = self.permanent(source_id)
                     .ok_or_else(|| format!("Permanent {} not on battlefield", source_id))?;
                 if !entry.tapped {
                     return Err("Permanent is not tapped".to_string());
@@ -181,16 +200,36 @@ impl GameState {
                     ));
                 }
                 player.life_total -= *amount as i64;
+                self.sync_team_life(player_id);
                 Ok(())
             }
             Cost::SacrificeSelf => {
-                self.change_zone(source_id, crate::types::zones::Zone::Graveyard)
+                self.change_zone(source_id, crate::types::zones::Zone::Graveyard)?;
+                Ok(())
+            }
+            Cost::AddCounters(counter_type, n) => {
+                let entry = self.battlefield.get_mut(&source_id)
+                    .ok_or_else(|| format!("Permanent {} not on battlefield", source_id))?;
+                if *counter_type == CounterType::Lore {
+                    // Lore counters can trigger chapter abilities (rule 714.2b)
+                    self.add_lore_counters(source_id, *n);
+                } else {
+                    entry.add_counters(*counter_type, *n);
+                }
+                Ok(())
+            }
+            Cost::RemoveCounters(counter_type, n) => {
+                let entry = self.battlefield.get_mut(&source_id)
+                    .ok_or_else(|| format!("Permanent {} not on battlefield", source_id))?;
+                if entry.counter_count(*counter_type) < *n {
+                    return Err(format!("Cannot remove {} {:?} counter(s)", n, counter_type));
+                }
+                entry.remove_counters(*counter_type, *n);
+                Ok(())
             }
             Cost::Sacrifice(_, _)
             | Cost::Discard(_, _)
-            | Cost::ExileFromGraveyard(_, _)
-            | Cost::RemoveCounters(_, _)
-            | Cost::AddCounters(_, _) => {
+            | Cost::ExileFromGraveyard(_, _) => {
                 Err(format!("Cost {:?} payment not yet implemented", cost))
             }
         }
@@ -243,6 +282,49 @@ pub fn assemble_total_cost(
     apply_cost_modifications(base_costs)
 }
 
+/// Combine every mana component of `costs` into one, placed where the first
+/// was: the total cost is paid as a single mana payment (rule 601.2f), so a
+/// generic allocation covers the base cost, kicker and replicate together.
+pub(crate) fn combine_mana_costs(costs: Vec<Cost>) -> Vec<Cost> {
+    let mut combined: Vec<Cost> = Vec::with_capacity(costs.len());
+    let mut mana_at = None;
+    for cost in costs {
+        match (cost, mana_at) {
+            (Cost::Mana(extra), Some(i)) => {
+                if let Cost::Mana(total) = &mut combined[i] {
+                    total.symbols.extend(extra.symbols);
+                    total.symbols.sort_by_key(|s| !matches!(s, ManaSymbol::Generic | ManaSymbol::X));
+                }
+            }
+            (Cost::Mana(mana), None) => {
+                mana_at = Some(combined.len());
+                combined.push(Cost::Mana(mana));
+            }
+            (other, _) => combined.push(other),
+        }
+    }
+    combined
+}
+
+/// `cost` with up to `n` of its generic mana removed. A reduction never
+/// touches colored mana (rule 601.2f); emerge, convoke, improvise and delve
+/// all pay for generic mana this way.
+pub(crate) fn reduce_generic(cost: &ManaCost, n: usize) -> ManaCost {
+    let mut removed = 0;
+    let symbols = cost.symbols.iter()
+        .filter(|s| {
+            if removed < n && matches!(s, ManaSymbol::Generic) {
+                removed += 1;
+                false
+            } else {
+                true
+            }
+        })
+        .copied()
+        .collect();
+    ManaCost::from_symbols(symbols)
+}
+
 /// Cost modification pipeline stub (rule 601.2f).
 ///
 /// In the full implementation (L15, Phase 5 Layers), this applies:
@@ -545,6 +627,25 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_combine_mana_costs_merges_kicker_into_base() {
+        use crate::types::costs::AdditionalCost;
+        let base = ManaCost::build(&[ManaType::Red], 1);
+        let kicker = AdditionalCost::Kicker(vec![
+            Cost::PayLife(2),
+            Cost::Mana(ManaCost::build(&[ManaType::Red], 1)),
+        ]);
+        let result = combine_mana_costs(assemble_total_cost(&base, None, &[&kicker], 0));
+        assert_eq!(result.len(), 2);
+        if let Cost::Mana(mc) = &result[0] {
+            assert_eq!(mc.generic_count(), 2);
+            assert_eq!(mc.colored_count(ManaType::Red), 2);
+        } else {
+            panic!("Expected Cost::Mana first");
+        }
+        assert_eq!(result[1], Cost::PayLife(2));
+    }
+
     #[test]
     fn test_assemble_alt_plus_additional() {
         use crate::types::costs::{AlternativeCost, AdditionalCost};
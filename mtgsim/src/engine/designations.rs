@@ -0,0 +1,149 @@
+//! The monarch (rule 724) and the initiative (rule 725).
+//!
+//! Each is a designation at most one player has at a time. Both come with
+//! inherent triggered abilities that have no source (rules 724.2, 725.2);
+//! they're queued with `queue_designation_trigger` like any other trigger:
+//! - The monarch draws a card at the beginning of their end step, and a
+//!   creature that deals combat damage to the monarch makes its controller
+//!   the monarch.
+//! - A player who takes the initiative ventures into Undercity, and so does
+//!   the player who has it at the beginning of their upkeep. Creatures
+//!   dealing combat damage to that player take it for their controller.
+//!
+//! If the player with either designation leaves the game, it passes to the
+//! active player, or to the next player in turn order if the active player
+//! is the one leaving (rules 724.4, 725.4).
+
+use crate::events::event::GameEvent;
+use crate::state::game_state::{Designation, GameState, StepType, TriggerSource};
+use crate::types::card_types::DungeonType;
+use crate::types::effects::{AmountExpr, Effect, EffectRecipient, Primitive};
+use crate::types::ids::{ObjectId, PlayerId};
+
+impl GameState {
+    /// `player` becomes the monarch (rule 724.1).
+    pub fn become_monarch(&mut self, player: PlayerId) {
+        if self.monarch == Some(player) {
+            return;
+        }
+        self.monarch = Some(player);
+        self.events.emit(GameEvent::BecameMonarch { player });
+    }
+
+    /// `player` takes the initiative (rule 725.1), and ventures into
+    /// Undercity as it triggers (rule 725.2) — even if they already had it.
+    pub fn take_initiative(&mut self, player: PlayerId) {
+        self.initiative = Some(player);
+        self.events.emit(GameEvent::TookInitiative { player });
+        self.queue_designation_trigger(player, Designation::Initiative, venture_into_undercity());
+    }
+
+    /// Queue the designations' inherent abilities that trigger at the
+    /// beginning of `step`: the monarch's end-step draw (rule 724.2) and
+    /// the initiative's upkeep venture (rule 725.2).
+    pub(crate) fn queue_designation_step_triggers(&mut self, step: StepType) {
+        let active = self.active_players();
+        match step {
+            StepType::Upkeep => {
+                if let Some(player) = self.initiative.filter(|p| active.contains(p)) {
+                    self.queue_designation_trigger(player, Designation::Initiative, venture_into_undercity());
+                }
+            }
+            StepType::End => {
+                if let Some(player) = self.monarch.filter(|p| active.contains(p)) {
+                    let draw = Effect::Atom(
+                        Primitive::DrawCards(AmountExpr::Fixed(1)),
+                        EffectRecipient::Controller,
+                    );
+                    self.queue_designation_trigger(player, Designation::Monarch, draw);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// `source` dealt combat damage to `player`: queue the designations'
+    /// steal triggers for the source's controller (rules 724.2, 725.2). The
+    /// initiative's triggers once per player however many of their
+    /// creatures dealt damage.
+    pub(crate) fn designation_combat_damage_triggers(&mut self, source: ObjectId, player: PlayerId) {
+        let Some(controller) = self.permanent(source).map(|entry| entry.controller) else {
+            return;
+        };
+        if self.monarch == Some(player) {
+            let effect = Effect::Atom(Primitive::BecomeMonarch, EffectRecipient::Controller);
+            self.queue_designation_trigger(controller, Designation::Monarch, effect);
+        }
+        let already = self.pending_triggers.iter().any(|t| {
+            t.controller == controller
+                && t.source == TriggerSource::Designation(Designation::Initiative)
+                && t.effect == Effect::Atom(Primitive::TakeInitiative, EffectRecipient::Controller)
+        });
+        if self.initiative == Some(player) && !already {
+            let effect = Effect::Atom(Primitive::TakeInitiative, EffectRecipient::Controller);
+            self.queue_designation_trigger(controller, Designation::Initiative, effect);
+        }
+    }
+
+    /// `leaving` is leaving the game: pass on the designations they have
+    /// (rules 724.4, 725.4).
+    pub(crate) fn pass_designations_from(&mut self, leaving: PlayerId) {
+        let heir = if self.active_player != leaving && self.is_in_game(self.active_player) {
+            Some(self.active_player)
+        } else {
+            self.next_turn_player_after(leaving)
+        };
+        if self.monarch == Some(leaving) {
+            self.monarch = None;
+            if let Some(heir) = heir {
+                self.become_monarch(heir);
+            }
+        }
+        if self.initiative == Some(leaving) {
+            self.initiative = None;
+            if let Some(heir) = heir {
+                self.take_initiative(heir);
+            }
+        }
+    }
+}
+
+fn venture_into_undercity() -> Effect {
+    Effect::Atom(Primitive::VentureInto(DungeonType::Undercity), EffectRecipient::Controller)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::state::game_state::{GameState, StepType, TriggerSource, Designation};
+
+    #[test]
+    fn test_monarch_draws_at_the_beginning_of_their_end_step() {
+        let mut game = GameState::new(2, 20);
+        game.queue_designation_step_triggers(StepType::End);
+        assert!(game.pending_triggers.is_empty(), "nobody is the monarch");
+
+        game.become_monarch(1);
+        game.queue_designation_step_triggers(StepType::End);
+        assert!(game.pending_triggers.is_empty(), "only on the monarch's own turn");
+
+        game.become_monarch(0);
+        game.queue_designation_step_triggers(StepType::End);
+        assert_eq!(game.pending_triggers.len(), 1);
+        assert_eq!(game.pending_triggers[0].source, TriggerSource::Designation(Designation::Monarch));
+    }
+
+    #[test]
+    fn test_designations_pass_to_the_active_player_when_their_holder_leaves() {
+        let mut game = GameState::new(3, 20);
+        game.become_monarch(2);
+        game.initiative = Some(2);
+        game.player_lost[2] = true;
+        game.pass_designations_from(2);
+        assert_eq!(game.monarch, Some(0));
+        assert_eq!(game.initiative, Some(0));
+
+        game.player_lost[0] = true;
+        game.pass_designations_from(0);
+        assert_eq!(game.monarch, Some(1), "the active player is leaving, so the next player");
+    }
+}
@@ -0,0 +1,209 @@
+//! Double-faced cards (rule 712) and day/night (rule 726).
+//!
+//! A double-faced card's `CardData` is its front face; `back_face` holds the
+//! other one. Which face is up is zone state: `StackEntry::cast_face` for a
+//! spell, `BattlefieldEntity::transformed` for a permanent, and the front
+//! face everywhere else (rule 712.8a). `GameState::face_up_data` reads it,
+//! and the layer system starts from that face's characteristics.
+//!
+//! Transforming isn't a zone change. The permanent stays the same object —
+//! counters, attachments, damage and control carry over — but it gets a new
+//! timestamp (rule 613.7f), and the static abilities of the face now up
+//! replace those of the face now down.
+//!
+//! Modal DFCs never transform (rule 701.28c); instead their owner picks a
+//! face to cast or play (rule 712.12), and a permanent spell or land enters
+//! with that face up (rule 712.14).
+
+use crate::events::event::GameEvent;
+use crate::objects::card_data::{CardFace, DoubleFacedKind};
+use crate::oracle::characteristics::has_keyword;
+use crate::state::game_state::{DayNight, GameState};
+use crate::types::ids::{ObjectId, PlayerId};
+use crate::types::keywords::KeywordAbility;
+use crate::ui::ask::ask_choose_face;
+use crate::ui::decision::DecisionProvider;
+
+impl GameState {
+    /// Transform `id` (rule 701.28a). Returns whether it transformed: only a
+    /// phased-in transforming double-faced permanent can (rule 701.28c), and
+    /// a daybound or nightbound one only as day and night change (rule
+    /// 702.145).
+    pub fn transform(&mut self, id: ObjectId) -> bool {
+        if has_keyword(self, id, KeywordAbility::Daybound)
+            || has_keyword(self, id, KeywordAbility::Nightbound)
+        {
+            return false;
+        }
+        self.turn_over(id)
+    }
+
+    fn turn_over(&mut self, id: ObjectId) -> bool {
+        let transforms = self.is_phased_in(id)
+            && self.objects.get(&id)
+                .is_some_and(|obj| obj.card_data.is_double_faced(DoubleFacedKind::Transforming));
+        if !transforms {
+            return false;
+        }
+
+        let timestamp = self.allocate_timestamp();
+        let entry = self.battlefield.get_mut(&id).unwrap();
+        entry.transformed = !entry.transformed;
+        entry.timestamp = timestamp;
+        let controller = entry.controller;
+
+        self.continuous_effects.remove_static_by_source(id);
+        self.register_static_effects(id, controller);
+        self.events.emit(GameEvent::Transformed { object_id: id });
+        true
+    }
+
+    /// Which of `faces` `player` casts or plays `card_id` as (rule 712.12).
+    /// Only a modal DFC offers a choice; everything else has one face, and a
+    /// card with no usable face at all is tried front face up so the caller
+    /// reports why it can't be used.
+    pub(crate) fn choose_face(
+        &self,
+        player: PlayerId,
+        card_id: ObjectId,
+        faces: &[CardFace],
+        decisions: &dyn DecisionProvider,
+    ) -> CardFace {
+        if faces.is_empty() {
+            return CardFace::Front;
+        }
+        ask_choose_face(decisions, self, player, card_id, faces)
+    }
+
+    /// Day/night as a permanent enters (rule 702.145): a daybound permanent
+    /// enters transformed if it's night, and if it's neither day nor night,
+    /// a daybound permanent makes it day and a nightbound one makes it
+    /// night.
+    pub(crate) fn apply_day_night_on_entry(&mut self, id: ObjectId) {
+        if has_keyword(self, id, KeywordAbility::Daybound) {
+            match self.day_night {
+                Some(DayNight::Night) => {
+                    if self.objects[&id].card_data.is_double_faced(DoubleFacedKind::Transforming) {
+                        self.battlefield.get_mut(&id).unwrap().transformed = true;
+                    }
+                }
+                Some(DayNight::Day) => {}
+                None => self.set_day_night(DayNight::Day),
+            }
+        } else if has_keyword(self, id, KeywordAbility::Nightbound) && self.day_night.is_none() {
+            self.set_day_night(DayNight::Night);
+        }
+    }
+
+    /// Day/night as a turn begins (rule 726.3a): day becomes night if the
+    /// previous turn's active player cast no spells during it, and night
+    /// becomes day if they cast two or more.
+    pub(crate) fn update_day_night(&mut self) {
+        let spells = std::mem::take(&mut self.active_player_spells_this_turn);
+        match self.day_night {
+            Some(DayNight::Day) if spells == 0 => self.set_day_night(DayNight::Night),
+            Some(DayNight::Night) if spells >= 2 => self.set_day_night(DayNight::Day),
+            _ => {}
+        }
+    }
+
+    /// Make it day or night (rule 726). As it becomes night, each daybound
+    /// permanent transforms; as it becomes day, each nightbound permanent
+    /// does (rule 702.145).
+    pub fn set_day_night(&mut self, day_night: DayNight) {
+        if self.day_night == Some(day_night) {
+            return;
+        }
+        self.day_night = Some(day_night);
+        self.events.emit(GameEvent::DayNightChanged { day_night });
+
+        let keyword = match day_night {
+            DayNight::Night => KeywordAbility::Daybound,
+            DayNight::Day => KeywordAbility::Nightbound,
+        };
+        let mut turning: Vec<ObjectId> = self.permanents()
+            .map(|(&id, _)| id)
+            .filter(|&id| has_keyword(self, id, keyword))
+            .collect();
+        turning.sort();
+        for id in turning {
+            self.turn_over(id);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::objects::card_data::CardDataBuilder;
+    use crate::objects::object::GameObject;
+    use crate::state::game_state::{DayNight, GameState};
+    use crate::types::card_types::*;
+    use crate::types::ids::ObjectId;
+    use crate::types::keywords::KeywordAbility;
+    use crate::types::zones::Zone;
+
+    fn werewolf() -> std::sync::Arc<crate::objects::card_data::CardData> {
+        let back = CardDataBuilder::new("Night Side")
+            .card_type(CardType::Creature)
+            .power_toughness(4, 4)
+            .keyword(KeywordAbility::Nightbound)
+            .build();
+        CardDataBuilder::new("Day Side")
+            .card_type(CardType::Creature)
+            .power_toughness(2, 2)
+            .keyword(KeywordAbility::Daybound)
+            .transforms_into(back)
+            .build()
+    }
+
+    fn put(game: &mut GameState, card: std::sync::Arc<crate::objects::card_data::CardData>) -> ObjectId {
+        let id = game.add_object(GameObject::new(card, 0, Zone::Battlefield));
+        game.place_on_battlefield(id, 0);
+        id
+    }
+
+    #[test]
+    fn test_daybound_permanent_makes_it_day() {
+        let mut game = GameState::new(2, 20);
+        assert_eq!(game.day_night, None);
+        let id = put(&mut game, werewolf());
+        assert_eq!(game.day_night, Some(DayNight::Day));
+        assert!(!game.battlefield[&id].transformed);
+    }
+
+    #[test]
+    fn test_daybound_permanent_enters_transformed_at_night() {
+        let mut game = GameState::new(2, 20);
+        game.set_day_night(DayNight::Night);
+        let id = put(&mut game, werewolf());
+        assert!(game.battlefield[&id].transformed);
+        assert_eq!(game.face_up_data(id).unwrap().name, "Night Side");
+    }
+
+    #[test]
+    fn test_day_night_changes_follow_spells_cast() {
+        let mut game = GameState::new(2, 20);
+        let id = put(&mut game, werewolf());
+
+        game.update_day_night();
+        assert_eq!(game.day_night, Some(DayNight::Night));
+        assert!(game.battlefield[&id].transformed);
+
+        game.active_player_spells_this_turn = 1;
+        game.update_day_night();
+        assert_eq!(game.day_night, Some(DayNight::Night), "one spell isn't enough");
+
+        game.active_player_spells_this_turn = 2;
+        game.update_day_night();
+        assert_eq!(game.day_night, Some(DayNight::Day));
+        assert!(!game.battlefield[&id].transformed);
+    }
+
+    #[test]
+    fn test_daybound_permanent_ignores_other_transform_effects() {
+        let mut game = GameState::new(2, 20);
+        let id = put(&mut game, werewolf());
+        assert!(!game.transform(id));
+        assert!(!game.battlefield[&id].transformed);
+    }
+}
@@ -0,0 +1,157 @@
+//! Venturing into the dungeon (rule 701.49) and completing dungeons (rule
+//! 309.7).
+//!
+//! A player has at most one dungeon in the command zone, tracked with the
+//! room their venture marker is in (`GameState::venture_markers`). Each
+//! venture either puts a new dungeon there with the marker in its topmost
+//! room, or moves the marker along an arrow to a room the player chooses.
+//! Entering a room triggers its room ability (rule 309.4c).
+//!
+//! With the marker in the bottommost room and no room ability of the
+//! dungeon left to resolve, the dungeon is completed: state-based actions
+//! remove it from the game (SBA 704.5t), as does venturing again (rule
+//! 701.49c).
+
+use crate::events::event::GameEvent;
+use crate::objects::object::GameObject;
+use crate::state::game_state::GameState;
+use crate::types::card_types::{DungeonType, Subtype};
+use crate::types::ids::{ObjectId, PlayerId};
+use crate::types::zones::Zone;
+use crate::ui::ask::{ask_choose_dungeon, ask_choose_dungeon_room};
+use crate::ui::decision::DecisionProvider;
+
+impl GameState {
+    /// The dungeon `player` has in the command zone, if any.
+    pub fn dungeon_of(&self, player: PlayerId) -> Option<ObjectId> {
+        self.venture_markers.keys()
+            .copied()
+            .find(|id| self.objects.get(id).is_some_and(|obj| obj.owner == player))
+    }
+
+    /// Which dungeon `id` is.
+    pub fn dungeon_type(&self, id: ObjectId) -> Option<DungeonType> {
+        self.objects.get(&id)?.card_data.subtypes.iter().find_map(|subtype| match subtype {
+            Subtype::Dungeon(kind) => Some(*kind),
+            _ => None,
+        })
+    }
+
+    /// `player` ventures into the dungeon (rule 701.49a), or into `into`
+    /// specifically — Undercity, for the initiative (rule 701.49c). Moving
+    /// on from a bottommost room completes that dungeon first.
+    pub fn venture(
+        &mut self,
+        player: PlayerId,
+        into: Option<DungeonType>,
+        decisions: &dyn DecisionProvider,
+    ) -> Result<(), String> {
+        if let Some(id) = self.dungeon_of(player) {
+            let kind = self.dungeon_type(id).ok_or("venture marker on a non-dungeon")?;
+            let rooms = kind.rooms();
+            let current = self.venture_markers[&id];
+            if !rooms[current].leads_to.is_empty() {
+                let next = ask_choose_dungeon_room(
+                    decisions, self, player, id, &rooms[current].leads_to,
+                );
+                self.enter_room(player, id, next);
+                return Ok(());
+            }
+            self.complete_dungeon(id)?;
+        }
+
+        let kind = match into {
+            Some(kind) => kind,
+            None => ask_choose_dungeon(decisions, self, player, DungeonType::VENTURE_CHOICES),
+        };
+        let id = self.add_object(GameObject::new(kind.card(), player, Zone::Command));
+        self.command.push(id);
+        self.enter_room(player, id, 0);
+        Ok(())
+    }
+
+    /// Move `player`'s venture marker into `room` of dungeon `id`, and
+    /// trigger the room's ability (rule 309.4c).
+    fn enter_room(&mut self, player: PlayerId, id: ObjectId, room: usize) {
+        let Some(kind) = self.dungeon_type(id) else { return };
+        let room_def = kind.rooms().swap_remove(room);
+        self.venture_markers.insert(id, room);
+        self.queue_trigger(player, id, room_def.effect.bind_controller(player));
+        self.events.emit(GameEvent::VenturedInto {
+            player,
+            dungeon_id: id,
+            room: room_def.name.to_string(),
+        });
+    }
+
+    /// Whether dungeon `id` is complete (rule 309.7): its venture marker is
+    /// on the bottommost room, and none of its room abilities has triggered
+    /// without leaving the stack.
+    pub(crate) fn dungeon_is_complete(&self, id: ObjectId) -> bool {
+        let (Some(&room), Some(kind)) = (self.venture_markers.get(&id), self.dungeon_type(id)) else {
+            return false;
+        };
+        kind.rooms()[room].leads_to.is_empty() && !self.has_unresolved_trigger_from(id)
+    }
+
+    /// Complete dungeon `id`: its owner removes it from the game (rule
+    /// 309.7), and has completed it from then on.
+    pub(crate) fn complete_dungeon(&mut self, id: ObjectId) -> Result<(), String> {
+        let kind = self.dungeon_type(id).ok_or("not a dungeon")?;
+        let owner = self.get_object(id)?.owner;
+        self.remove_from_zone_collection(id, Zone::Command)?;
+        self.objects.remove(&id);
+        self.venture_markers.remove(&id);
+        self.players[owner].completed_dungeons.push(kind);
+        self.events.emit(GameEvent::DungeonCompleted { player: owner, dungeon: kind });
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::state::game_state::GameState;
+    use crate::types::card_types::DungeonType;
+    use crate::ui::choice_types::ChoiceKind;
+    use crate::ui::decision::ScriptedDecisionProvider;
+
+    #[test]
+    fn test_venture_walks_the_chosen_path_then_completes() {
+        let mut game = GameState::new(2, 20);
+        let dp = ScriptedDecisionProvider::new();
+        game.venture(0, Some(DungeonType::LostMineOfPhandelver), &dp).unwrap();
+        let id = game.dungeon_of(0).unwrap();
+        assert_eq!(game.venture_markers[&id], 0);
+
+        dp.expect_pick_n(ChoiceKind::ChooseDungeonRoom { dungeon_id: id }, vec![1]);
+        game.venture(0, None, &dp).unwrap();
+        assert_eq!(game.venture_markers[&id], 2, "Mine Tunnels");
+
+        dp.expect_pick_n(ChoiceKind::ChooseDungeonRoom { dungeon_id: id }, vec![0]);
+        game.venture(0, None, &dp).unwrap();
+        game.venture(0, None, &dp).unwrap();
+        assert_eq!(game.venture_markers[&id], 6, "Temple of Dumathoin");
+        assert!(!game.dungeon_is_complete(id), "room abilities still pending");
+
+        game.pending_triggers.clear();
+        assert!(game.dungeon_is_complete(id));
+        game.venture(0, None, &dp).unwrap();
+        assert_eq!(game.players[0].completed_dungeons, vec![DungeonType::LostMineOfPhandelver]);
+        assert!(!game.objects.contains_key(&id));
+        assert_ne!(game.dungeon_of(0), Some(id));
+    }
+
+    #[test]
+    fn test_room_trigger_outlives_its_completed_dungeon() {
+        let mut game = GameState::new(2, 20);
+        let dp = ScriptedDecisionProvider::new();
+        game.venture(0, Some(DungeonType::LostMineOfPhandelver), &dp).unwrap();
+        let id = game.dungeon_of(0).unwrap();
+        let name = game.objects[&id].card_data.name.clone();
+
+        game.complete_dungeon(id).unwrap();
+        assert!(game.put_pending_triggers_on_stack(&dp).unwrap());
+        let ability = game.stack.last().unwrap();
+        assert_eq!(game.objects[ability].card_data.name, name);
+    }
+}
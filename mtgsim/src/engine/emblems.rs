@@ -0,0 +1,97 @@
+//! Emblems (rule 114).
+//!
+//! An emblem is an object in the command zone with abilities and nothing
+//! else — no name, types or mana cost (rule 114.3). Planeswalker ultimates
+//! are the usual source. Its static abilities register continuous effects
+//! like a permanent's do, with the timestamp it got as it was created
+//! (rule 613.7e); its triggered abilities trigger through `fire_triggers`.
+//! Nothing can make an emblem leave the command zone (rule 114.4), so
+//! neither ever stops.
+
+use crate::events::event::GameEvent;
+use crate::objects::card_data::{AbilityDef, CardDataBuilder};
+use crate::objects::object::GameObject;
+use crate::state::game_state::GameState;
+use crate::types::ids::{ObjectId, PlayerId};
+use crate::types::zones::Zone;
+
+impl GameState {
+    /// Put an emblem with `abilities` into the command zone under
+    /// `player`'s control (rule 114.2), and return it.
+    pub fn create_emblem(&mut self, player: PlayerId, abilities: Vec<AbilityDef>) -> ObjectId {
+        let mut builder = CardDataBuilder::new("");
+        for ability in abilities {
+            builder = builder.ability(ability);
+        }
+        let id = self.add_object(GameObject::new(builder.build(), player, Zone::Command));
+        self.command.push(id);
+        let timestamp = self.allocate_timestamp();
+        self.emblems.insert(id, timestamp);
+        self.register_static_effects(id, player);
+        self.events.emit(GameEvent::EmblemCreated { emblem_id: id, controller: player });
+        id
+    }
+
+    /// The emblems `player` controls — the ones they own, since an emblem's
+    /// controller never changes (rule 114.2) — in the order they were
+    /// created.
+    pub fn emblems_of(&self, player: PlayerId) -> Vec<ObjectId> {
+        let mut emblems: Vec<(u64, ObjectId)> = self.emblems.iter()
+            .filter(|(id, _)| self.objects.get(id).is_some_and(|obj| obj.owner == player))
+            .map(|(&id, &timestamp)| (timestamp, id))
+            .collect();
+        emblems.sort();
+        emblems.into_iter().map(|(_, id)| id).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::objects::card_data::{AbilityDef, AbilityType, CardDataBuilder};
+    use crate::objects::object::GameObject;
+    use crate::oracle::characteristics::get_effective_power;
+    use crate::state::game_state::GameState;
+    use crate::types::card_types::CardType;
+    use crate::types::effects::*;
+    use crate::types::ids::new_ability_id;
+    use crate::types::zones::Zone;
+
+    fn anthem() -> AbilityDef {
+        AbilityDef {
+            id: new_ability_id(),
+            ability_type: AbilityType::Static,
+            costs: Vec::new(),
+            effect: Effect::Atom(
+                Primitive::ModifyPowerToughness(
+                    AmountExpr::Fixed(1),
+                    AmountExpr::Fixed(1),
+                    Duration::WhileSourceOnBattlefield,
+                ),
+                EffectRecipient::FilteredPermanents(PermanentFilter::And(
+                    Box::new(PermanentFilter::ByType(CardType::Creature)),
+                    Box::new(PermanentFilter::ByController(PlayerRef::You)),
+                )),
+            ),
+        }
+    }
+
+    #[test]
+    fn test_emblem_static_ability_applies_from_the_command_zone() {
+        let mut game = GameState::new(2, 20);
+        let bear = CardDataBuilder::new("Bear")
+            .card_type(CardType::Creature)
+            .power_toughness(2, 2)
+            .build();
+        let ours = game.add_object(GameObject::new(bear.clone(), 0, Zone::Battlefield));
+        game.place_on_battlefield(ours, 0);
+        let theirs = game.add_object(GameObject::new(bear, 1, Zone::Battlefield));
+        game.place_on_battlefield(theirs, 1);
+
+        let emblem = game.create_emblem(0, vec![anthem()]);
+        assert_eq!(game.objects[&emblem].zone, Zone::Command);
+        assert_eq!(game.emblems_of(0), vec![emblem]);
+        assert!(game.emblems_of(1).is_empty());
+        assert_eq!(get_effective_power(&game, ours), Some(3));
+        assert_eq!(get_effective_power(&game, theirs), Some(2));
+    }
+}
@@ -0,0 +1,193 @@
+//! Face-down spells and permanents (rule 708): morph and megamorph (rule
+//! 702.37), disguise (rule 702.168), manifest (rule 701.40) and cloak (rule
+//! 701.58).
+//!
+//! Face down is zone state, like which face of a double-faced card is up: a
+//! spell cast face down has `StackEntry::cast_face` `FaceDown`, and a
+//! face-down permanent has `BattlefieldEntity::face_down` set. Either way
+//! `GameState::face_up` reports `CardFace::FaceDown`, and the layer system
+//! starts from the 2/2 that `face_down_characteristics` describes. In every
+//! other zone the card is face up again, so a face-down spell or permanent
+//! that leaves is revealed (rule 708.9) without anything else happening.
+//!
+//! What a face-down object really is, is hidden information (rule 708.5):
+//! its controller may look at it, and nobody else may until it's turned
+//! face up. `is_hidden_from` is what the display layer asks.
+
+use crate::events::event::GameEvent;
+use crate::objects::card_data::{CardFace, FaceDownKind, MorphKind};
+use crate::state::game_state::GameState;
+use crate::types::card_types::CardType;
+use crate::types::costs::Cost;
+use crate::types::effects::CounterType;
+use crate::types::ids::{ObjectId, PlayerId};
+use crate::types::mana::ManaCost;
+use crate::types::zones::Zone;
+use crate::ui::decision::DecisionProvider;
+
+impl GameState {
+    /// Manifest (rule 701.40a) or cloak (rule 701.58a) the top card of
+    /// `player`'s library: put it onto the battlefield face down. Returns
+    /// the permanent, or None if the library is empty.
+    pub fn manifest_top_card(
+        &mut self,
+        player: PlayerId,
+        kind: FaceDownKind,
+    ) -> Result<Option<ObjectId>, String> {
+        let Some(&card_id) = self.get_player(player)?.library.last() else {
+            return Ok(None);
+        };
+        let id = self.move_object_as(card_id, Zone::Battlefield, CardFace::FaceDown(kind))?;
+        Ok(Some(id))
+    }
+
+    /// The mana cost to turn face-down permanent `id` face up: its morph,
+    /// megamorph or disguise cost (rules 702.37e, 702.168d), or — if it was
+    /// manifested or cloaked and is a creature card — its mana cost (rules
+    /// 701.40b, 701.58b). None if it isn't face down, or is a manifested
+    /// noncreature card with no way to turn itself face up.
+    pub fn face_up_cost(&self, id: ObjectId) -> Option<ManaCost> {
+        let kind = self.permanent(id)?.face_down?;
+        let card = &self.objects.get(&id)?.card_data;
+        if let Some(morph) = &card.morph {
+            return Some(morph.cost.clone());
+        }
+        let manifested = matches!(kind, FaceDownKind::Manifest | FaceDownKind::Cloak);
+        (manifested && card.types.contains(&CardType::Creature))
+            .then(|| card.mana_cost.clone().unwrap_or_else(ManaCost::zero))
+    }
+
+    /// The face-down permanents `player` controls that they could turn face
+    /// up, in a stable order.
+    pub fn turnable_face_up(&self, player: PlayerId) -> Vec<ObjectId> {
+        let mut ids: Vec<ObjectId> = self.permanents()
+            .filter(|(_, entry)| entry.controller == player)
+            .map(|(&id, _)| id)
+            .filter(|&id| self.face_up_cost(id).is_some())
+            .collect();
+        ids.sort();
+        ids
+    }
+
+    /// Turn face-down permanent `id` face up by paying `face_up_cost`. This
+    /// is a special action (rule 116.2b): it doesn't use the stack, and
+    /// `player` may take it any time they have priority (rule 702.37e).
+    pub fn turn_face_up(
+        &mut self,
+        player: PlayerId,
+        id: ObjectId,
+        decisions: &dyn DecisionProvider,
+    ) -> Result<(), String> {
+        let entry = self.permanent(id)
+            .ok_or_else(|| format!("Permanent {} not on battlefield", id))?;
+        if entry.controller != player {
+            return Err("Can only turn face up permanents you control".to_string());
+        }
+        let cost = self.face_up_cost(id)
+            .ok_or_else(|| format!("Permanent {} can't be turned face up", id))?;
+
+        let costs = vec![Cost::Mana(cost)];
+        self.run_mana_ability_window(player, id, &costs, decisions);
+        self.can_pay_costs(&costs, player, id)?;
+        let generic_allocation = self.choose_generic_allocation(&costs, player, decisions);
+        self.pay_costs(&costs, player, id, &generic_allocation)?;
+
+        self.flip_face_up(id);
+        Ok(())
+    }
+
+    /// Turn face-down permanent `id` face up (rule 708.8), at no cost.
+    /// Returns whether it was face down. It stays the same object, but gets
+    /// a new timestamp (rule 613.7f), and a megamorph card gets its +1/+1
+    /// counter (rule 702.37b).
+    pub fn flip_face_up(&mut self, id: ObjectId) -> bool {
+        if self.permanent(id).is_none_or(|entry| entry.face_down.is_none()) {
+            return false;
+        }
+        let megamorph = self.objects[&id].card_data.morph.as_ref()
+            .is_some_and(|morph| morph.kind == MorphKind::Megamorph);
+
+        let timestamp = self.allocate_timestamp();
+        let entry = self.battlefield.get_mut(&id).unwrap();
+        entry.face_down = None;
+        entry.timestamp = timestamp;
+        if megamorph {
+            entry.add_counters(CounterType::PlusOnePlusOne, 1);
+        }
+        let controller = entry.controller;
+
+        self.continuous_effects.remove_static_by_source(id);
+        self.register_static_effects(id, controller);
+        self.events.emit(GameEvent::TurnedFaceUp { object_id: id });
+        true
+    }
+
+    /// Whether what `id` really is is hidden from `viewer`: it's a face-down
+    /// spell or permanent that someone else controls (rule 708.5).
+    pub fn is_hidden_from(&self, id: ObjectId, viewer: PlayerId) -> bool {
+        if !matches!(self.face_up(id), CardFace::FaceDown(_)) {
+            return false;
+        }
+        let controller = self.battlefield.get(&id).map(|entry| entry.controller)
+            .or_else(|| self.stack_entries.get(&id).map(|entry| entry.controller));
+        controller != Some(viewer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use crate::objects::card_data::{CardData, CardDataBuilder, CardFace, FaceDownKind};
+    use crate::objects::object::GameObject;
+    use crate::oracle::characteristics::{get_effective_power, has_keyword};
+    use crate::state::game_state::GameState;
+    use crate::types::card_types::CardType;
+    use crate::types::ids::ObjectId;
+    use crate::types::keywords::KeywordAbility;
+    use crate::types::mana::{ManaCost, ManaType};
+    use crate::types::zones::Zone;
+
+    fn on_top_of_library(game: &mut GameState, card: Arc<CardData>) -> ObjectId {
+        let id = game.add_object(GameObject::new(card, 0, Zone::Library));
+        game.players[0].library.push(id);
+        id
+    }
+
+    #[test]
+    fn test_manifested_card_is_a_nameless_2_2() {
+        let mut game = GameState::new(2, 20);
+        let sorcery = CardDataBuilder::new("Secret Sorcery").card_type(CardType::Sorcery).build();
+        let card = on_top_of_library(&mut game, sorcery);
+
+        let id = game.manifest_top_card(0, FaceDownKind::Manifest).unwrap().unwrap();
+        assert_eq!(game.previous_identity(id), Some(card));
+        assert_eq!(game.face_up(id), CardFace::FaceDown(FaceDownKind::Manifest));
+        assert_eq!(game.face_up_data(id).unwrap().name, "");
+        assert_eq!(get_effective_power(&game, id), Some(2));
+        assert!(game.is_hidden_from(id, 1));
+        assert!(!game.is_hidden_from(id, 0), "its controller may look at it");
+        assert_eq!(game.face_up_cost(id), None, "a noncreature card can't be turned face up");
+    }
+
+    #[test]
+    fn test_cloaked_card_has_ward_until_turned_face_up() {
+        let mut game = GameState::new(2, 20);
+        let bear = CardDataBuilder::new("Bear")
+            .card_type(CardType::Creature)
+            .mana_cost(ManaCost::build(&[ManaType::Green], 1))
+            .power_toughness(2, 2)
+            .build();
+        on_top_of_library(&mut game, bear);
+        let id = game.manifest_top_card(0, FaceDownKind::Cloak).unwrap().unwrap();
+        assert!(has_keyword(&game, id, KeywordAbility::Ward));
+        assert_eq!(game.face_up_cost(id).unwrap().mana_value(), 2);
+
+        let timestamp = game.battlefield[&id].timestamp;
+        assert!(game.flip_face_up(id));
+        assert_eq!(game.face_up_data(id).unwrap().name, "Bear");
+        assert!(!has_keyword(&game, id, KeywordAbility::Ward));
+        assert!(game.battlefield[&id].timestamp > timestamp, "new timestamp (rule 613.7f)");
+        assert!(!game.flip_face_up(id));
+    }
+}
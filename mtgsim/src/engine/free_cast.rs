@@ -0,0 +1,134 @@
+//! Casting spells without paying their mana costs (rule 118.9).
+//!
+//! Cascade and discover exile cards from the top of a library until a
+//! nonland card with low enough mana value, which the player may then cast
+//! for free while the ability resolves (rules 702.85a, 701.57a) — from
+//! exile, and a sorcery as readily as an instant. Omniscience's static
+//! ability instead offers "without paying its mana cost" as an alternative
+//! cost for each spell cast from hand. Either way `cast_spell_with` does the
+//! casting, with what a `CastPermission` allows.
+
+use rand::seq::SliceRandom;
+
+use crate::engine::cast::CastPermission;
+use crate::objects::card_data::AbilityType;
+use crate::oracle::board::permanents_controlled_by;
+use crate::oracle::characteristics::{get_effective_abilities, get_mana_value, has_type};
+use crate::state::game_state::GameState;
+use crate::types::card_types::CardType;
+use crate::types::effects::{AmountExpr, Effect, EffectRecipient, Primitive};
+use crate::types::ids::{ObjectId, PlayerId};
+use crate::types::zones::Zone;
+use crate::ui::ask::ask_cast_without_paying;
+use crate::ui::decision::DecisionProvider;
+
+impl GameState {
+    /// Whether `player` controls a permanent that lets them cast spells
+    /// from their hand without paying their mana costs (Omniscience).
+    pub fn may_cast_from_hand_without_paying(&self, player: PlayerId) -> bool {
+        permanents_controlled_by(self, player).into_iter().any(|id| {
+            get_effective_abilities(self, id).iter().any(|ability| {
+                ability.ability_type == AbilityType::Static
+                    && matches!(ability.effect, Effect::Atom(Primitive::MayCastFromHandWithoutPaying, _))
+            })
+        })
+    }
+
+    /// Cascade, for a spell with mana value `mana_value` (rule 702.85a).
+    /// Returns the spell `player` cast, if they cast one.
+    pub fn cascade(
+        &mut self,
+        player: PlayerId,
+        mana_value: u64,
+        dp: &dyn DecisionProvider,
+    ) -> Result<Option<ObjectId>, String> {
+        let (found, mut rest) = self.exile_until_nonland(player, |mv| mv < mana_value)?;
+        let mut cast = None;
+        if let Some(card) = found {
+            cast = self.offer_free_cast(player, card, CastPermission::free_from(Zone::Exile), dp);
+            if cast.is_none() {
+                rest.push(card);
+            }
+        }
+        self.put_on_bottom_in_random_order(rest)?;
+        Ok(cast)
+    }
+
+    /// Discover `n` (rule 701.57a). A card that isn't cast goes to
+    /// `player`'s hand. Returns the spell they cast, if they cast one.
+    pub fn discover(
+        &mut self,
+        player: PlayerId,
+        n: u64,
+        dp: &dyn DecisionProvider,
+    ) -> Result<Option<ObjectId>, String> {
+        let (found, rest) = self.exile_until_nonland(player, |mv| mv <= n)?;
+        let mut cast = None;
+        if let Some(card) = found {
+            cast = self.offer_free_cast(player, card, CastPermission::free_from(Zone::Exile), dp);
+            if cast.is_none() {
+                self.change_zone(card, Zone::Hand)?;
+            }
+        }
+        self.put_on_bottom_in_random_order(rest)?;
+        Ok(cast)
+    }
+
+    /// Queue the cascade trigger of `spell`, a spell with mana value
+    /// `mana_value` (rule 702.85a).
+    pub(crate) fn queue_cascade_trigger(&mut self, controller: PlayerId, spell: ObjectId, mana_value: u32) {
+        let effect = Effect::Atom(
+            Primitive::Cascade(AmountExpr::Fixed(mana_value as u64)),
+            EffectRecipient::Implicit,
+        );
+        self.queue_trigger(controller, spell, effect);
+    }
+
+    /// Let `player` cast `card` without paying its mana cost, as
+    /// `permission` allows. If there's no face it could be cast as, it isn't
+    /// offered; if they decline, or can't — it has no legal targets, say —
+    /// it stays where it is.
+    pub(crate) fn offer_free_cast(
+        &mut self,
+        player: PlayerId,
+        card: ObjectId,
+        permission: CastPermission,
+        dp: &dyn DecisionProvider,
+    ) -> Option<ObjectId> {
+        if self.faces_castable_with(card, &permission).is_empty()
+            || !ask_cast_without_paying(dp, self, player, card)
+        {
+            return None;
+        }
+        self.cast_spell_with(player, card, permission, dp).ok()
+    }
+
+    /// Exile cards from the top of `player`'s library one at a time until
+    /// a nonland card whose mana value `fits`. Returns that card, if one
+    /// turned up, and the others exiled on the way.
+    fn exile_until_nonland(
+        &mut self,
+        player: PlayerId,
+        fits: impl Fn(u64) -> bool,
+    ) -> Result<(Option<ObjectId>, Vec<ObjectId>), String> {
+        let mut passed = Vec::new();
+        while let Some(&top) = self.get_player(player)?.library.last() {
+            let card = self.change_zone(top, Zone::Exile)?;
+            if !has_type(self, card, CardType::Land) && fits(get_mana_value(self, card) as u64) {
+                return Ok((Some(card), passed));
+            }
+            passed.push(card);
+        }
+        Ok((None, passed))
+    }
+
+    /// Put `cards` on the bottom of their owners' libraries in a random
+    /// order.
+    fn put_on_bottom_in_random_order(&mut self, mut cards: Vec<ObjectId>) -> Result<(), String> {
+        cards.shuffle(&mut rand::rng());
+        for card in cards {
+            self.put_on_bottom_of_library(card)?;
+        }
+        Ok(())
+    }
+}
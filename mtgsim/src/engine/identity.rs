@@ -0,0 +1,117 @@
+//! Object identity across zone changes (rule 400.7).
+//!
+//! An object that moves from one zone to another becomes a new object with
+//! no memory of or relation to its previous existence. Every zone change
+//! gives the moving card a fresh `ObjectId`, so whatever still names the
+//! old one — a "target creature gets +3/+3 until end of turn" effect, a
+//! spell targeting it, an Aura attached to it — stops finding it.
+//!
+//! The rules make exceptions (rules 400.7a–j): last known information
+//! (rule 608.2h) stays recorded under the old id, and an effect that moves
+//! an object can go on to find the new object it became ("exile it, then
+//! return it to the battlefield"). For those, each new id keeps a lineage
+//! link back to the one it replaced.
+//!
+//! The only zone change that doesn't make a new object is one being
+//! reversed: a spell whose casting turned out to be illegal goes back to
+//! where it came from as the object it was (rule 732.1b).
+
+use crate::state::game_state::GameState;
+use crate::types::ids::{new_object_id, ObjectId};
+
+impl GameState {
+    /// Give the object `old` a fresh identity as it changes zones, and
+    /// return it. Called between taking it out of its old zone and putting
+    /// it into its new one.
+    pub(crate) fn renew_identity(&mut self, old: ObjectId) -> ObjectId {
+        self.reassign_identity(old, new_object_id())
+    }
+
+    /// Undo `current`'s last zone change: it becomes `previous` again, as
+    /// if it had never moved (rule 732.1b).
+    pub(crate) fn revert_identity(&mut self, current: ObjectId, previous: ObjectId) -> ObjectId {
+        let earlier = self.previous_identities.get(&previous).copied();
+        let id = self.reassign_identity(current, previous);
+        self.next_identities.remove(&current);
+        self.next_identities.remove(&previous);
+        self.previous_identities.remove(&current);
+        match earlier {
+            Some(earlier) => self.previous_identities.insert(previous, earlier),
+            None => self.previous_identities.remove(&previous),
+        };
+        id
+    }
+
+    fn reassign_identity(&mut self, old: ObjectId, new: ObjectId) -> ObjectId {
+        let Some(mut obj) = self.objects.remove(&old) else {
+            return old;
+        };
+        obj.id = new;
+        self.objects.insert(new, obj);
+        self.previous_identities.insert(new, old);
+        self.next_identities.insert(old, new);
+        if self.on_adventure.remove(&old) {
+            self.on_adventure.insert(new);
+        }
+        new
+    }
+
+    /// The object `id` has become: `id` itself if it hasn't changed zones
+    /// since, otherwise the identity it has in the zone it's in now.
+    pub fn current_identity(&self, id: ObjectId) -> ObjectId {
+        let mut current = id;
+        while let Some(&next) = self.next_identities.get(&current) {
+            current = next;
+        }
+        current
+    }
+
+    /// The identity `id` had before its last zone change, if it's changed
+    /// zones.
+    pub fn previous_identity(&self, id: ObjectId) -> Option<ObjectId> {
+        self.previous_identities.get(&id).copied()
+    }
+
+    /// The identity the card `id` had when it was first put into the game.
+    /// Things that follow a card rather than an object — commander tax and
+    /// commander damage (rules 903.8, 903.10a) — are keyed by it.
+    pub fn original_identity(&self, id: ObjectId) -> ObjectId {
+        let mut original = id;
+        while let Some(&previous) = self.previous_identities.get(&original) {
+            original = previous;
+        }
+        original
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::objects::card_data::CardDataBuilder;
+    use crate::objects::object::GameObject;
+    use crate::state::game_state::GameState;
+    use crate::types::card_types::CardType;
+    use crate::types::zones::Zone;
+
+    #[test]
+    fn test_each_zone_change_makes_a_new_object() {
+        let mut game = GameState::new(2, 20);
+        let bear = CardDataBuilder::new("Bear")
+            .card_type(CardType::Creature)
+            .power_toughness(2, 2)
+            .build();
+        let id = game.add_object(GameObject::new(bear, 0, Zone::Battlefield));
+        game.place_on_battlefield(id, 0);
+
+        let in_graveyard = game.move_object(id, Zone::Graveyard).unwrap();
+        let returned = game.move_object(in_graveyard, Zone::Battlefield).unwrap();
+        assert_ne!(in_graveyard, id);
+        assert_ne!(returned, in_graveyard);
+        assert!(!game.objects.contains_key(&id));
+        assert_eq!(game.objects[&returned].id, returned);
+        assert!(game.battlefield.contains_key(&returned));
+
+        assert_eq!(game.previous_identity(returned), Some(in_graveyard));
+        assert_eq!(game.current_identity(id), returned);
+        assert_eq!(game.original_identity(returned), id);
+    }
+}
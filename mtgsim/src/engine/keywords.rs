@@ -1,31 +1,128 @@
 // Non-combat keyword ability hooks.
 //
 // These functions handle keyword behaviors that trigger during damage
-// resolution (lifelink, deathtouch) rather than during combat damage
-// assignment. Called from perform_action in actions.rs.
+// resolution (infect, wither, toxic, lifelink, deathtouch) rather than during
+// combat damage assignment. Called from perform_action in actions.rs, so every
+// damage source — combat, spells, abilities — goes through them.
 
 use crate::events::event::{DamageTarget, GameEvent};
-use crate::oracle::characteristics::has_keyword;
+use crate::oracle::characteristics::{get_toxic_value, has_keyword, has_type, is_creature};
 use crate::state::game_state::GameState;
+use crate::types::card_types::CardType;
+use crate::types::effects::CounterType;
 use crate::types::ids::ObjectId;
 use crate::types::keywords::KeywordAbility;
 
+/// Apply the results of `amount` damage from `source` to `target` (rule 120.3).
+///
+/// - Player: loses that much life (120.3a), or gets that many poison
+///   counters if the source has infect (120.3b).
+/// - Planeswalker: loses that many loyalty counters (120.3c).
+/// - Battle: loses that many defense counters (120.3h).
+/// - Creature: gets that many -1/-1 counters if the source has wither or
+///   infect (120.3d), otherwise the damage is marked on it (120.3e).
+///
+/// A permanent with several of these types gets each result. Returns the
+/// player-facing event (`LifeChanged` or `PoisonCountersAdded`) for the
+/// caller to emit after `DamageDealt`.
+pub fn apply_damage_results(
+    game: &mut GameState,
+    source: ObjectId,
+    target: &DamageTarget,
+    amount: u64,
+) -> Result<Option<GameEvent>, String> {
+    let has_infect = has_keyword(game, source, KeywordAbility::Infect);
+    match target {
+        DamageTarget::Object(id) => {
+            if !game.battlefield.contains_key(id) {
+                return Err(format!("Target object {} not on battlefield", id));
+            }
+            let is_planeswalker = has_type(game, *id, CardType::Planeswalker);
+            let is_battle = has_type(game, *id, CardType::Battle);
+            let is_creature = is_creature(game, *id);
+            let as_counters = has_infect || has_keyword(game, source, KeywordAbility::Wither);
+            let entry = game.battlefield.get_mut(id).unwrap();
+            if is_planeswalker {
+                entry.remove_counters(CounterType::Loyalty, amount as u32);
+            }
+            if is_battle {
+                entry.remove_counters(CounterType::Defense, amount as u32);
+            }
+            if is_creature && as_counters {
+                entry.add_counters(CounterType::MinusOneMinusOne, amount as u32);
+            } else if is_creature || !(is_planeswalker || is_battle) {
+                entry.damage_marked += amount as u32;
+            }
+            Ok(None)
+        }
+        DamageTarget::Player(pid) => {
+            let player = game.get_player_mut(*pid)?;
+            if has_infect {
+                player.poison_counters += amount as u32;
+                return Ok(Some(GameEvent::PoisonCountersAdded {
+                    player_id: *pid,
+                    amount: amount as u32,
+                    source,
+                }));
+            }
+            let old = player.life_total;
+            player.life_total -= amount as i64;
+            let new = player.life_total;
+            game.sync_team_life(*pid);
+            Ok(Some(GameEvent::LifeChanged {
+                player_id: *pid,
+                old,
+                new,
+                source: Some(source),
+            }))
+        }
+    }
+}
+
+/// Apply toxic: combat damage dealt to a player by a creature with toxic
+/// gives that player poison counters equal to the creature's total toxic
+/// value, in addition to the damage's other results (rule 702.164c).
+///
+/// Emits a `PoisonCountersAdded` event when counters are added.
+pub fn apply_toxic(
+    game: &mut GameState,
+    source: ObjectId,
+    target: &DamageTarget,
+    is_combat: bool,
+) -> Result<(), String> {
+    let DamageTarget::Player(pid) = target else {
+        return Ok(());
+    };
+    if !is_combat {
+        return Ok(());
+    }
+    let toxic = get_toxic_value(game, source);
+    if toxic == 0 {
+        return Ok(());
+    }
+    game.get_player_mut(*pid)?.poison_counters += toxic;
+    game.events.emit(GameEvent::PoisonCountersAdded { player_id: *pid, amount: toxic, source });
+    Ok(())
+}
+
 /// Apply the deathtouch flag to a damage target if the source has deathtouch.
 ///
 /// Rule 702.2b: Any nonzero damage dealt by a source with deathtouch is
 /// considered lethal for SBA purposes. We mark the target's
-/// `damaged_by_deathtouch` flag, which is checked in SBA 704.5g and
-/// cleared during cleanup (rule 514.2).
+/// `damaged_by_deathtouch` flag, which is checked in SBA 704.5h and
+/// cleared during cleanup (rule 514.2). The flag alone records the damage:
+/// wither or infect damage leaves none marked (rule 120.3d).
 ///
-/// Returns Ok(()) always; the flag is only set if the target is on the
+/// The flag is only set if `amount` is nonzero and the target is on the
 /// battlefield.
 pub fn apply_deathtouch_flag(
     game: &mut GameState,
     source: ObjectId,
     target: &DamageTarget,
+    amount: u64,
 ) {
     // Pre-check before mutable borrow (borrow checker: has_keyword reads objects)
-    if !has_keyword(game, source, KeywordAbility::Deathtouch) {
+    if amount == 0 || !has_keyword(game, source, KeywordAbility::Deathtouch) {
         return;
     }
     if let DamageTarget::Object(id) = target {
@@ -56,6 +153,7 @@ pub fn apply_lifelink(
         let p = game.get_player_mut(controller)?;
         p.life_total += amount as i64;
         let new_life = p.life_total;
+        game.sync_team_life(controller);
         game.events.emit(GameEvent::LifeChanged {
             player_id: controller,
             old: old_life,
@@ -101,7 +199,7 @@ mod tests {
         let source = setup_creature(&mut game, &[KeywordAbility::Deathtouch]);
         let target = setup_creature(&mut game, &[]);
 
-        apply_deathtouch_flag(&mut game, source, &DamageTarget::Object(target));
+        apply_deathtouch_flag(&mut game, source, &DamageTarget::Object(target), 1);
         assert!(game.battlefield.get(&target).unwrap().damaged_by_deathtouch);
     }
 
@@ -111,7 +209,7 @@ mod tests {
         let source = setup_creature(&mut game, &[]); // no deathtouch
         let target = setup_creature(&mut game, &[]);
 
-        apply_deathtouch_flag(&mut game, source, &DamageTarget::Object(target));
+        apply_deathtouch_flag(&mut game, source, &DamageTarget::Object(target), 1);
         assert!(!game.battlefield.get(&target).unwrap().damaged_by_deathtouch);
     }
 
@@ -121,7 +219,7 @@ mod tests {
         let source = setup_creature(&mut game, &[KeywordAbility::Deathtouch]);
 
         // Should not panic or error — just does nothing for player targets
-        apply_deathtouch_flag(&mut game, source, &DamageTarget::Player(1));
+        apply_deathtouch_flag(&mut game, source, &DamageTarget::Player(1), 1);
     }
 
     // --- Lifelink tests ---
@@ -152,4 +250,77 @@ mod tests {
         apply_lifelink(&mut game, source, 2).unwrap();
         assert_eq!(game.events.len(), 1);
     }
+
+    // --- Damage results (infect, wither, toxic) ---
+
+    #[test]
+    fn test_plain_damage_marks_creature_and_costs_life() {
+        let mut game = GameState::new(2, 20);
+        let source = setup_creature(&mut game, &[]);
+        let target = setup_creature(&mut game, &[]);
+
+        assert!(apply_damage_results(&mut game, source, &DamageTarget::Object(target), 2)
+            .unwrap().is_none());
+        assert_eq!(game.battlefield.get(&target).unwrap().damage_marked, 2);
+
+        let event = apply_damage_results(&mut game, source, &DamageTarget::Player(1), 2).unwrap();
+        assert!(matches!(event, Some(GameEvent::LifeChanged { player_id: 1, old: 20, new: 18, .. })));
+        assert_eq!(game.players[1].life_total, 18);
+    }
+
+    #[test]
+    fn test_infect_damage_is_counters_and_poison() {
+        let mut game = GameState::new(2, 20);
+        let source = setup_creature(&mut game, &[KeywordAbility::Infect]);
+        let target = setup_creature(&mut game, &[]);
+
+        apply_damage_results(&mut game, source, &DamageTarget::Object(target), 2).unwrap();
+        let entry = game.battlefield.get(&target).unwrap();
+        assert_eq!(entry.damage_marked, 0);
+        assert_eq!(entry.counter_count(CounterType::MinusOneMinusOne), 2);
+
+        let event = apply_damage_results(&mut game, source, &DamageTarget::Player(1), 2).unwrap();
+        assert!(matches!(event, Some(GameEvent::PoisonCountersAdded { player_id: 1, amount: 2, .. })));
+        assert_eq!(game.players[1].life_total, 20);
+        assert_eq!(game.players[1].poison_counters, 2);
+    }
+
+    #[test]
+    fn test_wither_damage_is_counters_but_players_lose_life() {
+        let mut game = GameState::new(2, 20);
+        let source = setup_creature(&mut game, &[KeywordAbility::Wither]);
+        let target = setup_creature(&mut game, &[]);
+
+        apply_damage_results(&mut game, source, &DamageTarget::Object(target), 1).unwrap();
+        let entry = game.battlefield.get(&target).unwrap();
+        assert_eq!(entry.damage_marked, 0);
+        assert_eq!(entry.counter_count(CounterType::MinusOneMinusOne), 1);
+
+        apply_damage_results(&mut game, source, &DamageTarget::Player(1), 1).unwrap();
+        assert_eq!(game.players[1].life_total, 19);
+        assert_eq!(game.players[1].poison_counters, 0);
+    }
+
+    #[test]
+    fn test_toxic_only_on_combat_damage_to_players() {
+        let mut game = GameState::new(2, 20);
+        let source = setup_creature(&mut game, &[KeywordAbility::Toxic(2)]);
+        let target = setup_creature(&mut game, &[]);
+
+        apply_toxic(&mut game, source, &DamageTarget::Player(1), false).unwrap();
+        apply_toxic(&mut game, source, &DamageTarget::Object(target), true).unwrap();
+        assert_eq!(game.players[1].poison_counters, 0);
+
+        apply_toxic(&mut game, source, &DamageTarget::Player(1), true).unwrap();
+        assert_eq!(game.players[1].poison_counters, 2);
+    }
+
+    #[test]
+    fn test_toxic_values_are_cumulative() {
+        let mut game = GameState::new(2, 20);
+        let source = setup_creature(&mut game, &[KeywordAbility::Toxic(1), KeywordAbility::Toxic(2)]);
+
+        apply_toxic(&mut game, source, &DamageTarget::Player(1), true).unwrap();
+        assert_eq!(game.players[1].poison_counters, 3);
+    }
 }
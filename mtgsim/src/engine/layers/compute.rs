@@ -88,9 +88,10 @@ fn compute_to_ceiling(
     }
 
     let obj = game.objects.get(&id)?;
-    let card = &obj.card_data;
+    let card = obj.face(game.face_up(id));
 
-    // Start from printed (base) characteristics
+    // Start from printed (base) characteristics of the face that's up
+    // (rule 712.8)
     let mut chars = EffectiveCharacteristics {
         name: card.name.clone(),
         mana_cost: card.mana_cost.clone(),
@@ -132,6 +133,10 @@ fn compute_to_ceiling(
 /// already happened and cannot be taken back, so there is no ability to go
 /// looking for.
 ///
+/// A phased-out source is treated as though it doesn't exist (CR 702.26b),
+/// so none of its static abilities do either. The registry rows stay put —
+/// phasing isn't leaving the battlefield — and apply again once it phases in.
+///
 /// Existence is not the same as surviving, and only existence is decided here.
 /// An instant that grants first strike until end of turn creates an effect that
 /// exists for the turn no matter what — but Humility, applying later in layer 6,
@@ -148,6 +153,9 @@ fn static_ability_still_exists(
         EffectOrigin::Resolution => return true,
         EffectOrigin::StaticAbility { ability } => ability,
     };
+    if game.battlefield.get(&effect.source).is_some_and(|e| e.phased_out) {
+        return false;
+    }
 
     match compute_to_ceiling(game, effect.source, layer_index, cache) {
         Some(source_frame) => source_frame.abilities.iter().any(|a| a.id == ability_id),
@@ -202,6 +210,18 @@ fn apply_effects(
             break;
         }
 
+        // Rule 702.151b: a permanent with reconfigure that's attached to a
+        // creature isn't a creature and loses its creature subtypes. The
+        // keyword is read from the layer-4 frame, so only printed (or
+        // copied) reconfigure counts — granting it in layer 6 comes too late.
+        if layer == Layer::Layer4Type
+            && chars.keywords.contains(&crate::types::keywords::KeywordAbility::Reconfigure)
+            && game.battlefield.get(&id).is_some_and(|e| e.attached_to.is_some())
+        {
+            chars.types.remove(&crate::types::card_types::CardType::Creature);
+            chars.subtypes.retain(|s| !matches!(s, crate::types::card_types::Subtype::Creature(_)));
+        }
+
         // Apply registered effects in this layer
         if has_registered {
             let effects = game.continuous_effects.effects_in_layer(layer);
@@ -255,9 +275,12 @@ fn effect_applies_to(
     match &effect.affected {
         AffectedSet::SourceOnly => effect.source == id,
         AffectedSet::Fixed(ids) => ids.contains(&id),
+        AffectedSet::AttachedTo => {
+            game.battlefield.get(&effect.source).and_then(|e| e.attached_to) == Some(id)
+        }
         AffectedSet::Filter { filter, controller } => {
-            // Object must be on the battlefield for filter-based effects
-            if !game.battlefield.contains_key(&id) {
+            // Object must be a phased-in permanent for filter-based effects
+            if !game.is_phased_in(id) {
                 return false;
             }
             // Check controller constraint
@@ -273,7 +296,7 @@ fn effect_applies_to(
 }
 
 /// Check if a permanent's current characteristics match a filter.
-fn permanent_matches_filter(
+pub(crate) fn permanent_matches_filter(
     filter: &crate::types::effects::PermanentFilter,
     chars: &EffectiveCharacteristics,
 ) -> bool {
@@ -123,6 +123,10 @@ pub enum AffectedSet {
     /// A fixed set captured at effect creation time.
     /// Pump spells use this — the target is locked at resolution.
     Fixed(Vec<ObjectId>),
+    /// Whatever the source is currently attached to ("equipped creature gets
+    /// +2/+0"). Read from the source's `attached_to` on every computation, so
+    /// the effect moves with the Equipment or Aura (rule 301.5, 303.4).
+    AttachedTo,
 }
 
 /// CR-level identity of a continuous effect, for CR 613.6.
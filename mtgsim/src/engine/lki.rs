@@ -0,0 +1,133 @@
+//! Last known information (rule 608.2h).
+//!
+//! As an object leaves the battlefield or the stack, its characteristics
+//! (through every layer), controller and counters are recorded under the
+//! `ObjectId` it had there. Effects that refer to it afterwards — "its
+//! power", "its controller", "if it was a creature" — read the record
+//! instead of the card it's become in its new zone, and so do its
+//! leaves-the-battlefield abilities, which "look back in time" (rule
+//! 603.10a).
+//!
+//! A record is replaced the next time the same object leaves either zone,
+//! and otherwise kept for the rest of the game: a delayed trigger can need
+//! it turns later.
+
+use crate::engine::layers::types::EffectiveCharacteristics;
+use crate::objects::card_data::TriggerEvent;
+use crate::oracle::characteristics::{get_effective_power, get_effective_toughness};
+use crate::state::game_state::{GameState, LastKnownInformation};
+use crate::types::card_types::CardType;
+use crate::types::ids::{ObjectId, PlayerId};
+use crate::types::zones::Zone;
+
+impl GameState {
+    /// Record `id`'s last known information as it leaves `zone` (the
+    /// battlefield or the stack), controlled by `controller`. Called before
+    /// anything about its departure is cleaned up, so its static abilities
+    /// and the effects on it still apply.
+    pub(crate) fn record_last_known_information(&mut self, id: ObjectId, zone: Zone, controller: PlayerId) {
+        let Some(characteristics) = crate::engine::layers::compute_characteristics(self, id) else {
+            return;
+        };
+        let counters = self.battlefield.get(&id)
+            .map(|entry| entry.counters.clone())
+            .unwrap_or_default();
+        self.last_known.insert(id, LastKnownInformation { zone, characteristics, controller, counters });
+    }
+
+    /// `id`'s last known information, if it has left the battlefield or
+    /// the stack.
+    pub fn last_known_information(&self, id: ObjectId) -> Option<&LastKnownInformation> {
+        self.last_known.get(&id)
+    }
+
+    /// `id`'s characteristics as a permanent or spell: its current ones
+    /// while it's on the battlefield or the stack, its last known ones once
+    /// it's left (rule 608.2h). None if it's never been in either zone.
+    pub fn characteristics_or_last_known(&self, id: ObjectId) -> Option<EffectiveCharacteristics> {
+        if self.is_phased_in(id) || self.stack_entries.contains_key(&id) {
+            return crate::engine::layers::compute_characteristics(self, id);
+        }
+        self.last_known.get(&id).map(|lki| lki.characteristics.clone())
+    }
+
+    /// `id`'s power, or its last known power once it's left the battlefield.
+    pub fn power_or_last_known(&self, id: ObjectId) -> Option<i32> {
+        if self.is_phased_in(id) {
+            return get_effective_power(self, id);
+        }
+        self.last_known.get(&id)?.characteristics.power
+    }
+
+    /// `id`'s toughness, or its last known toughness once it's left the
+    /// battlefield.
+    pub fn toughness_or_last_known(&self, id: ObjectId) -> Option<i32> {
+        if self.is_phased_in(id) {
+            return get_effective_toughness(self, id);
+        }
+        self.last_known.get(&id)?.characteristics.toughness
+    }
+
+    /// Who controls `id` — or, once it's left the battlefield or the stack,
+    /// who last controlled it there.
+    pub fn controller_or_last_known(&self, id: ObjectId) -> Option<PlayerId> {
+        if let Some(entry) = self.battlefield.get(&id) {
+            return Some(entry.controller);
+        }
+        if let Some(entry) = self.stack_entries.get(&id) {
+            return Some(entry.controller);
+        }
+        self.last_known.get(&id).map(|lki| lki.controller)
+    }
+
+    /// `id` just died: queue its "when this creature dies" abilities — the
+    /// ones it had as it last existed on the battlefield, controlled by its
+    /// last controller there (rule 603.10a).
+    pub(crate) fn fire_dies_triggers(&mut self, id: ObjectId) {
+        let Some(lki) = self.last_known.get(&id).filter(|lki| lki.zone == Zone::Battlefield) else {
+            return;
+        };
+        if !lki.characteristics.types.contains(&CardType::Creature) {
+            return;
+        }
+        let controller = lki.controller;
+        let effects: Vec<_> = lki.characteristics.abilities.iter()
+            .filter(|ability| ability.trigger_event() == Some(TriggerEvent::Dies))
+            .map(|ability| ability.effect.clone())
+            .collect();
+        for effect in effects {
+            self.queue_trigger(controller, id, effect.bind_controller(controller));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::objects::card_data::CardDataBuilder;
+    use crate::objects::object::GameObject;
+    use crate::state::game_state::GameState;
+    use crate::types::card_types::CardType;
+    use crate::types::effects::CounterType;
+    use crate::types::zones::Zone;
+
+    #[test]
+    fn test_leaving_the_battlefield_records_last_known_information() {
+        let mut game = GameState::new(2, 20);
+        let bear = CardDataBuilder::new("Bear")
+            .card_type(CardType::Creature)
+            .power_toughness(2, 2)
+            .build();
+        let id = game.add_object(GameObject::new(bear, 0, Zone::Battlefield));
+        game.place_on_battlefield(id, 1);
+        game.battlefield.get_mut(&id).unwrap().add_counters(CounterType::PlusOnePlusOne, 1);
+        assert!(game.last_known_information(id).is_none());
+
+        game.move_object(id, Zone::Graveyard).unwrap();
+        let lki = game.last_known_information(id).unwrap();
+        assert_eq!(lki.zone, Zone::Battlefield);
+        assert_eq!(lki.controller, 1);
+        assert_eq!(lki.counters.get(&CounterType::PlusOnePlusOne), Some(&1));
+        assert_eq!(game.power_or_last_known(id), Some(3));
+        assert_eq!(game.controller_or_last_known(id), Some(1));
+    }
+}
@@ -0,0 +1,264 @@
+//! Paying for a spell's mana with something other than mana.
+//!
+//! Convoke taps creatures, each paying for one generic mana or one mana of
+//! its colors (rule 702.51a); improvise taps artifacts and delve exiles cards
+//! from the graveyard, each paying for one generic mana (rules 702.126a,
+//! 702.66a). None of them changes the total cost (rules 702.51b, 702.66b,
+//! 702.126b): they're chosen in the 601.2g window once it's locked in,
+//! before any mana abilities, which then only need to cover what's left.
+//! The permanents are tapped right away, so none of them can also be tapped
+//! for mana, and the cards are exiled as the rest is paid.
+//!
+//! Emerge is different — an alternative cost whose total is reduced by the
+//! mana value of the creature sacrificed for it (rule 702.119a). `cast.rs`
+//! handles it with `reduce_generic`.
+
+use std::collections::HashSet;
+
+use crate::engine::costs::reduce_generic;
+use crate::oracle::board::permanents_controlled_by;
+use crate::oracle::characteristics::{get_effective_colors, has_keyword, has_type};
+use crate::state::game_state::GameState;
+use crate::types::card_types::CardType;
+use crate::types::costs::Cost;
+use crate::types::ids::{ObjectId, PlayerId};
+use crate::types::keywords::KeywordAbility;
+use crate::types::mana::{ManaCost, ManaSymbol, ManaType};
+use crate::types::zones::Zone;
+use crate::ui::ask::{ask_convoke, ask_delve, ask_improvise};
+use crate::ui::decision::DecisionProvider;
+
+/// What a player chose to pay part of a spell's mana cost with.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ManaSubstitutes {
+    /// Creatures tapped for convoke.
+    pub convoked: Vec<ObjectId>,
+    /// Artifacts tapped for improvise.
+    pub improvised: Vec<ObjectId>,
+    /// Cards exiled from the graveyard for delve.
+    pub delved: Vec<ObjectId>,
+}
+
+impl GameState {
+    /// Let `player` choose what to convoke, improvise and delve for `spell`,
+    /// as its keywords allow. Returns the choices and `total_costs` with the
+    /// mana they pay for taken out of its mana component. Err if the chosen
+    /// creatures can't all pay for something.
+    pub(crate) fn choose_mana_substitutes(
+        &self,
+        player: PlayerId,
+        spell: ObjectId,
+        total_costs: Vec<Cost>,
+        dp: &dyn DecisionProvider,
+    ) -> Result<(ManaSubstitutes, Vec<Cost>), String> {
+        let mut substitutes = ManaSubstitutes::default();
+        let Some(mut cost) = total_costs.iter().find_map(|c| match c {
+            Cost::Mana(mana) => Some(mana.clone()),
+            _ => None,
+        }) else {
+            return Ok((substitutes, total_costs));
+        };
+
+        if has_keyword(self, spell, KeywordAbility::Convoke) {
+            let candidates = self.untapped_of_type(player, CardType::Creature, &HashSet::new());
+            let chosen = ask_convoke(dp, self, player, spell, &cost, &candidates, cost.symbols.len());
+            let colors: Vec<Vec<ManaType>> = chosen.iter().map(|&id| self.convoke_colors(id)).collect();
+            let (rest, unused) = convoke_payment(&cost, &colors);
+            if unused > 0 {
+                return Err("A convoked creature has nothing left to pay for".to_string());
+            }
+            cost = rest;
+            substitutes.convoked = chosen;
+        }
+        if has_keyword(self, spell, KeywordAbility::Improvise) {
+            let convoked = substitutes.convoked.iter().copied().collect();
+            let candidates = self.untapped_of_type(player, CardType::Artifact, &convoked);
+            let chosen = ask_improvise(dp, self, player, spell, &cost, &candidates, cost.generic_count() as usize);
+            cost = reduce_generic(&cost, chosen.len());
+            substitutes.improvised = chosen;
+        }
+        if has_keyword(self, spell, KeywordAbility::Delve) {
+            let candidates = self.get_player(player)?.graveyard.clone();
+            let chosen = ask_delve(dp, self, player, spell, &cost, &candidates, cost.generic_count() as usize);
+            cost = reduce_generic(&cost, chosen.len());
+            substitutes.delved = chosen;
+        }
+
+        let total_costs = total_costs.into_iter()
+            .map(|c| match c {
+                Cost::Mana(_) => Cost::Mana(cost.clone()),
+                other => other,
+            })
+            .collect();
+        Ok((substitutes, total_costs))
+    }
+
+    /// Tap the convoked and improvised permanents, before the mana ability
+    /// window opens. Err, tapping none of them, if one is already tapped.
+    pub(crate) fn tap_substitutes(&mut self, substitutes: &ManaSubstitutes) -> Result<(), String> {
+        let permanents: Vec<ObjectId> = substitutes.convoked.iter().chain(&substitutes.improvised).copied().collect();
+        for id in &permanents {
+            let entry = self.battlefield.get(id)
+                .ok_or_else(|| format!("Permanent {:?} not found on the battlefield", id))?;
+            if entry.tapped {
+                return Err(format!("Permanent {:?} is already tapped", id));
+            }
+        }
+        for id in &permanents {
+            self.battlefield.get_mut(id).unwrap().tapped = true;
+        }
+        Ok(())
+    }
+
+    /// Untap what `tap_substitutes` tapped, when casting is rolled back.
+    pub(crate) fn untap_substitutes(&mut self, substitutes: &ManaSubstitutes) {
+        for id in substitutes.convoked.iter().chain(&substitutes.improvised) {
+            if let Some(entry) = self.battlefield.get_mut(id) {
+                entry.tapped = false;
+            }
+        }
+    }
+
+    /// Exile the delved cards as the rest of the total cost is paid (rule
+    /// 601.2h).
+    pub(crate) fn exile_delved(&mut self, substitutes: &ManaSubstitutes) -> Result<(), String> {
+        for &card in &substitutes.delved {
+            self.change_zone(card, Zone::Exile)?;
+        }
+        Ok(())
+    }
+
+    /// What's left of `cost` after everything `player` could convoke,
+    /// improvise or delve for a spell with `keywords` pays what it can —
+    /// for deciding whether it's castable. Permanents in `reserved` are left
+    /// to tap for mana instead.
+    pub fn cost_after_mana_substitutes(
+        &self,
+        player: PlayerId,
+        keywords: &HashSet<KeywordAbility>,
+        cost: &ManaCost,
+        reserved: &HashSet<ObjectId>,
+    ) -> ManaCost {
+        let mut cost = cost.clone();
+        let mut reserved = reserved.clone();
+        if keywords.contains(&KeywordAbility::Convoke) {
+            let creatures = self.untapped_of_type(player, CardType::Creature, &reserved);
+            let colors: Vec<Vec<ManaType>> = creatures.iter().map(|&id| self.convoke_colors(id)).collect();
+            cost = convoke_payment(&cost, &colors).0;
+            reserved.extend(creatures);
+        }
+        if keywords.contains(&KeywordAbility::Improvise) {
+            let artifacts = self.untapped_of_type(player, CardType::Artifact, &reserved);
+            cost = reduce_generic(&cost, artifacts.len());
+        }
+        if keywords.contains(&KeywordAbility::Delve) {
+            cost = reduce_generic(&cost, self.players[player].graveyard.len());
+        }
+        cost
+    }
+
+    /// Untapped permanents of `card_type` that `player` controls, other
+    /// than `excluded`. Summoning sickness doesn't matter: tapping for
+    /// convoke or improvise isn't a {T} cost.
+    fn untapped_of_type(&self, player: PlayerId, card_type: CardType, excluded: &HashSet<ObjectId>) -> Vec<ObjectId> {
+        permanents_controlled_by(self, player).into_iter()
+            .filter(|id| !excluded.contains(id))
+            .filter(|id| self.battlefield.get(id).is_some_and(|entry| !entry.tapped))
+            .filter(|&id| has_type(self, id, card_type))
+            .collect()
+    }
+
+    /// The colors of mana a convoked creature can pay for: its own.
+    fn convoke_colors(&self, creature: ObjectId) -> Vec<ManaType> {
+        get_effective_colors(self, creature).into_iter().map(ManaType::from_color).collect()
+    }
+}
+
+/// Pay what creatures can of `cost` by convoke — each given by the colors
+/// of mana it can pay for. As many colored symbols as possible are paid,
+/// then generic ones. Returns what's left of the cost and how many
+/// creatures had nothing to pay for.
+fn convoke_payment(cost: &ManaCost, creatures: &[Vec<ManaType>]) -> (ManaCost, usize) {
+    // Match creatures to the colored symbols they can pay for, taking each
+    // unmatched creature in turn and reassigning earlier ones where that
+    // frees a symbol up.
+    let mut paid_by: Vec<Option<usize>> = vec![None; cost.symbols.len()];
+    for creature in 0..creatures.len() {
+        let mut visited = vec![false; cost.symbols.len()];
+        assign_convoke(creature, creatures, &cost.symbols, &mut paid_by, &mut visited);
+    }
+
+    let mut unused = creatures.len() - paid_by.iter().flatten().count();
+    let mut rest = Vec::new();
+    for (symbol, payer) in cost.symbols.iter().zip(&paid_by) {
+        if payer.is_some() {
+            continue;
+        }
+        if *symbol == ManaSymbol::Generic && unused > 0 {
+            unused -= 1;
+            continue;
+        }
+        rest.push(*symbol);
+    }
+    (ManaCost::from_symbols(rest), unused)
+}
+
+/// Find a colored symbol for `creature` to pay for, moving the creature
+/// that pays for one already to another if it can go elsewhere.
+fn assign_convoke(
+    creature: usize,
+    creatures: &[Vec<ManaType>],
+    symbols: &[ManaSymbol],
+    paid_by: &mut [Option<usize>],
+    visited: &mut [bool],
+) -> bool {
+    for (i, symbol) in symbols.iter().enumerate() {
+        if visited[i] || !convoke_pays_for(&creatures[creature], symbol) {
+            continue;
+        }
+        visited[i] = true;
+        let free = match paid_by[i] {
+            None => true,
+            Some(other) => assign_convoke(other, creatures, symbols, paid_by, visited),
+        };
+        if free {
+            paid_by[i] = Some(creature);
+            return true;
+        }
+    }
+    false
+}
+
+/// Whether a creature of `colors` can pay for `symbol` by convoke, other
+/// than as generic mana. A hybrid symbol takes either color (rule 702.51a).
+fn convoke_pays_for(colors: &[ManaType], symbol: &ManaSymbol) -> bool {
+    match symbol {
+        ManaSymbol::Colored(t) | ManaSymbol::MonoHybrid(t) | ManaSymbol::Phyrexian(t) => colors.contains(t),
+        ManaSymbol::Hybrid(a, b) | ManaSymbol::HybridPhyrexian(a, b) => colors.contains(a) || colors.contains(b),
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_convoke_payment_matches_colors_before_generic() {
+        // {1}{W}{G}: a green-white creature must take {W} for the green one
+        // to pay {G}, and the colorless one pays {1}.
+        let cost = ManaCost::build(&[ManaType::White, ManaType::Green], 1);
+        let creatures = vec![vec![ManaType::Green, ManaType::White], vec![ManaType::Green], vec![]];
+        let (rest, unused) = convoke_payment(&cost, &creatures);
+        assert!(rest.symbols.is_empty());
+        assert_eq!(unused, 0);
+    }
+
+    #[test]
+    fn test_convoke_payment_reports_unused_creatures() {
+        let cost = ManaCost::build(&[ManaType::Red], 0);
+        let (rest, unused) = convoke_payment(&cost, &[vec![ManaType::Blue]]);
+        assert_eq!(rest, cost);
+        assert_eq!(unused, 1);
+    }
+}
@@ -8,7 +8,26 @@ pub mod resolve;
 pub mod targeting;
 pub mod cast;
 pub mod stack;
+pub mod copy;
+pub mod retarget;
+pub mod free_cast;
+pub mod mana_substitutes;
 pub mod priority;
 pub mod combat;
+pub mod battles;
+pub mod phasing;
+pub mod double_faced;
+pub mod multipart;
+pub mod face_down;
+pub mod sagas;
+pub mod triggers;
+pub mod emblems;
+pub mod designations;
+pub mod dungeons;
+pub mod lki;
+pub mod identity;
 pub mod keywords;
 pub mod layers;
+pub mod multiplayer;
+pub mod commander;
+pub mod pregame;
@@ -0,0 +1,145 @@
+//! Split cards (rule 709), fuse (rule 702.102), aftermath (rule 702.127)
+//! and adventurer cards (rule 715).
+//!
+//! A multi-part card's `CardData` is its left half or main card;
+//! `second_half` holds the other half. Which half a spell was cast as is
+//! `StackEntry::cast_face`, the same as a double-faced card's face, so the
+//! layer system reads the right characteristics through
+//! `GameState::face_up_data`. Off the stack a split card has both halves'
+//! characteristics combined (rule 709.4), and an adventurer only its main
+//! card's (rule 715.4).
+//!
+//! Where each half can be cast from is decided here: split halves from
+//! hand, an aftermath half only from a graveyard, both halves of a fuse card
+//! together only from hand, and an adventurer's creature from exile once
+//! its Adventure has sent it there.
+
+use crate::objects::card_data::{CardFace, MultiPartKind};
+use crate::state::game_state::GameState;
+use crate::types::ids::ObjectId;
+use crate::types::zones::Zone;
+
+impl GameState {
+    /// The faces or halves `card_id` can be cast as from the zone it's in.
+    /// Empty if it can't be cast from there at all.
+    pub fn faces_castable_from_zone(&self, card_id: ObjectId) -> Vec<CardFace> {
+        let Some(obj) = self.objects.get(&card_id) else {
+            return Vec::new();
+        };
+        let kind = obj.card_data.second_half.as_ref().map(|half| half.kind);
+        let from_hand = self.in_castable_zone(card_id);
+        obj.castable_faces().into_iter()
+            .filter(|&face| match (kind, face) {
+                (Some(MultiPartKind::Aftermath), CardFace::Back) => obj.zone == Zone::Graveyard,
+                (Some(MultiPartKind::Fuse), CardFace::Combined) => obj.zone == Zone::Hand,
+                _ if from_hand => true,
+                (Some(MultiPartKind::Adventure), CardFace::Front) => {
+                    obj.zone == Zone::Exile && self.on_adventure.contains(&card_id)
+                }
+                _ => false,
+            })
+            .collect()
+    }
+
+    /// Whether `id`, cast as `face`, is an Adventure spell (rule 715.3).
+    pub(crate) fn is_adventure_spell(&self, id: ObjectId, face: CardFace) -> bool {
+        face == CardFace::Back && self.multi_part_kind(id) == Some(MultiPartKind::Adventure)
+    }
+
+    /// Where spell `id`, cast as `face`, goes as it finishes resolving (rule
+    /// 608.2n): an Adventure goes on an adventure in exile (rule 715.4),
+    /// and anything else to its owner's graveyard unless `leaving_stack_zone`
+    /// exiles it.
+    pub(crate) fn resolved_spell_zone(&self, id: ObjectId, face: CardFace) -> Zone {
+        if self.is_adventure_spell(id, face) {
+            Zone::Exile
+        } else {
+            self.leaving_stack_zone(id, face, Zone::Graveyard)
+        }
+    }
+
+    /// Where spell `id`, cast as `face`, goes when it would leave the stack
+    /// for `to`: exile, if it's an aftermath half (rule 702.127a).
+    pub(crate) fn leaving_stack_zone(&self, id: ObjectId, face: CardFace, to: Zone) -> Zone {
+        if face == CardFace::Back && self.multi_part_kind(id) == Some(MultiPartKind::Aftermath) {
+            Zone::Exile
+        } else {
+            to
+        }
+    }
+
+    fn multi_part_kind(&self, id: ObjectId) -> Option<MultiPartKind> {
+        self.objects.get(&id)?.card_data.second_half.as_ref().map(|half| half.kind)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use crate::objects::card_data::{CardData, CardDataBuilder, CardFace};
+    use crate::objects::object::GameObject;
+    use crate::state::game_state::GameState;
+    use crate::types::card_types::CardType;
+    use crate::types::ids::ObjectId;
+    use crate::types::mana::{ManaCost, ManaType};
+    use crate::types::zones::Zone;
+
+    fn sorcery(name: &str, cost: ManaCost) -> Arc<CardData> {
+        CardDataBuilder::new(name).card_type(CardType::Sorcery).mana_cost(cost).build()
+    }
+
+    fn add(game: &mut GameState, card: Arc<CardData>, zone: Zone) -> ObjectId {
+        let id = game.add_object(GameObject::new(card, 0, zone));
+        match zone {
+            Zone::Hand => game.players[0].hand.push(id),
+            Zone::Graveyard => game.players[0].graveyard.push(id),
+            Zone::Exile => game.exile.push(id),
+            _ => {}
+        }
+        id
+    }
+
+    fn aftermath_card() -> Arc<CardData> {
+        CardDataBuilder::new("First")
+            .card_type(CardType::Sorcery)
+            .mana_cost(ManaCost::build(&[ManaType::Red], 1))
+            .aftermath(sorcery("Second", ManaCost::build(&[ManaType::Black], 0)))
+            .build()
+    }
+
+    #[test]
+    fn test_split_card_has_combined_characteristics_off_the_stack() {
+        let mut game = GameState::new(2, 20);
+        let id = add(&mut game, aftermath_card(), Zone::Hand);
+        assert_eq!(game.face_up(id), CardFace::Combined);
+        let data = game.face_up_data(id).unwrap();
+        assert_eq!(data.name, "First // Second");
+        assert_eq!(data.mana_cost.as_ref().unwrap().mana_value(), 3);
+    }
+
+    #[test]
+    fn test_aftermath_halves_are_castable_from_different_zones() {
+        let mut game = GameState::new(2, 20);
+        let in_hand = add(&mut game, aftermath_card(), Zone::Hand);
+        let in_graveyard = add(&mut game, aftermath_card(), Zone::Graveyard);
+        assert_eq!(game.faces_castable_from_zone(in_hand), vec![CardFace::Front]);
+        assert_eq!(game.faces_castable_from_zone(in_graveyard), vec![CardFace::Back]);
+    }
+
+    #[test]
+    fn test_adventurer_is_castable_from_exile_only_on_an_adventure() {
+        let mut game = GameState::new(2, 20);
+        let adventurer = CardDataBuilder::new("Adventurer")
+            .card_type(CardType::Creature)
+            .power_toughness(2, 2)
+            .adventure(sorcery("Quest", ManaCost::zero()))
+            .build();
+        let id = add(&mut game, adventurer, Zone::Exile);
+        assert!(game.faces_castable_from_zone(id).is_empty());
+
+        game.on_adventure.insert(id);
+        assert_eq!(game.faces_castable_from_zone(id), vec![CardFace::Front]);
+        assert_eq!(game.face_up(id), CardFace::Front, "no combined characteristics");
+    }
+}
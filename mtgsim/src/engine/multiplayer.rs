@@ -0,0 +1,312 @@
+//! Multiplayer rules (rule 800) for free-for-all games.
+//!
+//! Turn order, priority and APNAP ordering skip players who have left the
+//! game (rule 800.4). When a player leaves a game with other players still in
+//! it, everything they own leaves with them and everything they control is
+//! cleaned up (rule 800.4a) — see `remove_player_from_game`.
+//!
+//! In a two-player game the first loss ends the game (rule 104.2a), so none
+//! of the leaving logic runs there.
+//!
+//! Team games (Two-Headed Giant, rule 810) seat consecutive players as a
+//! team. Teammates share a turn and a life total, and a side is a team
+//! rather than a player: "opponent" and "active player" are team-aware here,
+//! and the rest of the engine asks these helpers instead of comparing ids.
+
+use crate::events::event::GameEvent;
+use crate::state::game_state::GameState;
+use crate::types::ids::{ObjectId, PlayerId};
+use crate::types::mana::ManaPool;
+use crate::types::zones::Zone;
+
+impl GameState {
+    /// Whether `player` is still in the game (hasn't lost or left).
+    pub fn is_in_game(&self, player: PlayerId) -> bool {
+        !self.player_lost.get(player).copied().unwrap_or(true)
+    }
+
+    /// Players still in the game, in seat order.
+    pub fn players_in_game(&self) -> Vec<PlayerId> {
+        (0..self.num_players()).filter(|&pid| self.is_in_game(pid)).collect()
+    }
+
+    /// The next player in turn order after `player` who is still in the game
+    /// (rule 101.4). Returns None when nobody else is left.
+    pub fn next_player_in_game(&self, player: PlayerId) -> Option<PlayerId> {
+        let n = self.num_players();
+        (1..=n)
+            .map(|offset| (player + offset) % n)
+            .find(|&pid| pid != player && self.is_in_game(pid))
+    }
+
+    /// Players still in the game in APNAP order: the active player first,
+    /// then the others in turn order (rule 101.4). If the active player has
+    /// left, the order starts with the next player after them.
+    pub fn apnap_order(&self) -> Vec<PlayerId> {
+        let n = self.num_players();
+        (0..n)
+            .map(|offset| (self.active_player + offset) % n)
+            .filter(|&pid| self.is_in_game(pid))
+            .collect()
+    }
+
+    /// Every other player still in the game who isn't on `player`'s team —
+    /// in a free-for-all game, all of them are opponents (rule 102.3).
+    pub fn opponents_of(&self, player: PlayerId) -> Vec<PlayerId> {
+        self.players_in_game().into_iter().filter(|&pid| self.is_opponent(player, pid)).collect()
+    }
+
+    /// Who takes the next turn: the next player in turn order, or in a team
+    /// game the first remaining player of the next team (rule 810.2).
+    pub fn next_active_player(&self) -> Option<PlayerId> {
+        self.next_turn_player_after(self.active_player)
+    }
+
+    /// Who takes the turn after `player`'s in turn order — the rule behind
+    /// `next_active_player`, from any seat. Turn order resumes from the
+    /// regular turn's player after an extra turn (rule 500.7).
+    pub fn next_turn_player_after(&self, player: PlayerId) -> Option<PlayerId> {
+        let Some(team) = self.team_of(player) else {
+            return self.next_player_in_game(player);
+        };
+        let n = self.teams.len();
+        (1..=n)
+            .map(|offset| &self.teams[(team + offset) % n])
+            .find_map(|members| members.iter().copied().find(|&pid| self.is_in_game(pid)))
+            .filter(|&pid| pid != player)
+    }
+
+    // --- Teams (rule 810) ---
+
+    /// Seat consecutive players as teams of `team_size` (rule 810.1): with
+    /// four players and teams of two, players 0 and 1 face players 2 and 3.
+    pub fn set_teams(&mut self, team_size: usize) -> Result<(), String> {
+        let n = self.num_players();
+        if team_size < 2 || !n.is_multiple_of(team_size) || n / team_size < 2 {
+            return Err(format!("Can't split {} players into teams of {}", n, team_size));
+        }
+        self.teams = (0..n)
+            .collect::<Vec<_>>()
+            .chunks(team_size)
+            .map(|c| c.to_vec())
+            .collect();
+        Ok(())
+    }
+
+    /// Index of `player`'s team, or None in a game without teams.
+    pub fn team_of(&self, player: PlayerId) -> Option<usize> {
+        self.teams.iter().position(|t| t.contains(&player))
+    }
+
+    /// `player` and their teammates, in seat order. Just `player` in a game
+    /// without teams.
+    pub fn team_members(&self, player: PlayerId) -> Vec<PlayerId> {
+        match self.team_of(player) {
+            Some(team) => self.teams[team].clone(),
+            None => vec![player],
+        }
+    }
+
+    /// Whether two players are on the same side (the same player, or
+    /// teammates).
+    pub fn same_team(&self, a: PlayerId, b: PlayerId) -> bool {
+        a == b || self.team_of(a).is_some_and(|team| self.teams[team].contains(&b))
+    }
+
+    /// Whether `b` is an opponent of `a` (rule 102.3).
+    pub fn is_opponent(&self, a: PlayerId, b: PlayerId) -> bool {
+        !self.same_team(a, b)
+    }
+
+    /// The active players still in the game: the active player, or the whole
+    /// active team in a team game (rule 810.2).
+    pub fn active_players(&self) -> Vec<PlayerId> {
+        self.team_members(self.active_player)
+            .into_iter()
+            .filter(|&pid| self.is_in_game(pid))
+            .collect()
+    }
+
+    /// Whether `player` is an active player (rule 102.1 / 810.2).
+    pub fn is_active_player(&self, player: PlayerId) -> bool {
+        self.same_team(self.active_player, player)
+    }
+
+    /// How many sides — players, or teams in a team game — are still in the
+    /// game. The game is over once fewer than two remain (rule 104.2).
+    pub fn sides_in_game(&self) -> usize {
+        let living = self.players_in_game();
+        if self.teams.is_empty() {
+            return living.len();
+        }
+        self.teams.iter()
+            .filter(|team| team.iter().any(|pid| living.contains(pid)))
+            .count()
+    }
+
+    /// Copy `player`'s life total to their teammates. Teammates share a life
+    /// total (rule 810.9): every change to one player's life is a change to
+    /// the team's, so callers sync after adjusting it.
+    pub fn sync_team_life(&mut self, player: PlayerId) {
+        let life = self.players[player].life_total;
+        for mate in self.team_members(player) {
+            self.players[mate].life_total = life;
+        }
+    }
+
+    /// Whether any of `player`'s teammates has lost — which loses the game for
+    /// the whole team (rule 810.8a).
+    pub fn teammates_lost(&self, player: PlayerId) -> bool {
+        self.team_members(player)
+            .into_iter()
+            .any(|pid| pid != player && self.player_lost[pid])
+    }
+
+    /// Poison counters across `player`'s team (rule 810.10). Just the
+    /// player's own count in a game without teams.
+    pub fn team_poison_counters(&self, player: PlayerId) -> u32 {
+        self.team_members(player)
+            .into_iter()
+            .map(|pid| self.players[pid].poison_counters)
+            .sum()
+    }
+
+    /// A player leaves a multiplayer game (rule 800.4a):
+    /// 1. all objects they own leave the game;
+    /// 2. effects they control end, including any giving them control of
+    ///    objects;
+    /// 3. abilities they control on the stack, or waiting to be put on it,
+    ///    cease to exist;
+    /// 4. any remaining objects they control are exiled;
+    /// 5. if they're the monarch or have the initiative, it passes on
+    ///    (rules 724.4, 725.4).
+    ///
+    /// The caller has already set `player_lost`. Objects that leave the game
+    /// are removed from their zone and from `objects`.
+    pub fn remove_player_from_game(&mut self, player: PlayerId) -> Result<(), String> {
+        // 1. Objects owned by the player leave the game.
+        let mut owned: Vec<ObjectId> = self.objects.values()
+            .filter(|obj| obj.owner == player)
+            .map(|obj| obj.id)
+            .collect();
+        owned.sort();
+        for id in owned {
+            self.leave_game(id)?;
+            self.emblems.remove(&id);
+            self.venture_markers.remove(&id);
+        }
+
+        // 2. Effects controlled by the player end.
+        self.continuous_effects.remove_by_controller(player);
+
+        // 3. Abilities on the stack controlled by the player cease to exist.
+        let abilities: Vec<ObjectId> = self.stack.iter()
+            .copied()
+            .filter(|id| {
+                self.stack_entries.get(id)
+                    .is_some_and(|e| e.controller == player && !e.is_spell)
+            })
+            .collect();
+        for id in abilities {
+            self.stack.retain(|&s| s != id);
+            self.stack_entries.remove(&id);
+            self.objects.remove(&id);
+        }
+        self.pending_triggers.retain(|t| t.controller != player);
+
+        // 4. Remaining objects the player controls — borrowed permanents and
+        // spells owned by someone else — are exiled.
+        let mut controlled: Vec<ObjectId> = self.battlefield.values()
+            .filter(|e| e.controller == player)
+            .map(|e| e.object_id)
+            .chain(self.stack.iter().copied().filter(|id| {
+                self.stack_entries.get(id).is_some_and(|e| e.controller == player)
+            }))
+            .collect();
+        controlled.sort();
+        for id in controlled {
+            self.change_zone(id, Zone::Exile)?;
+        }
+
+        // Their mana pool goes with them.
+        self.players[player].mana_pool = ManaPool::new();
+
+        // 5. The monarch and the initiative pass to another player.
+        self.pass_designations_from(player);
+
+        self.events.emit(GameEvent::PlayerLeftGame { player_id: player });
+        Ok(())
+    }
+
+    /// Take one object out of the game entirely: out of its zone, out of
+    /// `objects`.
+    fn leave_game(&mut self, id: ObjectId) -> Result<(), String> {
+        let zone = self.get_object(id)?.zone;
+        if zone == Zone::Battlefield {
+            self.remove_from_combat(id);
+        }
+        self.cleanup_zone_state(id, zone);
+        self.remove_from_zone_collection(id, zone)?;
+        self.objects.remove(&id);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_turn_order_skips_players_who_left() {
+        let mut game = GameState::new(4, 20);
+        game.active_player = 1;
+        game.player_lost[2] = true;
+
+        assert_eq!(game.players_in_game(), vec![0, 1, 3]);
+        assert_eq!(game.next_player_in_game(1), Some(3));
+        assert_eq!(game.next_player_in_game(3), Some(0));
+        assert_eq!(game.apnap_order(), vec![1, 3, 0]);
+        assert_eq!(game.opponents_of(1), vec![0, 3]);
+    }
+
+    #[test]
+    fn test_teams_seat_consecutive_players() {
+        let mut game = GameState::new(4, 30);
+        game.set_teams(2).unwrap();
+        assert_eq!(game.teams, vec![vec![0, 1], vec![2, 3]]);
+        assert!(game.same_team(0, 1));
+        assert!(game.is_opponent(1, 2));
+        assert_eq!(game.opponents_of(0), vec![2, 3]);
+        assert_eq!(game.active_players(), vec![0, 1]);
+        assert!(game.is_active_player(1));
+        assert!(!game.is_active_player(2));
+        assert_eq!(game.next_active_player(), Some(2));
+        assert_eq!(game.sides_in_game(), 2);
+
+        assert!(GameState::new(4, 30).set_teams(3).is_err());
+        assert!(GameState::new(2, 30).set_teams(2).is_err());
+    }
+
+    #[test]
+    fn test_team_shares_life_and_counts_poison_together() {
+        let mut game = GameState::new(4, 30);
+        game.set_teams(2).unwrap();
+        game.players[3].life_total -= 4;
+        game.sync_team_life(3);
+        assert_eq!(game.players[2].life_total, 26);
+        assert_eq!(game.players[0].life_total, 30);
+
+        game.players[2].poison_counters = 6;
+        game.players[3].poison_counters = 5;
+        assert_eq!(game.team_poison_counters(2), 11);
+        assert_eq!(game.team_poison_counters(0), 0);
+    }
+
+    #[test]
+    fn test_next_player_none_when_alone() {
+        let mut game = GameState::new(3, 20);
+        game.player_lost[1] = true;
+        game.player_lost[2] = true;
+        assert_eq!(game.next_player_in_game(0), None);
+    }
+}
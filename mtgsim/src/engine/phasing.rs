@@ -0,0 +1,167 @@
+//! Phasing (rule 702.26).
+//!
+//! A phased-out permanent stays on the battlefield — its `BattlefieldEntity`
+//! keeps its counters, attachments, control and timestamp — but is treated
+//! as though it doesn't exist (702.26b). Every subsystem that scans the
+//! battlefield does so through `GameState::permanents`, which skips it, and
+//! its static abilities stop generating effects (see `compute.rs`).
+//!
+//! Phasing isn't a zone change: nothing enters or leaves the battlefield,
+//! and a permanent that phases in hasn't come under a new controller, so it
+//! isn't summoning sick (702.26d).
+//!
+//! Permanents phase in and out directly — by the phasing keyword during the
+//! untap step (502.1), or by an effect like Teferi's Protection — and the
+//! permanents attached to them follow indirectly (702.26g).
+
+use crate::events::event::GameEvent;
+use crate::oracle::characteristics::has_keyword;
+use crate::state::game_state::GameState;
+use crate::types::ids::{ObjectId, PlayerId};
+use crate::types::keywords::KeywordAbility;
+
+impl GameState {
+    /// Phase out `id` directly (rule 702.26a), along with everything attached
+    /// to it, which phases out indirectly (rule 702.26g). A phased-out
+    /// creature is removed from combat (rule 506.4). Does nothing if `id`
+    /// isn't a phased-in permanent.
+    pub fn phase_out(&mut self, id: ObjectId) {
+        if self.is_phased_in(id) {
+            self.phase_out_with(id, false);
+        }
+    }
+
+    fn phase_out_with(&mut self, id: ObjectId, indirectly: bool) {
+        self.remove_from_combat(id);
+        let Some(entry) = self.battlefield.get_mut(&id) else { return };
+        entry.phased_out = true;
+        entry.phased_out_indirectly = indirectly;
+        let attached_by = entry.attached_by.clone();
+        self.events.emit(GameEvent::PhasedOut { object_id: id });
+
+        for attachment in attached_by {
+            if self.is_phased_in(attachment) {
+                self.phase_out_with(attachment, true);
+            }
+        }
+    }
+
+    /// Phase in `id`, along with the permanents that phased out indirectly
+    /// with it (rule 702.26g). It comes back attached to whatever it was
+    /// attached to, if that's still there (rule 702.26h); otherwise it phases
+    /// in unattached and SBAs deal with it.
+    pub fn phase_in(&mut self, id: ObjectId) {
+        let Some(entry) = self.battlefield.get_mut(&id) else { return };
+        if !entry.phased_out {
+            return;
+        }
+        entry.phased_out = false;
+        entry.phased_out_indirectly = false;
+        let attached_by = entry.attached_by.clone();
+        self.events.emit(GameEvent::PhasedIn { object_id: id });
+
+        for attachment in attached_by {
+            if self.battlefield.get(&attachment).is_some_and(|e| e.phased_out_indirectly) {
+                self.phase_in(attachment);
+            }
+        }
+    }
+
+    /// The phasing turn-based action (rule 502.1): phased-in permanents with
+    /// phasing that the active players control phase out, and phased-out
+    /// permanents they controlled when those phased out phase in — all at
+    /// once, so nothing that phases in this way phases straight back out.
+    /// Permanents that phased out indirectly wait for their host.
+    pub(crate) fn process_phasing(&mut self, active: &[PlayerId]) {
+        let mut phasing_out: Vec<ObjectId> = self.permanents()
+            .filter(|(id, entry)| {
+                active.contains(&entry.controller)
+                    && has_keyword(self, **id, KeywordAbility::Phasing)
+            })
+            .map(|(id, _)| *id)
+            .collect();
+        let mut phasing_in: Vec<ObjectId> = self.battlefield.values()
+            .filter(|e| e.phased_out && !e.phased_out_indirectly && active.contains(&e.controller))
+            .map(|e| e.object_id)
+            .collect();
+        phasing_out.sort();
+        phasing_in.sort();
+
+        for id in phasing_out {
+            self.phase_out(id);
+        }
+        for id in phasing_in {
+            self.phase_in(id);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::objects::card_data::CardDataBuilder;
+    use crate::objects::object::GameObject;
+    use crate::state::game_state::GameState;
+    use crate::types::card_types::*;
+    use crate::types::ids::ObjectId;
+    use crate::types::keywords::KeywordAbility;
+    use crate::types::zones::Zone;
+
+    fn put_creature(game: &mut GameState, controller: usize, phasing: bool) -> ObjectId {
+        let mut builder = CardDataBuilder::new("Test Creature")
+            .card_type(CardType::Creature)
+            .power_toughness(2, 2);
+        if phasing {
+            builder = builder.keyword(KeywordAbility::Phasing);
+        }
+        let obj = GameObject::new(builder.build(), controller, Zone::Battlefield);
+        let id = game.add_object(obj);
+        game.place_on_battlefield(id, controller);
+        id
+    }
+
+    #[test]
+    fn test_phased_out_permanent_is_not_listed() {
+        let mut game = GameState::new(2, 20);
+        let id = put_creature(&mut game, 0, false);
+        game.phase_out(id);
+
+        assert!(game.battlefield.contains_key(&id));
+        assert!(!game.is_phased_in(id));
+        assert_eq!(game.permanents().count(), 0);
+
+        game.phase_in(id);
+        assert!(game.is_phased_in(id));
+    }
+
+    #[test]
+    fn test_attachments_phase_out_and_in_indirectly() {
+        let mut game = GameState::new(2, 20);
+        let host = put_creature(&mut game, 0, false);
+        let attachment = put_creature(&mut game, 0, false);
+        game.battlefield.get_mut(&attachment).unwrap().attach_to(host);
+        game.battlefield.get_mut(&host).unwrap().attached_by.push(attachment);
+
+        game.phase_out(host);
+        assert!(game.battlefield[&attachment].phased_out_indirectly);
+
+        // An indirectly phased-out permanent doesn't phase in on its own.
+        game.process_phasing(&[0]);
+        assert!(game.is_phased_in(host));
+        assert!(game.is_phased_in(attachment));
+        assert_eq!(game.battlefield[&attachment].attached_to, Some(host));
+    }
+
+    #[test]
+    fn test_phasing_keyword_alternates_each_untap() {
+        let mut game = GameState::new(2, 20);
+        let id = put_creature(&mut game, 0, true);
+        let other = put_creature(&mut game, 1, true);
+
+        game.process_phasing(&[0]);
+        assert!(!game.is_phased_in(id));
+        assert!(game.is_phased_in(other), "only the active player's permanents phase");
+
+        game.process_phasing(&[0]);
+        assert!(game.is_phased_in(id));
+    }
+}
@@ -0,0 +1,143 @@
+//! Pregame actions (rule 103.6).
+//!
+//! Once every player has kept an opening hand, each player in turn order may
+//! take the actions their opening hand allows — `CardData::opening_hand_action`.
+//! `Game::setup` asks which to take; this module performs them:
+//! - a Leyline begins the game on the battlefield, under its owner's control
+//!   since before the first turn (`controller_since_turn` 0, the pregame
+//!   sentinel), so a creature put there isn't summoning sick;
+//! - a revealed Chancellor stays in hand and leaves a `DelayedTrigger` that
+//!   goes on the stack as its controller's first upkeep begins.
+//!
+//! Delayed triggers choose no targets when put on the stack — none of the
+//! first-upkeep Chancellors' abilities target.
+
+use crate::objects::card_data::OpeningHandAction;
+use crate::state::game_state::{DelayedTrigger, GameState, TriggerSource};
+use crate::types::ids::{ObjectId, PlayerId};
+use crate::types::zones::Zone;
+
+impl GameState {
+    /// The cards in `player`'s hand that have an opening-hand action.
+    pub fn opening_hand_candidates(&self, player: PlayerId) -> Vec<ObjectId> {
+        self.players[player].hand.iter()
+            .copied()
+            .filter(|id| self.objects.get(id).is_some_and(|o| o.card_data.opening_hand_action.is_some()))
+            .collect()
+    }
+
+    /// Take `card_id`'s opening-hand action on behalf of its owner.
+    pub fn take_opening_hand_action(&mut self, card_id: ObjectId) -> Result<(), String> {
+        let obj = self.get_object(card_id)?;
+        if obj.zone != Zone::Hand {
+            return Err(format!("{} isn't in an opening hand", card_id));
+        }
+        let owner = obj.owner;
+        let action = obj.card_data.opening_hand_action.clone()
+            .ok_or_else(|| format!("{} has no opening-hand action", obj.card_data.name))?;
+        match action {
+            OpeningHandAction::BeginOnBattlefield => {
+                let card_id = self.change_zone(card_id, Zone::Battlefield)?;
+                if let Some(entry) = self.battlefield.get_mut(&card_id) {
+                    entry.controller_since_turn = 0;
+                }
+            }
+            OpeningHandAction::RevealForFirstUpkeep(effect) => {
+                self.first_upkeep_triggers.push(DelayedTrigger {
+                    controller: owner,
+                    source: card_id,
+                    effect: effect.bind_controller(owner),
+                });
+            }
+        }
+        Ok(())
+    }
+
+    /// Put every delayed first-upkeep trigger controlled by an active player
+    /// on the stack, in the order the cards were revealed (rule 603.3). Called
+    /// as the upkeep step begins; a player's first upkeep is the first one
+    /// in which they're active, so each trigger fires exactly once.
+    pub(crate) fn put_first_upkeep_triggers_on_stack(&mut self) {
+        let active = self.active_players();
+        let (due, waiting): (Vec<_>, Vec<_>) = std::mem::take(&mut self.first_upkeep_triggers)
+            .into_iter()
+            .partition(|t| active.contains(&t.controller));
+        self.first_upkeep_triggers = waiting;
+
+        for trigger in due {
+            if self.objects.contains_key(&trigger.source) {
+                let source = TriggerSource::Object(trigger.source);
+                let card_data = self.trigger_source_data(source);
+                self.push_triggered_ability(trigger.controller, source, card_data, trigger.effect, Vec::new());
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::objects::object::GameObject;
+    use crate::cards::pregame::leyline_of_vitality;
+    use crate::objects::card_data::CardDataBuilder;
+    use crate::oracle::characteristics::has_summoning_sickness;
+    use crate::types::effects::{AmountExpr, Effect, EffectRecipient, Primitive};
+
+    fn in_hand(game: &mut GameState, card: std::sync::Arc<crate::objects::card_data::CardData>, player: PlayerId) -> ObjectId {
+        let obj = GameObject::new(card, player, Zone::Hand);
+        let id = obj.id;
+        game.add_object(obj);
+        game.players[player].hand.push(id);
+        id
+    }
+
+    fn revealer() -> std::sync::Arc<crate::objects::card_data::CardData> {
+        CardDataBuilder::new("Test Chancellor")
+            .opening_hand_action(OpeningHandAction::RevealForFirstUpkeep(Box::new(
+                Effect::Atom(Primitive::GainLife(AmountExpr::Fixed(3)), EffectRecipient::Controller),
+            )))
+            .build()
+    }
+
+    #[test]
+    fn test_leyline_begins_on_battlefield_without_sickness() {
+        let mut game = GameState::new(2, 20);
+        let id = in_hand(&mut game, leyline_of_vitality(), 1);
+        assert_eq!(game.opening_hand_candidates(1), vec![id]);
+        assert!(game.opening_hand_candidates(0).is_empty());
+
+        game.take_opening_hand_action(id).unwrap();
+        let id = game.current_identity(id);
+        let entry = &game.battlefield[&id];
+        assert_eq!(entry.controller, 1);
+        assert_eq!(entry.controller_since_turn, 0);
+        assert!(!has_summoning_sickness(&game, id));
+    }
+
+    #[test]
+    fn test_reveal_waits_for_controllers_first_upkeep() {
+        let mut game = GameState::new(2, 20);
+        let id = in_hand(&mut game, revealer(), 1);
+        game.take_opening_hand_action(id).unwrap();
+        assert_eq!(game.players[1].hand, vec![id]);
+
+        // Player 0's upkeep: not player 1's trigger yet.
+        game.put_first_upkeep_triggers_on_stack();
+        assert!(game.stack.is_empty());
+
+        game.active_player = 1;
+        game.put_first_upkeep_triggers_on_stack();
+        assert_eq!(game.stack.len(), 1);
+        let entry = &game.stack_entries[&game.stack[0]];
+        assert_eq!(entry.controller, 1);
+        assert_eq!(entry.ability_source, Some(id));
+        assert!(game.first_upkeep_triggers.is_empty());
+    }
+
+    #[test]
+    fn test_card_without_action_is_rejected() {
+        let mut game = GameState::new(2, 20);
+        let id = in_hand(&mut game, crate::cards::creatures::grizzly_bears(), 0);
+        assert!(game.take_opening_hand_action(id).is_err());
+    }
+}
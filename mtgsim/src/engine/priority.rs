@@ -33,9 +33,14 @@ impl GameState {
         // --- Rule 117.5: SBAs before granting priority ---
         self.perform_sba_and_triggers(decisions)?;
 
-        let num_players = self.num_players();
+        // Only players still in the game receive priority (rule 800.4a);
+        // all of them must pass in succession for the round to end.
+        let num_players = self.players_in_game().len();
         let mut consecutive_passes = 0;
-        let mut current_priority = self.active_player;
+        let mut current_priority = self.apnap_order()
+            .first()
+            .copied()
+            .unwrap_or(self.active_player);
 
         loop {
             self.priority_player = current_priority;
@@ -100,11 +105,18 @@ impl GameState {
                 let (exec_result, was_mana_ability) = match &action {
                     PriorityAction::Pass => unreachable!(),
                     PriorityAction::CastSpell(card_id) => (
-                        self.cast_spell(current_priority, *card_id, decisions),
+                        self.cast_spell(current_priority, *card_id, decisions).map(|_| ()),
                         false,
                     ),
-                    PriorityAction::PlayLand(card_id) => (
-                        self.play_land(current_priority, *card_id, Zone::Hand),
+                    PriorityAction::PlayLand(card_id) => {
+                        let faces = self.get_object(*card_id)
+                            .map(|obj| obj.land_faces())
+                            .unwrap_or_default();
+                        let face = self.choose_face(current_priority, *card_id, &faces, decisions);
+                        (self.play_land_as(current_priority, *card_id, Zone::Hand, face).map(|_| ()), false)
+                    }
+                    PriorityAction::TurnFaceUp(permanent_id) => (
+                        self.turn_face_up(current_priority, *permanent_id, decisions),
                         false,
                     ),
                     PriorityAction::ActivateAbility(permanent_id, ability_id) => {
@@ -177,7 +189,8 @@ impl GameState {
                         }
                     }
                     // Next player gets priority (117.3d)
-                    current_priority = (current_priority + 1) % num_players;
+                    current_priority = self.next_player_in_game(current_priority)
+                        .unwrap_or(current_priority);
                 }
 
                 PriorityAction::CastSpell(_) => {
@@ -202,6 +215,14 @@ impl GameState {
                     // player keeps priority, caller loops back.
                     return Ok(PriorityResult::ActionTaken);
                 }
+
+                PriorityAction::TurnFaceUp(_) => {
+                    // Turning a permanent face up is a special action too
+                    // (rule 116.2b), but what it turns into may need SBAs
+                    // before the player gets priority back (rule 117.5).
+                    self.perform_sba_and_triggers(decisions)?;
+                    return Ok(PriorityResult::ActionTaken);
+                }
             }
         }
     }
@@ -221,6 +242,11 @@ impl GameState {
                     // Continue looping — more priority passing needed
                 }
             }
+            // Rule 723.1c: once an effect ends the turn, nobody gets
+            // priority again before the cleanup step.
+            if self.turn_plan.is_ending() {
+                return Ok(());
+            }
         }
     }
 
@@ -237,7 +263,7 @@ impl GameState {
             self.check_state_based_actions_loop(decisions)?;
 
             // Step 2: Place triggered abilities on the stack (rule 603.3)
-            let triggers_placed = false; // Phase 7 stub
+            let triggers_placed = self.put_pending_triggers_on_stack(decisions)?;
 
             // Step 3: If no triggers were placed, we're stable
             if !triggers_placed {
@@ -312,7 +338,7 @@ mod tests {
         // First round: player 0 casts bolt (returns ActionTaken immediately)
         let result = game.run_priority_round(&decisions).unwrap();
         assert_eq!(result, PriorityResult::ActionTaken);
-        assert!(game.stack.contains(&card_id));
+        assert!(game.stack.contains(&game.current_identity(card_id)));
 
         // Second round: both pass, stack resolves
         decisions.expect_pick_n(ChoiceKind::PriorityAction, vec![0]);
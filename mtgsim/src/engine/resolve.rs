@@ -1,10 +1,13 @@
 use crate::engine::actions::GameAction;
 use crate::events::event::DamageTarget;
+use crate::objects::card_data::FaceDownKind;
 use crate::state::game_state::GameState;
 use crate::types::effects::{
-    AmountExpr, Effect, Primitive, EffectRecipient, SelectionFilter,
+    AmountExpr, Condition, CounterType, Effect, Primitive, EffectRecipient, PermanentFilter,
+    SelectionFilter,
 };
 use crate::types::ids::{ObjectId, PlayerId};
+use crate::types::zones::Zone;
 use crate::ui::decision::DecisionProvider;
 
 /// Context passed through effect resolution.
@@ -19,6 +22,8 @@ pub struct ResolutionContext {
     pub controller: PlayerId,
     /// Resolved targets (validated before resolution begins)
     pub targets: Vec<ResolvedTarget>,
+    /// For an ability, the permanent it came from (`StackEntry::ability_source`).
+    pub ability_source: Option<ObjectId>,
 }
 
 /// A resolved target — validated as legal when the spell/ability was put on the
@@ -58,9 +63,11 @@ impl GameState {
                 Ok(())
             }
 
-            Effect::Conditional(_condition, _inner) => {
-                // Phase 6: evaluate condition, then resolve inner if true
-                Err("Conditional effects not yet implemented".to_string())
+            Effect::Conditional(condition, inner) => {
+                if self.evaluate_condition(condition, ctx)? {
+                    self.resolve_effect(inner, ctx, dp)?;
+                }
+                Ok(())
             }
 
             Effect::Optional(_inner) => {
@@ -89,7 +96,7 @@ impl GameState {
         primitive: &Primitive,
         recipient: &EffectRecipient,
         ctx: &ResolutionContext,
-        _dp: &dyn DecisionProvider,
+        dp: &dyn DecisionProvider,
     ) -> Result<(), String> {
         match primitive {
             // === Phase 2 primitives ===
@@ -161,7 +168,8 @@ impl GameState {
 
             Primitive::CounterSpell => {
                 // Counter target spell on the stack (rule 701.6a).
-                // The countered spell is put into its owner's graveyard.
+                // The countered spell is put into its owner's graveyard —
+                // or exile, if it's an aftermath half (rule 702.127a).
                 //
                 // The zone change goes through `execute_action(ZoneChange)` so
                 // that the Phase 6 replacement pipeline can observe it.
@@ -170,8 +178,10 @@ impl GameState {
                 for target in &ctx.targets {
                     if let ResolvedTarget::Object(id) = target {
                         let id = *id;
-                        if self.stack.contains(&id) {
-                            self.change_zone(id, crate::types::zones::Zone::Graveyard)?;
+                        if let Some(entry) = self.stack_entries.get(&id) {
+                            let to = self.leaving_stack_zone(
+                                id, entry.cast_face, crate::types::zones::Zone::Graveyard);
+                            self.change_zone(id, to)?;
                             self.events.emit(crate::events::event::GameEvent::SpellCountered {
                                 spell_id: id,
                                 countered_by: ctx.source,
@@ -205,25 +215,105 @@ impl GameState {
                 Ok(())
             }
 
+            Primitive::CopySpell(amount_expr) => {
+                // Copy target spell or ability (rule 707.10) — or, from a
+                // storm, replicate or casualty trigger, the spell it came
+                // from. Something that has left the stack can't be copied,
+                // so a spell countered in response gets no copies.
+                let times = self.evaluate_amount(amount_expr, ctx)?;
+                let originals: Vec<ObjectId> = match recipient {
+                    EffectRecipient::Target(_, _) => ctx.targets.iter()
+                        .filter_map(|t| match t {
+                            ResolvedTarget::Object(id) => Some(*id),
+                            ResolvedTarget::Player(_) => None,
+                        })
+                        .collect(),
+                    _ => ctx.ability_source.into_iter().collect(),
+                };
+                for original in originals {
+                    if !self.stack_entries.contains_key(&original) {
+                        continue;
+                    }
+                    for _ in 0..times {
+                        self.copy_stack_object(original, ctx.controller, dp)?;
+                    }
+                }
+                Ok(())
+            }
+
+            Primitive::Cascade(amount_expr) | Primitive::Discover(amount_expr) => {
+                // Exile from the top of the library until a nonland card
+                // that can be cast for free (rules 702.85a, 701.57a).
+                let mana_value = self.evaluate_amount(amount_expr, ctx)?;
+                let player_id = self.resolve_player_for_self(recipient, ctx);
+                match primitive {
+                    Primitive::Cascade(_) => self.cascade(player_id, mana_value, dp)?,
+                    _ => self.discover(player_id, mana_value, dp)?,
+                };
+                Ok(())
+            }
+
+            Primitive::MayCastFromHandWithoutPaying => {
+                // A static ability's permission, which `cast_spell` reads;
+                // there's nothing to do as an effect.
+                Ok(())
+            }
+
+            Primitive::ChangeTarget | Primitive::ChangeTargetToSource | Primitive::ChooseNewTargets => {
+                // Change the targets of target spell or ability (rule
+                // 115.7). Spellskite's "to Spellskite" means the permanent
+                // the ability came from, only while it's still there.
+                for target in &ctx.targets {
+                    let ResolvedTarget::Object(spell) = *target else {
+                        continue;
+                    };
+                    match primitive {
+                        Primitive::ChangeTarget => {
+                            self.change_target(spell, ctx.controller, ctx.source, dp)?;
+                        }
+                        Primitive::ChangeTargetToSource => {
+                            let Some(source) = ctx.ability_source else {
+                                continue;
+                            };
+                            let new_target = ResolvedTarget::Object(source);
+                            self.change_target_to(spell, new_target, ctx.controller, ctx.source, dp)?;
+                        }
+                        _ => {
+                            self.choose_new_targets_for(spell, ctx.controller, ctx.source, dp)?;
+                        }
+                    }
+                }
+                Ok(())
+            }
+
             // === Phase 2 primitives: Destroy & Untap ===
 
-            Primitive::Destroy => {
-                // Destroy target permanent (rule 701.7a).
+            Primitive::Destroy | Primitive::DestroyNoRegeneration => {
+                // Destroy target permanent (rule 701.8a).
                 // Moves the permanent from battlefield to its owner's graveyard.
-                // Indestructible permanents can't be destroyed (rule 702.12b).
+                // Indestructible permanents can't be destroyed (rule 702.12b);
+                // a regeneration shield replaces the destruction unless the
+                // effect says it can't be regenerated (rule 701.19c).
+                // If not on battlefield, destroy does nothing (rule 701.8b).
+                let can_regenerate = matches!(primitive, Primitive::Destroy);
                 for target in &ctx.targets {
                     if let ResolvedTarget::Object(id) = target {
-                        if self.battlefield.contains_key(id) {
-                            if crate::oracle::characteristics::has_keyword(self, *id, crate::types::keywords::KeywordAbility::Indestructible) {
-                                continue;
-                            }
-                            self.execute_action(GameAction::ZoneChange {
-                                object: *id,
-                                from: crate::types::zones::Zone::Battlefield,
-                                to: crate::types::zones::Zone::Graveyard,
-                            })?;
-                        }
-                        // If not on battlefield, destroy does nothing (rule 701.7b)
+                        self.execute_action(GameAction::Destroy { object: *id, can_regenerate })?;
+                    }
+                }
+                Ok(())
+            }
+
+            Primitive::Regenerate => {
+                // "Regenerate target creature" or, with no targets,
+                // "Regenerate [this]" (rule 701.19a).
+                if ctx.targets.is_empty() {
+                    let object = ctx.ability_source.unwrap_or(ctx.source);
+                    return self.execute_action(GameAction::Regenerate { object });
+                }
+                for target in &ctx.targets {
+                    if let ResolvedTarget::Object(id) = target {
+                        self.execute_action(GameAction::Regenerate { object: *id })?;
                     }
                 }
                 Ok(())
@@ -241,6 +331,83 @@ impl GameState {
                 Ok(())
             }
 
+            // === Attachment (rule 701.3) ===
+
+            Primitive::Attach => {
+                // "Attach [this] to target creature" — equip, reconfigure.
+                // Rule 301.5c: an Equipment that's also a creature can't
+                // equip a creature unless it has reconfigure; the attempt
+                // does nothing (rule 701.3b).
+                let attachment = ctx.ability_source.unwrap_or(ctx.source);
+                if !self.battlefield.contains_key(&attachment) {
+                    return Ok(());
+                }
+                if crate::oracle::characteristics::is_creature(self, attachment)
+                    && !crate::oracle::characteristics::has_keyword(
+                        self, attachment, crate::types::keywords::KeywordAbility::Reconfigure)
+                {
+                    return Ok(());
+                }
+                for host in self.collect_battlefield_targets(ctx) {
+                    self.execute_action(GameAction::Attach { attachment, host })?;
+                }
+                Ok(())
+            }
+
+            Primitive::Unattach => {
+                let attachment = ctx.ability_source.unwrap_or(ctx.source);
+                self.execute_action(GameAction::Unattach { attachment })
+            }
+
+            // === Phasing (rule 702.26) ===
+
+            Primitive::PhaseOut => {
+                // "Target permanent phases out" or "all permanents you
+                // control phase out". They're all chosen first, then phase
+                // out together, attachments along with their hosts.
+                let ids = match recipient {
+                    EffectRecipient::FilteredPermanents(filter) => {
+                        self.collect_filtered_permanents(filter, ctx)
+                    }
+                    _ => self.collect_battlefield_targets(ctx),
+                };
+                for id in ids {
+                    self.phase_out(id);
+                }
+                Ok(())
+            }
+
+            // === Double-faced cards (rule 712) ===
+
+            Primitive::Transform => {
+                // "Transform target creature", "transform all Humans", or
+                // with no targets, "transform [this]" (rule 701.28).
+                let ids = match recipient {
+                    EffectRecipient::FilteredPermanents(filter) => {
+                        self.collect_filtered_permanents(filter, ctx)
+                    }
+                    _ if ctx.targets.is_empty() => vec![ctx.ability_source.unwrap_or(ctx.source)],
+                    _ => self.collect_battlefield_targets(ctx),
+                };
+                for id in ids {
+                    self.transform(id);
+                }
+                Ok(())
+            }
+
+            Primitive::Manifest(amount_expr) | Primitive::Cloak(amount_expr) => {
+                let count = self.evaluate_amount(amount_expr, ctx)?;
+                let player_id = self.resolve_player_for_self(recipient, ctx);
+                let kind = match primitive {
+                    Primitive::Manifest(_) => FaceDownKind::Manifest,
+                    _ => FaceDownKind::Cloak,
+                };
+                for _ in 0..count {
+                    self.manifest_top_card(player_id, kind)?;
+                }
+                Ok(())
+            }
+
             // === Phase LB: continuous effect primitives ===
 
             Primitive::ModifyPowerToughness(power_expr, toughness_expr, duration) => {
@@ -270,6 +437,28 @@ impl GameState {
                 Ok(())
             }
 
+            Primitive::GrantKeyword(keyword, duration) => {
+                let target_ids = self.collect_battlefield_targets(ctx);
+                if target_ids.is_empty() {
+                    return Ok(());
+                }
+                let timestamp = self.allocate_timestamp();
+                let effect = crate::engine::layers::ContinuousEffect {
+                    id: 0,
+                    source: ctx.source,
+                    origin: crate::engine::layers::EffectOrigin::Resolution,
+                    layer: crate::engine::layers::Layer::Layer6Ability,
+                    duration: *duration,
+                    controller: ctx.controller,
+                    created_on_turn: self.turn_number,
+                    timestamp,
+                    affected: crate::engine::layers::AffectedSet::Fixed(target_ids),
+                    modification: crate::engine::layers::EffectModification::GrantKeyword(*keyword),
+                };
+                self.continuous_effects.add(effect);
+                Ok(())
+            }
+
             Primitive::SetPowerToughness(power_expr, toughness_expr, duration) => {
                 let power = self.evaluate_amount(power_expr, ctx)? as i32;
                 let toughness = self.evaluate_amount(toughness_expr, ctx)? as i32;
@@ -419,25 +608,126 @@ impl GameState {
                 Ok(())
             }
 
+            Primitive::ReturnToHand | Primitive::PutOnTopOfLibrary | Primitive::Exile => {
+                // Return target objects to their owners' hands, put them on
+                // top of their owners' libraries (rule 400.3), or exile them
+                // (rule 406.2). A commander's owner may put it into the
+                // command zone instead (rules 903.9a, 903.9b).
+                let to = match primitive {
+                    Primitive::ReturnToHand => Zone::Hand,
+                    Primitive::Exile => Zone::Exile,
+                    _ => Zone::Library,
+                };
+                for target in &ctx.targets {
+                    if let ResolvedTarget::Object(id) = target
+                        && self.objects.contains_key(id)
+                    {
+                        self.change_zone_with_commander_choice(*id, to, dp)?;
+                    }
+                }
+                Ok(())
+            }
+
+            Primitive::ReturnToBattlefield => {
+                // "Return that card to the battlefield": if an earlier step
+                // of these instructions moved the target, it's a new object
+                // now, and this follows it to where it went (rule 400.7).
+                // It enters under its owner's control. A token that's left
+                // the battlefield can't come back (rule 111.8).
+                for target in &ctx.targets {
+                    let ResolvedTarget::Object(id) = target else { continue };
+                    let card = self.current_identity(*id);
+                    let returnable = self.objects.get(&card).is_some_and(|obj| {
+                        matches!(obj.zone, Zone::Exile | Zone::Graveyard) && !obj.is_token
+                    });
+                    if returnable {
+                        self.change_zone(card, Zone::Battlefield)?;
+                    }
+                }
+                Ok(())
+            }
+
+            // === Turn structure ===
+
+            Primitive::ExtraTurn => {
+                // "Target player takes an extra turn" or, with no target,
+                // "take an extra turn after this one" (rule 500.7).
+                let player = self.resolve_player_for_self(recipient, ctx);
+                self.add_extra_turn(player);
+                Ok(())
+            }
+
+            Primitive::AdditionalCombatPhase { followed_by_main } => {
+                self.add_combat_phase(*followed_by_main);
+                Ok(())
+            }
+
+            Primitive::Skip(target) => {
+                let player = self.resolve_player_for_self(recipient, ctx);
+                self.add_skip(player, *target);
+                Ok(())
+            }
+
+            Primitive::EndTheTurn => self.end_the_turn(),
+
+            // === Counters ===
+
+            Primitive::AddCounters(counter_type, amount_expr) => {
+                // "Put N counters on target permanent". Lore counters go
+                // through the Saga path so chapter abilities trigger.
+                let count = self.evaluate_amount(amount_expr, ctx)? as u32;
+                for id in self.collect_battlefield_targets(ctx) {
+                    if *counter_type == CounterType::Lore {
+                        self.add_lore_counters(id, count);
+                    } else if let Some(entry) = self.battlefield.get_mut(&id) {
+                        entry.add_counters(*counter_type, count);
+                    }
+                }
+                Ok(())
+            }
+
+            // === Command zone ===
+
+            Primitive::CreateEmblem(abilities) => {
+                self.create_emblem(ctx.controller, abilities.clone());
+                Ok(())
+            }
+
+            Primitive::BecomeMonarch => {
+                let player = self.resolve_player_for_self(recipient, ctx);
+                self.become_monarch(player);
+                Ok(())
+            }
+
+            Primitive::TakeInitiative => {
+                let player = self.resolve_player_for_self(recipient, ctx);
+                self.take_initiative(player);
+                Ok(())
+            }
+
+            Primitive::Venture => {
+                let player = self.resolve_player_for_self(recipient, ctx);
+                self.venture(player, None, dp)
+            }
+
+            Primitive::VentureInto(dungeon) => {
+                let player = self.resolve_player_for_self(recipient, ctx);
+                self.venture(player, Some(*dungeon), dp)
+            }
+
             // === Phase 3+ primitives — stubs ===
 
-            Primitive::Exile
-            | Primitive::Sacrifice
-            | Primitive::ReturnToHand
-            | Primitive::ReturnToBattlefield
-            | Primitive::PutOnTopOfLibrary
+            Primitive::Sacrifice
             | Primitive::PutOnBottomOfLibrary
             | Primitive::ShuffleIntoLibrary
             | Primitive::Mill(_)
             | Primitive::Discard(_)
             | Primitive::Scry(_)
             | Primitive::Surveil(_)
-            | Primitive::AddCounters(_, _)
             | Primitive::RemoveCounters(_, _)
             | Primitive::CreateToken(_, _)
             | Primitive::Fight
             | Primitive::Tap
-            | Primitive::GrantKeyword(_, _)
             | Primitive::RemoveAbility(_, _)
             | Primitive::GainControl(_) => {
                 Err(format!("Primitive {:?} not yet implemented", primitive))
@@ -447,12 +737,13 @@ impl GameState {
 
     // --- Helper: collect battlefield targets ---
 
-    /// Extract object IDs from resolved targets that are currently on the battlefield.
+    /// Extract object IDs from resolved targets that are currently phased-in
+    /// permanents.
     fn collect_battlefield_targets(&self, ctx: &ResolutionContext) -> Vec<ObjectId> {
         ctx.targets.iter()
             .filter_map(|t| {
                 if let ResolvedTarget::Object(id) = t {
-                    if self.battlefield.contains_key(id) {
+                    if self.is_phased_in(*id) {
                         return Some(*id);
                     }
                 }
@@ -461,12 +752,29 @@ impl GameState {
             .collect()
     }
 
+    /// The phased-in permanents matching `filter` as it resolves, with
+    /// "you" bound to the resolving object's controller. Sorted for
+    /// deterministic processing.
+    fn collect_filtered_permanents(
+        &self,
+        filter: &crate::types::effects::PermanentFilter,
+        ctx: &ResolutionContext,
+    ) -> Vec<ObjectId> {
+        let filter = SelectionFilter::Permanent(filter.bind_controller(ctx.controller));
+        let mut ids: Vec<ObjectId> = self.permanents()
+            .map(|(&id, _)| id)
+            .filter(|&id| self.validate_selection(&filter, &ResolvedTarget::Object(id)).is_ok())
+            .collect();
+        ids.sort();
+        ids
+    }
+
     // --- Helper: evaluate AmountExpr ---
 
     fn evaluate_amount(
         &self,
         expr: &AmountExpr,
-        _ctx: &ResolutionContext,
+        ctx: &ResolutionContext,
     ) -> Result<u64, String> {
         match expr {
             AmountExpr::Fixed(n) => Ok(*n),
@@ -478,10 +786,19 @@ impl GameState {
                 Err("CountOf amount resolution not yet implemented".to_string())
             }
             AmountExpr::TargetPower => {
-                Err("TargetPower amount resolution not yet implemented".to_string())
+                // The first target's power as the effect resolves — or as
+                // it last existed, if it's left the battlefield (rule
+                // 608.2h). 0 if it never had any.
+                let power = self.first_object_target(ctx)
+                    .and_then(|id| self.power_or_last_known(id))
+                    .unwrap_or(0);
+                Ok(power.max(0) as u64)
             }
             AmountExpr::TargetToughness => {
-                Err("TargetToughness amount resolution not yet implemented".to_string())
+                let toughness = self.first_object_target(ctx)
+                    .and_then(|id| self.toughness_or_last_known(id))
+                    .unwrap_or(0);
+                Ok(toughness.max(0) as u64)
             }
             AmountExpr::DamageDealt => {
                 Err("DamageDealt amount resolution not yet implemented".to_string())
@@ -513,7 +830,7 @@ impl GameState {
         use crate::types::card_types::{EnchantmentType, Subtype};
         use crate::types::effects::{EffectRecipient, TargetCount};
 
-        let obj = self.get_object(aura_id)?;
+        let data = self.face_up_data(aura_id).ok_or_else(|| format!("Object {} not found", aura_id))?;
 
         // Only applies to Auras.
         if !crate::oracle::characteristics::has_subtype(
@@ -523,13 +840,13 @@ impl GameState {
         }
 
         // Read the enchant filter directly from card data.
-        let filter = match &obj.card_data.enchant_filter {
+        let filter = match &data.enchant_filter {
             Some(f) => f.clone(),
             // Aura with no enchant_filter — card data bug.
             // Fall back to "enchant permanent" so the game doesn't crash,
             // but warn loudly so we catch it.
             None => {
-                let name = &self.get_object(aura_id)?.card_data.name;
+                let name = &data.name;
                 eprintln!(
                     "[WARN] Aura {:?} (id={}) has no enchant_filter set — \
                      falling back to \"enchant permanent\". This is a card data bug.",
@@ -573,6 +890,60 @@ impl GameState {
         }
     }
 
+    // --- Helper: conditions ---
+
+    /// Whether `condition` holds as the effect resolves. Conditions about
+    /// an object that's left the battlefield use its last known
+    /// information (rule 608.2h).
+    fn evaluate_condition(
+        &self,
+        condition: &Condition,
+        ctx: &ResolutionContext,
+    ) -> Result<bool, String> {
+        match condition {
+            Condition::ControlPermanent(filter) => Ok(self.permanents().any(|(&id, entry)| {
+                entry.controller == ctx.controller && self.permanent_matches(id, filter)
+            })),
+            Condition::OpponentControlsPermanent(filter) => Ok(self.permanents().any(|(&id, entry)| {
+                self.is_opponent(ctx.controller, entry.controller) && self.permanent_matches(id, filter)
+            })),
+            Condition::LifeAtLeast(amount) => {
+                let life = self.get_player(ctx.controller)?.life_total;
+                Ok(life >= self.evaluate_amount(amount, ctx)? as i64)
+            }
+            Condition::LifeAtMost(amount) => {
+                let life = self.get_player(ctx.controller)?.life_total;
+                Ok(life <= self.evaluate_amount(amount, ctx)? as i64)
+            }
+            Condition::SourceOnBattlefield => {
+                Ok(self.is_phased_in(ctx.ability_source.unwrap_or(ctx.source)))
+            }
+            Condition::TargetWas(filter) => Ok(self.first_object_target(ctx)
+                .and_then(|id| self.characteristics_or_last_known(id))
+                .is_some_and(|chars| crate::engine::layers::compute::permanent_matches_filter(filter, &chars))),
+            Condition::CardInGraveyard(_)
+            | Condition::SpellWasKicked
+            | Condition::ModeChosen(_) => {
+                Err(format!("Condition {:?} not yet implemented", condition))
+            }
+        }
+    }
+
+    /// Whether permanent `id` matches `filter` with its current
+    /// characteristics.
+    fn permanent_matches(&self, id: ObjectId, filter: &PermanentFilter) -> bool {
+        crate::engine::layers::compute_characteristics(self, id)
+            .is_some_and(|chars| crate::engine::layers::compute::permanent_matches_filter(filter, &chars))
+    }
+
+    /// The first object among the resolved targets, wherever it is now.
+    fn first_object_target(&self, ctx: &ResolutionContext) -> Option<ObjectId> {
+        ctx.targets.iter().find_map(|target| match target {
+            ResolvedTarget::Object(id) => Some(*id),
+            ResolvedTarget::Player(_) => None,
+        })
+    }
+
     // --- Helper: determine which player an effect applies to ---
 
     /// For effects that target "you" (the controller) or use EffectRecipient::Implicit,
@@ -585,6 +956,13 @@ impl GameState {
     ) -> PlayerId {
         match recipient {
             EffectRecipient::Implicit | EffectRecipient::Controller => ctx.controller,
+            EffectRecipient::TargetController => {
+                // The first object target's controller, as it last existed
+                // if it's left (rule 608.2h)
+                self.first_object_target(ctx)
+                    .and_then(|id| self.controller_or_last_known(id))
+                    .unwrap_or(ctx.controller)
+            }
             EffectRecipient::Target(SelectionFilter::Player, _) => {
                 // Use the first resolved player target
                 for t in &ctx.targets {
@@ -634,6 +1012,7 @@ mod tests {
             source,
             controller: 0,
             targets,
+            ability_source: None,
         }
     }
 
@@ -871,7 +1250,7 @@ mod tests {
         let performed = game.check_state_based_actions(&test_dp()).unwrap();
         assert!(performed);
         assert!(!game.battlefield.contains_key(&aura_id));
-        assert_eq!(game.get_object(aura_id).unwrap().zone, Zone::Graveyard);
+        assert_eq!(game.get_object(game.current_identity(aura_id)).unwrap().zone, Zone::Graveyard);
     }
 
     #[test]
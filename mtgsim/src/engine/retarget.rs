@@ -0,0 +1,265 @@
+//! Changing the targets of spells and abilities on the stack (rule 115.7).
+//!
+//! "Change the target" moves a target to another legal one, and leaves it
+//! unchanged — even if it's become illegal — when there's none (rule
+//! 115.7a). Deflection and Misdirection change the target of a spell with a
+//! single target to one their controller chooses; Spellskite changes a
+//! target to itself. "Choose new targets" lets a player keep or change each
+//! target (rule 115.7d), as copies do (rule 707.10c). Either way only the
+//! final set of targets is checked (rule 115.7e), with `validate_targets`
+//! against the spell's own `EffectRecipient`.
+
+use crate::engine::resolve::ResolvedTarget;
+use crate::events::event::GameEvent;
+use crate::oracle::legality::enumerate_legal_selections;
+use crate::state::game_state::GameState;
+use crate::types::effects::{Effect, EffectRecipient};
+use crate::types::ids::{ObjectId, PlayerId};
+use crate::ui::ask::{ask_change_target, ask_choose_new_targets};
+use crate::ui::decision::DecisionProvider;
+
+impl GameState {
+    /// Change the target of `spell`, a spell or ability with a single
+    /// target, to another legal one of `chooser`'s choice (rule 115.7a).
+    /// Returns whether it changed.
+    pub fn change_target(
+        &mut self,
+        spell: ObjectId,
+        chooser: PlayerId,
+        changed_by: ObjectId,
+        dp: &dyn DecisionProvider,
+    ) -> Result<bool, String> {
+        let Some(entry) = self.stack_entries.get(&spell) else {
+            return Ok(false);
+        };
+        if entry.fused_half.as_ref().is_some_and(|h| !h.chosen_targets.is_empty()) {
+            return Ok(false);
+        }
+        let &[current] = entry.chosen_targets.as_slice() else {
+            return Ok(false);
+        };
+        let recipient = self.extract_recipient(&entry.effect);
+        let EffectRecipient::Target(filter, _) = &recipient else {
+            return Ok(false);
+        };
+        let alternatives: Vec<ResolvedTarget> = enumerate_legal_selections(self, filter, Some(spell))
+            .into_iter()
+            .filter(|&t| t != current)
+            .filter(|t| self.validate_targets(&recipient, std::slice::from_ref(t)).is_ok())
+            .collect();
+        if alternatives.is_empty() {
+            return Ok(false);
+        }
+        let new_target = ask_change_target(dp, self, chooser, &recipient, spell, &alternatives);
+        self.retarget(spell, vec![new_target], changed_by)?;
+        Ok(true)
+    }
+
+    /// Change a target of `spell` to `new_target` — "change the target of
+    /// target spell or ability to Spellskite" (rule 115.7a). Nothing changes
+    /// if `new_target` isn't legal for it, or is already one of its targets.
+    /// With several targets, `chooser` picks which one changes. Returns
+    /// whether one did.
+    pub fn change_target_to(
+        &mut self,
+        spell: ObjectId,
+        new_target: ResolvedTarget,
+        chooser: PlayerId,
+        changed_by: ObjectId,
+        dp: &dyn DecisionProvider,
+    ) -> Result<bool, String> {
+        let Some(entry) = self.stack_entries.get(&spell) else {
+            return Ok(false);
+        };
+        // A spell or ability can't target itself (rule 115.5).
+        if new_target == ResolvedTarget::Object(spell) || entry.chosen_targets.contains(&new_target) {
+            return Ok(false);
+        }
+        let recipient = self.extract_recipient(&entry.effect);
+        if !matches!(recipient, EffectRecipient::Target(_, _)) {
+            return Ok(false);
+        }
+        let current = entry.chosen_targets.clone();
+        let with_new = |old: ResolvedTarget| -> Vec<ResolvedTarget> {
+            current.iter().map(|&t| if t == old { new_target } else { t }).collect()
+        };
+        let changeable: Vec<ResolvedTarget> = current.iter().copied()
+            .filter(|&old| self.validate_targets(&recipient, &with_new(old)).is_ok())
+            .collect();
+        let old = match changeable.as_slice() {
+            [] => return Ok(false),
+            [only] => *only,
+            _ => ask_change_target(dp, self, chooser, &recipient, spell, &changeable),
+        };
+        self.retarget(spell, with_new(old), changed_by)?;
+        Ok(true)
+    }
+
+    /// "You may choose new targets for" `spell` (rule 115.7d) — for each of
+    /// a fused split spell's halves too. Returns whether any changed.
+    pub fn choose_new_targets_for(
+        &mut self,
+        spell: ObjectId,
+        chooser: PlayerId,
+        changed_by: ObjectId,
+        dp: &dyn DecisionProvider,
+    ) -> Result<bool, String> {
+        let Some(entry) = self.stack_entries.get(&spell).cloned() else {
+            return Ok(false);
+        };
+        let targets = self.choose_new_targets(chooser, spell, &entry.effect, &entry.chosen_targets, dp);
+        let half_targets = entry.fused_half.as_ref()
+            .map(|half| self.choose_new_targets(chooser, spell, &half.effect, &half.chosen_targets, dp));
+        let changed = targets != entry.chosen_targets
+            || half_targets.as_ref().zip(entry.fused_half.as_ref())
+                .is_some_and(|(new, half)| *new != half.chosen_targets);
+        if !changed {
+            return Ok(false);
+        }
+        if let Some(new) = half_targets
+            && let Some(half) = self.stack_entries.get_mut(&spell).and_then(|e| e.fused_half.as_mut())
+        {
+            half.chosen_targets = new;
+        }
+        self.retarget(spell, targets, changed_by)?;
+        Ok(true)
+    }
+
+    /// The targets `spell_id`, following `effect`, ends up with once
+    /// `chooser` may choose new ones: each of `current` kept or changed, in
+    /// its place, so a spell whose targets play different roles keeps them
+    /// (rules 115.7d, 707.10c). If the final set isn't legal for `effect`'s
+    /// recipient (rule 115.7e), none change. Only targets can change, not
+    /// what the instructions "choose".
+    pub(crate) fn choose_new_targets(
+        &self,
+        chooser: PlayerId,
+        spell_id: ObjectId,
+        effect: &Effect,
+        current: &[ResolvedTarget],
+        dp: &dyn DecisionProvider,
+    ) -> Vec<ResolvedTarget> {
+        let recipient = self.extract_recipient(effect);
+        let EffectRecipient::Target(filter, _) = &recipient else {
+            return current.to_vec();
+        };
+        if current.is_empty() {
+            return Vec::new();
+        }
+        let legal = enumerate_legal_selections(self, filter, Some(spell_id));
+        let proposed = ask_choose_new_targets(dp, self, chooser, &recipient, spell_id, current, &legal);
+        let mut new_ones = proposed.iter().copied().filter(|t| !current.contains(t));
+        let targets: Vec<ResolvedTarget> = current.iter()
+            .map(|&t| if proposed.contains(&t) { t } else { new_ones.next().unwrap_or(t) })
+            .collect();
+        if self.validate_targets(&recipient, &targets).is_ok() { targets } else { current.to_vec() }
+    }
+
+    /// Give `spell` the targets `targets`.
+    fn retarget(
+        &mut self,
+        spell: ObjectId,
+        targets: Vec<ResolvedTarget>,
+        changed_by: ObjectId,
+    ) -> Result<(), String> {
+        let entry = self.stack_entries.get_mut(&spell)
+            .ok_or_else(|| format!("Object {} is not a spell or ability on the stack", spell))?;
+        entry.chosen_targets = targets;
+        self.events.emit(GameEvent::TargetsChanged { spell_id: spell, changed_by });
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::engine::resolve::ResolvedTarget;
+    use crate::objects::card_data::{AbilityDef, AbilityType, CardDataBuilder, CardFace};
+    use crate::objects::object::GameObject;
+    use crate::state::game_state::{GameState, StackEntry};
+    use crate::types::card_types::CardType;
+    use crate::types::effects::{AmountExpr, Effect, EffectRecipient, Primitive, SelectionFilter, TargetCount};
+    use crate::types::ids::{new_ability_id, ObjectId};
+    use crate::types::zones::Zone;
+    use crate::ui::choice_types::ChoiceKind;
+    use crate::ui::decision::ScriptedDecisionProvider;
+
+    /// A spell dealing 2 damage to target player, aimed at player 1.
+    fn spell_at_player_one(game: &mut GameState) -> ObjectId {
+        let effect = Effect::Atom(
+            Primitive::DealDamage(AmountExpr::Fixed(2)),
+            EffectRecipient::Target(SelectionFilter::Player, TargetCount::Exactly(1)),
+        );
+        let data = CardDataBuilder::new("Shock")
+            .card_type(CardType::Instant)
+            .ability(AbilityDef {
+                id: new_ability_id(),
+                ability_type: AbilityType::Spell,
+                costs: Vec::new(),
+                effect: effect.clone(),
+            })
+            .build();
+        let id = game.add_object(GameObject::new(data, 0, Zone::Stack));
+        game.stack.push(id);
+        game.stack_entries.insert(id, StackEntry {
+            object_id: id,
+            controller: 0,
+            chosen_targets: vec![ResolvedTarget::Player(1)],
+            chosen_modes: Vec::new(),
+            x_value: None,
+            effect,
+            is_spell: true,
+            ability_source: None,
+            chosen_alternative_cost: None,
+            additional_costs_paid: Vec::new(),
+            cast_face: CardFace::Front,
+            fused_half: None,
+            is_copy: false,
+            is_triggered: false,
+        });
+        id
+    }
+
+    #[test]
+    fn test_change_target_to_illegal_object_leaves_it_unchanged() {
+        let mut game = GameState::new(2, 20);
+        let spell = spell_at_player_one(&mut game);
+        let creature = CardDataBuilder::new("Wall")
+            .card_type(CardType::Creature)
+            .power_toughness(0, 4)
+            .build();
+        let wall = game.add_object(GameObject::new(creature, 1, Zone::Battlefield));
+        game.place_on_battlefield(wall, 1);
+
+        let dp = ScriptedDecisionProvider::new();
+        let changed = game.change_target_to(spell, ResolvedTarget::Object(wall), 1, wall, &dp).unwrap();
+        assert!(!changed, "a creature isn't a legal target for \"target player\"");
+        assert_eq!(game.stack_entries[&spell].chosen_targets, vec![ResolvedTarget::Player(1)]);
+
+        let changed = game.change_target_to(spell, ResolvedTarget::Player(0), 1, wall, &dp).unwrap();
+        assert!(changed);
+        assert_eq!(game.stack_entries[&spell].chosen_targets, vec![ResolvedTarget::Player(0)]);
+    }
+
+    #[test]
+    fn test_new_targets_keep_their_places() {
+        let mut game = GameState::new(3, 20);
+        let spell = spell_at_player_one(&mut game);
+        let effect = Effect::Atom(
+            Primitive::DealDamage(AmountExpr::Fixed(2)),
+            EffectRecipient::Target(SelectionFilter::Player, TargetCount::Exactly(2)),
+        );
+        let entry = game.stack_entries.get_mut(&spell).unwrap();
+        entry.effect = effect.clone();
+        entry.chosen_targets = vec![ResolvedTarget::Player(1), ResolvedTarget::Player(2)];
+
+        // Options: [Player(1), Player(2), Player(0)]. Keep Player(2) and
+        // change Player(1) to Player(0).
+        let dp = ScriptedDecisionProvider::new();
+        dp.expect_pick_n(
+            ChoiceKind::ChooseNewTargets { recipient: EffectRecipient::Implicit, spell_id: spell },
+            vec![1, 2],
+        );
+        let targets = game.choose_new_targets(0, spell, &effect, &game.stack_entries[&spell].chosen_targets, &dp);
+        assert_eq!(targets, vec![ResolvedTarget::Player(0), ResolvedTarget::Player(2)]);
+    }
+}
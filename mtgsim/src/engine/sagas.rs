@@ -0,0 +1,145 @@
+//! Sagas (rule 714).
+//!
+//! A Saga enters with a lore counter (rule 714.3a) and gets another as its
+//! controller's precombat main phase begins (rule 714.3b). Its chapter
+//! abilities are triggered abilities keyed by lore-counter thresholds: each
+//! triggers as the count goes from below its chapter number to at least it
+//! (rule 714.2b). Once the count reaches the final chapter and no chapter
+//! ability of the Saga is still waiting or on the stack, state-based
+//! actions sacrifice it (rule 714.4, SBA 704.5s).
+
+use crate::oracle::characteristics::{get_effective_abilities, has_subtype};
+use crate::state::game_state::GameState;
+use crate::types::card_types::{EnchantmentType, Subtype};
+use crate::types::effects::CounterType;
+use crate::types::ids::ObjectId;
+
+impl GameState {
+    /// Whether `id` is a Saga.
+    pub fn is_saga(&self, id: ObjectId) -> bool {
+        has_subtype(self, id, &Subtype::Enchantment(EnchantmentType::Saga))
+    }
+
+    /// The chapter number of `id`'s final chapter ability (rule 714.2c), or
+    /// None if it has no chapter abilities.
+    pub fn final_chapter(&self, id: ObjectId) -> Option<u32> {
+        get_effective_abilities(self, id).iter()
+            .filter_map(|ability| ability.chapter())
+            .max()
+    }
+
+    /// Put `n` lore counters on Saga `id`, queueing each chapter ability
+    /// whose chapter number the count reaches from below (rule 714.2b), in
+    /// chapter order.
+    pub fn add_lore_counters(&mut self, id: ObjectId, n: u32) {
+        let Some(entry) = self.battlefield.get_mut(&id) else { return };
+        let before = entry.counter_count(CounterType::Lore);
+        entry.add_counters(CounterType::Lore, n);
+        let after = before + n;
+        let controller = entry.controller;
+
+        let mut chapters: Vec<_> = get_effective_abilities(self, id).into_iter()
+            .filter_map(|ability| ability.chapter().map(|chapter| (chapter, ability.effect)))
+            .filter(|(chapter, _)| before < *chapter && *chapter <= after)
+            .collect();
+        chapters.sort_by_key(|(chapter, _)| *chapter);
+        for (_, effect) in chapters {
+            self.queue_trigger(controller, id, effect.bind_controller(controller));
+        }
+    }
+
+    /// Turn-based action as the precombat main phase begins: each active
+    /// player puts a lore counter on each Saga they control (rule 714.3b).
+    pub(crate) fn add_precombat_lore_counters(&mut self) {
+        let active = self.active_players();
+        let mut sagas: Vec<ObjectId> = self.permanents()
+            .filter(|(_, entry)| active.contains(&entry.controller))
+            .map(|(&id, _)| id)
+            .filter(|&id| self.is_saga(id))
+            .collect();
+        sagas.sort();
+        for id in sagas {
+            self.add_lore_counters(id, 1);
+        }
+    }
+
+    /// Whether state-based actions sacrifice Saga `id` (rule 714.4): its lore
+    /// counters are at least its final chapter number, and none of its
+    /// chapter abilities has triggered without leaving the stack. Only a
+    /// Saga with one or more chapter abilities is ever sacrificed this way:
+    /// one that has lost them (to Blood Moon, say) stays (rule 714.4).
+    pub(crate) fn saga_is_finished(&self, id: ObjectId) -> bool {
+        let Some(entry) = self.permanent(id) else { return false };
+        let Some(last) = self.final_chapter(id) else { return false };
+        entry.counter_count(CounterType::Lore) >= last
+            && !self.has_unresolved_trigger_from(id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::objects::card_data::CardDataBuilder;
+    use crate::objects::object::GameObject;
+    use crate::state::game_state::GameState;
+    use crate::types::card_types::{CardType, EnchantmentType, Subtype};
+    use crate::types::effects::{AmountExpr, CounterType, Effect, EffectRecipient, Primitive};
+    use crate::types::ids::ObjectId;
+    use crate::types::zones::Zone;
+
+    fn gain_life(amount: u64) -> Effect {
+        Effect::Atom(Primitive::GainLife(AmountExpr::Fixed(amount)), EffectRecipient::Controller)
+    }
+
+    fn three_chapter_saga(game: &mut GameState) -> ObjectId {
+        let card = CardDataBuilder::new("Test Saga")
+            .card_type(CardType::Enchantment)
+            .subtype(Subtype::Enchantment(EnchantmentType::Saga))
+            .chapter(1, gain_life(1))
+            .chapter(2, gain_life(2))
+            .chapter(3, gain_life(3))
+            .build();
+        let id = game.add_object(GameObject::new(card, 0, Zone::Battlefield));
+        game.place_on_battlefield(id, 0);
+        id
+    }
+
+    #[test]
+    fn test_saga_enters_with_a_lore_counter_and_triggers_chapter_one() {
+        let mut game = GameState::new(2, 20);
+        let saga = three_chapter_saga(&mut game);
+        assert!(game.is_saga(saga));
+        assert_eq!(game.final_chapter(saga), Some(3));
+        assert_eq!(game.battlefield[&saga].counter_count(CounterType::Lore), 1);
+        assert_eq!(game.pending_triggers.len(), 1);
+        assert!(!game.saga_is_finished(saga));
+    }
+
+    #[test]
+    fn test_several_lore_counters_trigger_each_chapter_reached() {
+        let mut game = GameState::new(2, 20);
+        let saga = three_chapter_saga(&mut game);
+        game.pending_triggers.clear();
+
+        game.add_lore_counters(saga, 2);
+        let effects: Vec<_> = game.pending_triggers.iter().map(|t| t.effect.clone()).collect();
+        assert_eq!(effects, vec![gain_life(2), gain_life(3)]);
+        assert!(!game.saga_is_finished(saga), "its chapter abilities haven't left the stack");
+
+        game.pending_triggers.clear();
+        assert!(game.saga_is_finished(saga));
+    }
+
+    #[test]
+    fn test_saga_without_chapter_abilities_is_not_sacrificed() {
+        let mut game = GameState::new(2, 20);
+        let data = CardDataBuilder::new("Blank Saga")
+            .card_type(CardType::Enchantment)
+            .subtype(Subtype::Enchantment(EnchantmentType::Saga))
+            .build();
+        let id = game.add_object(GameObject::new(data, 0, Zone::Battlefield));
+        game.place_on_battlefield(id, 0);
+
+        assert_eq!(game.final_chapter(id), None);
+        assert!(!game.saga_is_finished(id));
+    }
+}
@@ -8,6 +8,7 @@ use crate::oracle::characteristics::{
 use crate::types::keywords::KeywordAbility;
 use crate::state::game_state::GameState;
 use crate::types::card_types::{ArtifactType, CardType, EnchantmentType, Subtype, Supertype};
+use crate::engine::actions::GameAction;
 use crate::engine::resolve::ResolvedTarget;
 use crate::types::effects::CounterType;
 use crate::types::ids::ObjectId;
@@ -20,6 +21,9 @@ use crate::ui::decision::DecisionProvider;
 /// SBAs are checked whenever a player would receive priority. They don't use
 /// the stack — they just happen. If any SBA is performed, they're all checked
 /// again before a player actually gets priority.
+///
+/// Only phased-in permanents are checked: a phased-out permanent is treated
+/// as though it doesn't exist (rule 702.26b), whatever its state.
 
 impl GameState {
     /// Check and perform all state-based actions.
@@ -29,6 +33,16 @@ impl GameState {
         decisions: &dyn DecisionProvider,
     ) -> Result<bool, String> {
         let mut any_performed = false;
+        let lost_before = self.player_lost.clone();
+
+        // 704.6d — Commander: a commander put into a graveyard or exile since
+        // the last check may be put into the command zone (rule 903.9a).
+        // Checked first so commanders that die in this round of SBAs are
+        // offered on the next one, as the rule requires.
+        if self.return_commanders_to_command_zone(decisions)? {
+            self.events.emit(GameEvent::StateBasedActionPerformed);
+            any_performed = true;
+        }
 
         // 704.5a — Player with 0 or less life loses the game
         for i in 0..self.players.len() {
@@ -57,8 +71,14 @@ impl GameState {
         }
 
         // 704.5c — Player with 10 or more poison counters loses the game
+        // 704.6b — In Two-Headed Giant, a team with 15 or more loses instead
         for i in 0..self.players.len() {
-            if self.players[i].poison_counters >= 10 && !self.player_lost[i] {
+            let poisoned = if self.teams.is_empty() {
+                self.players[i].poison_counters >= 10
+            } else {
+                self.team_poison_counters(i) >= 15
+            };
+            if poisoned && !self.player_lost[i] {
                 self.player_lost[i] = true;
                 self.events.emit(GameEvent::PlayerLost {
                     player_id: i,
@@ -86,8 +106,34 @@ impl GameState {
             }
         }
 
+        // 810.8a — In a team game, a player losing means their team loses
+        for i in 0..self.players.len() {
+            if !self.player_lost[i] && self.teammates_lost(i) {
+                self.player_lost[i] = true;
+                self.events.emit(GameEvent::PlayerLost {
+                    player_id: i,
+                    reason: LossReason::TeamLost,
+                });
+                self.events.emit(GameEvent::StateBasedActionPerformed);
+                any_performed = true;
+            }
+        }
+
+        // 800.4a — In a multiplayer game that goes on, players who just lost
+        // leave it, taking their objects with them. With one side (or none)
+        // left the game is over and nothing needs to leave.
+        if self.sides_in_game() >= 2 {
+            let leaving: Vec<usize> = (0..self.players.len())
+                .filter(|&i| self.player_lost[i] && !lost_before[i])
+                .collect();
+            for i in leaving {
+                self.remove_player_from_game(i)?;
+            }
+        }
+
         // 704.5f — Creature with toughness 0 or less is put into owner's graveyard
-        let zero_toughness: Vec<ObjectId> = self.battlefield.keys()
+        let zero_toughness: Vec<ObjectId> = self.permanents()
+            .map(|(id, _)| id)
             .filter(|id| {
                 if is_creature(self, **id) {
                     let effective_t = get_effective_toughness(self, **id).unwrap_or(0);
@@ -106,17 +152,20 @@ impl GameState {
         }
 
         // 704.5g — Creature with lethal damage is destroyed
-        // Also handles deathtouch (rule 702.2b): any nonzero damage from a
-        // deathtouch source is lethal.
-        let lethal_damage: Vec<ObjectId> = self.battlefield.keys()
+        // 704.5h — Creature dealt damage by a deathtouch source is destroyed
+        // (rule 702.2b): any nonzero damage from a deathtouch source is lethal,
+        // even wither or infect damage that left none marked.
+        // Both are destruction, so indestructible (702.12b) and regeneration
+        // (701.19a) apply — unlike 704.5f above.
+        let lethal_damage: Vec<ObjectId> = self.permanents()
+            .map(|(id, _)| id)
             .filter(|id| {
                 if is_creature(self, **id) {
                     let effective_t = get_effective_toughness(self, **id).unwrap_or(0);
                     if effective_t <= 0 { return false; } // handled by 704.5f
                     let entry = self.battlefield.get(id).unwrap();
                     // Normal lethal damage OR any damage from deathtouch source
-                    return entry.damage_marked >= effective_t as u32
-                        || (entry.damage_marked > 0 && entry.damaged_by_deathtouch);
+                    return entry.damage_marked >= effective_t as u32 || entry.damaged_by_deathtouch;
                 }
                 false
             })
@@ -128,15 +177,13 @@ impl GameState {
             if has_keyword(self, id, KeywordAbility::Indestructible) {
                 continue;
             }
-            let owner = self.objects.get(&id).map(|o| o.owner).unwrap_or(0);
-            // TODO: check for regeneration
-            self.change_zone(id, Zone::Graveyard)?;
-            self.events.emit(GameEvent::CreatureDied { creature_id: id, owner });
+            self.execute_action(GameAction::Destroy { object: id, can_regenerate: true })?;
             any_performed = true;
         }
 
         // 704.5i — Planeswalker with 0 loyalty is put into owner's graveyard
-        let pw_zero_loyalty: Vec<ObjectId> = self.battlefield.keys()
+        let pw_zero_loyalty: Vec<ObjectId> = self.permanents()
+            .map(|(id, _)| id)
             .filter(|id| {
                 if self.objects.contains_key(id) {
                     if has_type(self, **id, CardType::Planeswalker) {
@@ -157,13 +204,104 @@ impl GameState {
             any_performed = true;
         }
 
+        // 704.5v — Battle with 0 defense is put into its owner's graveyard,
+        // unless it's the source of a triggered ability that hasn't left the
+        // stack. A Siege always is: its defeat ability (310.11b) triggered
+        // when the last defense counter was removed, so it's handed to
+        // `defeat_siege` instead.
+        let mut battles_zero_defense: Vec<ObjectId> = self.permanents()
+            .filter(|(id, entry)| {
+                has_type(self, **id, CardType::Battle)
+                    && entry.counter_count(CounterType::Defense) == 0
+            })
+            .map(|(id, _)| *id)
+            .collect();
+        battles_zero_defense.sort();
+
+        for id in battles_zero_defense {
+            if self.is_siege(id) {
+                self.defeat_siege(id, decisions)?;
+            } else {
+                let owner = self.objects.get(&id).map(|o| o.owner).unwrap_or(0);
+                self.change_zone(id, Zone::Graveyard)?;
+                self.events.emit(GameEvent::BattleDied { object_id: id, owner });
+            }
+            self.events.emit(GameEvent::StateBasedActionPerformed);
+            any_performed = true;
+        }
+
+        // 704.5w — Battle with no protector (or whose protector left the
+        // game) that isn't being attacked: its controller chooses a new one.
+        // 704.5x — Siege whose controller is also its protector: its
+        // controller chooses an opponent to protect it.
+        // Either way, with no eligible player the battle goes to its owner's
+        // graveyard.
+        let mut battles_need_protector: Vec<ObjectId> = self.permanents()
+            .filter(|(id, entry)| {
+                if !has_type(self, **id, CardType::Battle) {
+                    return false;
+                }
+                match entry.protector {
+                    Some(p) if self.player_lost.get(p).copied().unwrap_or(true) => {
+                        !self.battle_is_attacked(**id)
+                    }
+                    Some(p) => p == entry.controller && self.is_siege(**id),
+                    None => !self.battle_is_attacked(**id),
+                }
+            })
+            .map(|(id, _)| *id)
+            .collect();
+        battles_need_protector.sort();
+
+        for id in battles_need_protector {
+            if !self.choose_battle_protector(id, decisions)? {
+                let owner = self.objects.get(&id).map(|o| o.owner).unwrap_or(0);
+                self.change_zone(id, Zone::Graveyard)?;
+                self.events.emit(GameEvent::BattleDied { object_id: id, owner });
+            }
+            self.events.emit(GameEvent::StateBasedActionPerformed);
+            any_performed = true;
+        }
+
+        // 704.5s — Saga whose lore counters have reached its final chapter,
+        // and that isn't the source of a chapter ability that has triggered
+        // but not yet left the stack, is sacrificed (rule 714.4).
+        let mut finished_sagas: Vec<ObjectId> = self.permanents()
+            .map(|(&id, _)| id)
+            .filter(|&id| self.is_saga(id) && self.saga_is_finished(id))
+            .collect();
+        finished_sagas.sort();
+
+        for id in finished_sagas {
+            let owner = self.objects.get(&id).map(|o| o.owner).unwrap_or(0);
+            self.change_zone(id, Zone::Graveyard)?;
+            self.events.emit(GameEvent::SagaSacrificed { object_id: id, owner });
+            self.events.emit(GameEvent::StateBasedActionPerformed);
+            any_performed = true;
+        }
+
+        // 704.5t — a dungeon whose venture marker is on its bottommost room,
+        // and that isn't the source of a room ability that has triggered but
+        // not yet left the stack, is completed and removed from the game.
+        let mut completed_dungeons: Vec<ObjectId> = self.venture_markers.keys()
+            .copied()
+            .filter(|&id| self.dungeon_is_complete(id))
+            .collect();
+        completed_dungeons.sort();
+
+        for id in completed_dungeons {
+            self.complete_dungeon(id)?;
+            self.events.emit(GameEvent::StateBasedActionPerformed);
+            any_performed = true;
+        }
+
         // 704.5j — Legend rule: if a player controls two or more legendary
         // permanents with the same name, they choose one to keep and the
         // rest are put into their owners' graveyards.
         {
             // Group legendary permanents by (controller, effective_name)
             let mut legend_groups: HashMap<(usize, String), Vec<ObjectId>> = HashMap::new();
-            for (&id, entry) in &self.battlefield {
+            for (&id, entry) in self.permanents() {
                 if self.objects.contains_key(&id) {
                     if has_supertype(self, id, Supertype::Legendary) {
                         let name = get_effective_name(self, id);
@@ -206,9 +344,9 @@ impl GameState {
         //
         // Collect aura IDs in a single pass to avoid borrow-checker issues:
         // we need &self.objects for subtype checks but &mut self for move_object.
-        let auras_to_graveyard: Vec<ObjectId> = self.battlefield.iter()
+        let auras_to_graveyard: Vec<ObjectId> = self.permanents()
             .filter_map(|(&id, entry)| {
-                let obj = self.objects.get(&id)?;
+                let data = self.face_up_data(id)?;
                 if !has_subtype(self, id, &Subtype::Enchantment(EnchantmentType::Aura)) {
                     return None;
                 }
@@ -221,7 +359,7 @@ impl GameState {
                             return Some(id);
                         }
                         // 704.5n: host doesn't match enchant filter
-                        if let Some(filter) = &obj.card_data.enchant_filter {
+                        if let Some(filter) = &data.enchant_filter {
                             let candidate = ResolvedTarget::Object(host_id);
                             if self.validate_selection(filter, &candidate).is_err() {
                                 return Some(id);
@@ -243,7 +381,7 @@ impl GameState {
 
         // 704.5p — Equipment/Fortification attached to non-creature → unattach
         // Equipment stays on the battlefield; only the attachment is broken.
-        let equip_bad_host: Vec<(ObjectId, ObjectId)> = self.battlefield.iter()
+        let equip_bad_host: Vec<(ObjectId, ObjectId)> = self.permanents()
             .filter_map(|(&id, entry)| {
                 self.objects.get(&id)?;
                 let has_equip = has_subtype(self, id, &Subtype::Artifact(ArtifactType::Equipment));
@@ -274,7 +412,7 @@ impl GameState {
         // Equipment, nor Fortification is attached to another permanent, it becomes
         // unattached. This catches illegal attachment state that may arise from
         // type-changing effects.
-        let illegal_attachments: Vec<(ObjectId, ObjectId)> = self.battlefield.iter()
+        let illegal_attachments: Vec<(ObjectId, ObjectId)> = self.permanents()
             .filter_map(|(&id, entry)| {
                 self.objects.get(&id)?;
                 let is_aura = has_subtype(self, id, &Subtype::Enchantment(EnchantmentType::Aura));
@@ -310,7 +448,7 @@ impl GameState {
         // 704.5q — +1/+1 and -1/-1 counter annihilation
         // If a permanent has both +1/+1 and -1/-1 counters, remove pairs
         // until only one type remains.
-        let annihilation_targets: Vec<(ObjectId, u32)> = self.battlefield.iter()
+        let annihilation_targets: Vec<(ObjectId, u32)> = self.permanents()
             .filter_map(|(&id, entry)| {
                 let plus = entry.counter_count(CounterType::PlusOnePlusOne);
                 let minus = entry.counter_count(CounterType::MinusOneMinusOne);
@@ -351,6 +489,23 @@ impl GameState {
             any_performed = true;
         }
 
+        // 704.5e — A copy of a spell in a zone other than the stack ceases
+        // to exist. A copy of a permanent spell that resolved is a token
+        // instead (rule 111.12), handled by 704.5d above.
+        let copies_to_remove: Vec<(ObjectId, Zone)> = self.objects.iter()
+            .filter(|(_, obj)| obj.is_copy && !obj.is_token
+                && !matches!(obj.zone, Zone::Stack | Zone::Battlefield))
+            .map(|(&id, obj)| (id, obj.zone))
+            .collect();
+
+        for (id, zone) in copies_to_remove {
+            self.remove_from_zone_collection(id, zone)?;
+            self.objects.remove(&id);
+            self.events.emit(GameEvent::CopyCeasedToExist { object_id: id });
+            self.events.emit(GameEvent::StateBasedActionPerformed);
+            any_performed = true;
+        }
+
         Ok(any_performed)
     }
 
@@ -403,7 +558,7 @@ mod tests {
         assert!(performed);
         assert!(!game.battlefield.contains_key(&bears_id));
         assert_eq!(game.players[0].graveyard.len(), 1);
-        assert_eq!(game.get_object(bears_id).unwrap().zone, Zone::Graveyard);
+        assert_eq!(game.get_object(game.current_identity(bears_id)).unwrap().zone, Zone::Graveyard);
     }
 
     #[test]
@@ -425,15 +580,15 @@ mod tests {
         let performed = game.check_state_based_actions(&ScriptedDecisionProvider::new()).unwrap();
         assert!(performed);
         assert!(!game.battlefield.contains_key(&id));
-        assert_eq!(game.get_object(id).unwrap().zone, Zone::Graveyard);
+        assert_eq!(game.get_object(game.current_identity(id)).unwrap().zone, Zone::Graveyard);
     }
 
     #[test]
-    fn test_sba_deathtouch_zero_damage_no_destroy() {
+    fn test_sba_deathtouch_without_marked_damage_destroys_creature() {
         let mut game = GameState::new(2, 20);
 
-        // Creature with deathtouch flag but 0 damage (shouldn't happen normally,
-        // but verify the guard)
+        // Deathtouch flag but no marked damage: the damage was dealt as
+        // -1/-1 counters by a source with wither or infect.
         let data = CardDataBuilder::new("Earth Elemental")
             .card_type(CardType::Creature)
             .power_toughness(4, 5)
@@ -446,8 +601,8 @@ mod tests {
         bf.damaged_by_deathtouch = true;
 
         let performed = game.check_state_based_actions(&ScriptedDecisionProvider::new()).unwrap();
-        assert!(!performed);
-        assert!(game.battlefield.contains_key(&id));
+        assert!(performed);
+        assert!(!game.battlefield.contains_key(&id));
     }
 
     #[test]
@@ -723,7 +878,7 @@ mod tests {
         let performed = game.check_state_based_actions(&ScriptedDecisionProvider::new()).unwrap();
         assert!(performed);
         assert!(!game.battlefield.contains_key(&pw_id));
-        assert_eq!(game.get_object(pw_id).unwrap().zone, Zone::Graveyard);
+        assert_eq!(game.get_object(game.current_identity(pw_id)).unwrap().zone, Zone::Graveyard);
     }
 
     #[test]
@@ -797,7 +952,7 @@ mod tests {
         let performed = game.check_state_based_actions(&ScriptedDecisionProvider::new()).unwrap();
         assert!(performed);
         assert!(!game.battlefield.contains_key(&pw_id));
-        assert_eq!(game.get_object(pw_id).unwrap().zone, Zone::Graveyard);
+        assert_eq!(game.get_object(game.current_identity(pw_id)).unwrap().zone, Zone::Graveyard);
     }
 
     // -----------------------------------------------------------------------
@@ -823,7 +978,7 @@ mod tests {
         let performed = game.check_state_based_actions(&ScriptedDecisionProvider::new()).unwrap();
         assert!(performed);
         assert!(!game.battlefield.contains_key(&aura_id));
-        assert_eq!(game.get_object(aura_id).unwrap().zone, Zone::Graveyard);
+        assert_eq!(game.get_object(game.current_identity(aura_id)).unwrap().zone, Zone::Graveyard);
 
         // Verify AuraDied event was emitted
         let has_event = game.events.events().iter().any(|e| {
@@ -878,7 +1033,7 @@ mod tests {
         let performed = game.check_state_based_actions(&ScriptedDecisionProvider::new()).unwrap();
         assert!(performed);
         assert!(!game.battlefield.contains_key(&aura_id));
-        assert_eq!(game.get_object(aura_id).unwrap().zone, Zone::Graveyard);
+        assert_eq!(game.get_object(game.current_identity(aura_id)).unwrap().zone, Zone::Graveyard);
     }
 
     #[test]
@@ -1054,7 +1209,7 @@ mod tests {
         let performed = game.check_state_based_actions(&ScriptedDecisionProvider::new()).unwrap();
         assert!(performed);
         assert!(!game.battlefield.contains_key(&aura_id));
-        assert_eq!(game.get_object(aura_id).unwrap().zone, Zone::Graveyard);
+        assert_eq!(game.get_object(game.current_identity(aura_id)).unwrap().zone, Zone::Graveyard);
     }
 
     // -----------------------------------------------------------------------
@@ -1171,7 +1326,7 @@ mod tests {
 
         // Aura should be in the graveyard
         assert!(!game.battlefield.contains_key(&aura_id));
-        assert_eq!(game.get_object(aura_id).unwrap().zone, Zone::Graveyard);
+        assert_eq!(game.get_object(game.current_identity(aura_id)).unwrap().zone, Zone::Graveyard);
 
         // Land should still be on the battlefield, with no attachments
         assert!(game.battlefield.contains_key(&land_id));
@@ -1,8 +1,8 @@
-use crate::oracle::characteristics::{has_permanent_type, has_subtype};
+use crate::oracle::characteristics::{has_permanent_type, has_subtype, has_type};
 use crate::engine::resolve::{ResolutionContext, ResolvedTarget};
 use crate::events::event::GameEvent;
 use crate::state::game_state::GameState;
-use crate::types::card_types::{EnchantmentType, Subtype};
+use crate::types::card_types::{CardType, EnchantmentType, Subtype};
 use crate::types::effects::EffectRecipient;
 use crate::types::zones::Zone;
 use crate::ui::decision::DecisionProvider;
@@ -32,33 +32,45 @@ impl GameState {
         // manually below instead of going through move_object (which would try
         // to remove from the stack Vec a second time).
         let object_id = self.stack.pop().unwrap();
+        // Read while the stack entry still says which face or half was cast:
+        // an Adventure is an instant or sorcery spell even though the card's
+        // creature is a permanent (rule 715.3).
+        let is_permanent_type = has_permanent_type(self, object_id);
         let entry = self.stack_entries.remove(&object_id)
             .ok_or_else(|| format!("No StackEntry for object {}", object_id))?;
 
         // --- Re-validate targets (rule 608.2b) ---
-        let recipient = self.extract_recipient(&entry.effect);
-        let has_targets = matches!(recipient, EffectRecipient::Target(_, _));
-
-        if has_targets && !self.any_targets_still_legal(&recipient, &entry.chosen_targets) {
+        // A fused split spell is countered only if every target of both its
+        // halves is illegal; a half whose targets are all gone does nothing.
+        let live = self.targets_live(&entry.effect, &entry.chosen_targets);
+        let fused_live = entry.fused_half.as_ref()
+            .map(|half| self.targets_live(&half.effect, &half.chosen_targets));
+        if !live && !fused_live.unwrap_or(false) {
             // All targets illegal — spell/ability fizzles (is countered by game rules)
             self.handle_fizzle(object_id, &entry)?;
             return Ok(());
         }
 
         // --- Resolve the effect (rule 608.2c-m) ---
-        let ctx = ResolutionContext {
+        let mut ctx = ResolutionContext {
             source: object_id,
             controller: entry.controller,
             targets: entry.chosen_targets.clone(),
+            ability_source: entry.ability_source,
         };
-        self.resolve_effect(&entry.effect, &ctx, dp)?;
+        if live {
+            self.resolve_effect(&entry.effect, &ctx, dp)?;
+        }
+        if let Some(half) = &entry.fused_half && fused_live == Some(true) {
+            ctx.targets = half.chosen_targets.clone();
+            self.resolve_effect(&half.effect, &ctx, dp)?;
+        }
 
         // --- Post-resolution (rule 608.2n) ---
         // We already removed the object from self.stack above, so we handle
         // zone transitions manually to avoid move_object double-removing.
         if entry.is_spell {
             self.get_object(object_id)?;
-            let is_permanent_type = has_permanent_type(self, object_id);
 
             if is_permanent_type {
                 // Permanent spell: move to battlefield.
@@ -81,8 +93,13 @@ impl GameState {
                 // need its own ZoneChange dispatch that skips the stack-Vec
                 // removal step. Tracked in codebase-state.md → Deferred
                 // Migrations → Before Replacement.
-                self.get_object_mut(object_id)?.zone = Zone::Battlefield;
-                self.init_zone_state_with_controller(object_id, controller)?;
+                self.record_last_known_information(object_id, Zone::Stack, controller);
+                let object_id = self.renew_identity(object_id);
+                let obj = self.get_object_mut(object_id)?;
+                obj.zone = Zone::Battlefield;
+                // A copy of a permanent spell becomes a token (rule 111.12).
+                obj.is_token |= entry.is_copy;
+                self.init_zone_state_with_controller(object_id, controller, entry.cast_face)?;
                 // Carry X value from the stack entry to the permanent (rule 107.3f)
                 if let Some(bf_entry) = self.battlefield.get_mut(&object_id) {
                     bf_entry.x_value = entry.x_value;
@@ -98,6 +115,13 @@ impl GameState {
                     controller,
                 });
 
+                // Rule 310.8a: as a battle enters, its controller chooses
+                // its protector. `init_etb_counters` assigned a default;
+                // here the controller makes the actual choice.
+                if has_type(self, object_id, CardType::Battle) {
+                    self.choose_battle_protector(object_id, dp)?;
+                }
+
                 // Rule 303.4f: Aura spell resolves → enters attached to its
                 // target.  The fizzle check (608.2b) at the top of this
                 // function guarantees the target is still legal — if it
@@ -121,17 +145,36 @@ impl GameState {
                     }
                 }
             } else {
-                // Instant/sorcery: move to owner's graveyard.
+                // Instant/sorcery: move to owner's graveyard — or to exile
+                // if it ended the turn, which exiles the object that's
+                // resolving along with the rest of the stack (rule 723.1a),
+                // if it's an aftermath half (rule 702.127a), or if it's an
+                // Adventure, which goes on an adventure (rule 715.4).
                 // REPLACEMENT-BYPASS: same rationale as the battlefield path
                 // above — object was already popped from the stack Vec.
                 let owner = self.get_object(object_id)?.owner;
-                self.get_object_mut(object_id)?.zone = Zone::Graveyard;
-                self.get_player_mut(owner)?.graveyard.push(object_id);
+                let to = if self.turn_plan.is_ending() {
+                    Zone::Exile
+                } else {
+                    if !entry.is_copy && self.is_adventure_spell(object_id, entry.cast_face) {
+                        self.on_adventure.insert(object_id);
+                    }
+                    self.resolved_spell_zone(object_id, entry.cast_face)
+                };
+                self.record_last_known_information(object_id, Zone::Stack, entry.controller);
+                let object_id = self.renew_identity(object_id);
+                self.get_object_mut(object_id)?.zone = to;
+                if to == Zone::Exile {
+                    self.exile.push(object_id);
+                } else {
+                    self.get_player_mut(owner)?.graveyard.push(object_id);
+                }
+                self.note_commander_zone_change(object_id, to);
                 self.events.emit(GameEvent::ZoneChange {
                     object_id,
                     owner,
                     from: Zone::Stack,
-                    to: Zone::Graveyard,
+                    to,
                 });
             }
         } else {
@@ -157,17 +200,26 @@ impl GameState {
         entry: &crate::state::game_state::StackEntry,
     ) -> Result<(), String> {
         if entry.is_spell {
-            // Move to graveyard manually (already removed from stack Vec).
+            // Move to graveyard manually (already removed from stack Vec) —
+            // or exile, for an aftermath half (rule 702.127a).
             // REPLACEMENT-BYPASS: same stack-pop-first rationale as the
             // battlefield/graveyard paths in `resolve_top_of_stack`.
             let owner = self.get_object(object_id)?.owner;
-            self.get_object_mut(object_id)?.zone = Zone::Graveyard;
-            self.get_player_mut(owner)?.graveyard.push(object_id);
+            let to = self.leaving_stack_zone(object_id, entry.cast_face, Zone::Graveyard);
+            self.record_last_known_information(object_id, Zone::Stack, entry.controller);
+            let object_id = self.renew_identity(object_id);
+            self.get_object_mut(object_id)?.zone = to;
+            if to == Zone::Exile {
+                self.exile.push(object_id);
+            } else {
+                self.get_player_mut(owner)?.graveyard.push(object_id);
+            }
+            self.note_commander_zone_change(object_id, to);
             self.events.emit(GameEvent::ZoneChange {
                 object_id,
                 owner,
                 from: Zone::Stack,
-                to: Zone::Graveyard,
+                to,
             });
         } else {
             // Ability: just remove from objects
@@ -181,8 +233,17 @@ impl GameState {
         Ok(())
     }
 
+    /// Whether instructions with `effect` still have something to act on:
+    /// true unless they target and every one of `targets` is now illegal
+    /// (rule 608.2b).
+    fn targets_live(&self, effect: &crate::types::effects::Effect, targets: &[ResolvedTarget]) -> bool {
+        let recipient = self.extract_recipient(effect);
+        !matches!(recipient, EffectRecipient::Target(_, _))
+            || self.any_targets_still_legal(&recipient, targets)
+    }
+
     /// Extract the EffectRecipient from an Effect for re-validation purposes.
-    fn extract_recipient(&self, effect: &crate::types::effects::Effect) -> EffectRecipient {
+    pub(crate) fn extract_recipient(&self, effect: &crate::types::effects::Effect) -> EffectRecipient {
         match effect {
             crate::types::effects::Effect::Atom(_, ts) => ts.clone(),
             crate::types::effects::Effect::Sequence(effects) => {
@@ -202,7 +263,7 @@ impl GameState {
 #[cfg(test)]
 mod tests {
     use crate::engine::resolve::ResolvedTarget;
-    use crate::objects::card_data::{AbilityDef, AbilityType, CardDataBuilder};
+    use crate::objects::card_data::{AbilityDef, AbilityType, CardDataBuilder, CardFace};
     use crate::objects::object::GameObject;
     use crate::state::game_state::{GameState, StackEntry};
     use crate::types::card_types::CardType;
@@ -273,8 +334,13 @@ mod tests {
             x_value: None,
             effect,
             is_spell: true,
+            ability_source: None,
             chosen_alternative_cost: None,
             additional_costs_paid: Vec::new(),
+            cast_face: CardFace::Front,
+            fused_half: None,
+            is_copy: false,
+            is_triggered: false,
         });
         id
     }
@@ -290,6 +356,7 @@ mod tests {
         );
 
         game.resolve_top_of_stack(&test_dp()).unwrap();
+        let bolt_id = game.current_identity(bolt_id);
 
         // Player 1 should have lost 3 life
         assert_eq!(game.players[1].life_total, 17);
@@ -321,6 +388,7 @@ mod tests {
         );
 
         game.resolve_top_of_stack(&test_dp()).unwrap();
+        let recall_id = game.current_identity(recall_id);
 
         // Player 0 should have drawn 3 cards
         assert_eq!(game.players[0].hand.len(), 3);
@@ -400,8 +468,13 @@ mod tests {
             x_value: None,
             effect: Effect::Sequence(vec![]),
             is_spell: true,
+            ability_source: None,
             chosen_alternative_cost: None,
             additional_costs_paid: Vec::new(),
+            cast_face: CardFace::Front,
+            fused_half: None,
+            is_copy: false,
+            is_triggered: false,
         });
         id
     }
@@ -412,6 +485,7 @@ mod tests {
         let bears_id = put_permanent_on_stack(&mut game, make_grizzly_bears(), 0);
 
         game.resolve_top_of_stack(&test_dp()).unwrap();
+        let bears_id = game.current_identity(bears_id);
 
         // Creature should be on the battlefield, not on the stack or in graveyard
         assert_eq!(game.get_object(bears_id).unwrap().zone, Zone::Battlefield);
@@ -436,6 +510,7 @@ mod tests {
         let bears_id = put_permanent_on_stack(&mut game, make_grizzly_bears(), 0);
 
         game.resolve_top_of_stack(&test_dp()).unwrap();
+        let bears_id = game.current_identity(bears_id);
 
         // Creature entered on turn 1, turn_number is 1, so it has summoning sickness
         assert!(crate::oracle::characteristics::has_summoning_sickness(&game, bears_id));
@@ -475,8 +550,13 @@ mod tests {
             x_value,
             effect: Effect::Sequence(vec![]),
             is_spell: true,
+            ability_source: None,
             chosen_alternative_cost: None,
             additional_costs_paid: Vec::new(),
+            cast_face: CardFace::Front,
+            fused_half: None,
+            is_copy: false,
+            is_triggered: false,
         });
         id
     }
@@ -491,6 +571,7 @@ mod tests {
         let id = put_permanent_on_stack_with_x(&mut game, card, 0, Some(3));
 
         game.resolve_top_of_stack(&test_dp()).unwrap();
+        let id = game.current_identity(id);
 
         let bf_entry = game.battlefield.get(&id).unwrap();
         assert_eq!(bf_entry.x_value, Some(3));
@@ -502,6 +583,7 @@ mod tests {
         let bears_id = put_permanent_on_stack(&mut game, make_grizzly_bears(), 0);
 
         game.resolve_top_of_stack(&test_dp()).unwrap();
+        let bears_id = game.current_identity(bears_id);
 
         let bf_entry = game.battlefield.get(&bears_id).unwrap();
         assert_eq!(bf_entry.x_value, None);
@@ -538,8 +620,13 @@ mod tests {
             x_value: None,
             effect: Effect::Sequence(vec![]),
             is_spell: true,
+            ability_source: None,
             chosen_alternative_cost: None,
             additional_costs_paid: Vec::new(),
+            cast_face: CardFace::Front,
+            fused_half: None,
+            is_copy: false,
+            is_triggered: false,
         });
         id
     }
@@ -564,6 +651,7 @@ mod tests {
         );
 
         game.resolve_top_of_stack(&test_dp()).unwrap();
+        let aura_id = game.current_identity(aura_id);
 
         // Aura should be on the battlefield
         assert_eq!(game.get_object(aura_id).unwrap().zone, Zone::Battlefield);
@@ -592,6 +680,7 @@ mod tests {
         );
 
         game.resolve_top_of_stack(&test_dp()).unwrap();
+        let aura_id = game.current_identity(aura_id);
 
         // Host should have the Aura in its attached_by list
         let host_entry = game.battlefield.get(&creature_id).unwrap();
@@ -625,6 +714,7 @@ mod tests {
 
         // Resolve — Bolt should fizzle
         game.resolve_top_of_stack(&test_dp()).unwrap();
+        let bolt_id = game.current_identity(bolt_id);
 
         // Player 1's life should be unchanged (bolt didn't redirect to player)
         assert_eq!(game.players[1].life_total, 20);
@@ -18,15 +18,18 @@ impl GameState {
         targets: &[ResolvedTarget],
     ) -> Result<(), String> {
         match recipient {
-            EffectRecipient::Implicit | EffectRecipient::FilteredPermanents { .. } => {
+            EffectRecipient::Implicit
+            | EffectRecipient::FilteredPermanents { .. }
+            | EffectRecipient::AttachedPermanent => {
                 if !targets.is_empty() {
                     return Err("Spell has no targets but targets were provided".to_string());
                 }
                 Ok(())
             }
 
-            EffectRecipient::Controller => {
-                // "You" doesn't use the targets list — the controller is implicit
+            EffectRecipient::Controller | EffectRecipient::TargetController => {
+                // "You" and "its controller" don't use the targets list — the
+                // player is implied
                 Ok(())
             }
 
@@ -80,6 +83,10 @@ impl GameState {
             SelectionFilter::Any => self.validate_any_target(target),
             SelectionFilter::Permanent(pf) => self.validate_permanent_target(target, pf),
             SelectionFilter::Spell => self.validate_spell_target(target),
+            SelectionFilter::InstantOrSorcerySpell => {
+                self.validate_instant_or_sorcery_spell_target(target)
+            }
+            SelectionFilter::SpellWithSingleTarget => self.validate_single_target_spell_target(target),
         }
     }
 
@@ -115,7 +122,8 @@ impl GameState {
         }
     }
 
-    /// Validate "any target" — creature or planeswalker on battlefield, or player.
+    /// Validate "any target" — creature, planeswalker, or battle on the
+    /// battlefield, or player (rule 115.4).
     fn validate_any_target(&self, target: &ResolvedTarget) -> Result<(), String> {
         match target {
             ResolvedTarget::Player(pid) => {
@@ -129,11 +137,12 @@ impl GameState {
                 self.get_object(*id)?;
                 if has_type(self, *id, CardType::Creature)
                     || has_type(self, *id, CardType::Planeswalker)
+                    || has_type(self, *id, CardType::Battle)
                 {
                     Ok(())
                 } else {
                     Err(format!(
-                        "Target {} is not a creature or planeswalker", id
+                        "Target {} is not a creature, planeswalker, or battle", id
                     ))
                 }
             }
@@ -177,9 +186,46 @@ impl GameState {
         }
     }
 
-    /// Check whether an object is on the battlefield.
+    /// Validate a target is an instant or sorcery spell on the stack — not
+    /// an ability.
+    fn validate_instant_or_sorcery_spell_target(&self, target: &ResolvedTarget) -> Result<(), String> {
+        match target {
+            ResolvedTarget::Object(id) => {
+                let is_spell = self.stack_entries.get(id).is_some_and(|e| e.is_spell);
+                if !is_spell
+                    || !(has_type(self, *id, CardType::Instant) || has_type(self, *id, CardType::Sorcery))
+                {
+                    return Err(format!("Target {} is not an instant or sorcery spell", id));
+                }
+                Ok(())
+            }
+            ResolvedTarget::Player(_) => {
+                Err("Expected a spell target, got a player".to_string())
+            }
+        }
+    }
+
+    /// Validate a target is a spell on the stack — not an ability — with
+    /// exactly one target.
+    fn validate_single_target_spell_target(&self, target: &ResolvedTarget) -> Result<(), String> {
+        match target {
+            ResolvedTarget::Object(id) => {
+                match self.stack_entries.get(id) {
+                    Some(entry) if entry.is_spell && entry.chosen_targets.len() == 1
+                        && entry.fused_half.as_ref().is_none_or(|h| h.chosen_targets.is_empty()) => Ok(()),
+                    _ => Err(format!("Target {} is not a spell with a single target", id)),
+                }
+            }
+            ResolvedTarget::Player(_) => {
+                Err("Expected a spell target, got a player".to_string())
+            }
+        }
+    }
+
+    /// Check whether an object is on the battlefield. A phased-out permanent
+    /// can't be chosen and stops being a legal target (rule 702.26b).
     fn require_on_battlefield(&self, id: ObjectId) -> Result<(), String> {
-        if !self.battlefield.contains_key(&id) {
+        if !self.is_phased_in(id) {
             return Err(format!("Object {} is not on the battlefield", id));
         }
         Ok(())
@@ -249,8 +295,10 @@ impl GameState {
             // Choose effects don't target — they never fizzle.
             EffectRecipient::Implicit
             | EffectRecipient::Controller
+            | EffectRecipient::TargetController
             | EffectRecipient::Choose(_, _)
-            | EffectRecipient::FilteredPermanents { .. } => true,
+            | EffectRecipient::FilteredPermanents { .. }
+            | EffectRecipient::AttachedPermanent => true,
             EffectRecipient::Target(_, _) => {
                 targets.iter().any(|t| {
                     self.is_single_target_legal(recipient, t)
@@ -280,9 +328,10 @@ impl GameState {
                 if !self.players.is_empty() {
                     return true;
                 }
-                self.battlefield.keys()
-                    .filter(|&&id| Some(id) != exclude_id)
-                    .any(|&id| {
+                self.permanents()
+                    .map(|(&id, _)| id)
+                    .filter(|&id| Some(id) != exclude_id)
+                    .any(|id| {
                         let candidate = ResolvedTarget::Object(id);
                         self.validate_selection(filter, &candidate).is_ok()
                     })
@@ -292,9 +341,13 @@ impl GameState {
                 self.stack.iter()
                     .any(|&id| Some(id) != exclude_id)
             }
-            _ => self.battlefield.keys()
+            SelectionFilter::InstantOrSorcerySpell | SelectionFilter::SpellWithSingleTarget => self.stack.iter()
                 .filter(|&&id| Some(id) != exclude_id)
-                .any(|&id| {
+                .any(|&id| self.validate_selection(filter, &ResolvedTarget::Object(id)).is_ok()),
+            _ => self.permanents()
+                .map(|(&id, _)| id)
+                .filter(|&id| Some(id) != exclude_id)
+                .any(|id| {
                     let candidate = ResolvedTarget::Object(id);
                     self.validate_selection(filter, &candidate).is_ok()
                 }),
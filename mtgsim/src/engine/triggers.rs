@@ -0,0 +1,272 @@
+//! Triggered abilities (rule 603).
+//!
+//! An ability that triggers is queued in `GameState::pending_triggers`
+//! (rule 603.2), and waits there until the next time a player would receive
+//! priority. Then, after state-based actions, each player in APNAP order
+//! puts the abilities they control on the stack in the order they choose,
+//! choosing targets as they do (rule 603.3b). `perform_sba_and_triggers` drives this.
+//!
+//! What triggers so far: abilities with a `TriggerEvent` the engine checks
+//! for (on permanents and emblems, and dies triggers from last known
+//! information), Saga chapters, dungeon rooms, and the monarch's and the
+//! initiative's inherent abilities. Other "when/whenever"
+//! triggers of `AbilityType::Triggered` abilities still need matching
+//! events against trigger conditions.
+
+use std::sync::Arc;
+
+use crate::objects::card_data::{CardData, CardDataBuilder, CardFace, TriggerEvent};
+use crate::objects::object::GameObject;
+use crate::state::game_state::{Designation, GameState, PendingTrigger, StackEntry, TriggerSource};
+use crate::engine::resolve::ResolvedTarget;
+use crate::types::effects::{Effect, EffectRecipient};
+use crate::types::ids::{ObjectId, PlayerId};
+use crate::types::zones::Zone;
+use crate::ui::ask::ask_order_triggers;
+use crate::ui::decision::DecisionProvider;
+
+impl GameState {
+    /// Queue a triggered ability of `source` to be put on the stack the next
+    /// time a player would receive priority (rule 603.3). `effect` should
+    /// already be bound to `controller` (rule 109.5).
+    pub fn queue_trigger(&mut self, controller: PlayerId, source: ObjectId, effect: Effect) {
+        let source = TriggerSource::Object(source);
+        let source_data = self.trigger_source_data(source);
+        self.pending_triggers.push(PendingTrigger { controller, source, effect, source_data });
+    }
+
+    /// Queue one of `designation`'s inherent triggered abilities (rules
+    /// 724.2, 725.2), controlled by `controller`.
+    pub fn queue_designation_trigger(
+        &mut self,
+        controller: PlayerId,
+        designation: Designation,
+        effect: Effect,
+    ) {
+        let source = TriggerSource::Designation(designation);
+        let effect = effect.bind_controller(controller);
+        let source_data = self.trigger_source_data(source);
+        self.pending_triggers.push(PendingTrigger { controller, source, effect, source_data });
+    }
+
+    /// The card data a triggered ability of `source` carries on the stack:
+    /// its source's — from the card it's become if it's changed zones since
+    /// (rule 400.7), or its last known name if it's left the game — or, for
+    /// a designation's ability, a blank card with the designation's name.
+    pub(crate) fn trigger_source_data(&self, source: TriggerSource) -> Arc<CardData> {
+        match source {
+            TriggerSource::Object(id) => match self.objects.get(&self.current_identity(id)) {
+                Some(obj) => obj.card_data.clone(),
+                None => {
+                    let name = self.last_known.get(&id)
+                        .map(|lki| lki.characteristics.name.clone())
+                        .unwrap_or_default();
+                    CardDataBuilder::new(&name).build()
+                }
+            },
+            TriggerSource::Designation(designation) => CardDataBuilder::new(designation.name()).build(),
+        }
+    }
+
+    /// Queue every ability of `player`'s permanents and emblems that
+    /// triggers on `event`, in a stable order.
+    pub(crate) fn fire_triggers(&mut self, event: TriggerEvent, player: PlayerId) {
+        let mut sources: Vec<ObjectId> = self.permanents()
+            .filter(|(_, entry)| entry.controller == player)
+            .map(|(&id, _)| id)
+            .chain(self.emblems_of(player))
+            .collect();
+        sources.sort();
+        for id in sources {
+            let effects: Vec<Effect> = crate::oracle::characteristics::get_effective_abilities(self, id)
+                .into_iter()
+                .filter(|ability| ability.trigger_event() == Some(event))
+                .map(|ability| ability.effect)
+                .collect();
+            for effect in effects {
+                self.queue_trigger(player, id, effect.bind_controller(player));
+            }
+        }
+    }
+
+    /// Whether `source` has a triggered ability that has triggered and not
+    /// yet left the stack: pending, or on the stack (rules 309.7, 714.4).
+    /// Activated abilities and copies don't count.
+    pub fn has_unresolved_trigger_from(&self, source: ObjectId) -> bool {
+        self.pending_triggers.iter().any(|t| t.source == TriggerSource::Object(source))
+            || self.stack_entries.values()
+                .any(|entry| entry.is_triggered && entry.ability_source == Some(source))
+    }
+
+    /// Put every pending triggered ability on the stack (rule 603.3b): the
+    /// active player's first, then each other player's in turn order, so the
+    /// last player's resolve first. Each player puts theirs on in the order
+    /// they choose. Returns whether any were put on the stack.
+    ///
+    /// An ability whose targets can't legally be chosen is removed from the
+    /// stack instead (rule 603.3d).
+    pub fn put_pending_triggers_on_stack(
+        &mut self,
+        decisions: &dyn DecisionProvider,
+    ) -> Result<bool, String> {
+        if self.pending_triggers.is_empty() {
+            return Ok(false);
+        }
+        let mut pending = std::mem::take(&mut self.pending_triggers);
+        let mut placed = false;
+        for player in self.apnap_order() {
+            let (mine, rest): (Vec<_>, Vec<_>) = pending.into_iter()
+                .partition(|t| t.controller == player);
+            pending = rest;
+            for trigger in self.order_triggers(player, mine, decisions) {
+                let recipient = match &trigger.effect {
+                    Effect::Atom(_, recipient) => recipient.clone(),
+                    _ => EffectRecipient::Implicit,
+                };
+                let ability_id = self.push_triggered_ability(
+                    trigger.controller, trigger.source, trigger.source_data, trigger.effect, Vec::new(),
+                );
+                match self.choose_spell_targets(player, ability_id, &recipient, decisions) {
+                    Ok(targets) => {
+                        self.stack_entries.get_mut(&ability_id).unwrap().chosen_targets = targets;
+                        placed = true;
+                    }
+                    Err(_) => {
+                        self.stack.retain(|&id| id != ability_id);
+                        self.stack_entries.remove(&ability_id);
+                        self.objects.remove(&ability_id);
+                    }
+                }
+            }
+        }
+        Ok(placed)
+    }
+
+    /// `triggers`, which `player` controls and which triggered at once, in
+    /// the order they choose to put them on the stack (rule 603.3b) — unless
+    /// they're all the same ability of the same source, when the order can't
+    /// matter.
+    fn order_triggers(
+        &self,
+        player: PlayerId,
+        triggers: Vec<PendingTrigger>,
+        decisions: &dyn DecisionProvider,
+    ) -> Vec<PendingTrigger> {
+        let alike = triggers.windows(2).all(|pair| pair[0].source == pair[1].source && pair[0].effect == pair[1].effect);
+        if alike {
+            return triggers;
+        }
+        let sources: Vec<TriggerSource> = triggers.iter().map(|t| t.source).collect();
+        let order = ask_order_triggers(decisions, self, player, &sources);
+        let mut triggers: Vec<Option<PendingTrigger>> = triggers.into_iter().map(Some).collect();
+        order.into_iter().filter_map(|i| triggers[i].take()).collect()
+    }
+
+    /// Put a triggered ability of `source` on the stack with `targets`
+    /// already chosen, and return the object representing it. Like an
+    /// activated ability, it's tracked by a minimal object carrying
+    /// `card_data`, from `trigger_source_data`.
+    pub(crate) fn push_triggered_ability(
+        &mut self,
+        controller: PlayerId,
+        source: TriggerSource,
+        card_data: Arc<CardData>,
+        effect: Effect,
+        targets: Vec<ResolvedTarget>,
+    ) -> ObjectId {
+        let ability_source = match source {
+            TriggerSource::Object(id) => Some(id),
+            TriggerSource::Designation(_) => None,
+        };
+        let ability_obj = GameObject::new(card_data, controller, Zone::Stack);
+        let ability_id = ability_obj.id;
+        self.objects.insert(ability_id, ability_obj);
+        self.stack.push(ability_id);
+        self.stack_entries.insert(ability_id, StackEntry {
+            object_id: ability_id,
+            controller,
+            chosen_targets: targets,
+            chosen_modes: Vec::new(),
+            x_value: None,
+            effect,
+            is_spell: false,
+            ability_source,
+            chosen_alternative_cost: None,
+            additional_costs_paid: Vec::new(),
+            cast_face: CardFace::Front,
+            fused_half: None,
+            is_copy: false,
+            is_triggered: true,
+        });
+        ability_id
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::cards::planeswalkers::chandra_torch_of_defiance;
+    use crate::objects::card_data::CardDataBuilder;
+    use crate::objects::object::GameObject;
+    use crate::state::game_state::{GameState, Phase, PhaseType};
+    use crate::types::effects::{AmountExpr, CounterType, Effect, EffectRecipient, Primitive};
+    use crate::types::zones::Zone;
+    use crate::ui::choice_types::ChoiceKind;
+    use crate::ui::decision::ScriptedDecisionProvider;
+
+    fn gain_life(amount: u64) -> Effect {
+        Effect::Atom(Primitive::GainLife(AmountExpr::Fixed(amount)), EffectRecipient::Controller)
+    }
+
+    #[test]
+    fn test_pending_triggers_go_on_the_stack_in_apnap_order() {
+        let mut game = GameState::new(2, 20);
+        let card = CardDataBuilder::new("Source").build();
+        let ours = game.add_object(GameObject::new(card.clone(), 0, Zone::Battlefield));
+        let theirs = game.add_object(GameObject::new(card, 1, Zone::Battlefield));
+
+        game.queue_trigger(1, theirs, gain_life(1).bind_controller(1));
+        game.queue_trigger(0, ours, gain_life(2).bind_controller(0));
+        assert!(game.has_unresolved_trigger_from(ours));
+
+        let dp = ScriptedDecisionProvider::new();
+        assert!(game.put_pending_triggers_on_stack(&dp).unwrap());
+        assert!(game.pending_triggers.is_empty());
+        assert_eq!(game.stack.len(), 2);
+        // The active player's goes on first, so the other player's resolves first.
+        let top = game.stack_entries[game.stack.last().unwrap()].ability_source;
+        assert_eq!(top, Some(theirs));
+        assert!(game.has_unresolved_trigger_from(ours));
+
+        assert!(!game.put_pending_triggers_on_stack(&dp).unwrap());
+    }
+
+    #[test]
+    fn test_player_orders_their_simultaneous_triggers() {
+        let mut game = GameState::new(2, 20);
+        let card = CardDataBuilder::new("Source").build();
+        let first = game.add_object(GameObject::new(card.clone(), 0, Zone::Battlefield));
+        let second = game.add_object(GameObject::new(card, 0, Zone::Battlefield));
+        game.queue_trigger(0, first, gain_life(1).bind_controller(0));
+        game.queue_trigger(0, second, gain_life(2).bind_controller(0));
+
+        let dp = ScriptedDecisionProvider::new();
+        dp.expect_ordering(ChoiceKind::OrderTriggers, vec![1, 0]);
+        game.put_pending_triggers_on_stack(&dp).unwrap();
+        let top = game.stack_entries[game.stack.last().unwrap()].ability_source;
+        assert_eq!(top, Some(first), "the second went on first");
+    }
+
+    #[test]
+    fn test_activated_ability_is_not_an_unresolved_trigger() {
+        let mut game = GameState::new(2, 20);
+        game.turn_number = 1;
+        game.phase = Phase::new(PhaseType::Precombat);
+        let chandra = game.add_object(GameObject::new(chandra_torch_of_defiance(), 0, Zone::Battlefield));
+        game.place_on_battlefield(chandra, 0).add_counters(CounterType::Loyalty, 7);
+
+        let dp = ScriptedDecisionProvider::new();
+        game.activate_ability(0, chandra, 2, &dp).unwrap();
+        assert!(!game.stack.is_empty());
+        assert!(!game.has_unresolved_trigger_from(chandra));
+    }
+}
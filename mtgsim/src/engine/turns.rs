@@ -1,48 +1,60 @@
-use crate::state::game_state::{GameState, Phase, PhaseType, StepType, next_step, next_phase};
+use crate::events::event::GameEvent;
+use crate::objects::card_data::TriggerEvent;
+use crate::state::game_state::{GameState, Phase, PhaseType, StepType};
+use crate::state::turn_plan::TurnPlan;
+use crate::types::effects::SkipTarget;
+use crate::types::ids::PlayerId;
 use crate::types::mana::{ManaEmptyReason, BlanketPersistenceSet};
+use crate::types::zones::Zone;
 
 /// Turn structure engine.
 ///
-/// Handles advancing through phases and steps, processing phase/step-specific
-/// actions (untap, draw, etc.), and turn transitions.
+/// Handles advancing through the phases and steps the `TurnPlan` lays out,
+/// processing phase/step-specific actions (untap, draw, etc.), and turn
+/// transitions. Effects edit the plan through the methods at the end of
+/// this block: extra turns, additional phases, skips and ending the turn.
 
 impl GameState {
-    /// Advance the game state to the next step or phase.
+    /// Advance the game state to the next step or phase of the turn plan.
     ///
     /// Returns the new (PhaseType, Option<StepType>) after advancing.
     pub fn advance_turn(&mut self) -> Result<(PhaseType, Option<StepType>), String> {
+        // `phase` is public and may have been set directly since the plan
+        // last moved; line the plan up with it (see `TurnPlan::sync_to`).
+        self.turn_plan.sync_to(self.phase);
+
         // If we're in a phase with steps, try to advance to the next step
         if let Some(current_step) = self.phase.step {
-            if let Some(next) = next_step(self.phase.phase_type, current_step) {
-                // Execute end-of-step cleanup for the old step
-                self.on_step_end(current_step)?;
+            // Execute end-of-step cleanup for the old step
+            self.on_step_end(current_step)?;
 
+            if let Some(next) = self.next_planned_step() {
                 // Move to the next step within this phase
                 self.phase.step = Some(next);
+                self.turn_plan.entered(self.phase);
                 self.on_step_begin(next)?;
 
                 return Ok((self.phase.phase_type, self.phase.step));
             }
             // No more steps in this phase — fall through to advance phase
-            self.on_step_end(current_step)?;
         }
 
-        // Advance to the next phase
-        let old_phase = self.phase.phase_type;
-        self.on_phase_end(old_phase)?;
-
-        let new_phase_type = next_phase(old_phase);
+        // Advance to the next phase; once the plan runs out, the turn ends
+        self.on_phase_end(self.phase.phase_type)?;
 
-        // Check for turn transition (Ending -> Beginning = new turn)
-        if old_phase == PhaseType::Ending && new_phase_type == PhaseType::Beginning {
-            self.on_turn_end()?;
-            self.turn_number += 1;
-            self.active_player = (self.active_player + 1) % self.num_players();
-            self.priority_player = self.active_player;
-        }
+        let phase = match self.next_planned_phase() {
+            Some(phase) => phase,
+            None => {
+                self.on_turn_end()?;
+                self.begin_next_turn();
+                self.next_planned_phase()
+                    .ok_or_else(|| format!("Turn {} has no phases", self.turn_number))?
+            }
+        };
 
-        self.phase = Phase::new(new_phase_type);
-        self.on_phase_begin(new_phase_type)?;
+        self.phase = phase;
+        self.turn_plan.entered(phase);
+        self.on_phase_begin(phase.phase_type)?;
 
         // If the new phase starts with a step, process that step's begin
         if let Some(step) = self.phase.step {
@@ -52,9 +64,109 @@ impl GameState {
         Ok((self.phase.phase_type, self.phase.step))
     }
 
+    /// The next step of the current phase that isn't skipped.
+    fn next_planned_step(&mut self) -> Option<StepType> {
+        loop {
+            let step = self.turn_plan.next_step()?;
+            let skip = match step {
+                StepType::Untap => Some(SkipTarget::UntapStep),
+                StepType::Upkeep => Some(SkipTarget::UpkeepStep),
+                StepType::Draw => Some(SkipTarget::DrawStep),
+                _ => None,
+            };
+            if !skip.is_some_and(|target| self.take_skip(target, self.active_player)) {
+                return Some(step);
+            }
+        }
+    }
+
+    /// The next phase of the turn that isn't skipped, starting at its first
+    /// step that isn't skipped. A phase whose steps are all skipped doesn't
+    /// happen either. Returns None once the turn is over.
+    fn next_planned_phase(&mut self) -> Option<Phase> {
+        loop {
+            let planned = self.turn_plan.next_phase()?;
+            let phase_type = planned.phase_type;
+            if phase_type == PhaseType::Combat
+                && self.take_skip(SkipTarget::CombatPhase, self.active_player)
+            {
+                continue;
+            }
+            if planned.steps.is_empty() {
+                return Some(Phase { phase_type, step: None });
+            }
+            if let Some(step) = self.next_planned_step() {
+                return Some(Phase { phase_type, step: Some(step) });
+            }
+        }
+    }
+
+    /// Start the next turn: the most recent extra turn if any are waiting
+    /// (rule 500.7), otherwise the next player's in turn order. After extra
+    /// turns, turn order picks up where it left off. Players who have left
+    /// the game don't take turns (rule 800.4a); in a team game the next team
+    /// takes its turn (rule 810.2). Skipped turns are passed over without
+    /// being taken (rule 614.10).
+    fn begin_next_turn(&mut self) {
+        let mut regular = self.turn_plan.take_resume_point().unwrap_or(self.active_player);
+        let next = loop {
+            let (player, extra) = match self.turn_plan.take_extra_turn() {
+                Some(player) => (player, true),
+                None => match self.next_turn_player_after(regular) {
+                    Some(player) => (player, false),
+                    None => break self.active_player,
+                },
+            };
+            if !extra {
+                regular = player;
+            }
+            if !self.is_in_game(player) || self.take_skip(SkipTarget::Turn, player) {
+                continue;
+            }
+            if extra {
+                self.turn_plan.set_resume_point(regular);
+            }
+            break player;
+        };
+
+        self.turn_number += 1;
+        self.active_player = next;
+        self.priority_player = next;
+        self.turn_plan.begin_turn();
+        self.spells_cast_this_turn = 0;
+
+        // Per-turn resets happen as the turn begins, whether or not its
+        // untap step is skipped: land drops (rule 305.2) and "until your next
+        // turn" effects of the active player(s) (rule 611.2b).
+        for active in self.active_players() {
+            self.players[active].reset_lands_played();
+            self.continuous_effects.remove_expired_at_turn_start(active, self.turn_number);
+        }
+
+        // Rule 726.3a: day and night change as the turn begins
+        self.update_day_night();
+    }
+
+    /// Use up a pending skip of `target` held by `player` or a teammate —
+    /// teammates share a turn (rule 810.2). Returns whether one was used.
+    fn take_skip(&mut self, target: SkipTarget, player: PlayerId) -> bool {
+        let members = self.team_members(player);
+        match self.turn_plan.take_skip(target, |p| members.contains(&p)) {
+            Some(skipper) => {
+                self.events.emit(GameEvent::Skipped { player: skipper, what: target });
+                true
+            }
+            None => false,
+        }
+    }
+
     // --- Phase lifecycle callbacks ---
 
-    fn on_phase_begin(&mut self, _phase_type: PhaseType) -> Result<(), String> {
+    fn on_phase_begin(&mut self, phase_type: PhaseType) -> Result<(), String> {
+        // Rule 714.3b: lore counters go on Sagas as the precombat main phase begins
+        if phase_type == PhaseType::Precombat {
+            self.add_precombat_lore_counters();
+        }
         // Future: emit PhaseBegin events for triggered abilities
         Ok(())
     }
@@ -90,27 +202,36 @@ impl GameState {
     fn on_step_begin(&mut self, step_type: StepType) -> Result<(), String> {
         match step_type {
             StepType::Untap => {
-                // Expire "until your next turn" effects for the active player
-                self.continuous_effects.remove_expired_at_turn_start(
-                    self.active_player,
-                    self.turn_number,
-                );
                 self.process_untap_step()?;
             }
             StepType::Draw => {
                 self.process_draw_step()?;
             }
-            StepType::Upkeep
-            | StepType::BeginCombat
+            StepType::Upkeep => {
+                // Delayed triggers from opening-hand reveals (rule 103.6)
+                self.put_first_upkeep_triggers_on_stack();
+                for player in self.active_players() {
+                    self.fire_triggers(TriggerEvent::YourUpkeep, player);
+                }
+                self.queue_designation_step_triggers(StepType::Upkeep);
+                self.priority_player = self.active_player;
+            }
+            StepType::BeginCombat
             | StepType::DeclareAttackers
             | StepType::DeclareBlockers
             | StepType::FirstStrikeDamage
             | StepType::CombatDamage
-            | StepType::EndCombat
-            | StepType::End => {
+            | StepType::EndCombat => {
                 // Active player gets priority
                 self.priority_player = self.active_player;
             }
+            StepType::End => {
+                for player in self.active_players() {
+                    self.fire_triggers(TriggerEvent::YourEndStep, player);
+                }
+                self.queue_designation_step_triggers(StepType::End);
+                self.priority_player = self.active_player;
+            }
             StepType::Cleanup => {
                 // Rule 514.1: Discard to hand size — requires DecisionProvider (future)
                 // TODO: wire up discard-to-hand-size once DecisionProvider is integrated
@@ -120,6 +241,7 @@ impl GameState {
                 for (_id, entry) in &mut self.battlefield {
                     entry.damage_marked = 0;
                     entry.damaged_by_deathtouch = false;
+                    entry.regeneration_shields = 0;
                 }
 
                 // Rule 514.2: End "until end of turn" continuous effects
@@ -151,25 +273,83 @@ impl GameState {
     }
 
     fn on_turn_end(&mut self) -> Result<(), String> {
-        // Per-turn resets (land drops, etc.) happen in process_untap_step,
-        // which is the canonical location per rules (rule 502).
+        // Per-turn resets (land drops, "until your next turn" effects)
+        // happen in begin_next_turn, so a skipped untap step doesn't skip
+        // them.
+        Ok(())
+    }
+
+    // --- Turn plan edits ---
+
+    /// The turn plan, first lined up with the current phase in case that
+    /// was set directly (see `TurnPlan::sync_to`). Effects edit it through
+    /// here so their edits are kept.
+    pub fn edit_turn_plan(&mut self) -> &mut TurnPlan {
+        self.turn_plan.sync_to(self.phase);
+        &mut self.turn_plan
+    }
+
+    /// `player` takes an extra turn after this one (rule 500.7).
+    pub fn add_extra_turn(&mut self, player: PlayerId) {
+        self.turn_plan.add_extra_turn(player);
+        self.events.emit(GameEvent::ExtraTurnAdded { player });
+    }
+
+    /// After the current phase, there is an additional combat phase,
+    /// followed by an additional main phase if `followed_by_main` (rule
+    /// 500.8). The main phase after a combat phase is a postcombat main
+    /// phase (rule 505.1a).
+    pub fn add_combat_phase(&mut self, followed_by_main: bool) {
+        let mut phases = vec![PhaseType::Combat];
+        if followed_by_main {
+            phases.push(PhaseType::Postcombat);
+        }
+        self.edit_turn_plan().add_phases_after_current(&phases);
+        self.events.emit(GameEvent::PhasesAdded { phases });
+    }
+
+    /// `player` skips their next `target` (rule 614.10).
+    pub fn add_skip(&mut self, player: PlayerId, target: SkipTarget) {
+        self.turn_plan.add_skip(player, target);
+    }
+
+    /// End the turn (rule 723.1): exile every object on the stack — spells
+    /// go to exile, abilities cease to exist — remove all creatures from
+    /// combat, and skip straight to the cleanup step. Until it begins, no
+    /// player gets priority (see `run_priority_loop`), and a resolving spell
+    /// that ended the turn is exiled too (see `resolve_top_of_stack`).
+    pub fn end_the_turn(&mut self) -> Result<(), String> {
+        for id in self.stack.clone().into_iter().rev() {
+            if self.stack_entries.get(&id).is_some_and(|e| e.is_spell) {
+                self.change_zone(id, Zone::Exile)?;
+            } else {
+                self.stack.retain(|&s| s != id);
+                self.stack_entries.remove(&id);
+                self.objects.remove(&id);
+            }
+        }
+        for entry in self.battlefield.values_mut() {
+            entry.clear_combat_state();
+        }
+        self.edit_turn_plan().end_turn();
+        self.events.emit(GameEvent::TurnEndedEarly { player: self.active_player });
         Ok(())
     }
 
     // --- Step processors ---
 
-    /// Untap step: untap all permanents controlled by the active player,
-    /// reset land drops (rule 502)
+    /// Untap step: phasing, then untap all permanents controlled by the
+    /// active player (rule 502). In a team game both teammates are active
+    /// players and untap together (rule 810.2).
     fn process_untap_step(&mut self) -> Result<(), String> {
-        let active = self.active_player;
+        let active = self.active_players();
 
-        // Reset land drops for the new turn
-        let player = self.get_player_mut(active)?;
-        player.reset_lands_played();
+        // Rule 502.1: phasing happens before anything untaps
+        self.process_phasing(&active);
 
-        // Untap permanents controlled by the active player
+        // Untap permanents controlled by the active player(s)
         for (_id, entry) in &mut self.battlefield {
-            if entry.controller == active {
+            if active.contains(&entry.controller) && !entry.phased_out {
                 entry.tapped = false;
             }
         }
@@ -178,18 +358,22 @@ impl GameState {
         Ok(())
     }
 
-    /// Draw step: active player draws a card, then gets priority (rule 504)
+    /// Draw step: active player draws a card, then gets priority (rule 504).
+    /// In a team game each active player draws (rule 810.2).
     fn process_draw_step(&mut self) -> Result<(), String> {
         let active = self.active_player;
 
-        // Rule 103.8a: first player skips the draw step of their first turn.
+        // Rule 103.8a: first player skips the draw step of their first turn
+        // (rule 103.8b: the first team, in Two-Headed Giant).
         // The skip_first_draw flag is set during Game::new() based on GameConfig.
         // This is a one-time flag — in-game "skip draw" effects use replacement
         // effects (Phase 6), not boolean flags.
         if self.skip_first_draw {
             self.skip_first_draw = false;
         } else {
-            self.draw_card(active)?; // Ok(None) on empty library just flags SBA
+            for pid in self.active_players() {
+                self.draw_card(pid)?; // Ok(None) on empty library just flags SBA
+            }
         }
 
         self.priority_player = active;
@@ -1,6 +1,6 @@
 use crate::events::event::GameEvent;
+use crate::objects::card_data::CardFace;
 use crate::state::game_state::GameState;
-use crate::types::card_types::CardType;
 use crate::types::ids::{ObjectId, PlayerId};
 use crate::types::zones::Zone;
 
@@ -31,14 +31,41 @@ impl GameState {
     /// `// REPLACEMENT-BYPASS:` and are documented structural exceptions
     /// because the stack-pop-first pattern removes the object from the
     /// stack `Vec` before resolution begins.
-    pub(crate) fn move_object(&mut self, id: ObjectId, to: Zone) -> Result<(), String> {
+    pub(crate) fn move_object(&mut self, id: ObjectId, to: Zone) -> Result<ObjectId, String> {
+        self.move_object_as(id, to, CardFace::Front)
+    }
+
+    /// `move_object`, with a double-faced card that's moving onto the
+    /// battlefield entering with `face` up (rule 712.14). Only a modal DFC
+    /// played as its back face enters that way.
+    pub(crate) fn move_object_as(&mut self, id: ObjectId, to: Zone, face: CardFace) -> Result<ObjectId, String> {
+        self.move_object_with_identity(id, to, face, None)
+    }
+
+    /// Move `id` back to `to` as `previous`, the object it was before its
+    /// last zone change — reversing a spell's illegal casting (rule
+    /// 732.1b). Returns `previous`.
+    pub(crate) fn undo_zone_change(&mut self, id: ObjectId, to: Zone, previous: ObjectId) -> Result<ObjectId, String> {
+        self.move_object_with_identity(id, to, CardFace::Front, Some(previous))
+    }
+
+    /// Every zone change makes the object a new one with a fresh
+    /// `ObjectId` (rule 400.7), returned here; `previous` restores the old
+    /// one instead.
+    fn move_object_with_identity(
+        &mut self,
+        id: ObjectId,
+        to: Zone,
+        face: CardFace,
+        previous: Option<ObjectId>,
+    ) -> Result<ObjectId, String> {
         let from = {
             let obj = self.get_object(id)?;
             obj.zone
         };
 
         if from == to {
-            return Ok(()); // no-op
+            return Ok(id); // no-op
         }
 
         // Clean up zone-specific state for the old zone (before removal,
@@ -48,16 +75,29 @@ impl GameState {
         // Remove from old zone's collection
         self.remove_from_zone_collection(id, from)?;
 
+        // It becomes a new object (rule 400.7)
+        let old_id = id;
+        let id = match previous {
+            Some(previous) => self.revert_identity(id, previous),
+            None => self.renew_identity(id),
+        };
+
         // Add to new zone's collection
         self.add_to_zone_collection(id, to)?;
 
         // Initialize zone-specific state for the new zone
-        self.init_zone_state(id, to)?;
+        self.init_zone_state(id, to, face)?;
 
         // Update the object's zone field
         let owner = self.get_object(id)?.owner;
         let obj = self.get_object_mut(id)?;
         obj.zone = to;
+        self.note_commander_zone_change(id, to);
+        // A card stays on an adventure only while it's in exile — or on
+        // the stack, being cast from there (rule 715.4).
+        if !matches!(to, Zone::Exile | Zone::Stack) {
+            self.on_adventure.remove(&id);
+        }
 
         // Emit zone change event
         self.events.emit(GameEvent::ZoneChange {
@@ -67,7 +107,11 @@ impl GameState {
             to,
         });
 
-        Ok(())
+        if from == Zone::Battlefield && to == Zone::Graveyard {
+            self.fire_dies_triggers(old_id);
+        }
+
+        Ok(id)
     }
 
     /// Draw a card: move top of library to hand.
@@ -92,7 +136,7 @@ impl GameState {
             *player.library.last().unwrap()
         };
 
-        self.move_object(card_id, Zone::Hand)?;
+        let card_id = self.move_object(card_id, Zone::Hand)?;
         Ok(Some(card_id))
     }
 
@@ -111,14 +155,46 @@ impl GameState {
         Ok(drawn)
     }
 
+    /// Put an object on the bottom of its owner's library, moving it there
+    /// first if it's in another zone. Bottom of library = first element.
+    /// Returns its id in the library.
+    pub fn put_on_bottom_of_library(&mut self, id: ObjectId) -> Result<ObjectId, String> {
+        let id = self.change_zone(id, Zone::Library)?;
+        let owner = self.get_object(id)?.owner;
+        let library = &mut self.get_player_mut(owner)?.library;
+        if let Some(pos) = library.iter().position(|&x| x == id) {
+            library.remove(pos);
+            library.insert(0, id);
+        }
+        Ok(id)
+    }
+
     /// Play a land to the battlefield (special action, not a spell).
     ///
     /// The `from` parameter specifies which zone the land is being played from.
     /// Normally this is `Zone::Hand`, but continuous effects can allow playing
     /// lands from other zones (e.g. graveyard via Crucible of Worlds).
-    pub fn play_land(&mut self, player_id: PlayerId, card_id: ObjectId, from: Zone) -> Result<(), String> {
+    ///
+    /// A modal double-faced card is played as its first land face; use
+    /// `play_land_as` to pick the face. Returns the land's id on the
+    /// battlefield.
+    pub fn play_land(&mut self, player_id: PlayerId, card_id: ObjectId, from: Zone) -> Result<ObjectId, String> {
+        let face = self.get_object(card_id)?.land_faces().first().copied().unwrap_or_default();
+        self.play_land_as(player_id, card_id, from, face)
+    }
+
+    /// Play `card_id` as its `face` — for a modal double-faced card, either
+    /// face that's a land (rule 712.12). It enters the battlefield with that
+    /// face up (rule 712.14).
+    pub fn play_land_as(
+        &mut self,
+        player_id: PlayerId,
+        card_id: ObjectId,
+        from: Zone,
+        face: CardFace,
+    ) -> Result<ObjectId, String> {
         // Rule 505.6b: Only the active player can play a land
-        if player_id != self.active_player {
+        if !self.is_active_player(player_id) {
             return Err("Only the active player can play a land".to_string());
         }
 
@@ -145,7 +221,7 @@ impl GameState {
         // play-from-hand legality, which happens before the object is a permanent,
         // so the layer system has nothing to contribute. Same exemption as
         // engine/cast.rs -- see "Before Layers" in plans/codebase-state.md.
-        if !obj.card_data.types.contains(&CardType::Land) {
+        if !obj.land_faces().contains(&face) {
             return Err("This card is not a land".to_string());
         }
 
@@ -156,13 +232,13 @@ impl GameState {
         }
 
         // Move to battlefield
-        self.move_object(card_id, Zone::Battlefield)?;
+        let land_id = self.move_object_as(card_id, Zone::Battlefield, face)?;
 
         // Increment land drop counter
         let player = self.get_player_mut(player_id)?;
         player.lands_played_this_turn += 1;
 
-        Ok(())
+        Ok(land_id)
     }
 
     // --- Internal helpers ---
@@ -277,11 +353,11 @@ impl GameState {
 
     /// Initialize zone-specific state when entering a zone.
     /// Default controller is the object's owner (correct for play_land, tokens, etc.).
-    pub(crate) fn init_zone_state(&mut self, id: ObjectId, zone: Zone) -> Result<(), String> {
+    pub(crate) fn init_zone_state(&mut self, id: ObjectId, zone: Zone, face: CardFace) -> Result<(), String> {
         if zone == Zone::Battlefield {
             let obj = self.get_object(id)?;
             let controller = obj.owner; // default controller is owner
-            self.place_on_battlefield(id, controller);
+            self.place_on_battlefield_as(id, controller, face);
         }
         Ok(())
     }
@@ -294,8 +370,9 @@ impl GameState {
         &mut self,
         id: ObjectId,
         controller: PlayerId,
+        face: CardFace,
     ) -> Result<(), String> {
-        self.place_on_battlefield(id, controller);
+        self.place_on_battlefield_as(id, controller, face);
         Ok(())
     }
 
@@ -304,7 +381,18 @@ impl GameState {
     /// Called BEFORE remove_from_zone_collection so we can still read
     /// the departing entity's state. The BattlefieldEntity itself is
     /// removed afterwards by remove_from_zone_collection.
-    fn cleanup_zone_state(&mut self, id: ObjectId, zone: Zone) {
+    pub(crate) fn cleanup_zone_state(&mut self, id: ObjectId, zone: Zone) {
+        // Last known information (rule 608.2h), while static abilities and
+        // the effects on it still apply.
+        let controller = match zone {
+            Zone::Battlefield => self.battlefield.get(&id).map(|entry| entry.controller),
+            Zone::Stack => self.stack_entries.get(&id).map(|entry| entry.controller),
+            _ => None,
+        };
+        if let Some(controller) = controller {
+            self.record_last_known_information(id, zone, controller);
+        }
+
         if zone == Zone::Battlefield {
             // Remove any continuous effects generated by this source (rule 611.2a).
             // Static abilities stop applying the moment the source leaves.
@@ -376,8 +464,7 @@ mod tests {
         game.players[0].library.push(forest_id);
 
         // Draw it
-        let drawn = game.draw_card(0).unwrap();
-        assert_eq!(drawn, Some(forest_id));
+        let forest_id = game.draw_card(0).unwrap().unwrap();
         assert!(game.players[0].library.is_empty());
         assert_eq!(game.players[0].hand.len(), 1);
         assert_eq!(game.players[0].hand[0], forest_id);
@@ -412,7 +499,7 @@ mod tests {
         game.players[0].hand.push(forest_id);
 
         // Play it
-        game.play_land(0, forest_id, Zone::Hand).unwrap();
+        let forest_id = game.play_land(0, forest_id, Zone::Hand).unwrap();
 
         assert!(game.players[0].hand.len() == 1); // drew 1 card during draw step
         assert!(game.battlefield.contains_key(&forest_id));
@@ -475,11 +562,12 @@ mod tests {
         game.place_on_battlefield(forest_id, 0);
 
         // Move to graveyard
-        game.move_object(forest_id, Zone::Graveyard).unwrap();
+        let card_id = game.move_object(forest_id, Zone::Graveyard).unwrap();
 
         assert!(!game.battlefield.contains_key(&forest_id));
-        assert_eq!(game.players[0].graveyard.len(), 1);
-        assert_eq!(game.get_object(forest_id).unwrap().zone, Zone::Graveyard);
+        assert_eq!(game.players[0].graveyard, vec![card_id]);
+        assert_eq!(game.get_object(card_id).unwrap().zone, Zone::Graveyard);
+        assert!(game.get_object(forest_id).is_err(), "a new object (rule 400.7)");
     }
 
     #[test]
@@ -1,7 +1,9 @@
 use crate::types::ids::{ObjectId, PlayerId};
 use crate::types::zones::Zone;
 use crate::types::mana::ManaType;
-use crate::state::game_state::{PhaseType, StepType};
+use crate::types::effects::SkipTarget;
+use crate::types::card_types::DungeonType;
+use crate::state::game_state::{DayNight, PhaseType, StepType};
 
 use std::collections::HashMap;
 
@@ -19,6 +21,8 @@ use std::collections::HashMap;
 #[derive(Debug, Clone)]
 pub enum GameEvent {
     // --- Zone transitions ---
+    /// `object_id` is the new object in `to` (rule 400.7); the one it was
+    /// is `GameState::previous_identity(object_id)`.
     ZoneChange {
         object_id: ObjectId,
         owner: PlayerId,
@@ -47,10 +51,28 @@ pub enum GameEvent {
     StepEnd { step: StepType },
     TurnBegin { player: PlayerId, turn_number: u32 },
     TurnEnd { player: PlayerId, turn_number: u32 },
+    /// An effect added phases directly after the current one (rule 500.8).
+    PhasesAdded { phases: Vec<PhaseType> },
+    /// An effect gave a player an extra turn (rule 500.7).
+    ExtraTurnAdded { player: PlayerId },
+    /// A player skipped a step, phase or turn (rule 614.10).
+    Skipped { player: PlayerId, what: SkipTarget },
+    /// An effect ended the turn (rule 723).
+    TurnEndedEarly { player: PlayerId },
 
     // --- Permanents ---
     PermanentEnteredBattlefield { object_id: ObjectId, controller: PlayerId },
     PermanentLeftBattlefield { object_id: ObjectId },
+    /// A permanent phased out, directly or along with its host (rule 702.26).
+    PhasedOut { object_id: ObjectId },
+    /// A phased-out permanent phased back in (rule 702.26).
+    PhasedIn { object_id: ObjectId },
+    /// A double-faced permanent turned over to its other face (rule 701.28).
+    Transformed { object_id: ObjectId },
+    /// A face-down permanent was turned face up (rule 708.8).
+    TurnedFaceUp { object_id: ObjectId },
+    /// It became day or night (rule 726).
+    DayNightChanged { day_night: DayNight },
 
     // --- Life ---
     LifeChanged { player_id: PlayerId, old: i64, new: i64, source: Option<ObjectId> },
@@ -67,22 +89,64 @@ pub enum GameEvent {
     /// Spell or ability fizzled (countered by game rules due to all targets
     /// becoming illegal). No source object — this is a game-rules counter.
     SpellFizzled { spell_id: ObjectId },
+    /// A copy of a spell or ability was put on the stack (rule 707.10).
+    SpellCopied { original: ObjectId, copy: ObjectId, controller: PlayerId },
+    /// A spell's or ability's targets were changed by an effect (rule 115.7).
+    TargetsChanged { spell_id: ObjectId, changed_by: ObjectId },
 
     // --- Creatures ---
     CreatureDied { creature_id: ObjectId, owner: PlayerId },
+    /// A regeneration shield replaced a permanent's destruction (rule 701.19a).
+    Regenerated { object_id: ObjectId },
 
     // --- Permanents put into graveyard by SBA ---
     /// A planeswalker was put into its owner's graveyard by SBA (704.5i, 0 loyalty).
     PlaneswalkerDied { object_id: ObjectId, owner: PlayerId },
     /// A permanent was put into its owner's graveyard by the legend rule (704.5j).
     LegendRuleSacrificed { object_id: ObjectId, owner: PlayerId },
+    /// A Saga that read its final chapter was sacrificed by SBA (704.5s).
+    SagaSacrificed { object_id: ObjectId, owner: PlayerId },
+    /// A battle was put into its owner's graveyard by SBA (704.5v, 0 defense,
+    /// or 704.5w/x with no eligible protector).
+    BattleDied { object_id: ObjectId, owner: PlayerId },
+
+    // --- Battles ---
+    /// A player was designated protector of a battle (rule 310.8a).
+    ProtectorChosen { battle_id: ObjectId, protector: PlayerId },
+    /// The last defense counter was removed from a Siege and it was exiled
+    /// by its defeat ability (rule 310.11b). `object_id` is the card in exile.
+    SiegeDefeated { object_id: ObjectId, controller: PlayerId },
+
+    // --- Command zone (rules 114, 309, 724, 725) ---
+    /// An emblem was put into the command zone (rule 114.2).
+    EmblemCreated { emblem_id: ObjectId, controller: PlayerId },
+    /// A player became the monarch (rule 724.1).
+    BecameMonarch { player: PlayerId },
+    /// A player took the initiative (rule 725.1).
+    TookInitiative { player: PlayerId },
+    /// A player's venture marker moved into a dungeon room (rule 701.49).
+    VenturedInto { player: PlayerId, dungeon_id: ObjectId, room: String },
+    /// A dungeon was completed and removed from the game (rule 309.7).
+    DungeonCompleted { player: PlayerId, dungeon: DungeonType },
 
     // --- Player loss ---
     PlayerLost { player_id: PlayerId, reason: LossReason },
+    /// A player who lost left a multiplayer game, taking their objects with
+    /// them (rule 800.4a).
+    PlayerLeftGame { player_id: PlayerId },
 
     // --- Counters ---
     /// +1/+1 and -1/-1 counters annihilated each other on a permanent (rule 704.5q).
     CountersAnnihilated { object_id: ObjectId, pairs_removed: u32 },
+    /// A player got poison counters — from infect damage (rule 120.3b) or
+    /// toxic (rule 702.164c).
+    PoisonCountersAdded { player_id: PlayerId, amount: u32, source: ObjectId },
+
+    // --- Attachment (rule 701.3) ---
+    /// A permanent became attached to a new host (equip, reconfigure).
+    Attached { attachment: ObjectId, host: ObjectId },
+    /// A permanent became unattached by an effect (reconfigure's unattach).
+    Unattached { attachment: ObjectId, former_host: ObjectId },
 
     // --- Attachment SBAs ---
     /// An Aura was put into its owner's graveyard by SBA 704.5m/704.5n
@@ -96,6 +160,8 @@ pub enum GameEvent {
     /// A token in a non-battlefield zone ceased to exist (rule 704.5d).
     /// Not a zone change — the token is simply removed from the game.
     TokenCeasedToExist { object_id: ObjectId },
+    /// A copy of a spell left the stack and ceased to exist (rule 704.5e).
+    CopyCeasedToExist { object_id: ObjectId },
 
     // --- State-based ---
     StateBasedActionPerformed,
@@ -112,6 +178,8 @@ pub enum LossReason {
     PoisonCounters,
     /// Dealt 21 or more combat damage by a single commander (rule 704.5)
     CommanderDamage,
+    /// A teammate lost, so the whole team did (rule 810.8a)
+    TeamLost,
 }
 
 /// What damage is being dealt to
@@ -1,10 +1,13 @@
 use std::collections::HashSet;
-use std::sync::Arc;
+use std::sync::{Arc, OnceLock};
 
 use crate::types::card_types::{CardType, Supertype, Subtype};
 use crate::types::colors::Color;
 use crate::types::costs::{AdditionalCost, AlternativeCost, Cost};
-use crate::types::effects::{AmountExpr, Effect, ManaOutput, Primitive, EffectRecipient, SelectionFilter};
+use crate::types::effects::{
+    AmountExpr, CounterType, Effect, ManaOutput, PermanentFilter, PlayerRef, Primitive,
+    EffectRecipient, SelectionFilter, TargetCount,
+};
 use crate::types::keywords::KeywordAbility;
 use crate::types::mana::{ManaCost, ManaType};
 use crate::types::ids::AbilityId;
@@ -42,6 +45,227 @@ pub struct CardData {
     /// Additional costs this card can optionally pay (rule 118.8).
     /// Multiple may be paid in a single cast (e.g. kicker + buyback).
     pub additional_costs: Vec<AdditionalCost>,
+    /// What this card lets its owner do from their opening hand (rule
+    /// 103.6). None for almost every card.
+    pub opening_hand_action: Option<OpeningHandAction>,
+    /// The back face of a double-faced card (rule 712). None for
+    /// single-faced cards. The fields above describe the front face.
+    pub back_face: Option<BackFace>,
+    /// The second half of a split card, or the Adventure of an adventurer
+    /// card (rules 709, 715). None for other cards. The fields above
+    /// describe the left half or the adventurer's main card.
+    pub second_half: Option<SecondHalf>,
+    /// Morph, megamorph or disguise (rules 702.37, 702.168): this card can
+    /// be cast face down for {3} and turned face up for the given cost.
+    /// None for other cards.
+    pub morph: Option<Morph>,
+}
+
+/// How a double-faced card's faces relate (rule 712.1).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DoubleFacedKind {
+    /// A transforming double-faced card (rule 712.1a): cast front face up,
+    /// and turned over by the transform keyword action (rule 701.28).
+    Transforming,
+    /// A modal double-faced card (rule 712.1b): either face can be cast or
+    /// played, and it can't transform (rule 701.28c).
+    Modal,
+}
+
+/// The back face of a double-faced card, with the kind of card it's on.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BackFace {
+    pub kind: DoubleFacedKind,
+    pub face: Arc<CardData>,
+}
+
+/// How the two halves of a multi-part card relate.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MultiPartKind {
+    /// A split card (rule 709): either half can be cast.
+    Split,
+    /// A split card with fuse (rule 702.102): cast from hand, both halves
+    /// can also be cast together.
+    Fuse,
+    /// A split card whose second half has aftermath (rule 702.127): that
+    /// half can be cast only from a graveyard, and is exiled whenever it
+    /// would leave the stack.
+    Aftermath,
+    /// An adventurer card (rule 715): its Adventure is cast as the second
+    /// half, resolves into exile, and the creature can then be cast from
+    /// there.
+    Adventure,
+}
+
+impl MultiPartKind {
+    /// Whether this is a split card layout, whose characteristics off the
+    /// stack are both halves' combined (rule 709.4). An adventurer has only
+    /// its main card's (rule 715.4).
+    pub fn is_split(self) -> bool {
+        !matches!(self, MultiPartKind::Adventure)
+    }
+}
+
+/// The second half of a multi-part card, with the kind of card it's on.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SecondHalf {
+    pub kind: MultiPartKind,
+    pub half: Arc<CardData>,
+    /// Both halves' characteristics combined (rule 709.4), built with the
+    /// card. None for an adventurer.
+    pub combined: Option<Arc<CardData>>,
+}
+
+/// Which keyword lets a card be cast face down.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MorphKind {
+    /// Morph (rule 702.37a).
+    Morph,
+    /// Megamorph (rule 702.37b): as it's turned face up, it gets a +1/+1
+    /// counter.
+    Megamorph,
+    /// Disguise (rule 702.168a): face down, it also has ward {2}.
+    Disguise,
+}
+
+impl MorphKind {
+    /// How a spell cast face down with this ability is face down.
+    pub fn face_down_kind(self) -> FaceDownKind {
+        match self {
+            MorphKind::Morph | MorphKind::Megamorph => FaceDownKind::Morph,
+            MorphKind::Disguise => FaceDownKind::Disguise,
+        }
+    }
+}
+
+/// A card's morph, megamorph or disguise ability and the cost to turn it
+/// face up.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Morph {
+    pub kind: MorphKind,
+    pub cost: ManaCost,
+}
+
+/// How a spell or permanent came to be face down (rule 708.2a), which
+/// decides its face-down characteristics.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FaceDownKind {
+    /// Cast with morph or megamorph (rule 702.37c).
+    Morph,
+    /// Cast with disguise (rule 702.168a).
+    Disguise,
+    /// Manifested (rule 701.40a).
+    Manifest,
+    /// Cloaked (rule 701.58a).
+    Cloak,
+}
+
+impl FaceDownKind {
+    /// Whether it has ward {2} while face down (rules 702.168a, 701.58a).
+    pub fn has_ward(self) -> bool {
+        matches!(self, FaceDownKind::Disguise | FaceDownKind::Cloak)
+    }
+}
+
+/// The characteristics of a face-down spell or permanent (rule 708.2): a
+/// 2/2 creature with no name, text, subtypes or mana cost — with ward {2}
+/// if it was disguised or cloaked.
+pub fn face_down_characteristics(kind: FaceDownKind) -> &'static Arc<CardData> {
+    static PLAIN: OnceLock<Arc<CardData>> = OnceLock::new();
+    static WARDED: OnceLock<Arc<CardData>> = OnceLock::new();
+    let face_down = || CardDataBuilder::new("").card_type(CardType::Creature).power_toughness(2, 2);
+    if kind.has_ward() {
+        WARDED.get_or_init(|| face_down().keyword(KeywordAbility::Ward).build())
+    } else {
+        PLAIN.get_or_init(|| face_down().build())
+    }
+}
+
+/// Which face of a double-faced card is up (rule 712.8) — or which half of
+/// a split or adventurer card a spell was cast as. For a split card `Front`
+/// and `Back` are its left and right halves, and for an adventurer its
+/// creature and its Adventure (rule 715.2). Single-faced cards are always
+/// `Front` unless face down.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum CardFace {
+    #[default]
+    Front,
+    Back,
+    /// Both halves of a split card at once: its characteristics in every
+    /// zone but the stack (rule 709.4), and a fused split spell's on it
+    /// (rule 702.102c).
+    Combined,
+    /// Neither face: a face-down spell or permanent, which has the
+    /// characteristics `face_down_characteristics` gives it (rule 708.2).
+    FaceDown(FaceDownKind),
+}
+
+impl CardData {
+    /// Whether this is a double-faced card of the given kind.
+    pub fn is_double_faced(&self, kind: DoubleFacedKind) -> bool {
+        self.back_face.as_ref().is_some_and(|back| back.kind == kind)
+    }
+
+    /// Whether this is a multi-part card of the given kind.
+    pub fn is_multi_part(&self, kind: MultiPartKind) -> bool {
+        self.second_half.as_ref().is_some_and(|half| half.kind == kind)
+    }
+
+    /// The faces this card can be cast or played as (rule 712.12): either
+    /// face of a modal DFC, either half of a split or adventurer card (rules
+    /// 709.3, 715.3) — or both halves of a fuse card (rule 702.102a) — and
+    /// the front face of everything else — plus face down, for a card with
+    /// morph, megamorph or disguise (rules 702.37c, 702.168a). Which of them
+    /// can be cast from the zone the card is in is up to
+    /// `GameState::faces_castable_from_zone`.
+    pub fn playable_faces(&self) -> Vec<CardFace> {
+        let mut faces = if self.is_double_faced(DoubleFacedKind::Modal) {
+            vec![CardFace::Front, CardFace::Back]
+        } else if self.is_multi_part(MultiPartKind::Fuse) {
+            vec![CardFace::Front, CardFace::Back, CardFace::Combined]
+        } else if self.second_half.is_some() {
+            vec![CardFace::Front, CardFace::Back]
+        } else {
+            vec![CardFace::Front]
+        };
+        if let Some(morph) = &self.morph {
+            faces.push(CardFace::FaceDown(morph.kind.face_down_kind()));
+        }
+        faces
+    }
+}
+
+/// The characteristics of a split card's two halves combined (rule 709.4):
+/// both names, the total mana cost, and every color, type, keyword and
+/// ability of either half.
+fn combine_halves(left: &CardData, right: &CardData) -> CardData {
+    let mut symbols = left.mana_cost.clone().map(|c| c.symbols).unwrap_or_default();
+    symbols.extend(right.mana_cost.clone().map(|c| c.symbols).unwrap_or_default());
+    let mut combined = left.clone();
+    combined.name = format!("{} // {}", left.name, right.name);
+    combined.mana_cost = Some(ManaCost::from_symbols(symbols));
+    combined.colors.extend(right.colors.iter().copied());
+    combined.types.extend(right.types.iter().copied());
+    combined.supertypes.extend(right.supertypes.iter().copied());
+    combined.subtypes.extend(right.subtypes.iter().cloned());
+    combined.keywords.extend(right.keywords.iter().cloned());
+    combined.abilities.extend(right.abilities.iter().cloned());
+    combined.second_half = None;
+    combined
+}
+
+/// An action a card allows from its owner's opening hand, taken in the
+/// pregame once all players have kept (rule 103.6).
+#[derive(Debug, Clone, PartialEq)]
+pub enum OpeningHandAction {
+    /// "If this card is in your opening hand, you may begin the game with it
+    /// on the battlefield." — the Leylines.
+    BeginOnBattlefield,
+    /// "You may reveal this card from your opening hand. If you do, at the
+    /// beginning of your first upkeep, [effect]." — the Chancellors. The card
+    /// stays in hand; revealing it creates a delayed triggered ability
+    /// (rule 603.7) whose controller is the revealing player.
+    RevealForFirstUpkeep(Box<Effect>),
 }
 
 /// The type of an ability
@@ -53,12 +277,35 @@ pub enum AbilityType {
     Activated,
     /// Triggered abilities (when/whenever/at)
     Triggered,
+    /// A triggered ability whose trigger event the engine checks for and
+    /// queues (rule 603.2).
+    TriggeredOn(TriggerEvent),
+    /// A Saga's chapter ability (rule 714.2): a triggered ability that
+    /// triggers as lore counters bring the count to its chapter number.
+    Chapter(u32),
     /// Static abilities (continuous effect)
     Static,
     /// Spell ability (the effect of an instant/sorcery)
     Spell,
 }
 
+/// A trigger event the engine watches for (rule 603.2). Only the events
+/// something checks for are listed; `AbilityType::Triggered` abilities
+/// with other trigger conditions don't trigger yet.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TriggerEvent {
+    /// "At the beginning of your upkeep" (rule 503.1).
+    YourUpkeep,
+    /// "At the beginning of your end step" (rule 513.1).
+    YourEndStep,
+    /// "Whenever you cast a spell" (rule 601.2i).
+    YouCastSpell,
+    /// "When this creature dies" — put into a graveyard from the battlefield
+    /// (rule 700.4). A leaves-the-battlefield ability: it triggers from the
+    /// abilities the creature had as it last existed there (rule 603.10a).
+    Dies,
+}
+
 /// Definition of a single ability on a card.
 ///
 /// This is the printed ability — at runtime, activated/triggered abilities
@@ -71,6 +318,47 @@ pub struct AbilityDef {
     pub effect: Effect,
 }
 
+impl AbilityDef {
+    /// A loyalty ability is an activated ability with a loyalty symbol in its
+    /// cost (rule 606.3) — modelled as adding or removing loyalty counters.
+    pub fn is_loyalty_ability(&self) -> bool {
+        self.ability_type == AbilityType::Activated
+            && self.costs.iter().any(|c| matches!(
+                c,
+                Cost::AddCounters(CounterType::Loyalty, _) | Cost::RemoveCounters(CounterType::Loyalty, _)
+            ))
+    }
+
+    /// An activated ability that attaches or unattaches its own source —
+    /// equip (rule 702.6a) and reconfigure (rule 702.151a).
+    pub fn is_attach_ability(&self) -> bool {
+        self.ability_type == AbilityType::Activated
+            && matches!(self.effect, Effect::Atom(Primitive::Attach | Primitive::Unattach, _))
+    }
+
+    /// The chapter number of a chapter ability (rule 714.2a).
+    pub fn chapter(&self) -> Option<u32> {
+        match self.ability_type {
+            AbilityType::Chapter(chapter) => Some(chapter),
+            _ => None,
+        }
+    }
+
+    /// The event a triggered ability triggers on, if the engine checks for it.
+    pub fn trigger_event(&self) -> Option<TriggerEvent> {
+        match self.ability_type {
+            AbilityType::TriggeredOn(event) => Some(event),
+            _ => None,
+        }
+    }
+
+    /// "Activate only as a sorcery": loyalty abilities (rule 606.3) and
+    /// attach abilities (rules 702.6a, 702.151a).
+    pub fn is_sorcery_speed(&self) -> bool {
+        self.is_loyalty_ability() || self.is_attach_ability()
+    }
+}
+
 // Effect and Primitive types are defined in types::effects and re-exported here
 // for convenience. See effect_system_plan.md for the full design.
 
@@ -116,6 +404,10 @@ impl CardDataBuilder {
                 enchant_filter: None,
                 alternative_costs: Vec::new(),
                 additional_costs: Vec::new(),
+                opening_hand_action: None,
+                back_face: None,
+                second_half: None,
+                morph: None,
             },
         }
     }
@@ -213,6 +505,92 @@ impl CardDataBuilder {
         self
     }
 
+    /// Shorthand: add a loyalty ability (rule 606). `delta` is the printed
+    /// loyalty cost — `+1` adds a loyalty counter, `-3` removes three, `0`
+    /// is a [0] ability.
+    pub fn loyalty_ability(mut self, delta: i32, effect: Effect) -> Self {
+        let cost = if delta >= 0 {
+            Cost::AddCounters(CounterType::Loyalty, delta as u32)
+        } else {
+            Cost::RemoveCounters(CounterType::Loyalty, delta.unsigned_abs())
+        };
+        self.data.abilities.push(AbilityDef {
+            id: crate::types::ids::new_ability_id(),
+            ability_type: AbilityType::Activated,
+            costs: vec![cost],
+            effect,
+        });
+        self
+    }
+
+    /// Shorthand: add a Saga's chapter ability (rule 714.2) for chapter
+    /// `chapter`. A chapter symbol with several numerals ("I, II — ...") is
+    /// one of these for each (rule 714.2a).
+    pub fn chapter(mut self, chapter: u32, effect: Effect) -> Self {
+        self.data.abilities.push(AbilityDef {
+            id: crate::types::ids::new_ability_id(),
+            ability_type: AbilityType::Chapter(chapter),
+            costs: Vec::new(),
+            effect,
+        });
+        self
+    }
+
+    /// Shorthand: add a triggered ability that triggers on `event`.
+    pub fn triggered_on(mut self, event: TriggerEvent, effect: Effect) -> Self {
+        self.data.abilities.push(AbilityDef {
+            id: crate::types::ids::new_ability_id(),
+            ability_type: AbilityType::TriggeredOn(event),
+            costs: Vec::new(),
+            effect,
+        });
+        self
+    }
+
+    /// Add equip (rule 702.6a): "[cost]: Attach to target creature you
+    /// control. Equip only as a sorcery." Also adds the `Equip` keyword.
+    pub fn equip(mut self, cost: ManaCost) -> Self {
+        self.data.keywords.insert(KeywordAbility::Equip);
+        self.data.abilities.push(Self::attach_ability(cost));
+        self
+    }
+
+    /// Add reconfigure (rule 702.151a): "[cost]: Attach to another target
+    /// creature you control" and "[cost]: Unattach", both sorcery-speed, the
+    /// second only while attached. Also adds the `Reconfigure` keyword, which
+    /// the layer system reads for "isn't a creature while attached"
+    /// (rule 702.151b).
+    pub fn reconfigure(mut self, cost: ManaCost) -> Self {
+        self.data.keywords.insert(KeywordAbility::Reconfigure);
+        self.data.abilities.push(Self::attach_ability(cost.clone()));
+        self.data.abilities.push(AbilityDef {
+            id: crate::types::ids::new_ability_id(),
+            ability_type: AbilityType::Activated,
+            costs: vec![Cost::Mana(cost)],
+            effect: Effect::Atom(Primitive::Unattach, EffectRecipient::Implicit),
+        });
+        self
+    }
+
+    fn attach_ability(cost: ManaCost) -> AbilityDef {
+        let creature_you_control = PermanentFilter::And(
+            Box::new(PermanentFilter::ByType(CardType::Creature)),
+            Box::new(PermanentFilter::ByController(PlayerRef::You)),
+        );
+        AbilityDef {
+            id: crate::types::ids::new_ability_id(),
+            ability_type: AbilityType::Activated,
+            costs: vec![Cost::Mana(cost)],
+            effect: Effect::Atom(
+                Primitive::Attach,
+                EffectRecipient::Target(
+                    SelectionFilter::Permanent(creature_you_control),
+                    TargetCount::Exactly(1),
+                ),
+            ),
+        }
+    }
+
     pub fn enchant_filter(mut self, filter: SelectionFilter) -> Self {
         self.data.enchant_filter = Some(filter);
         self
@@ -228,7 +606,84 @@ impl CardDataBuilder {
         self
     }
 
-    pub fn build(self) -> Arc<CardData> {
+    pub fn opening_hand_action(mut self, action: OpeningHandAction) -> Self {
+        self.data.opening_hand_action = Some(action);
+        self
+    }
+
+    /// Add morph (rule 702.37a): "You may cast this card face down as a 2/2
+    /// creature for {3}. Turn it face up any time for its morph cost."
+    pub fn morph(self, cost: ManaCost) -> Self {
+        self.face_down_ability(MorphKind::Morph, KeywordAbility::Morph, cost)
+    }
+
+    /// Add megamorph (rule 702.37b): morph, and it gets a +1/+1 counter as
+    /// it's turned face up.
+    pub fn megamorph(self, cost: ManaCost) -> Self {
+        self.face_down_ability(MorphKind::Megamorph, KeywordAbility::Megamorph, cost)
+    }
+
+    /// Add disguise (rule 702.168a): morph, except that face down it has
+    /// ward {2}.
+    pub fn disguise(self, cost: ManaCost) -> Self {
+        self.face_down_ability(MorphKind::Disguise, KeywordAbility::Disguise, cost)
+    }
+
+    fn face_down_ability(mut self, kind: MorphKind, keyword: KeywordAbility, cost: ManaCost) -> Self {
+        self.data.keywords.insert(keyword);
+        self.data.morph = Some(Morph { kind, cost });
+        self
+    }
+
+    /// Make this a transforming double-faced card (rule 712.1a) with `back`
+    /// as its back face.
+    pub fn transforms_into(mut self, back: Arc<CardData>) -> Self {
+        self.data.back_face = Some(BackFace { kind: DoubleFacedKind::Transforming, face: back });
+        self
+    }
+
+    /// Make this a modal double-faced card (rule 712.1b) with `back` as its
+    /// back face.
+    pub fn modal_back_face(mut self, back: Arc<CardData>) -> Self {
+        self.data.back_face = Some(BackFace { kind: DoubleFacedKind::Modal, face: back });
+        self
+    }
+
+    /// Make this a split card (rule 709) with `right` as its right half.
+    pub fn split_with(self, right: Arc<CardData>) -> Self {
+        self.second_half(MultiPartKind::Split, right)
+    }
+
+    /// Make this a split card with fuse (rule 702.102) with `right` as its
+    /// right half.
+    pub fn fuse_with(self, right: Arc<CardData>) -> Self {
+        self.second_half(MultiPartKind::Fuse, right)
+    }
+
+    /// Make this the first half of a split card whose second half,
+    /// `aftermath`, has aftermath (rule 702.127).
+    pub fn aftermath(self, aftermath: Arc<CardData>) -> Self {
+        self.second_half(MultiPartKind::Aftermath, aftermath)
+    }
+
+    /// Make this an adventurer card (rule 715) with `adventure` as its
+    /// Adventure.
+    pub fn adventure(self, adventure: Arc<CardData>) -> Self {
+        self.second_half(MultiPartKind::Adventure, adventure)
+    }
+
+    fn second_half(mut self, kind: MultiPartKind, half: Arc<CardData>) -> Self {
+        self.data.second_half = Some(SecondHalf { kind, half, combined: None });
+        self
+    }
+
+    pub fn build(mut self) -> Arc<CardData> {
+        // Combine once the first half is complete, whatever order the
+        // builder methods came in.
+        if let Some(second) = &self.data.second_half && second.kind.is_split() {
+            let combined = combine_halves(&self.data, &second.half);
+            self.data.second_half.as_mut().unwrap().combined = Some(Arc::new(combined));
+        }
         Arc::new(self.data)
     }
 }
@@ -296,6 +751,48 @@ mod tests {
         assert_eq!(indicator2.len(), 3);
     }
 
+    #[test]
+    fn test_loyalty_ability_builder() {
+        let effect = Effect::Atom(
+            Primitive::GainLife(AmountExpr::Fixed(2)),
+            EffectRecipient::Controller,
+        );
+        let walker = CardDataBuilder::new("Test Walker")
+            .card_type(CardType::Planeswalker)
+            .loyalty(3)
+            .loyalty_ability(1, effect.clone())
+            .loyalty_ability(-2, effect.clone())
+            .loyalty_ability(0, effect)
+            .build();
+
+        assert!(walker.abilities.iter().all(|a| a.is_loyalty_ability()));
+        assert_eq!(walker.abilities[0].costs, vec![Cost::AddCounters(CounterType::Loyalty, 1)]);
+        assert_eq!(walker.abilities[1].costs, vec![Cost::RemoveCounters(CounterType::Loyalty, 2)]);
+        assert_eq!(walker.abilities[2].costs, vec![Cost::AddCounters(CounterType::Loyalty, 0)]);
+    }
+
+    #[test]
+    fn test_equip_and_reconfigure_builders() {
+        let sword = CardDataBuilder::new("Test Sword")
+            .card_type(CardType::Artifact)
+            .equip(ManaCost::build(&[], 2))
+            .build();
+        assert!(sword.keywords.contains(&KeywordAbility::Equip));
+        assert_eq!(sword.abilities.len(), 1);
+        assert!(sword.abilities[0].is_attach_ability());
+        assert!(sword.abilities[0].is_sorcery_speed());
+        assert_eq!(sword.abilities[0].costs, vec![Cost::Mana(ManaCost::build(&[], 2))]);
+
+        let blades = CardDataBuilder::new("Test Blades")
+            .card_type(CardType::Creature)
+            .reconfigure(ManaCost::build(&[], 2))
+            .build();
+        assert!(blades.keywords.contains(&KeywordAbility::Reconfigure));
+        assert_eq!(blades.abilities.len(), 2);
+        assert!(blades.abilities.iter().all(|a| a.is_attach_ability()));
+        assert!(matches!(blades.abilities[1].effect, Effect::Atom(Primitive::Unattach, _)));
+    }
+
     #[test]
     fn test_card_data_default_no_costs() {
         let card = CardDataBuilder::new("Vanilla Creature").build();
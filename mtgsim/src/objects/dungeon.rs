@@ -0,0 +1,136 @@
+//! Dungeon cards (rule 309): each is a graph of rooms, walked one room at
+//! a time by venturing (rule 701.49).
+//!
+//! Dungeons start the game outside it and are put into the command zone by
+//! venturing, so the engine builds them from their `DungeonType` rather
+//! than from a card pool. Room abilities the engine can't express yet do
+//! nothing and are noted "(omitted — reason)".
+//!
+//! Dungeon origins:
+//! - Lost Mine of Phandelver — Adventures in the Forgotten Realms (2021)
+//! - Undercity — Commander Legends: Battle for Baldur's Gate (2022)
+
+use std::sync::Arc;
+
+use crate::objects::card_data::{CardData, CardDataBuilder};
+use crate::types::card_types::{CardType, DungeonType, Subtype};
+use crate::types::effects::*;
+
+/// One room of a dungeon (rule 309.4). Its effect is a room ability: a
+/// triggered ability that triggers as the venture marker moves into the
+/// room (rule 309.4c).
+#[derive(Debug, Clone, PartialEq)]
+pub struct DungeonRoom {
+    pub name: &'static str,
+    pub effect: Effect,
+    /// The rooms an arrow leads to (rule 309.4b), as indices into the
+    /// dungeon's rooms. Empty for the bottommost room.
+    pub leads_to: Vec<usize>,
+}
+
+impl DungeonType {
+    /// The dungeons a player may choose when venturing into the dungeon
+    /// (rule 701.49a). Undercity isn't one: a player enters it only by
+    /// venturing into Undercity (rule 701.49c).
+    pub const VENTURE_CHOICES: &'static [DungeonType] = &[DungeonType::LostMineOfPhandelver];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            DungeonType::LostMineOfPhandelver => "Lost Mine of Phandelver",
+            DungeonType::Undercity => "Undercity",
+        }
+    }
+
+    /// The dungeon card as an object in the command zone.
+    pub fn card(self) -> Arc<CardData> {
+        CardDataBuilder::new(self.name())
+            .card_type(CardType::Dungeon)
+            .subtype(Subtype::Dungeon(self))
+            .build()
+    }
+
+    /// The dungeon's rooms, topmost first (rule 309.4a).
+    pub fn rooms(self) -> Vec<DungeonRoom> {
+        match self {
+            DungeonType::LostMineOfPhandelver => vec![
+                // Scry 1. (omitted — needs scry)
+                room("Cave Entrance", omitted(), &[1, 2]),
+                // Create a 1/1 red Goblin creature token. (omitted — needs tokens)
+                room("Goblin Lair", omitted(), &[3, 4]),
+                // Create a Treasure token. (omitted — needs tokens)
+                room("Mine Tunnels", omitted(), &[4, 5]),
+                room("Storeroom", put_counters(1), &[6]),
+                // Each opponent loses 1 life and you gain 1 life. (omitted —
+                // needs "each opponent")
+                room("Dark Pool", omitted(), &[6]),
+                // Target creature gets -4/-0 until your next turn. (omitted —
+                // needs negative amounts)
+                room("Fungi Cavern", omitted(), &[6]),
+                room("Temple of Dumathoin", draw_a_card(), &[]),
+            ],
+            DungeonType::Undercity => vec![
+                // Search your library for a basic land card, reveal it, put
+                // it into your hand, then shuffle. (omitted — needs search)
+                room("Secret Entrance", omitted(), &[1, 2]),
+                room("Forge", put_counters(2), &[3, 4]),
+                // Scry 2. (omitted — needs scry)
+                room("Lost Well", omitted(), &[4, 5]),
+                room("Trap!", Effect::Atom(
+                    Primitive::LoseLife(AmountExpr::Fixed(5)),
+                    EffectRecipient::Target(SelectionFilter::Player, TargetCount::Exactly(1)),
+                ), &[6]),
+                // Goad target creature. (omitted — needs goad)
+                room("Arena", omitted(), &[6, 7]),
+                // Create a Treasure token. (omitted — needs tokens)
+                room("Stash", omitted(), &[7]),
+                room("Archives", draw_a_card(), &[8]),
+                // Create a 4/1 black Skeleton creature token with menace.
+                // (omitted — needs tokens)
+                room("Catacombs", omitted(), &[8]),
+                // Reveal the top ten cards of your library. Put a creature
+                // card from among them onto the battlefield with three +1/+1
+                // counters on it. It gains hexproof until your next turn.
+                // Then shuffle. (omitted — needs library search)
+                room("Throne of the Dead Three", omitted(), &[]),
+            ],
+        }
+    }
+}
+
+fn room(name: &'static str, effect: Effect, leads_to: &[usize]) -> DungeonRoom {
+    DungeonRoom { name, effect, leads_to: leads_to.to_vec() }
+}
+
+/// A room ability the engine can't express yet: it does nothing.
+fn omitted() -> Effect {
+    Effect::Sequence(Vec::new())
+}
+
+/// "Put N +1/+1 counters on target creature."
+fn put_counters(n: u64) -> Effect {
+    Effect::Atom(
+        Primitive::AddCounters(CounterType::PlusOnePlusOne, AmountExpr::Fixed(n)),
+        EffectRecipient::Target(SelectionFilter::Creature, TargetCount::Exactly(1)),
+    )
+}
+
+fn draw_a_card() -> Effect {
+    Effect::Atom(Primitive::DrawCards(AmountExpr::Fixed(1)), EffectRecipient::Controller)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::types::card_types::DungeonType;
+
+    #[test]
+    fn test_every_arrow_leads_further_down() {
+        for dungeon in [DungeonType::LostMineOfPhandelver, DungeonType::Undercity] {
+            let rooms = dungeon.rooms();
+            for (i, room) in rooms.iter().enumerate() {
+                assert!(room.leads_to.iter().all(|&next| next > i && next < rooms.len()));
+            }
+            let bottommost: Vec<_> = rooms.iter().filter(|room| room.leads_to.is_empty()).collect();
+            assert_eq!(bottommost.len(), 1, "{:?} has one bottommost room", dungeon);
+        }
+    }
+}
@@ -1,2 +1,3 @@
 pub mod card_data;
 pub mod object;
+pub mod dungeon;
@@ -1,9 +1,10 @@
 use std::sync::Arc;
 
+use crate::types::card_types::CardType;
 use crate::types::ids::{ObjectId, PlayerId, new_object_id};
 use crate::types::zones::Zone;
 
-use super::card_data::CardData;
+use super::card_data::{face_down_characteristics, CardData, CardFace};
 
 /// A runtime game object — an instance of a card (or token, or copy) in the game.
 ///
@@ -57,6 +58,36 @@ impl GameObject {
     pub fn in_library(card_data: Arc<CardData>, owner: PlayerId) -> Self {
         Self::new(card_data, owner, Zone::Library)
     }
+
+    /// The faces this card can be cast as (rule 712.12): its playable faces
+    /// that aren't lands (rule 305.9). Empty for a land.
+    pub fn castable_faces(&self) -> Vec<CardFace> {
+        self.card_data.playable_faces().into_iter()
+            .filter(|&f| !self.face(f).types.contains(&CardType::Land))
+            .collect()
+    }
+
+    /// The faces this card can be played as a land (rules 305.1, 712.12).
+    pub fn land_faces(&self) -> Vec<CardFace> {
+        self.card_data.playable_faces().into_iter()
+            .filter(|&f| self.face(f).types.contains(&CardType::Land))
+            .collect()
+    }
+
+    /// The printed characteristics of one face of this card (rule 712.8),
+    /// or of one or both halves of a split or adventurer card (rules 709.4,
+    /// 715.4) — or the characteristics it has face down (rule 708.2).
+    /// Asking for a face or half the card doesn't have gets its front face.
+    pub fn face(&self, face: CardFace) -> &Arc<CardData> {
+        let data = &self.card_data;
+        match (face, &data.back_face, &data.second_half) {
+            (CardFace::FaceDown(kind), _, _) => face_down_characteristics(kind),
+            (CardFace::Back, Some(back), _) => &back.face,
+            (CardFace::Back, _, Some(second)) => &second.half,
+            (CardFace::Combined, _, Some(second)) => second.combined.as_ref().unwrap_or(data),
+            _ => data,
+        }
+    }
 }
 
 #[cfg(test)]
@@ -3,9 +3,10 @@
 use crate::state::game_state::GameState;
 use crate::types::ids::{ObjectId, PlayerId};
 
-/// Get all object IDs on the battlefield controlled by a player.
+/// Get all object IDs on the battlefield controlled by a player. Phased-out
+/// permanents are left out (rule 702.26b).
 pub fn permanents_controlled_by(game: &GameState, player_id: PlayerId) -> Vec<ObjectId> {
-    game.battlefield.iter()
+    game.permanents()
         .filter(|(_, entry)| entry.controller == player_id)
         .map(|(id, _)| *id)
         .collect()
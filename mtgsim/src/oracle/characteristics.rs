@@ -6,11 +6,12 @@
 use std::collections::HashSet;
 
 use crate::engine::layers::compute::compute_characteristics;
-use crate::objects::card_data::AbilityDef;
+use crate::objects::card_data::{AbilityDef, CardFace, DoubleFacedKind};
 use crate::state::game_state::GameState;
 use crate::types::card_types::{CardType, Subtype, Supertype};
 use crate::types::ids::ObjectId;
 use crate::types::keywords::KeywordAbility;
+use crate::types::mana::ManaCost;
 
 /// Check if a permanent has an effective keyword ability.
 /// Routes through the layer system — accounts for granted/removed keywords.
@@ -20,13 +21,72 @@ pub fn has_keyword(game: &GameState, id: ObjectId, keyword: KeywordAbility) -> b
         .unwrap_or(false)
 }
 
-/// Get the effective name of a game object.
+/// Total toxic value of a permanent: the sum of N over all its effective
+/// toxic abilities (rule 702.164b). 0 if it has none. Keywords are a set,
+/// so two instances of the same Toxic N count once.
+pub fn get_toxic_value(game: &GameState, id: ObjectId) -> u32 {
+    compute_characteristics(game, id)
+        .map(|chars| {
+            chars.keywords.iter()
+                .map(|kw| if let KeywordAbility::Toxic(n) = kw { *n } else { 0 })
+                .sum()
+        })
+        .unwrap_or(0)
+}
+
+/// Get the effective name of a game object. A split card off the stack, or
+/// a fused split spell, gets both halves' names joined with " // " — use
+/// `get_effective_names` or `has_name` to compare against a name.
 pub fn get_effective_name(game: &GameState, id: ObjectId) -> String {
     compute_characteristics(game, id)
         .map(|chars| chars.name)
         .unwrap_or_default()
 }
 
+/// Every name a game object has. A split card has both its halves' names
+/// in every zone but the stack, and so does a fused split spell on it (rule
+/// 709.4); everything else has one.
+pub fn get_effective_names(game: &GameState, id: ObjectId) -> Vec<String> {
+    if game.face_up(id) == CardFace::Combined
+        && let Some(obj) = game.objects.get(&id)
+        && let Some(second) = &obj.card_data.second_half
+    {
+        return vec![obj.card_data.name.clone(), second.half.name.clone()];
+    }
+    vec![get_effective_name(game, id)]
+}
+
+/// Does a game object have `name` as one of its names (rule 709.4)?
+pub fn has_name(game: &GameState, id: ObjectId, name: &str) -> bool {
+    get_effective_names(game, id).iter().any(|n| n == name)
+}
+
+/// The mana value of a game object (rule 202.3). A split card off the
+/// stack, or a fused split spell, has its halves' total (rule 709.4); a
+/// spell on the stack counts each {X} as the value chosen for it (rule
+/// 107.3b), and X is 0 everywhere else (rule 107.3c). A transforming
+/// double-faced card back face up has no mana cost of its own; its mana
+/// value is its front face's (rule 712.8e).
+pub fn get_mana_value(game: &GameState, id: ObjectId) -> u32 {
+    let Some(cost) = compute_characteristics(game, id)
+        .and_then(|chars| chars.mana_cost)
+        .or_else(|| transformed_front_mana_cost(game, id))
+    else {
+        return 0;
+    };
+    let x = game.stack_entries.get(&id).and_then(|entry| entry.x_value).unwrap_or(0);
+    cost.mana_value() as u32 + cost.x_count() as u32 * x as u32
+}
+
+/// The front face's mana cost of `id`, if it's a transforming double-faced
+/// card back face up (rule 712.8e).
+fn transformed_front_mana_cost(game: &GameState, id: ObjectId) -> Option<ManaCost> {
+    let obj = game.objects.get(&id)?;
+    let transformed = game.face_up(id) == CardFace::Back
+        && obj.card_data.is_double_faced(DoubleFacedKind::Transforming);
+    if transformed { obj.card_data.mana_cost.clone() } else { None }
+}
+
 /// Check if an object on the battlefield is currently a creature.
 /// Routes through the layer system — accounts for type-changing effects.
 pub fn is_creature(game: &GameState, id: ObjectId) -> bool {
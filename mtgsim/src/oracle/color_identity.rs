@@ -0,0 +1,145 @@
+//! Color identity (rule 903.4).
+//!
+//! A card's color identity is its colors plus the colors of every mana
+//! symbol in its mana cost and rules text, and the colors of its color
+//! indicator. Reminder text — parenthesized in `rules_text` — isn't rules
+//! text and doesn't count (rule 207.2). Hybrid symbols count both halves;
+//! generic, colorless, snow and X symbols count nothing.
+//!
+//! Reads printed card data only: color identity is fixed before the game
+//! begins and isn't changed by effects.
+
+use std::collections::HashSet;
+
+use crate::objects::card_data::CardData;
+use crate::types::colors::Color;
+use crate::types::mana::{ManaSymbol, ManaType};
+
+/// The color identity of a card (rule 903.4).
+pub fn color_identity(card: &CardData) -> HashSet<Color> {
+    let mut identity = card.colors.clone();
+    if let Some(cost) = &card.mana_cost {
+        identity.extend(cost.symbols.iter().flat_map(symbol_colors));
+    }
+    if let Some(indicator) = &card.color_indicator {
+        identity.extend(indicator.iter().copied());
+    }
+    identity.extend(rules_text_colors(&card.rules_text));
+    identity
+}
+
+/// The colors of one mana symbol in a mana cost.
+fn symbol_colors(symbol: &ManaSymbol) -> Vec<Color> {
+    match symbol {
+        ManaSymbol::Colored(m) | ManaSymbol::MonoHybrid(m) | ManaSymbol::Phyrexian(m) => {
+            mana_type_color(*m).into_iter().collect()
+        }
+        ManaSymbol::Hybrid(a, b) | ManaSymbol::HybridPhyrexian(a, b) => {
+            [*a, *b].into_iter().filter_map(mana_type_color).collect()
+        }
+        ManaSymbol::Generic | ManaSymbol::Colorless | ManaSymbol::Snow | ManaSymbol::X => Vec::new(),
+    }
+}
+
+fn mana_type_color(mana_type: ManaType) -> Option<Color> {
+    match mana_type {
+        ManaType::White => Some(Color::White),
+        ManaType::Blue => Some(Color::Blue),
+        ManaType::Black => Some(Color::Black),
+        ManaType::Red => Some(Color::Red),
+        ManaType::Green => Some(Color::Green),
+        ManaType::Colorless => None,
+    }
+}
+
+/// Colors of the mana symbols written in rules text, e.g. "{T}: Add {G}."
+/// or "{2/W}". Each `{...}` symbol is split on `/` and every W, U, B, R or G
+/// part counts; `{T}`, `{C}`, `{P}` and numbers don't. Symbols inside
+/// parentheses are reminder text and are skipped.
+fn rules_text_colors(text: &str) -> HashSet<Color> {
+    let mut colors = HashSet::new();
+    let mut paren_depth = 0usize;
+    let mut symbol: Option<String> = None;
+    for ch in text.chars() {
+        match (ch, symbol.as_mut()) {
+            ('}', Some(s)) => {
+                if paren_depth == 0 {
+                    colors.extend(s.split('/').filter_map(letter_color));
+                }
+                symbol = None;
+            }
+            (_, Some(s)) => s.push(ch),
+            ('{', None) => symbol = Some(String::new()),
+            ('(', None) => paren_depth += 1,
+            (')', None) => paren_depth = paren_depth.saturating_sub(1),
+            _ => {}
+        }
+    }
+    colors
+}
+
+fn letter_color(part: &str) -> Option<Color> {
+    match part.trim() {
+        "W" => Some(Color::White),
+        "U" => Some(Color::Blue),
+        "B" => Some(Color::Black),
+        "R" => Some(Color::Red),
+        "G" => Some(Color::Green),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cards::{basic_lands, creatures};
+    use crate::objects::card_data::CardDataBuilder;
+    use crate::types::mana::ManaCost;
+
+    #[test]
+    fn test_identity_from_mana_cost() {
+        let bears = creatures::grizzly_bears();
+        assert_eq!(color_identity(&bears), HashSet::from([Color::Green]));
+    }
+
+    #[test]
+    fn test_identity_from_rules_text_symbols() {
+        // "{T}: Add {G}." — a Forest's identity is green.
+        assert_eq!(color_identity(&basic_lands::forest()), HashSet::from([Color::Green]));
+
+        let signet = CardDataBuilder::new("Test Signet")
+            .mana_cost(ManaCost::build(&[], 2))
+            .rules_text("{1}, {T}: Add {W}{U}.")
+            .build();
+        assert_eq!(color_identity(&signet), HashSet::from([Color::White, Color::Blue]));
+    }
+
+    #[test]
+    fn test_hybrid_symbols_count_both_colors() {
+        let card = CardDataBuilder::new("Test Hybrid")
+            .mana_cost(ManaCost::from_symbols(vec![ManaSymbol::Hybrid(ManaType::Red, ManaType::Green)]))
+            .rules_text("{B/P}: Regenerate this creature.")
+            .build();
+        assert_eq!(
+            color_identity(&card),
+            HashSet::from([Color::Red, Color::Green, Color::Black]),
+        );
+    }
+
+    #[test]
+    fn test_reminder_text_and_colorless_symbols_ignored() {
+        let card = CardDataBuilder::new("Test Artifact")
+            .mana_cost(ManaCost::build(&[], 3))
+            .rules_text("Extort (Whenever you cast a spell, you may pay {W/B}.) {T}: Add {C}.")
+            .build();
+        assert!(color_identity(&card).is_empty());
+    }
+
+    #[test]
+    fn test_color_indicator_counts() {
+        let card = CardDataBuilder::new("Test Back Face")
+            .color_indicator(vec![Color::Blue])
+            .build();
+        assert_eq!(color_identity(&card), HashSet::from([Color::Blue]));
+    }
+}
@@ -0,0 +1,180 @@
+//! Deck construction checks against a format's `DeckLimits` (rule 100.2).
+//!
+//! `validate_deck` covers size, sideboard and copy limits for any format.
+//! `validate_commander_deck` adds the Commander rules: the commanders are
+//! legendary creatures in the deck (903.3), and every card's color identity
+//! is within the commanders' combined color identity (903.5c). Ban lists are
+//! the format's business — see `state::format`.
+
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+
+use crate::objects::card_data::CardData;
+use crate::oracle::color_identity::color_identity;
+use crate::state::game_config::DeckLimits;
+use crate::types::card_types::{CardType, Supertype};
+use crate::types::colors::Color;
+
+/// Check a deck's size, its sideboard's size, and copy counts across both
+/// against `limits`. Basic lands are exempt from copy limits (rule 100.2a).
+pub fn validate_deck(
+    deck: &[Arc<CardData>],
+    sideboard: &[Arc<CardData>],
+    limits: &DeckLimits,
+) -> Result<(), String> {
+    if deck.len() < limits.min_deck_size {
+        return Err(format!(
+            "Deck has {} cards, fewer than the minimum of {}",
+            deck.len(), limits.min_deck_size
+        ));
+    }
+    if let Some(max) = limits.max_deck_size
+        && deck.len() > max
+    {
+        return Err(format!("Deck has {} cards, more than the maximum of {}", deck.len(), max));
+    }
+    if let Some(max) = limits.sideboard_size
+        && sideboard.len() > max
+    {
+        return Err(format!("Sideboard has {} cards, more than the maximum of {}", sideboard.len(), max));
+    }
+    if let Some(max_copies) = limits.max_copies {
+        let mut counts: HashMap<&str, u32> = HashMap::new();
+        let cards = deck.iter().chain(sideboard);
+        for card in cards.filter(|c| !c.supertypes.contains(&Supertype::Basic)) {
+            *counts.entry(card.name.as_str()).or_insert(0) += 1;
+        }
+        let mut over: Vec<_> = counts.into_iter().filter(|&(_, n)| n > max_copies).collect();
+        over.sort();
+        if let Some((name, n)) = over.first() {
+            return Err(format!("Deck has {} copies of '{}', more than {}", n, name, max_copies));
+        }
+    }
+    Ok(())
+}
+
+/// Check a Commander deck (rule 903.5): `commanders` name the deck's
+/// commanders, which are part of the deck. Applies `validate_deck`, then
+/// requires each commander to be a legendary creature (903.3) — or a
+/// legendary planeswalker when `allow_planeswalkers` is set, as in Brawl —
+/// and every card to fit within the commanders' combined color identity
+/// (903.5c).
+pub fn validate_commander_deck(
+    deck: &[Arc<CardData>],
+    commanders: &[&str],
+    limits: &DeckLimits,
+    allow_planeswalkers: bool,
+) -> Result<(), String> {
+    if commanders.is_empty() {
+        return Err("A Commander deck needs a commander".to_string());
+    }
+    validate_deck(deck, &[], limits)?;
+
+    let mut identity: HashSet<Color> = HashSet::new();
+    for &name in commanders {
+        let card = deck.iter()
+            .find(|c| c.name == name)
+            .ok_or_else(|| format!("Commander '{}' isn't in the deck", name))?;
+        let eligible_type = card.types.contains(&CardType::Creature)
+            || (allow_planeswalkers && card.types.contains(&CardType::Planeswalker));
+        if !card.supertypes.contains(&Supertype::Legendary) || !eligible_type {
+            return Err(format!("Commander '{}' isn't a legendary creature", name));
+        }
+        identity.extend(color_identity(card));
+    }
+
+    for card in deck {
+        if !color_identity(card).is_subset(&identity) {
+            return Err(format!(
+                "'{}' is outside the commanders' color identity",
+                card.name
+            ));
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cards::basic_lands;
+    use crate::cards::creatures;
+    use crate::cards::phase5_pre_cards::isamaru_hound_of_konda;
+    use crate::cards::planeswalkers;
+    use crate::state::game_config::GameConfig;
+
+    fn commander_deck() -> Vec<Arc<CardData>> {
+        let mut deck = vec![isamaru_hound_of_konda()];
+        deck.extend((0..99).map(|_| basic_lands::plains()));
+        deck
+    }
+
+    #[test]
+    fn test_commander_deck_valid() {
+        let limits = GameConfig::commander().deck_limits;
+        assert!(validate_commander_deck(&commander_deck(), &["Isamaru, Hound of Konda"], &limits, false).is_ok());
+    }
+
+    #[test]
+    fn test_commander_deck_rejects_off_identity_card() {
+        let limits = GameConfig::commander().deck_limits;
+        let mut deck = commander_deck();
+        deck[1] = creatures::grizzly_bears();
+        let err = validate_commander_deck(&deck, &["Isamaru, Hound of Konda"], &limits, false).unwrap_err();
+        assert!(err.contains("Grizzly Bears"), "{}", err);
+    }
+
+    #[test]
+    fn test_commander_deck_rejects_duplicates_and_wrong_size() {
+        let limits = GameConfig::commander().deck_limits;
+        let mut deck = commander_deck();
+        deck[1] = isamaru_hound_of_konda();
+        assert!(validate_commander_deck(&deck, &["Isamaru, Hound of Konda"], &limits, false).is_err());
+
+        let mut short = commander_deck();
+        short.pop();
+        assert!(validate_commander_deck(&short, &["Isamaru, Hound of Konda"], &limits, false).is_err());
+    }
+
+    #[test]
+    fn test_commander_must_be_legendary_creature_in_deck() {
+        let limits = GameConfig::commander().deck_limits;
+        let mut deck = commander_deck();
+        deck[1] = creatures::savannah_lions();
+        assert!(validate_commander_deck(&deck, &["Savannah Lions"], &limits, false).is_err());
+        assert!(validate_commander_deck(&deck, &["Someone Else"], &limits, false).is_err());
+    }
+
+    #[test]
+    fn test_validate_deck_copy_limit() {
+        let limits = GameConfig::standard().deck_limits;
+        let mut deck: Vec<_> = (0..55).map(|_| basic_lands::forest()).collect();
+        deck.extend((0..5).map(|_| creatures::grizzly_bears()));
+        let err = validate_deck(&deck, &[], &limits).unwrap_err();
+        assert!(err.contains("5 copies of 'Grizzly Bears'"), "{}", err);
+        deck.pop();
+        deck.push(basic_lands::forest());
+        assert!(validate_deck(&deck, &[], &limits).is_ok());
+
+        // Copies in the sideboard count too.
+        assert!(validate_deck(&deck, &[creatures::grizzly_bears()], &limits).is_err());
+    }
+
+    #[test]
+    fn test_validate_deck_sideboard_size() {
+        let limits = GameConfig::standard().deck_limits;
+        let deck: Vec<_> = (0..60).map(|_| basic_lands::forest()).collect();
+        let sideboard: Vec<_> = (0..16).map(|_| basic_lands::island()).collect();
+        assert!(validate_deck(&deck, &sideboard[..15], &limits).is_ok());
+        assert!(validate_deck(&deck, &sideboard, &limits).is_err());
+    }
+
+    #[test]
+    fn test_planeswalker_commander_only_when_allowed() {
+        let limits = GameConfig::commander().deck_limits;
+        let mut deck = vec![planeswalkers::garruk_wildspeaker()];
+        deck.extend((0..99).map(|_| basic_lands::forest()));
+        assert!(validate_commander_deck(&deck, &["Garruk Wildspeaker"], &limits, false).is_err());
+        assert!(validate_commander_deck(&deck, &["Garruk Wildspeaker"], &limits, true).is_ok());
+    }
+}
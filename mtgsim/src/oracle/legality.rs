@@ -5,10 +5,13 @@
 // false positives are harmless (engine rejects via rollback), false negatives
 // are bugs. See `plans/atomic-tests/supplemental-docs/dp-middleware-and-candidate-enumeration.md`.
 
-use crate::oracle::characteristics::{has_keyword, has_summoning_sickness, is_creature};
+use crate::objects::card_data::AbilityDef;
+use crate::oracle::characteristics::{has_keyword, has_summoning_sickness, has_type, is_creature};
 use crate::oracle::mana_helpers::{activatable_abilities, castable_spells};
+use crate::state::battlefield::AttackTarget;
 use crate::state::game_state::{GameState, PhaseType};
 use crate::types::card_types::CardType;
+use crate::types::effects::{Effect, Primitive};
 use crate::types::ids::{ObjectId, PlayerId};
 use crate::types::keywords::KeywordAbility;
 use crate::ui::decision::PriorityAction;
@@ -16,13 +19,64 @@ use crate::ui::decision::PriorityAction;
 /// Check if a creature can attack (not summoning-sick, or has haste).
 /// Rule 702.10b: Haste bypasses summoning sickness for attacking.
 pub fn can_attack(game: &GameState, id: ObjectId) -> bool {
-    if game.battlefield.contains_key(&id) {
+    if game.is_phased_in(id) {
         !has_summoning_sickness(game, id)
     } else {
         false
     }
 }
 
+/// Whether the player could act at sorcery speed right now: active player,
+/// main phase, empty stack (rule 307.1).
+pub fn has_sorcery_timing(game: &GameState, player_id: PlayerId) -> bool {
+    game.is_active_player(player_id)
+        && matches!(game.phase.phase_type, PhaseType::Precombat | PhaseType::Postcombat)
+        && game.stack.is_empty()
+}
+
+/// Check the timing and other activation restrictions an activated ability
+/// carries beyond its costs (rule 602.5b): loyalty abilities (rule 606.3),
+/// sorcery-speed equip and reconfigure (rules 702.6a, 702.151a), and
+/// reconfigure's "activate only if attached" unattach half.
+pub fn can_activate_ability(
+    game: &GameState,
+    player_id: PlayerId,
+    source_id: ObjectId,
+    ability: &AbilityDef,
+) -> Result<(), String> {
+    if ability.is_loyalty_ability() {
+        return can_activate_loyalty_ability(game, player_id, source_id);
+    }
+    if ability.is_sorcery_speed() && !has_sorcery_timing(game, player_id) {
+        return Err("This ability can only be activated at sorcery speed".to_string());
+    }
+    if matches!(ability.effect, Effect::Atom(Primitive::Unattach, _))
+        && game.battlefield.get(&source_id).is_none_or(|e| e.attached_to.is_none())
+    {
+        return Err("This ability can only be activated while attached".to_string());
+    }
+    Ok(())
+}
+
+/// Check the extra activation rules for loyalty abilities (rule 606.3):
+/// only at sorcery speed, and only if none of the permanent's loyalty
+/// abilities has been activated yet this turn.
+pub fn can_activate_loyalty_ability(
+    game: &GameState,
+    player_id: PlayerId,
+    source_id: ObjectId,
+) -> Result<(), String> {
+    if !has_sorcery_timing(game, player_id) {
+        return Err("Loyalty abilities can only be activated at sorcery speed".to_string());
+    }
+    let entry = game.permanent(source_id)
+        .ok_or_else(|| format!("Permanent {} not on battlefield", source_id))?;
+    if entry.loyalty_activated_turn == Some(game.turn_number) {
+        return Err("A loyalty ability of this permanent was already activated this turn".to_string());
+    }
+    Ok(())
+}
+
 /// Get all lands in a player's hand that they can legally play this turn.
 ///
 /// Checks:
@@ -37,7 +91,7 @@ pub fn playable_lands(game: &GameState, player_id: PlayerId) -> Vec<ObjectId> {
     };
 
     // Timing: active player, main phase, empty stack
-    if player_id != game.active_player {
+    if !game.is_active_player(player_id) {
         return Vec::new();
     }
     let is_main = matches!(
@@ -60,7 +114,8 @@ pub fn playable_lands(game: &GameState, player_id: PlayerId) -> Vec<ObjectId> {
                 // play-from-hand legality, which happens before the object is a permanent,
                 // so the layer system has nothing to contribute. Same exemption as
                 // engine/cast.rs -- see "Before Layers" in plans/codebase-state.md.
-                .map(|obj| obj.card_data.types.contains(&CardType::Land))
+                // Either face of a modal DFC counts (rule 712.12).
+                .map(|obj| !obj.land_faces().is_empty())
                 .unwrap_or(false)
         })
         .collect()
@@ -71,7 +126,7 @@ pub fn playable_lands(game: &GameState, player_id: PlayerId) -> Vec<ObjectId> {
 /// Checks per-creature legality (rule 508.1a): on battlefield, is a creature,
 /// controlled by player, untapped, not summoning-sick (or has haste), no defender.
 pub fn legal_attackers(game: &GameState, player_id: PlayerId) -> Vec<ObjectId> {
-    game.battlefield.iter()
+    game.permanents()
         .filter_map(|(id, entry)| {
             if entry.controller != player_id {
                 return None;
@@ -93,13 +148,47 @@ pub fn legal_attackers(game: &GameState, player_id: PlayerId) -> Vec<ObjectId> {
         .collect()
 }
 
+/// Everything the given player's creatures could attack (rule 508.1b):
+/// each opponent, each planeswalker an opponent controls, and each battle an
+/// opponent protects — including a Siege the player controls themselves.
+///
+/// Players come first in seat order, then planeswalkers, then battles, each
+/// sorted by id so the option list is stable across runs.
+pub fn legal_attack_targets(game: &GameState, player_id: PlayerId) -> Vec<AttackTarget> {
+    let mut targets: Vec<AttackTarget> = game.opponents_of(player_id)
+        .into_iter()
+        .map(AttackTarget::Player)
+        .collect();
+
+    let mut walkers: Vec<ObjectId> = game.permanents()
+        .filter(|(id, entry)| {
+            game.is_opponent(player_id, entry.controller)
+                && has_type(game, **id, CardType::Planeswalker)
+        })
+        .map(|(id, _)| *id)
+        .collect();
+    walkers.sort();
+    targets.extend(walkers.into_iter().map(AttackTarget::Planeswalker));
+
+    let mut battles: Vec<ObjectId> = game.permanents()
+        .filter(|(id, entry)| {
+            entry.protector.is_some_and(|p| game.is_opponent(player_id, p))
+                && has_type(game, **id, CardType::Battle)
+        })
+        .map(|(id, _)| *id)
+        .collect();
+    battles.sort();
+    targets.extend(battles.into_iter().map(AttackTarget::Battle));
+    targets
+}
+
 /// Get all creatures controlled by a player that can legally block.
 ///
 /// A creature can block if it's on the battlefield, is a creature, untapped,
 /// and controlled by the defending player. Specific attacker legality
 /// (flying/reach checks) is handled during actual block declarations.
 pub fn legal_blockers(game: &GameState, player_id: PlayerId) -> Vec<ObjectId> {
-    game.battlefield.iter()
+    game.permanents()
         .filter_map(|(id, entry)| {
             if entry.controller != player_id {
                 return None;
@@ -131,6 +220,12 @@ pub fn candidate_priority_actions(game: &GameState, player_id: PlayerId) -> Vec<
         actions.push(PriorityAction::PlayLand(land_id));
     }
 
+    // Face-down permanents to turn face up — overapproximation (affordability
+    // is left to the engine)
+    for permanent_id in game.turnable_face_up(player_id) {
+        actions.push(PriorityAction::TurnFaceUp(permanent_id));
+    }
+
     // Castable spells — overapproximation (affordability is heuristic)
     for (spell_id, _sources) in castable_spells(game, player_id) {
         actions.push(PriorityAction::CastSpell(spell_id));
@@ -173,7 +268,7 @@ pub fn enumerate_legal_selections(
                 selections.push(ResolvedTarget::Player(pid));
             }
             // Creatures and planeswalkers on battlefield
-            for &id in game.battlefield.keys() {
+            for (&id, _) in game.permanents() {
                 if Some(id) == exclude_id {
                     continue;
                 }
@@ -191,9 +286,20 @@ pub fn enumerate_legal_selections(
                 selections.push(ResolvedTarget::Object(id));
             }
         }
+        SelectionFilter::InstantOrSorcerySpell | SelectionFilter::SpellWithSingleTarget => {
+            for &id in &game.stack {
+                if Some(id) == exclude_id {
+                    continue;
+                }
+                let candidate = ResolvedTarget::Object(id);
+                if game.validate_selection(filter, &candidate).is_ok() {
+                    selections.push(candidate);
+                }
+            }
+        }
         // Creature, Permanent(_), or other battlefield-based filters
         _ => {
-            for &id in game.battlefield.keys() {
+            for (&id, _) in game.permanents() {
                 if Some(id) == exclude_id {
                     continue;
                 }
@@ -4,12 +4,19 @@
 // Used by CLI (show affordable spells), Random DP (auto-tap), and future AI.
 // All functions are read-only queries over &GameState.
 
-use crate::objects::card_data::AbilityType;
+use std::collections::HashSet;
+
+use crate::engine::costs::reduce_generic;
+use crate::objects::card_data::{AbilityType, CardData, CardFace};
+use crate::oracle::board::permanents_controlled_by;
+use crate::oracle::characteristics::{get_mana_value, has_type};
 use crate::state::game_state::GameState;
 use crate::types::card_types::CardType;
+use crate::types::costs::{AlternativeCost, Cost};
 use crate::types::effects::EffectRecipient;
 use crate::types::ids::{AbilityId, ObjectId, PlayerId};
 use crate::types::mana::{ManaCost, ManaSymbol, ManaType};
+use crate::types::zones::Zone;
 
 /// A mana source: a permanent with a mana ability that can currently be activated.
 ///
@@ -95,7 +102,7 @@ pub fn find_mana_sources(
 pub fn available_mana_sources(game: &GameState, player_id: PlayerId) -> Vec<ManaSource> {
     let mut sources = Vec::new();
 
-    for (id, entry) in &game.battlefield {
+    for (id, entry) in game.permanents() {
         if entry.controller != player_id {
             continue;
         }
@@ -139,8 +146,13 @@ pub fn available_mana_sources(game: &GameState, player_id: PlayerId) -> Vec<Mana
     sources
 }
 
-/// For each spell in hand that passes timing checks, check if `find_mana_sources`
-/// can cover its cost. Returns spell ID + the mana sources that would need tapping.
+/// For each spell in hand — and each commander in the command zone (rule
+/// 903.8), aftermath half in the graveyard (rule 702.127a) and adventurer
+/// on an adventure in exile (rule 715.4) — that passes timing checks, check
+/// if `find_mana_sources` can cover its cost, commander tax included — or
+/// whether Omniscience means there's none to pay from hand. Returns spell ID + the mana sources that would need tapping. A card with
+/// several castable faces or halves is castable if any of them is (rules
+/// 709.3, 712.12).
 pub fn castable_spells(
     game: &GameState,
     player_id: PlayerId,
@@ -150,67 +162,127 @@ pub fn castable_spells(
         None => return Vec::new(),
     };
 
-    let mut result = Vec::new();
+    let owned_elsewhere = game.command.iter().chain(&game.exile)
+        .filter(|id| game.objects.get(id).is_some_and(|o| o.owner == player_id));
+    player.hand.iter().chain(&player.graveyard).chain(owned_elsewhere)
+        .filter_map(|&card_id| {
+            // Lands are never cast — they're played via the special action
+            // (rule 305.1), so a land face isn't a castable face.
+            let faces = game.faces_castable_from_zone(card_id);
+            faces.into_iter()
+                .find_map(|face| castable_face_sources(game, player_id, card_id, face))
+                .map(|sources| (card_id, sources))
+        })
+        .collect()
+}
 
-    for &card_id in &player.hand {
-        let obj = match game.objects.get(&card_id) {
-            Some(o) => o,
-            None => continue,
-        };
-
-        // Lands are never cast — they're played via the special action (rule 305.1)
-        // PRE-LAYER ZONE: reads printed types on purpose. This is cast-zone /
-        // play-from-hand legality, which happens before the object is a permanent,
-        // so the layer system has nothing to contribute. Same exemption as
-        // engine/cast.rs -- see "Before Layers" in plans/codebase-state.md.
-        if obj.card_data.types.contains(&CardType::Land) {
-            continue;
-        }
+/// The mana sources that would need tapping to cast `card_id` as `face`, or
+/// None if that face can't be cast right now.
+fn castable_face_sources(
+    game: &GameState,
+    player_id: PlayerId,
+    card_id: ObjectId,
+    face: CardFace,
+) -> Option<Vec<ManaSource>> {
+    // PRE-LAYER ZONE: reads printed characteristics on purpose. This is
+    // cast-zone legality, which happens before the object is a permanent,
+    // so the layer system has nothing to contribute. Same exemption as
+    // engine/cast.rs -- see "Before Layers" in plans/codebase-state.md.
+    let data = game.objects.get(&card_id)?.face(face);
 
-        // Must have a spell ability
-        let spell_ability = obj.card_data.abilities.iter()
-            .find(|a| a.ability_type == AbilityType::Spell);
-        if spell_ability.is_none() && !obj.card_data.types.iter().any(|t| t.is_permanent()) {
-            continue;
-        }
+    // Must have a spell ability
+    let spell_ability = data.abilities.iter()
+        .find(|a| a.ability_type == AbilityType::Spell);
+    if spell_ability.is_none() && !data.types.iter().any(|t| t.is_permanent()) {
+        return None;
+    }
 
-        // Timing check (sorcery-speed vs instant)
-        if !passes_timing_check(game, player_id, card_id) {
-            continue;
-        }
+    // Timing check (sorcery-speed vs instant)
+    if !passes_timing_check(game, player_id, card_id, face) {
+        return None;
+    }
 
-        // Target legality check (rule 601.2c): can't cast a spell that
-        // requires targets if no legal target exists.
-        if let Some(ability) = spell_ability {
-            let recipient = spell_recipient(&ability.effect);
-            if let EffectRecipient::Target(ref f, _) | EffectRecipient::Choose(ref f, _) = recipient {
-                if !game.has_any_legal_choice(f, None) {
-                    continue;
-                }
-            }
+    // Target legality check (rule 601.2c): can't cast a spell that
+    // requires targets if no legal target exists.
+    if let Some(ability) = spell_ability {
+        let recipient = spell_recipient(&ability.effect);
+        if let EffectRecipient::Target(ref f, _) | EffectRecipient::Choose(ref f, _) = recipient
+            && !game.has_any_legal_choice(f, None)
+        {
+            return None;
         }
+    }
 
-        // Check mana affordability
-        if let Some(ref mana_cost) = obj.card_data.mana_cost {
-            // Account for mana already floating in the pool
-            let pool = &game.players[player_id].mana_pool;
-            if pool.can_pay(mana_cost) {
-                // Already have enough floating mana, no tapping needed
-                result.push((card_id, Vec::new()));
-            } else {
-                // Color-sensitive subtract pool mana from cost, then check taps
-                let remaining = remaining_cost_after_pool(mana_cost, pool);
-                if let Some(sources) = find_mana_sources(game, player_id, &remaining) {
-                    result.push((card_id, sources));
-                }
-            }
-        } else {
-            // No mana cost (e.g., lands shouldn't have spell abilities, but handle gracefully)
-            result.push((card_id, Vec::new()));
-        }
+    // Omniscience lets a spell be cast from hand without paying its mana
+    // cost (rule 118.9), so there's nothing to tap.
+    if game.objects.get(&card_id)?.zone == Zone::Hand && game.may_cast_from_hand_without_paying(player_id) {
+        return Some(Vec::new());
     }
 
-    result
+    // Check mana affordability
+    if data.mana_cost.is_none() {
+        // No mana cost (e.g., lands shouldn't have spell abilities, but handle gracefully)
+        return Some(Vec::new());
+    }
+    let mana_cost = game.mana_cost_to_cast(card_id, face).ok()?;
+    if let Some(sources) = affordable_sources(game, player_id, data, &mana_cost) {
+        return Some(sources);
+    }
+
+    // Emerge: the alternative cost, reduced by the mana value of the
+    // creature sacrificed for it (rule 702.119a) — at best the largest.
+    let largest_creature = permanents_controlled_by(game, player_id).into_iter()
+        .filter(|&id| has_type(game, id, CardType::Creature))
+        .map(|id| get_mana_value(game, id) as usize)
+        .max()?;
+    data.alternative_costs.iter()
+        .filter_map(|alt| match alt {
+            AlternativeCost::Emerge(costs) => costs.iter().find_map(|c| match c {
+                Cost::Mana(mana) => Some(reduce_generic(mana, largest_creature)),
+                _ => None,
+            }),
+            _ => None,
+        })
+        .find_map(|cost| affordable_sources(game, player_id, data, &cost))
+}
+
+/// The mana sources that would need tapping to pay `mana_cost` for a spell
+/// with `data`, counting mana already floating in the pool and whatever
+/// its convoke, improvise or delve could pay for; None if it can't be paid.
+fn affordable_sources(
+    game: &GameState,
+    player_id: PlayerId,
+    data: &CardData,
+    mana_cost: &ManaCost,
+) -> Option<Vec<ManaSource>> {
+    if let Some(sources) = sources_to_pay(game, player_id, mana_cost) {
+        return Some(sources);
+    }
+    let remaining = remaining_cost_after_pool(mana_cost, &game.players[player_id].mana_pool);
+    // Permanents with mana abilities are kept back to tap for mana; the
+    // rest can be convoked or improvised.
+    let reserved: HashSet<ObjectId> = available_mana_sources(game, player_id).iter()
+        .map(|source| source.permanent_id)
+        .collect();
+    let reduced = game.cost_after_mana_substitutes(player_id, &data.keywords, &remaining, &reserved);
+    if reduced == remaining {
+        return None;
+    }
+    find_mana_sources(game, player_id, &reduced)
+}
+
+/// The mana sources that would need tapping to pay `mana_cost`, counting
+/// mana already floating in the pool, or None if it can't be paid.
+pub fn sources_to_pay(game: &GameState, player_id: PlayerId, mana_cost: &ManaCost) -> Option<Vec<ManaSource>> {
+    // Account for mana already floating in the pool
+    let pool = &game.players[player_id].mana_pool;
+    if pool.can_pay(mana_cost) {
+        // Already have enough floating mana, no tapping needed
+        return Some(Vec::new());
+    }
+    // Color-sensitive subtract pool mana from cost, then check taps
+    let remaining = remaining_cost_after_pool(mana_cost, pool);
+    find_mana_sources(game, player_id, &remaining)
 }
 
 /// Enumerate currently-activatable mana abilities for a player.
@@ -298,9 +370,10 @@ pub(crate) fn remaining_cost_after_pool(
     ManaCost::from_symbols(remaining_symbols)
 }
 
-/// Check if a card in hand passes the timing check for casting.
+/// Check if a card that can be cast as `face` from where it is passes the
+/// timing check for casting it that way.
 /// Mirrors the logic in `check_cast_legality` but as a read-only query.
-fn passes_timing_check(game: &GameState, player_id: PlayerId, card_id: ObjectId) -> bool {
+fn passes_timing_check(game: &GameState, player_id: PlayerId, card_id: ObjectId, face: CardFace) -> bool {
     let obj = match game.objects.get(&card_id) {
         Some(o) => o,
         None => return false,
@@ -311,8 +384,9 @@ fn passes_timing_check(game: &GameState, player_id: PlayerId, card_id: ObjectId)
         return false;
     }
 
-    // Must be in hand
-    if obj.zone != crate::types::zones::Zone::Hand {
+    // Must be in hand, be a commander in the command zone (rule 903.8), or
+    // be a half that can be cast from where it is (rules 702.127a, 715.4)
+    if !game.faces_castable_from_zone(card_id).contains(&face) {
         return false;
     }
 
@@ -320,15 +394,16 @@ fn passes_timing_check(game: &GameState, player_id: PlayerId, card_id: ObjectId)
     // play-from-hand legality, which happens before the object is a permanent,
     // so the layer system has nothing to contribute. Same exemption as
     // engine/cast.rs -- see "Before Layers" in plans/codebase-state.md.
-    let is_instant = obj.card_data.types.contains(&crate::types::card_types::CardType::Instant);
-    let has_flash = obj.card_data.keywords.contains(&crate::types::keywords::KeywordAbility::Flash);
+    let data = obj.face(face);
+    let is_instant = data.types.contains(&CardType::Instant);
+    let has_flash = data.keywords.contains(&crate::types::keywords::KeywordAbility::Flash);
 
     if is_instant || has_flash {
         return true; // can cast anytime with priority
     }
 
     // Sorcery-speed: active player, main phase, empty stack
-    if player_id != game.active_player {
+    if !game.is_active_player(player_id) {
         return false;
     }
     let is_main = matches!(
@@ -356,7 +431,7 @@ pub fn activatable_abilities(
 ) -> Vec<(ObjectId, usize, AbilityId)> {
     let mut result = Vec::new();
 
-    for (id, entry) in &game.battlefield {
+    for (id, entry) in game.permanents() {
         if entry.controller != player_id {
             continue;
         }
@@ -371,6 +446,12 @@ pub fn activatable_abilities(
                 continue;
             }
 
+            // Timing and other activation restrictions (loyalty, equip,
+            // reconfigure).
+            if crate::oracle::legality::can_activate_ability(game, player_id, *id, ability).is_err() {
+                continue;
+            }
+
             // Single-pass check: non-mana costs via engine, mana costs via
             // pool + available sources. No double-check.
             if !can_afford_ability_costs(game, player_id, *id, &ability.costs) {
@@ -413,7 +494,7 @@ fn can_afford_ability_costs(
                 }
             }
             other => {
-                if game.can_pay_costs(&[other.clone()], player_id, source_id).is_err() {
+                if game.can_pay_costs(std::slice::from_ref(other), player_id, source_id).is_err() {
                     return false;
                 }
             }
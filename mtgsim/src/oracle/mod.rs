@@ -2,3 +2,5 @@ pub mod characteristics;
 pub mod legality;
 pub mod board;
 pub mod mana_helpers;
+pub mod color_identity;
+pub mod deck_validation;
@@ -1,4 +1,5 @@
 use std::collections::HashMap;
+use crate::objects::card_data::FaceDownKind;
 use crate::types::ids::{ObjectId, PlayerId};
 use crate::types::effects::CounterType;
 
@@ -20,8 +21,19 @@ pub struct BattlefieldEntity {
     // Permanent state
     pub tapped: bool,
     pub flipped: bool,
-    pub face_down: bool,
+    /// Face down (rule 708), and how it got that way. Read through
+    /// `GameState::face_up`.
+    pub face_down: Option<FaceDownKind>,
+    /// Back face up (rule 712.8e): a transforming DFC that has transformed,
+    /// or a modal DFC that was cast or played as its back face. Read through
+    /// `GameState::face_up`.
+    pub transformed: bool,
+    /// Phased out (rule 702.26b): still on the battlefield, but treated as
+    /// though it doesn't exist. Read through `GameState::permanents`.
     pub phased_out: bool,
+    /// Phased out along with the permanent it's attached to rather than on
+    /// its own (rule 702.26g). Such a permanent phases in only with its host.
+    pub phased_out_indirectly: bool,
     /// The turn number when this permanent entered the battlefield.
     pub entered_battlefield_turn: u32,
     /// The turn number when the current controller gained control.
@@ -36,6 +48,9 @@ pub struct BattlefieldEntity {
     /// Checked in SBA 704.5g: any nonzero damage from deathtouch is lethal.
     /// Cleared in cleanup alongside damage_marked.
     pub damaged_by_deathtouch: bool,
+    /// Regeneration shields created this turn (rule 701.19a). Each one
+    /// replaces the next destruction of this permanent. Cleared in cleanup.
+    pub regeneration_shields: u32,
 
     // Combat state (transient, cleared at end of combat)
     pub attacking: Option<AttackingInfo>,
@@ -53,6 +68,14 @@ pub struct BattlefieldEntity {
     pub attached_to: Option<ObjectId>,
     /// Permanents attached to this one (Auras, Equipment, Fortifications targeting this).
     pub attached_by: Vec<ObjectId>,
+
+    /// The turn on which a loyalty ability of this permanent was last
+    /// activated (rule 606.3: once per turn). None if never activated.
+    pub loyalty_activated_turn: Option<u32>,
+
+    /// The player protecting this battle (rule 310.8). None for non-battles,
+    /// or for a battle whose protector has left the game (SBA 704.5w).
+    pub protector: Option<PlayerId>,
 }
 
 #[derive(Debug, Clone)]
@@ -67,7 +90,7 @@ pub struct BlockingInfo {
     pub blocking: Vec<ObjectId>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum AttackTarget {
     Player(PlayerId),
     Planeswalker(ObjectId),
@@ -82,18 +105,23 @@ impl BattlefieldEntity {
             timestamp,
             tapped: false,
             flipped: false,
-            face_down: false,
+            face_down: None,
+            transformed: false,
             phased_out: false,
+            phased_out_indirectly: false,
             entered_battlefield_turn: current_turn,
             controller_since_turn: current_turn,
             damage_marked: 0,
             damaged_by_deathtouch: false,
+            regeneration_shields: 0,
             attacking: None,
             blocking: None,
             counters: HashMap::new(),
             x_value: None,
             attached_to: None,
             attached_by: Vec::new(),
+            loyalty_activated_turn: None,
+            protector: None,
         }
     }
 
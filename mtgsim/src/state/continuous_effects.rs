@@ -3,8 +3,8 @@
 //! This is the data owner — lives on GameState. The computation logic
 //! lives in `engine/layers/compute.rs`.
 
-use crate::engine::layers::types::{ContinuousEffect, EffectId, Layer, Timestamp};
-use crate::types::ids::ObjectId;
+use crate::engine::layers::types::{ContinuousEffect, EffectId, EffectOrigin, Layer, Timestamp};
+use crate::types::ids::{ObjectId, PlayerId};
 
 /// Cheap, registry-wide facts that let `compute_characteristics` skip work it
 /// would otherwise have to do per object per layer.
@@ -95,6 +95,21 @@ impl ContinuousEffectRegistry {
         self.retain_effects(|e| e.source != source)
     }
 
+    /// Remove the effects generated by a source's static abilities, keeping
+    /// those its spells and abilities created on resolution. Used when a
+    /// permanent transforms and its static abilities change (rule 712.8).
+    pub fn remove_static_by_source(&mut self, source: ObjectId) -> Vec<ContinuousEffect> {
+        self.retain_effects(|e| {
+            e.source != source || !matches!(e.origin, EffectOrigin::StaticAbility { .. })
+        })
+    }
+
+    /// Remove all effects controlled by a given player.
+    /// Used when that player leaves a multiplayer game (rule 800.4a).
+    pub fn remove_by_controller(&mut self, controller: PlayerId) -> Vec<ContinuousEffect> {
+        self.retain_effects(|e| e.controller != controller)
+    }
+
     /// All effects in a layer, already in application order (CR 613.7).
     ///
     /// No sorting happens here. `effects` is *maintained* in
@@ -274,6 +289,19 @@ mod tests {
         assert_eq!(reg.iter().next().unwrap().source, src_b);
     }
 
+    #[test]
+    fn test_remove_by_controller() {
+        let mut reg = ContinuousEffectRegistry::new();
+        let src = Uuid::new_v4();
+        reg.add(make_effect(src, Layer::Layer7cModifyPT, 1));
+        reg.add(ContinuousEffect { controller: 2, ..make_effect(src, Layer::Layer6Ability, 2) });
+
+        let removed = reg.remove_by_controller(2);
+        assert_eq!(removed.len(), 1);
+        assert_eq!(removed[0].controller, 2);
+        assert_eq!(reg.len(), 1);
+    }
+
     // COVERS-PARTIAL: ATOM-613.7-001
     #[test]
     fn test_effects_in_layer_sorted_by_timestamp() {
@@ -0,0 +1,429 @@
+//! Formats (rule 100.6): a format supplies the `GameConfig` for a game,
+//! decides which decks are legal, and may hook game setup and how the game
+//! is won.
+//!
+//! `Format` implementors carry their `GameConfig` and `BanList` as public
+//! fields, so a variant of a shipped format (a different starting life, an
+//! updated ban list) is a struct update away. The ban lists here are
+//! well-known staples of each format, not a maintained copy of the official
+//! lists — replace `bans` for tournament use.
+//!
+//! `Game::with_format` is the entry point: it validates every submitted deck,
+//! builds the game from `config()`, runs `setup_game`, and keeps the format
+//! so `Game::check_game_over` can consult `check_game_over` first.
+
+use std::collections::{HashMap, HashSet};
+
+use crate::oracle::deck_validation::{validate_commander_deck, validate_deck};
+use crate::state::game::{Decklist, GameResult};
+use crate::state::game_config::GameConfig;
+use crate::state::game_state::GameState;
+
+/// One player's submission: main deck, sideboard, and — in Commander
+/// formats — the names of their commanders, which are part of the main deck.
+#[derive(Debug, Clone, Default)]
+pub struct DeckSubmission {
+    pub main: Decklist,
+    pub sideboard: Decklist,
+    pub commanders: Vec<String>,
+}
+
+impl DeckSubmission {
+    pub fn new(main: Decklist) -> Self {
+        DeckSubmission { main, ..Default::default() }
+    }
+
+    pub fn with_sideboard(mut self, sideboard: Decklist) -> Self {
+        self.sideboard = sideboard;
+        self
+    }
+
+    pub fn with_commanders(mut self, names: &[&str]) -> Self {
+        self.commanders = names.iter().map(|n| n.to_string()).collect();
+        self
+    }
+}
+
+/// Banned and restricted cards, keyed by card name (rule 100.6). A banned
+/// card can't be in the deck or sideboard at all; a restricted card may
+/// appear at most once across both. Basic lands aren't exempt.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BanList {
+    pub banned: HashSet<String>,
+    pub restricted: HashSet<String>,
+}
+
+impl BanList {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn ban(mut self, names: &[&str]) -> Self {
+        self.banned.extend(names.iter().map(|n| n.to_string()));
+        self
+    }
+
+    pub fn restrict(mut self, names: &[&str]) -> Self {
+        self.restricted.extend(names.iter().map(|n| n.to_string()));
+        self
+    }
+
+    /// Check every card in `deck` against the lists.
+    pub fn check(&self, deck: &DeckSubmission) -> Result<(), String> {
+        let mut counts: HashMap<&str, u32> = HashMap::new();
+        for card in deck.main.iter().chain(&deck.sideboard) {
+            if self.banned.contains(&card.name) {
+                return Err(format!("'{}' is banned", card.name));
+            }
+            *counts.entry(card.name.as_str()).or_insert(0) += 1;
+        }
+        let mut over: Vec<_> = counts.into_iter()
+            .filter(|&(name, n)| n > 1 && self.restricted.contains(name))
+            .collect();
+        over.sort();
+        if let Some((name, n)) = over.first() {
+            return Err(format!("'{}' is restricted, but the deck has {} copies", name, n));
+        }
+        Ok(())
+    }
+}
+
+/// A format: configuration plus the rules that vary between formats.
+///
+/// Only `name`, `config` and `ban_list` are required; the defaults give a
+/// format whose decks are checked against `DeckLimits` and the ban list,
+/// with no setup or win-condition changes.
+pub trait Format {
+    fn name(&self) -> &str;
+
+    fn config(&self) -> &GameConfig;
+
+    fn ban_list(&self) -> &BanList;
+
+    /// Whether a submitted deck is legal in this format (rule 100.2): deck
+    /// and sideboard sizes and copy limits from `config().deck_limits`
+    /// (basic lands exempt), then the ban list.
+    fn validate_decklist(&self, deck: &DeckSubmission) -> Result<(), String> {
+        validate_deck(&deck.main, &deck.sideboard, &self.config().deck_limits)?;
+        self.ban_list().check(deck)
+    }
+
+    /// Adjust the freshly built game before it starts — `decks[i]` is player
+    /// i's submission, whose main deck is already in their library.
+    fn setup_game(&self, _state: &mut GameState, _decks: &[DeckSubmission]) -> Result<(), String> {
+        Ok(())
+    }
+
+    /// A format-specific game result, checked before the standard one
+    /// (rule 104.2d — some formats add their own ways to win). `None`
+    /// defers to `Game::check_game_over`'s loss-flag logic.
+    fn check_game_over(&self, _state: &GameState) -> Option<GameResult> {
+        None
+    }
+}
+
+// --- Shipped formats ---
+
+/// Sixty-card constructed play: Standard and the other non-rotating
+/// formats differ only in their ban and restricted lists.
+#[derive(Debug, Clone)]
+pub struct Constructed {
+    pub name: String,
+    pub config: GameConfig,
+    pub bans: BanList,
+}
+
+impl Constructed {
+    /// Standard-like constructed with an empty ban list.
+    pub fn standard() -> Self {
+        Constructed {
+            name: "Standard".to_string(),
+            config: GameConfig::standard(),
+            bans: BanList::new(),
+        }
+    }
+
+    /// Vintage: the Power Nine and friends are restricted, not banned.
+    pub fn vintage() -> Self {
+        Constructed {
+            name: "Vintage".to_string(),
+            config: GameConfig::standard(),
+            bans: BanList::new()
+                .ban(&["Chaos Orb", "Falling Star", "Shahrazad"])
+                .restrict(&[
+                    "Ancestral Recall", "Black Lotus", "Time Walk", "Timetwister",
+                    "Mox Pearl", "Mox Sapphire", "Mox Jet", "Mox Ruby", "Mox Emerald",
+                    "Sol Ring", "Mana Crypt",
+                ]),
+        }
+    }
+}
+
+impl Format for Constructed {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn config(&self) -> &GameConfig {
+        &self.config
+    }
+
+    fn ban_list(&self) -> &BanList {
+        &self.bans
+    }
+}
+
+/// Draft and sealed: 40-card minimum, any number of copies, no bans.
+#[derive(Debug, Clone)]
+pub struct Limited {
+    pub config: GameConfig,
+    pub bans: BanList,
+}
+
+impl Limited {
+    pub fn new() -> Self {
+        Limited { config: GameConfig::limited(), bans: BanList::new() }
+    }
+}
+
+impl Default for Limited {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Format for Limited {
+    fn name(&self) -> &str {
+        "Limited"
+    }
+
+    fn config(&self) -> &GameConfig {
+        &self.config
+    }
+
+    fn ban_list(&self) -> &BanList {
+        &self.bans
+    }
+}
+
+/// Pauper: sixty-card constructed with commons only. Card rarity isn't
+/// modelled, so the commons-only rule is left to whoever assembles the card
+/// pool; the ban list is enforced.
+#[derive(Debug, Clone)]
+pub struct Pauper {
+    pub config: GameConfig,
+    pub bans: BanList,
+}
+
+impl Pauper {
+    pub fn new() -> Self {
+        Pauper {
+            config: GameConfig::standard(),
+            bans: BanList::new().ban(&[
+                "Cloud of Faeries", "Daze", "Empty the Warrens", "Frantic Search",
+                "Grapeshot", "Gush", "High Tide", "Hymn to Tourach",
+                "Peregrine Drake", "Treasure Cruise",
+            ]),
+        }
+    }
+}
+
+impl Default for Pauper {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Format for Pauper {
+    fn name(&self) -> &str {
+        "Pauper"
+    }
+
+    fn config(&self) -> &GameConfig {
+        &self.config
+    }
+
+    fn ban_list(&self) -> &BanList {
+        &self.bans
+    }
+}
+
+/// Commander (rule 903): a legendary creature commander, a singleton deck
+/// within its color identity, and the commander starting in the command
+/// zone.
+#[derive(Debug, Clone)]
+pub struct Commander {
+    pub config: GameConfig,
+    pub bans: BanList,
+}
+
+impl Commander {
+    pub fn new() -> Self {
+        Commander {
+            config: GameConfig::commander(),
+            bans: BanList::new().ban(&[
+                "Ancestral Recall", "Black Lotus", "Time Walk", "Timetwister",
+                "Mox Pearl", "Mox Sapphire", "Mox Jet", "Mox Ruby", "Mox Emerald",
+                "Channel", "Fastbond", "Library of Alexandria", "Limited Resources",
+                "Tinker", "Upheaval",
+            ]),
+        }
+    }
+}
+
+impl Default for Commander {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Format for Commander {
+    fn name(&self) -> &str {
+        "Commander"
+    }
+
+    fn config(&self) -> &GameConfig {
+        &self.config
+    }
+
+    fn ban_list(&self) -> &BanList {
+        &self.bans
+    }
+
+    fn validate_decklist(&self, deck: &DeckSubmission) -> Result<(), String> {
+        validate_commander_deck(&deck.main, &commander_names(deck), &self.config.deck_limits, false)?;
+        self.bans.check(deck)
+    }
+
+    fn setup_game(&self, state: &mut GameState, decks: &[DeckSubmission]) -> Result<(), String> {
+        designate_commanders(state, decks)
+    }
+}
+
+/// Brawl: Commander with a 60-card deck whose commander may be a legendary
+/// planeswalker. Standard legality of the card pool isn't checked.
+#[derive(Debug, Clone)]
+pub struct Brawl {
+    pub config: GameConfig,
+    pub bans: BanList,
+}
+
+impl Brawl {
+    pub fn new() -> Self {
+        Brawl { config: GameConfig::brawl(), bans: BanList::new() }
+    }
+}
+
+impl Default for Brawl {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Format for Brawl {
+    fn name(&self) -> &str {
+        "Brawl"
+    }
+
+    fn config(&self) -> &GameConfig {
+        &self.config
+    }
+
+    fn ban_list(&self) -> &BanList {
+        &self.bans
+    }
+
+    fn validate_decklist(&self, deck: &DeckSubmission) -> Result<(), String> {
+        validate_commander_deck(&deck.main, &commander_names(deck), &self.config.deck_limits, true)?;
+        self.bans.check(deck)
+    }
+
+    fn setup_game(&self, state: &mut GameState, decks: &[DeckSubmission]) -> Result<(), String> {
+        designate_commanders(state, decks)
+    }
+}
+
+fn commander_names(deck: &DeckSubmission) -> Vec<&str> {
+    deck.commanders.iter().map(String::as_str).collect()
+}
+
+/// Move each player's named commanders from their library to the command
+/// zone (rule 903.6).
+fn designate_commanders(state: &mut GameState, decks: &[DeckSubmission]) -> Result<(), String> {
+    for (player_id, deck) in decks.iter().enumerate() {
+        for name in &deck.commanders {
+            state.designate_commander_by_name(player_id, name)?;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cards::{alpha, basic_lands, creatures};
+    use crate::cards::phase5_pre_cards::isamaru_hound_of_konda;
+
+    fn forests(n: usize) -> Decklist {
+        (0..n).map(|_| basic_lands::forest()).collect()
+    }
+
+    #[test]
+    fn test_constructed_checks_limits_and_bans() {
+        let standard = Constructed::standard();
+        assert!(standard.validate_decklist(&DeckSubmission::new(forests(60))).is_ok());
+        assert!(standard.validate_decklist(&DeckSubmission::new(forests(59))).is_err());
+
+        let mut deck = forests(59);
+        deck.push(alpha::ancestral_recall());
+        let banned = Constructed {
+            bans: BanList::new().ban(&["Ancestral Recall"]),
+            ..Constructed::standard()
+        };
+        let err = banned.validate_decklist(&DeckSubmission::new(deck)).unwrap_err();
+        assert!(err.contains("banned"), "{}", err);
+    }
+
+    #[test]
+    fn test_vintage_restricted_allows_one_copy() {
+        let vintage = Constructed::vintage();
+        let mut deck = forests(59);
+        deck.push(alpha::ancestral_recall());
+        assert!(vintage.validate_decklist(&DeckSubmission::new(deck.clone())).is_ok());
+
+        let with_sideboard = DeckSubmission::new(deck).with_sideboard(vec![alpha::ancestral_recall()]);
+        let err = vintage.validate_decklist(&with_sideboard).unwrap_err();
+        assert!(err.contains("restricted"), "{}", err);
+    }
+
+    #[test]
+    fn test_limited_allows_any_number_of_copies() {
+        let mut deck = forests(20);
+        deck.extend((0..20).map(|_| creatures::grizzly_bears()));
+        assert!(Limited::new().validate_decklist(&DeckSubmission::new(deck)).is_ok());
+        assert!(Limited::new().validate_decklist(&DeckSubmission::new(forests(39))).is_err());
+    }
+
+    #[test]
+    fn test_commander_requires_commander_and_identity() {
+        let mut main = vec![isamaru_hound_of_konda()];
+        main.extend((0..99).map(|_| basic_lands::plains()));
+        let deck = DeckSubmission::new(main.clone());
+        assert!(Commander::new().validate_decklist(&deck).is_err());
+        let deck = deck.with_commanders(&["Isamaru, Hound of Konda"]);
+        assert!(Commander::new().validate_decklist(&deck).is_ok());
+
+        main[1] = alpha::ancestral_recall();
+        let deck = DeckSubmission::new(main).with_commanders(&["Isamaru, Hound of Konda"]);
+        assert!(Commander::new().validate_decklist(&deck).is_err());
+    }
+
+    #[test]
+    fn test_brawl_sixty_card_singleton() {
+        let mut main = vec![isamaru_hound_of_konda()];
+        main.extend((0..59).map(|_| basic_lands::plains()));
+        let deck = DeckSubmission::new(main).with_commanders(&["Isamaru, Hound of Konda"]);
+        assert!(Brawl::new().validate_decklist(&deck).is_ok());
+        assert!(Commander::new().validate_decklist(&deck).is_err());
+    }
+}
@@ -2,11 +2,15 @@ use std::sync::Arc;
 
 use crate::objects::card_data::CardData;
 use crate::objects::object::GameObject;
-use crate::state::game_config::GameConfig;
+use crate::oracle::deck_validation::validate_deck;
+use crate::state::format::{Commander, DeckSubmission, Format};
+use crate::state::game_config::{GameConfig, MulliganRule};
 use crate::state::game_state::{GameState, PhaseType, StepType};
-use crate::types::ids::PlayerId;
+use crate::types::ids::{ObjectId, PlayerId};
 use crate::types::zones::Zone;
-use crate::ui::ask::ask_choose_discard;
+use crate::ui::ask::{
+    ask_bottom_cards, ask_choose_discard, ask_mulligan, ask_opening_hand_actions, ask_scry,
+};
 use crate::ui::decision::DecisionProvider;
 
 /// A decklist: ordered list of card definitions that make up a player's deck.
@@ -16,6 +20,8 @@ pub type Decklist = Vec<Arc<CardData>>;
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum GameResult {
     Winner(PlayerId),
+    /// A team game was won by the team with these players (rule 810.8).
+    TeamWinner(Vec<PlayerId>),
     Draw,
 }
 
@@ -32,6 +38,8 @@ pub struct Game {
     pub state: GameState,
     pub config: GameConfig,
     pub result: Option<GameResult>,
+    /// The format the game was created with, if any (see `with_format`).
+    pub format: Option<Box<dyn Format>>,
 }
 
 impl Game {
@@ -45,16 +53,24 @@ impl Game {
 
     /// Create a new game from config and decklists.
     ///
-    /// Builds a `GameState` with the configured starting life and populates
-    /// each player's library from their decklist. Does NOT shuffle or draw
-    /// opening hands — that happens in `setup()`.
+    /// Rejects any decklist that breaks the config's `DeckLimits` (rule
+    /// 100.2), then builds a `GameState` with the configured starting life
+    /// and populates each player's library from their decklist. Does NOT
+    /// shuffle or draw opening hands — that happens in `setup()`.
     pub fn new(config: GameConfig, decklists: Vec<Decklist>) -> Result<Self, String> {
         let num_players = decklists.len();
         if num_players < 2 {
             return Err("Game requires at least 2 players".to_string());
         }
+        for (player_id, deck) in decklists.iter().enumerate() {
+            validate_deck(deck, &[], &config.deck_limits)
+                .map_err(|e| format!("Player {}: {}", player_id, e))?;
+        }
 
         let mut state = GameState::new(num_players, config.starting_life);
+        if let Some(team_size) = config.team_size {
+            state.set_teams(team_size)?;
+        }
 
         // Populate libraries from decklists
         for (player_id, decklist) in decklists.into_iter().enumerate() {
@@ -80,15 +96,56 @@ impl Game {
             state,
             config,
             result: None,
+            format: None,
         })
     }
 
-    /// Perform game setup: shuffle libraries and draw opening hands.
+    /// Create a game in `format`: validates each submission with the
+    /// format's `validate_decklist`, builds the game from its config and the
+    /// main decks, then runs its `setup_game` hook. The format is kept so
+    /// its win-condition override applies (see `check_game_over`).
+    pub fn with_format(format: Box<dyn Format>, decks: Vec<DeckSubmission>) -> Result<Self, String> {
+        for (player_id, deck) in decks.iter().enumerate() {
+            format.validate_decklist(deck)
+                .map_err(|e| format!("Player {}: {}", player_id, e))?;
+        }
+        let decklists = decks.iter().map(|d| d.main.clone()).collect();
+        let mut game = Self::new(format.config().clone(), decklists)?;
+        format.setup_game(&mut game.state, &decks)?;
+        game.format = Some(format);
+        Ok(game)
+    }
+
+    /// Create a Commander game (rule 903) with `config`. `commanders[i]`
+    /// names player i's commanders, which are part of their decklist.
     ///
-    /// Mulligan handling is stubbed — players always keep their first hand.
-    /// Full London mulligan support requires multiple `DecisionProvider`
-    /// calls per player and will be implemented when needed.
-    pub fn setup(&mut self, _decisions: &dyn DecisionProvider) -> Result<(), String> {
+    /// Shorthand for `with_format` with the `Commander` format: decks are
+    /// checked against the config's limits, the commanders' color identity
+    /// (903.5) and the Commander ban list, and the commanders start in the
+    /// command zone (903.6).
+    pub fn new_commander(
+        config: GameConfig,
+        decklists: Vec<Decklist>,
+        commanders: Vec<Vec<&str>>,
+    ) -> Result<Self, String> {
+        if commanders.len() != decklists.len() {
+            return Err(format!(
+                "{} decklists but {} commander lists",
+                decklists.len(), commanders.len()
+            ));
+        }
+        let decks = decklists.into_iter()
+            .zip(&commanders)
+            .map(|(main, names)| DeckSubmission::new(main).with_commanders(names))
+            .collect();
+        Self::with_format(Box::new(Commander { config, ..Commander::new() }), decks)
+    }
+
+    /// Perform game setup: shuffle libraries, draw opening hands, take
+    /// mulligans under the config's `MulliganRule` (see `take_mulligans`),
+    /// then the pregame actions opening hands allow (see
+    /// `take_pregame_actions`).
+    pub fn setup(&mut self, decisions: &dyn DecisionProvider) -> Result<(), String> {
         // Shuffle each player's library
         for player in &mut self.state.players {
             Self::shuffle_library(&mut player.library);
@@ -103,11 +160,97 @@ impl Game {
             }
         }
 
-        // TODO: mulligan decisions (London mulligan)
-        // For each player in turn order:
-        //   ask decisions.choose_mulligan(&self.state, player_id)
-        //   if mulligan: shuffle hand into library, draw 7, bottom N
+        self.take_mulligans(decisions)?;
+        self.take_pregame_actions(decisions)
+    }
+
+    /// Pregame actions (rule 103.6): starting with the starting player and
+    /// going in turn order, each player chooses which of their opening
+    /// hand's actions to take — Leylines, Chancellor reveals — and takes
+    /// them in the order chosen.
+    fn take_pregame_actions(&mut self, decisions: &dyn DecisionProvider) -> Result<(), String> {
+        for pid in self.state.apnap_order() {
+            let candidates = self.state.opening_hand_candidates(pid);
+            for card_id in ask_opening_hand_actions(decisions, &self.state, pid, &candidates) {
+                self.state.take_opening_hand_action(card_id)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Mulligans (rule 103.5). Starting with the starting player and going
+    /// in turn order, each player who hasn't kept declares whether they
+    /// mulligan; then everyone who did shuffles their hand into their
+    /// library and draws a new hand at the same time, and only they decide
+    /// again.
+    ///
+    /// Under London each new hand is a full hand, and once everyone has
+    /// kept, each player puts a card on the bottom of their library for
+    /// every mulligan they took. Under Paris and Vancouver each new hand is
+    /// one card smaller; Vancouver then lets each player who kept fewer than
+    /// a full hand scry 1. In a multiplayer game, or with
+    /// `free_first_mulligan` set, a player's first mulligan doesn't count
+    /// toward any of that (rule 103.5c).
+    fn take_mulligans(&mut self, decisions: &dyn DecisionProvider) -> Result<(), String> {
+        let rule = self.config.mulligan_rule;
+        if rule == MulliganRule::None {
+            return Ok(());
+        }
+        let full_hand = self.config.starting_hand_size;
+        let free_first = self.config.free_first_mulligan || self.state.num_players() > 2;
+        let counted = |taken: u32| {
+            let n = if free_first { taken.saturating_sub(1) } else { taken };
+            n as usize
+        };
+
+        let order = self.state.apnap_order();
+        let mut taken = vec![0u32; self.state.num_players()];
+        let mut deciding = order.clone();
+        while !deciding.is_empty() {
+            let mut mulligans = Vec::new();
+            for &pid in &deciding {
+                // Nothing left to mulligan down to.
+                if counted(taken[pid]) >= full_hand {
+                    continue;
+                }
+                if ask_mulligan(decisions, &self.state, pid, taken[pid]) {
+                    mulligans.push(pid);
+                }
+            }
+            for &pid in &mulligans {
+                taken[pid] += 1;
+                for id in self.state.players[pid].hand.clone() {
+                    self.state.change_zone(id, Zone::Library)?;
+                }
+                Self::shuffle_library(&mut self.state.players[pid].library);
+                let new_hand = match rule {
+                    MulliganRule::London => full_hand,
+                    _ => full_hand - counted(taken[pid]),
+                };
+                self.state.draw_cards(pid, new_hand as u64)?;
+            }
+            deciding = mulligans;
+        }
 
+        for &pid in &order {
+            match rule {
+                MulliganRule::London => {
+                    let hand = self.state.players[pid].hand.clone();
+                    let bottom = ask_bottom_cards(decisions, &self.state, pid, &hand, counted(taken[pid]));
+                    for id in bottom {
+                        self.state.put_on_bottom_of_library(id)?;
+                    }
+                }
+                MulliganRule::Vancouver if self.state.players[pid].hand.len() < full_hand => {
+                    let top: Vec<ObjectId> = self.state.players[pid].library.last().copied().into_iter().collect();
+                    let (_, bottom) = ask_scry(decisions, &self.state, pid, &top);
+                    for id in bottom {
+                        self.state.put_on_bottom_of_library(id)?;
+                    }
+                }
+                _ => {}
+            }
+        }
         Ok(())
     }
 
@@ -117,7 +260,8 @@ impl Game {
     /// 1. Turn-based actions (combat declarations, damage, cleanup discard)
     /// 2. Priority round (if the step grants priority)
     /// 3. Game-over check
-    /// 4. Advance to next step/phase
+    /// 4. Advance to the next step/phase of the turn plan — effects may have
+    ///    added phases, skipped steps or ended the turn (`GameState::turn_plan`)
     pub fn run_turn(&mut self, decisions: &dyn DecisionProvider) -> Result<(), String> {
         let starting_turn = self.state.turn_number;
 
@@ -132,17 +276,18 @@ impl Game {
             // 1. Turn-based actions for the current step
             self.process_turn_based_actions(phase_type, step, decisions)?;
 
-            // 2. Priority round (most steps grant priority)
-            //
             // Rule 508.8: if no creatures were declared as attackers, the
             // declare blockers and combat damage steps are skipped entirely
-            // (no turn-based actions, no priority).
-            let skipped_by_508_8 = !self.state.attacks_declared && matches!(
-                (phase_type, step),
-                (PhaseType::Combat, Some(StepType::DeclareBlockers))
-                | (PhaseType::Combat, Some(StepType::FirstStrikeDamage))
-                | (PhaseType::Combat, Some(StepType::CombatDamage))
-            );
+            // — they come out of the turn plan.
+            if step == Some(StepType::DeclareAttackers) && !self.state.attacks_declared {
+                self.state.edit_turn_plan().remove_steps(&[
+                    StepType::DeclareBlockers,
+                    StepType::FirstStrikeDamage,
+                    StepType::CombatDamage,
+                ]);
+            }
+
+            // 2. Priority round (most steps grant priority)
 
             // Rule 514.3a: Cleanup normally doesn't grant priority, but if
             // SBAs are performed during cleanup, players get priority and then
@@ -166,7 +311,7 @@ impl Game {
                     self.perform_cleanup_actions(decisions)?;
                 }
             } else {
-                let gets_priority = !skipped_by_508_8 && !matches!(
+                let gets_priority = !matches!(
                     (phase_type, step),
                     (PhaseType::Beginning, Some(StepType::Untap))  // rule 502.3
                 );
@@ -257,7 +402,18 @@ impl Game {
     /// the game result. In a 2-player game:
     /// - One player lost → other player wins
     /// - Both lost simultaneously → draw
+    ///
+    /// In a multiplayer game, losers leave (rule 800.4a) and play continues
+    /// until one player is left standing; if everyone remaining loses at
+    /// once, the game is a draw (rule 104.4a). In a team game the last team
+    /// standing wins.
+    ///
+    /// A format's own `check_game_over` is consulted first.
     pub fn check_game_over(&self) -> Option<GameResult> {
+        if let Some(result) = self.format.as_ref().and_then(|f| f.check_game_over(&self.state)) {
+            return Some(result);
+        }
+
         let losers: Vec<PlayerId> = self.state.player_lost.iter()
             .copied()
             .enumerate()
@@ -275,6 +431,16 @@ impl Game {
             return Some(GameResult::Draw);
         }
 
+        // Team game: the last team standing wins (rule 810.8)
+        if !self.state.teams.is_empty() {
+            let mut standing = self.state.teams.iter()
+                .filter(|team| team.iter().any(|&pid| !self.state.player_lost[pid]));
+            return match (standing.next(), standing.next()) {
+                (Some(team), None) => Some(GameResult::TeamWinner(team.clone())),
+                _ => None,
+            };
+        }
+
         if num_players == 2 {
             // Two-player game: the other player wins
             let winner = if losers[0] == 0 { 1 } else { 0 };
@@ -304,38 +470,41 @@ impl Game {
         for (_id, entry) in &mut self.state.battlefield {
             entry.damage_marked = 0;
             entry.damaged_by_deathtouch = false;
+            entry.regeneration_shields = 0;
         }
         // Rule 514.1: Discard to hand size
         self.handle_cleanup_discard(decisions)?;
         Ok(())
     }
 
-    /// Handle cleanup step discard to hand size (rule 514.1).
+    /// Handle cleanup step discard to hand size (rule 514.1). In a team
+    /// game each active player discards (rule 810.2).
     fn handle_cleanup_discard(
         &mut self,
         decisions: &dyn DecisionProvider,
     ) -> Result<(), String> {
-        let active = self.state.active_player;
-        let max = self.state.players[active].max_hand_size as usize;
+        for active in self.state.active_players() {
+            let max = self.state.players[active].max_hand_size as usize;
 
-        while self.state.players[active].hand.len() > max {
-            let hand: Vec<_> = self.state.players[active].hand.clone();
-            let card_id = ask_choose_discard(decisions, &self.state, active, &hand)
-                .ok_or("Player must choose a card to discard")?;
+            while self.state.players[active].hand.len() > max {
+                let hand: Vec<_> = self.state.players[active].hand.clone();
+                let card_id = ask_choose_discard(decisions, &self.state, active, &hand)
+                    .ok_or("Player must choose a card to discard")?;
 
-            // Verify the chosen card is in hand
-            if !self.state.players[active].hand.contains(&card_id) {
-                return Err("Chosen card is not in hand".to_string());
-            }
+                // Verify the chosen card is in hand
+                if !self.state.players[active].hand.contains(&card_id) {
+                    return Err("Chosen card is not in hand".to_string());
+                }
 
-            self.state.change_zone(card_id, Zone::Graveyard)?;
+                self.state.change_zone(card_id, Zone::Graveyard)?;
+            }
         }
 
         Ok(())
     }
 
     /// Shuffle a library in place using rand.
-    fn shuffle_library(library: &mut Vec<crate::types::ids::ObjectId>) {
+    fn shuffle_library(library: &mut Vec<ObjectId>) {
         use rand::seq::SliceRandom;
         let rng = &mut rand::rng();
         library.shuffle(rng);
@@ -449,6 +618,22 @@ mod tests {
         assert_eq!(game.check_game_over(), Some(GameResult::Draw));
     }
 
+    #[test]
+    fn test_check_game_over_multiplayer_last_player_standing() {
+        let config = GameConfig::test();
+        let mut game = Game::new(
+            config,
+            (0..4).map(|_| make_test_decklist(20)).collect(),
+        ).unwrap();
+
+        game.state.player_lost[1] = true;
+        assert!(game.check_game_over().is_none());
+        game.state.player_lost[3] = true;
+        assert!(game.check_game_over().is_none());
+        game.state.player_lost[0] = true;
+        assert_eq!(game.check_game_over(), Some(GameResult::Winner(2)));
+    }
+
     #[test]
     fn test_cleanup_sba_reloop() {
         // Rule 514.3a: cleanup SBA re-loop path exercises without panic.
@@ -471,14 +656,95 @@ mod tests {
 
         // Set poison to 10; SBA fires during upkeep priority, player 1 loses
         game.state.players[1].poison_counters = 10;
-        // SBA kills player 1 during upkeep — only 2 passes consumed before game ends
-        decisions.expect_pick_n(ChoiceKind::PriorityAction, vec![0]);
+        // SBA kills player 1 during upkeep — only the surviving player gets
+        // priority, so a single pass is consumed before the game ends
         decisions.expect_pick_n(ChoiceKind::PriorityAction, vec![0]);
         game.run_turn(&decisions).unwrap();
         assert!(game.is_over());
         assert_eq!(game.result, Some(GameResult::Winner(0)));
     }
 
+    fn mulligan_game(rule: MulliganRule, free_first_mulligan: bool) -> Game {
+        let mut config = GameConfig::test();
+        config.mulligan_rule = rule;
+        config.free_first_mulligan = free_first_mulligan;
+        Game::new(config, vec![make_test_decklist(20), make_test_decklist(20)]).unwrap()
+    }
+
+    #[test]
+    fn test_london_mulligan_bottoms_one_card_per_mulligan() {
+        let mut game = mulligan_game(MulliganRule::London, false);
+        let dp = ScriptedDecisionProvider::new();
+        // Player 0 mulligans, player 1 keeps; player 0 then keeps.
+        dp.expect_pick_n(ChoiceKind::Mulligan { mulligans_taken: 0 }, vec![0]);
+        dp.expect_pick_n(ChoiceKind::Mulligan { mulligans_taken: 0 }, vec![]);
+        dp.expect_pick_n(ChoiceKind::Mulligan { mulligans_taken: 1 }, vec![]);
+        dp.expect_pick_n(ChoiceKind::BottomCards { count: 1 }, vec![0]);
+        game.setup(&dp).unwrap();
+
+        assert_eq!(game.state.players[0].hand.len(), 6);
+        assert_eq!(game.state.players[0].library.len(), 14);
+        assert_eq!(game.state.players[1].hand.len(), 7);
+        let bottom = game.state.players[0].library[0];
+        assert!(!game.state.players[0].hand.contains(&bottom));
+    }
+
+    #[test]
+    fn test_free_first_mulligan_keeps_full_hand() {
+        let mut game = mulligan_game(MulliganRule::London, true);
+        let dp = ScriptedDecisionProvider::new();
+        dp.expect_pick_n(ChoiceKind::Mulligan { mulligans_taken: 0 }, vec![0]);
+        dp.expect_pick_n(ChoiceKind::Mulligan { mulligans_taken: 0 }, vec![]);
+        dp.expect_pick_n(ChoiceKind::Mulligan { mulligans_taken: 1 }, vec![]);
+        game.setup(&dp).unwrap();
+        assert_eq!(game.state.players[0].hand.len(), 7);
+    }
+
+    #[test]
+    fn test_first_mulligan_is_free_in_multiplayer() {
+        let mut config = GameConfig::standard();
+        config.deck_limits = GameConfig::test().deck_limits;
+        let decks = (0..3).map(|_| make_test_decklist(20)).collect();
+        let mut game = Game::new(config, decks).unwrap();
+        let dp = ScriptedDecisionProvider::new();
+        dp.expect_pick_n(ChoiceKind::Mulligan { mulligans_taken: 0 }, vec![0]);
+        dp.expect_pick_n(ChoiceKind::Mulligan { mulligans_taken: 0 }, vec![]);
+        dp.expect_pick_n(ChoiceKind::Mulligan { mulligans_taken: 0 }, vec![]);
+        dp.expect_pick_n(ChoiceKind::Mulligan { mulligans_taken: 1 }, vec![]);
+        game.setup(&dp).unwrap();
+        assert_eq!(game.state.players[0].hand.len(), 7);
+    }
+
+    #[test]
+    fn test_paris_mulligan_draws_one_fewer() {
+        let mut game = mulligan_game(MulliganRule::Paris, false);
+        let dp = ScriptedDecisionProvider::new();
+        dp.expect_pick_n(ChoiceKind::Mulligan { mulligans_taken: 0 }, vec![]);
+        dp.expect_pick_n(ChoiceKind::Mulligan { mulligans_taken: 0 }, vec![0]);
+        dp.expect_pick_n(ChoiceKind::Mulligan { mulligans_taken: 1 }, vec![0]);
+        dp.expect_pick_n(ChoiceKind::Mulligan { mulligans_taken: 2 }, vec![]);
+        game.setup(&dp).unwrap();
+        assert_eq!(game.state.players[0].hand.len(), 7);
+        assert_eq!(game.state.players[1].hand.len(), 5);
+        assert_eq!(game.state.players[1].library.len(), 15);
+    }
+
+    #[test]
+    fn test_vancouver_scry_after_short_keep() {
+        let mut game = mulligan_game(MulliganRule::Vancouver, false);
+        let dp = ScriptedDecisionProvider::new();
+        dp.expect_pick_n(ChoiceKind::Mulligan { mulligans_taken: 0 }, vec![0]);
+        dp.expect_pick_n(ChoiceKind::Mulligan { mulligans_taken: 0 }, vec![]);
+        dp.expect_pick_n(ChoiceKind::Mulligan { mulligans_taken: 1 }, vec![]);
+        // Only player 0 kept a short hand; they send the top card to the bottom.
+        dp.expect_pick_n(ChoiceKind::Scry { count: 1 }, vec![0]);
+        game.setup(&dp).unwrap();
+
+        assert_eq!(game.state.players[0].hand.len(), 6);
+        assert_eq!(game.state.players[0].library.len(), 14);
+        assert_eq!(game.state.players[1].hand.len(), 7);
+    }
+
     #[test]
     fn test_run_single_turn() {
         let config = GameConfig::test();
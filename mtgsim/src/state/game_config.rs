@@ -1,10 +1,10 @@
 /// Configuration that varies by format. Pure data, no behavior.
 ///
 /// Covers Standard, Modern, Pioneer, Limited, and most two-player formats
-/// out of the box. When Commander/Brawl are needed, a `Format` trait will
-/// provide `config()` and override behavioral hooks; `GameConfig` becomes
-/// a field of the `Format` implementor. The struct fields and their types
-/// don't change — only where the behavior lives.
+/// out of the box, plus Two-Headed Giant, Commander and Brawl presets.
+/// Format-specific behavior — ban lists, deck legality beyond `DeckLimits`,
+/// setup hooks, win conditions — lives on the `Format` trait
+/// (`state::format`), whose implementors carry a `GameConfig` as a field.
 
 /// Mulligan rule in use.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -13,6 +13,9 @@ pub enum MulliganRule {
     London,
     /// Older rule — shuffle back and draw one fewer.
     Paris,
+    /// Paris, then a player who kept fewer cards than a full opening hand
+    /// scries 1.
+    Vancouver,
     /// No mulligans (useful for tests).
     None,
 }
@@ -20,7 +23,8 @@ pub enum MulliganRule {
 /// Deck construction constraints for a format.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct DeckLimits {
-    /// Minimum deck size (60 standard, 40 limited, 99 commander).
+    /// Minimum deck size (60 standard, 40 limited, 100 commander including
+    /// the commander).
     pub min_deck_size: usize,
     /// Maximum deck size (None = unlimited).
     pub max_deck_size: Option<usize>,
@@ -39,7 +43,15 @@ pub struct GameConfig {
     /// Whether the first player draws on their first turn (false in standard 2-player).
     pub first_player_draws: bool,
     pub mulligan_rule: MulliganRule,
+    /// Whether a player's first mulligan is free — it doesn't count toward
+    /// the cards put on the bottom (rule 103.5c). Games with more than two
+    /// players get it regardless; this turns it on for two-player formats
+    /// such as Brawl.
+    pub free_first_mulligan: bool,
     pub deck_limits: DeckLimits,
+    /// Players per team, seated consecutively (rule 810.1). `None` for games
+    /// without teams.
+    pub team_size: Option<usize>,
 }
 
 impl GameConfig {
@@ -51,12 +63,14 @@ impl GameConfig {
             max_hand_size: 7,
             first_player_draws: false,
             mulligan_rule: MulliganRule::London,
+            free_first_mulligan: false,
             deck_limits: DeckLimits {
                 min_deck_size: 60,
                 max_deck_size: None,
                 max_copies: Some(4),
                 sideboard_size: Some(15),
             },
+            team_size: None,
         }
     }
 
@@ -68,12 +82,79 @@ impl GameConfig {
             max_hand_size: 7,
             first_player_draws: false,
             mulligan_rule: MulliganRule::London,
+            free_first_mulligan: false,
             deck_limits: DeckLimits {
                 min_deck_size: 40,
                 max_deck_size: None,
                 max_copies: None, // limited allows any number
                 sideboard_size: None,  // all unused cards are sideboard
             },
+            team_size: None,
+        }
+    }
+
+    /// Two-Headed Giant (rule 810): two teams of two sharing a 30-life total
+    /// (rule 810.9). The first team skips its first draw (rule 103.8b), and
+    /// the first mulligan is free (rule 103.5c).
+    pub fn two_headed_giant() -> Self {
+        GameConfig {
+            starting_life: 30,
+            starting_hand_size: 7,
+            max_hand_size: 7,
+            first_player_draws: false,
+            mulligan_rule: MulliganRule::London,
+            free_first_mulligan: true,
+            deck_limits: DeckLimits {
+                min_deck_size: 60,
+                max_deck_size: None,
+                max_copies: Some(4),
+                sideboard_size: Some(15),
+            },
+            team_size: Some(2),
+        }
+    }
+
+    /// Commander (rule 903): 40 life (903.7) and a 100-card singleton deck
+    /// including the commander (903.5a, 903.5b). Played multiplayer, so
+    /// nobody skips their first draw (rule 103.8c) and the first mulligan is
+    /// free (rule 103.5c).
+    pub fn commander() -> Self {
+        GameConfig {
+            starting_life: 40,
+            starting_hand_size: 7,
+            max_hand_size: 7,
+            first_player_draws: true,
+            mulligan_rule: MulliganRule::London,
+            free_first_mulligan: true,
+            deck_limits: DeckLimits {
+                min_deck_size: 100,
+                max_deck_size: Some(100),
+                max_copies: Some(1),
+                sideboard_size: None,
+            },
+            team_size: None,
+        }
+    }
+
+    /// Brawl, two-player (a Commander variant): 25 life and a 60-card
+    /// singleton deck including the commander. Two players, so the first
+    /// player skips their first draw (rule 103.8a); the first mulligan is
+    /// free, as in Commander.
+    pub fn brawl() -> Self {
+        GameConfig {
+            starting_life: 25,
+            starting_hand_size: 7,
+            max_hand_size: 7,
+            first_player_draws: false,
+            mulligan_rule: MulliganRule::London,
+            free_first_mulligan: true,
+            deck_limits: DeckLimits {
+                min_deck_size: 60,
+                max_deck_size: Some(60),
+                max_copies: Some(1),
+                sideboard_size: None,
+            },
+            team_size: None,
         }
     }
 
@@ -85,12 +166,14 @@ impl GameConfig {
             max_hand_size: 7,
             first_player_draws: true, // simplifies tests
             mulligan_rule: MulliganRule::None,
+            free_first_mulligan: false,
             deck_limits: DeckLimits {
                 min_deck_size: 0,
                 max_deck_size: None,
                 max_copies: None,
                 sideboard_size: None,
             },
+            team_size: None,
         }
     }
 }
@@ -120,6 +203,35 @@ mod tests {
         assert!(config.deck_limits.sideboard_size.is_none());
     }
 
+    #[test]
+    fn test_two_headed_giant_config() {
+        let config = GameConfig::two_headed_giant();
+        assert_eq!(config.starting_life, 30);
+        assert_eq!(config.team_size, Some(2));
+        assert!(!config.first_player_draws);
+        assert!(config.free_first_mulligan);
+        assert!(GameConfig::standard().team_size.is_none());
+    }
+
+    #[test]
+    fn test_commander_config() {
+        let config = GameConfig::commander();
+        assert_eq!(config.starting_life, 40);
+        assert_eq!(config.deck_limits.min_deck_size, 100);
+        assert_eq!(config.deck_limits.max_deck_size, Some(100));
+        assert_eq!(config.deck_limits.max_copies, Some(1));
+        assert!(config.free_first_mulligan);
+    }
+
+    #[test]
+    fn test_brawl_config() {
+        let config = GameConfig::brawl();
+        assert_eq!(config.starting_life, 25);
+        assert_eq!(config.deck_limits.max_deck_size, Some(60));
+        assert_eq!(config.deck_limits.max_copies, Some(1));
+        assert!(!config.first_player_draws);
+    }
+
     #[test]
     fn test_test_config() {
         let config = GameConfig::test();
@@ -1,14 +1,18 @@
 use std::collections::{HashMap, HashSet};
 
+use crate::engine::layers::types::EffectiveCharacteristics;
 use crate::engine::resolve::ResolvedTarget;
 use crate::events::event::EventLog;
+use crate::objects::card_data::{CardData, CardFace};
 use crate::objects::object::GameObject;
 use crate::state::battlefield::BattlefieldEntity;
 use crate::state::continuous_effects::ContinuousEffectRegistry;
 use crate::state::player::PlayerState;
+use crate::state::turn_plan::TurnPlan;
 use crate::types::costs::{AdditionalCost, AlternativeCost};
-use crate::types::effects::Effect;
+use crate::types::effects::{CounterType, Effect};
 use crate::types::ids::{ObjectId, PlayerId};
+use crate::types::zones::Zone;
 
 /// Metadata for a spell or ability on the stack.
 ///
@@ -33,12 +37,104 @@ pub struct StackEntry {
     /// Whether this is a spell (true) or an ability (false).
     /// Spells go to graveyard after resolution; abilities cease to exist.
     pub is_spell: bool,
+    /// For an ability, the object it came from (rule 113.7) — what "this
+    /// permanent" refers to while it resolves. None for spells.
+    pub ability_source: Option<ObjectId>,
     /// The alternative cost chosen for this spell, if any (rule 118.9).
     /// At most one alternative cost may be chosen per cast.
     pub chosen_alternative_cost: Option<AlternativeCost>,
     /// Additional costs that were paid for this spell (rule 118.8).
     /// Multiple additional costs can be paid (e.g. kicker + buyback).
     pub additional_costs_paid: Vec<AdditionalCost>,
+    /// The face a spell was cast as (rule 712.11) — or, for a split or
+    /// adventurer card, the half (rules 709.3, 715.3). `Combined` for a
+    /// fused split spell. Abilities are `Front`.
+    pub cast_face: CardFace,
+    /// A fused split spell's right half, with its own targets (rule
+    /// 702.102d). The fields above hold the left half's. None for every
+    /// other spell and ability.
+    pub fused_half: Option<FusedHalf>,
+    /// Whether this is a copy of a spell or ability (rule 707.10) rather
+    /// than one that was cast or activated. A copied spell ceases to exist
+    /// once it leaves the stack (SBA 704.5e).
+    pub is_copy: bool,
+    /// Whether this is a triggered ability that triggered (rule 603.3), as
+    /// opposed to an activated ability or a copy.
+    pub is_triggered: bool,
+}
+
+/// The right half of a fused split spell: its instructions, followed after
+/// the left half's as the spell resolves (rule 702.102d), and the targets
+/// chosen for them.
+#[derive(Debug, Clone)]
+pub struct FusedHalf {
+    pub effect: Effect,
+    pub chosen_targets: Vec<ResolvedTarget>,
+}
+
+/// A delayed triggered ability waiting for the beginning of its
+/// controller's first upkeep (rule 603.7) — created in the pregame by
+/// revealing a card from an opening hand (rule 103.6).
+#[derive(Debug, Clone)]
+pub struct DelayedTrigger {
+    pub controller: PlayerId,
+    /// The card that created it; the ability's source (rule 113.7).
+    pub source: ObjectId,
+    pub effect: Effect,
+}
+
+/// A triggered ability that has triggered but hasn't been put on the stack
+/// yet (rule 603.2). It goes there the next time a player would receive
+/// priority (rule 603.3).
+#[derive(Debug, Clone)]
+pub struct PendingTrigger {
+    pub controller: PlayerId,
+    pub source: TriggerSource,
+    pub effect: Effect,
+    /// The card data the ability's stack object carries, taken as it
+    /// triggered: the source may have left the game by the time it's put
+    /// on the stack — a token that died, a completed dungeon.
+    pub source_data: std::sync::Arc<CardData>,
+}
+
+/// Where a triggered ability comes from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TriggerSource {
+    /// The object whose ability triggered (rule 113.7).
+    Object(ObjectId),
+    /// One of a designation's inherent triggered abilities, which have no
+    /// source (rules 724.2, 725.2).
+    Designation(Designation),
+}
+
+/// A designation a player can have (rules 724, 725).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Designation {
+    Monarch,
+    Initiative,
+}
+
+impl Designation {
+    pub fn name(self) -> &'static str {
+        match self {
+            Designation::Monarch => "The Monarch",
+            Designation::Initiative => "The Initiative",
+        }
+    }
+}
+
+/// What an object was like the last time it existed in the battlefield or
+/// the stack, as it left (rule 608.2h). Effects that refer to an object
+/// that's left one of those zones use this "last known information" — its
+/// power, its controller, whether it was a creature (rules 113.7a,
+/// 603.10a).
+#[derive(Debug, Clone, PartialEq)]
+pub struct LastKnownInformation {
+    /// The zone it left: `Battlefield` or `Stack`.
+    pub zone: Zone,
+    pub characteristics: EffectiveCharacteristics,
+    pub controller: PlayerId,
+    pub counters: HashMap<CounterType, u32>,
 }
 
 /// The complete state of a game of Magic.
@@ -75,6 +171,9 @@ pub struct GameState {
     pub active_player: PlayerId,
     pub priority_player: PlayerId,
     pub phase: Phase,
+    /// What's left of this turn and which turns come next, as edited by
+    /// extra-turn, additional-phase, skip and end-the-turn effects.
+    pub turn_plan: TurnPlan,
 
     // --- Combat tracking ---
     pub attacks_declared: bool,
@@ -102,11 +201,75 @@ pub struct GameState {
     /// Per-player loss flags. SBAs set these; `Game::check_game_over` reads them.
     pub player_lost: Vec<bool>,
 
+    // --- Teams (rule 810, Two-Headed Giant) ---
+    /// Seat lists of each team, in turn order. Empty in games without teams,
+    /// where every player is on their own side. Set with `set_teams`.
+    pub teams: Vec<Vec<PlayerId>>,
+
+    // --- Commander (rule 903) ---
+    /// Commanders put into a graveyard or exile since state-based actions
+    /// were last checked. Their owners may move them to the command zone
+    /// (rule 903.9a); the SBA check asks and then clears the list.
+    pub commanders_to_return: Vec<ObjectId>,
+
+    // --- Pregame (rule 103.6) ---
+    /// Delayed triggers from opening-hand reveals, each put on the stack at
+    /// the beginning of its controller's first upkeep.
+    pub first_upkeep_triggers: Vec<DelayedTrigger>,
+
+    // --- Triggered abilities (rule 603) ---
+    /// Abilities that have triggered since a player last received
+    /// priority, in the order they triggered.
+    pub pending_triggers: Vec<PendingTrigger>,
+
+    // --- Last known information (rule 608.2h) ---
+    /// Each object's last known information from the last time it left the
+    /// battlefield or the stack, keyed by the `ObjectId` it had there.
+    pub last_known: HashMap<ObjectId, LastKnownInformation>,
+
+    // --- Object identity (rule 400.7) ---
+    /// The `ObjectId` each object had before its last zone change, keyed by
+    /// the one it has now.
+    pub previous_identities: HashMap<ObjectId, ObjectId>,
+    /// The `ObjectId` each object got as it last changed zones, keyed by
+    /// the one it had before.
+    pub next_identities: HashMap<ObjectId, ObjectId>,
+
+    // --- Command zone (rules 114, 309, 724, 725) ---
+    /// Emblems, each with the timestamp it got as it was created (rule
+    /// 613.7e). They stay in the command zone for the rest of the game.
+    pub emblems: HashMap<ObjectId, u64>,
+    /// Each dungeon in the command zone, and the index of the room its
+    /// venture marker is on (rule 309.4).
+    pub venture_markers: HashMap<ObjectId, usize>,
+    /// The monarch (rule 724), if anyone is.
+    pub monarch: Option<PlayerId>,
+    /// The player who has the initiative (rule 725), if anyone does.
+    pub initiative: Option<PlayerId>,
+
     // --- First-turn draw skip (rule 103.8a) ---
     /// If true, the first draw step is skipped (one-time flag for game setup).
-    /// In-game "skip draw" effects use the replacement effect system (Phase 6).
+    /// In-game "skip your next draw step" effects go in the `turn_plan`.
     pub skip_first_draw: bool,
 
+    // --- Day and night (rule 726) ---
+    /// Whether it's day or night. None until a permanent with daybound or
+    /// nightbound makes it one or the other (rule 726.2); once it is, it
+    /// never becomes neither again.
+    pub day_night: Option<DayNight>,
+    /// Spells cast this turn by the active player — read as the next turn
+    /// begins to decide whether day becomes night (rule 726.3a).
+    pub active_player_spells_this_turn: u32,
+    /// Spells cast this turn by any player — a storm spell's storm count is
+    /// how many were cast before it (rule 702.40a).
+    pub spells_cast_this_turn: u32,
+
+    // --- Adventures (rule 715) ---
+    /// Adventurer cards exiled by their own resolving Adventure. Their
+    /// owners may cast the creature from exile while they stay there (rule
+    /// 715.4).
+    pub on_adventure: HashSet<ObjectId>,
+
     // --- Continuous effects registry (CR 613) ---
     pub continuous_effects: ContinuousEffectRegistry,
 
@@ -114,6 +277,13 @@ pub struct GameState {
     pub events: EventLog,
 }
 
+/// The day/night designation (rule 726).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DayNight {
+    Day,
+    Night,
+}
+
 /// Turn phases
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum PhaseType {
@@ -192,15 +362,8 @@ pub fn next_step(phase_type: PhaseType, current_step: StepType) -> Option<StepTy
     }
 }
 
-/// Get the next phase in turn order.
-///
-/// **Future: TurnPlan for extra phases.** Effects like "after this phase, there
-/// is an additional combat phase followed by an additional main phase" cannot
-/// be expressed by a fixed state machine. When we implement combat (Phase 3),
-/// `next_phase` will be replaced by a `TurnPlan` — a mutable Vec of
-/// `(PhaseType, Vec<StepType>)` that the engine walks. Effects insert extra
-/// entries into the plan, and `advance_turn` reads from it instead of calling
-/// this function.
+/// Get the next phase in the normal turn order (rule 500.1). Effects that
+/// add or skip phases edit the `TurnPlan` built from this order.
 pub fn next_phase(phase_type: PhaseType) -> PhaseType {
     match phase_type {
         PhaseType::Beginning => PhaseType::Precombat,
@@ -230,13 +393,29 @@ impl GameState {
             active_player: 0,
             priority_player: 0,
             phase: Phase::new(PhaseType::Beginning),
+            turn_plan: TurnPlan::new(),
             attacks_declared: false,
             blockers_declared: false,
             blocker_damage_divisions: HashMap::new(),
             dealt_first_strike_damage: HashSet::new(),
             next_timestamp: 0,
             player_lost: vec![false; num_players],
+            teams: Vec::new(),
+            commanders_to_return: Vec::new(),
+            first_upkeep_triggers: Vec::new(),
+            pending_triggers: Vec::new(),
+            last_known: HashMap::new(),
+            previous_identities: HashMap::new(),
+            next_identities: HashMap::new(),
+            emblems: HashMap::new(),
+            venture_markers: HashMap::new(),
+            monarch: None,
+            initiative: None,
             skip_first_draw: false,
+            day_night: None,
+            active_player_spells_this_turn: 0,
+            spells_cast_this_turn: 0,
+            on_adventure: HashSet::new(),
             continuous_effects: ContinuousEffectRegistry::new(),
             events: EventLog::new(),
         }
@@ -256,17 +435,52 @@ impl GameState {
     /// Returns a mutable reference to the inserted entry so callers can tweak
     /// fields (e.g. `entry.tapped = true`) without a second lookup.
     pub fn place_on_battlefield(&mut self, id: ObjectId, controller: PlayerId) -> &mut BattlefieldEntity {
+        self.place_on_battlefield_as(id, controller, CardFace::Front)
+    }
+
+    /// `place_on_battlefield` with `face` up (rule 712.14), or face down.
+    /// Day/night can still turn a daybound permanent over as it enters (rule
+    /// 702.145b).
+    pub fn place_on_battlefield_as(
+        &mut self,
+        id: ObjectId,
+        controller: PlayerId,
+        face: CardFace,
+    ) -> &mut BattlefieldEntity {
         let ts = self.allocate_timestamp();
         let current_turn = self.turn_number;
-        let entry = BattlefieldEntity::new(id, controller, ts, current_turn);
+        let mut entry = BattlefieldEntity::new(id, controller, ts, current_turn);
+        entry.transformed = face == CardFace::Back;
+        if let CardFace::FaceDown(kind) = face {
+            entry.face_down = Some(kind);
+        }
         self.battlefield.insert(id, entry);
+        self.on_adventure.remove(&id);
 
+        self.apply_day_night_on_entry(id);
         self.init_etb_counters(id);
         self.register_static_effects(id, controller);
 
         self.battlefield.get_mut(&id).unwrap()
     }
 
+    /// Phased-in permanents: every battlefield entry except those phased out
+    /// (rule 702.26b). Subsystems that scan the battlefield — SBAs, combat,
+    /// targeting, continuous effects — go through here, not `battlefield`.
+    pub fn permanents(&self) -> impl Iterator<Item = (&ObjectId, &BattlefieldEntity)> {
+        self.battlefield.iter().filter(|(_, entry)| !entry.phased_out)
+    }
+
+    /// The battlefield entry for `id` if it is a phased-in permanent.
+    pub fn permanent(&self, id: ObjectId) -> Option<&BattlefieldEntity> {
+        self.battlefield.get(&id).filter(|entry| !entry.phased_out)
+    }
+
+    /// Whether `id` is on the battlefield and phased in.
+    pub fn is_phased_in(&self, id: ObjectId) -> bool {
+        self.permanent(id).is_some()
+    }
+
     /// The timestamp a continuous effect generated by `ability` on `id` gets
     /// (CR 613.7a).
     ///
@@ -299,6 +513,9 @@ impl GameState {
         id: ObjectId,
         _ability: &crate::objects::card_data::AbilityDef,
     ) -> u64 {
+        if let Some(&timestamp) = self.emblems.get(&id) {
+            return timestamp;
+        }
         match self.battlefield.get(&id) {
             Some(entry) => entry.timestamp,
             // Unreachable because the only caller is `register_static_effects`,
@@ -337,16 +554,17 @@ impl GameState {
     ///
     /// Reads printed abilities on purpose: it runs inside
     /// `place_on_battlefield`, before this object's own effect is registered,
-    /// so computing effective characteristics here would be circular.
-    fn register_static_effects(&mut self, id: ObjectId, controller: PlayerId) {
+    /// so computing effective characteristics here would be circular. They're
+    /// the abilities of the face that's up; transforming re-registers.
+    pub(crate) fn register_static_effects(&mut self, id: ObjectId, controller: PlayerId) {
         use crate::engine::layers::types::{
             AffectedSet, ContinuousEffect, EffectModification, EffectOrigin, Layer,
         };
         use crate::objects::card_data::AbilityType;
-        use crate::types::effects::{AmountExpr, Duration, Effect, EffectRecipient, Primitive};
+        use crate::types::effects::{AmountExpr, Effect, EffectRecipient, Primitive};
 
-        let abilities = if let Some(obj) = self.objects.get(&id) {
-            obj.card_data.abilities.clone()
+        let abilities = if let Some(data) = self.face_up_data(id) {
+            data.abilities.clone()
         } else {
             return;
         };
@@ -380,8 +598,10 @@ impl GameState {
                         }
                     }
                     EffectRecipient::Implicit => AffectedSet::SourceOnly,
+                    EffectRecipient::AttachedPermanent => AffectedSet::AttachedTo,
                     _ => continue,
                 };
+                let duration = self.static_effect_duration(id, recipient);
 
                 // Map primitive → (layer, modification)
                 let (layer, modification) = match primitive {
@@ -454,7 +674,7 @@ impl GameState {
                                 source: id,
                                 origin: EffectOrigin::StaticAbility { ability: ability.id },
                                 layer: Layer::Layer4Type,
-                                duration: Duration::WhileSourceOnBattlefield,
+                                duration,
                                 controller,
                                 created_on_turn: self.turn_number,
                                 timestamp: ts,
@@ -474,7 +694,7 @@ impl GameState {
                     source: id,
                     origin: EffectOrigin::StaticAbility { ability: ability.id },
                     layer,
-                    duration: Duration::WhileSourceOnBattlefield,
+                    duration,
                     controller,
                     created_on_turn: self.turn_number,
                     timestamp,
@@ -486,6 +706,36 @@ impl GameState {
         }
     }
 
+    /// Duration recorded on a static-ability effect. "Equipped creature" and
+    /// "enchanted creature" effects last while the host stays equipped or
+    /// enchanted by the source (rule 611.3a); `AffectedSet::AttachedTo` is
+    /// what enforces it. Everything else lasts while the source is on the
+    /// battlefield.
+    fn static_effect_duration(
+        &self,
+        id: ObjectId,
+        recipient: &crate::types::effects::EffectRecipient,
+    ) -> crate::types::effects::Duration {
+        use crate::types::card_types::{ArtifactType, EnchantmentType, Subtype};
+        use crate::types::effects::{Duration, EffectRecipient};
+
+        if self.emblems.contains_key(&id) {
+            // Emblems never leave the command zone (rule 114.4)
+            return Duration::Indefinite;
+        }
+        if *recipient != EffectRecipient::AttachedPermanent {
+            return Duration::WhileSourceOnBattlefield;
+        }
+        let has = |s: Subtype| crate::oracle::characteristics::has_subtype(self, id, &s);
+        if has(Subtype::Artifact(ArtifactType::Equipment)) {
+            Duration::WhileEquipped
+        } else if has(Subtype::Enchantment(EnchantmentType::Aura)) {
+            Duration::WhileEnchanted
+        } else {
+            Duration::WhileSourceOnBattlefield
+        }
+    }
+
     /// Walk a `PermanentFilter` to find `ByController(PlayerRef)` and resolve
     /// it to a concrete `PlayerId`. Returns `Some(id)` if the filter contains
     /// a controller constraint, `None` otherwise.
@@ -511,19 +761,43 @@ impl GameState {
     /// - Planeswalker loyalty (rule 306.5b): set loyalty counters equal to
     ///   printed loyalty. Replacement effects (e.g. Doubling Season) will
     ///   intercept this in the replacement-effect layer (Phase 7+).
+    /// - Battle defense (rule 310.4b): set defense counters equal to printed
+    ///   defense, and designate a default protector (rule 310.8a). A Siege's
+    ///   protector must be an opponent (rule 310.11a); the next opponent in
+    ///   turn order is used here because this path has no DecisionProvider.
+    ///   Spell resolution re-asks the controller via `choose_battle_protector`.
+    ///
+    /// - Saga lore counter (rule 714.3a): one lore counter, which triggers
+    ///   its chapter I ability.
     ///
-    /// Future: Sagas (lore counters), other ETB counter patterns.
+    /// Future: other ETB counter patterns.
     fn init_etb_counters(&mut self, id: ObjectId) {
-        if let Some(obj) = self.objects.get(&id) {
-            if crate::oracle::characteristics::has_type(
-                self, id, crate::types::card_types::CardType::Planeswalker) {
-                if let Some(loyalty) = obj.card_data.loyalty {
-                    if loyalty > 0 {
-                        self.battlefield.get_mut(&id).unwrap()
-                            .add_counters(crate::types::effects::CounterType::Loyalty, loyalty as u32);
-                    }
-                }
+        use crate::types::card_types::CardType;
+        use crate::types::effects::CounterType;
+
+        let Some(data) = self.face_up_data(id) else { return };
+        if crate::oracle::characteristics::has_type(self, id, CardType::Planeswalker)
+            && let Some(loyalty) = data.loyalty
+            && loyalty > 0
+        {
+            self.battlefield.get_mut(&id).unwrap()
+                .add_counters(CounterType::Loyalty, loyalty as u32);
+        }
+
+        let Some(data) = self.face_up_data(id) else { return };
+        if crate::oracle::characteristics::has_type(self, id, CardType::Battle) {
+            let defense = data.defense.unwrap_or(0);
+            let controller = self.battlefield[&id].controller;
+            let protector = self.battle_protector_candidates(id, controller).first().copied();
+            let entry = self.battlefield.get_mut(&id).unwrap();
+            if defense > 0 {
+                entry.add_counters(CounterType::Defense, defense as u32);
             }
+            entry.protector = protector;
+        }
+
+        if self.is_saga(id) {
+            self.add_lore_counters(id, 1);
         }
     }
 
@@ -546,6 +820,36 @@ impl GameState {
         self.objects.get_mut(&id).ok_or_else(|| format!("Object {} not found", id))
     }
 
+    /// Which face of `id` is up (rule 712.8). A double-faced card is front
+    /// face up in every zone except the stack, where it's the face it was
+    /// cast as, and the battlefield, where it's back face up once
+    /// transformed or if it entered that way. A split card off the stack has
+    /// both halves' characteristics (rule 709.4). A face-down permanent
+    /// has neither face up (rule 708.2).
+    pub fn face_up(&self, id: ObjectId) -> CardFace {
+        if let Some(entry) = self.battlefield.get(&id) {
+            match (entry.face_down, entry.transformed) {
+                (Some(kind), _) => CardFace::FaceDown(kind),
+                (None, true) => CardFace::Back,
+                (None, false) => CardFace::Front,
+            }
+        } else if let Some(entry) = self.stack_entries.get(&id) {
+            entry.cast_face
+        } else if self.objects.get(&id).is_some_and(|obj| {
+            obj.card_data.second_half.as_ref().is_some_and(|half| half.kind.is_split())
+        }) {
+            CardFace::Combined
+        } else {
+            CardFace::Front
+        }
+    }
+
+    /// The printed characteristics of the face of `id` that's up — what the
+    /// layer system starts from (rule 712.8).
+    pub fn face_up_data(&self, id: ObjectId) -> Option<&std::sync::Arc<CardData>> {
+        Some(self.objects.get(&id)?.face(self.face_up(id)))
+    }
+
     // --- Player accessors ---
 
     pub fn get_player(&self, id: PlayerId) -> Result<&PlayerState, String> {
@@ -616,8 +920,13 @@ mod tests {
             x_value: None,
             effect: Effect::Sequence(vec![]),
             is_spell: true,
+            ability_source: None,
             chosen_alternative_cost: None,
             additional_costs_paid: Vec::new(),
+            cast_face: CardFace::Front,
+            fused_half: None,
+            is_copy: false,
+            is_triggered: false,
         };
         assert!(entry.chosen_alternative_cost.is_none());
         assert!(entry.additional_costs_paid.is_empty());
@@ -1,6 +1,8 @@
 pub mod game_state;
 pub mod game_config;
+pub mod format;
 pub mod game;
 pub mod player;
 pub mod battlefield;
 pub mod continuous_effects;
+pub mod turn_plan;
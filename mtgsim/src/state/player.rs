@@ -1,5 +1,6 @@
 use std::collections::HashMap;
 
+use crate::types::card_types::DungeonType;
 use crate::types::ids::{ObjectId, PlayerId};
 use crate::types::mana::ManaPool;
 
@@ -26,6 +27,12 @@ pub struct PlayerState {
     // Counters tracked on the player (not on any game object)
     pub poison_counters: u32,
     pub commander_damage_taken: HashMap<ObjectId, u32>,
+    /// Times each of this player's commanders has been cast from the command
+    /// zone — the basis of commander tax (rule 903.8).
+    pub commander_casts: HashMap<ObjectId, u32>,
+
+    /// Dungeons this player has completed (rule 309.7), in order.
+    pub completed_dungeons: Vec<DungeonType>,
 
     // SBA flags — these are ONLY for state-based action checks (rule 704).
     // General per-turn tracking (e.g. "cast a spell this turn") should live
@@ -47,6 +54,8 @@ impl PlayerState {
             lands_played_this_turn: 0,
             poison_counters: 0,
             commander_damage_taken: HashMap::new(),
+            commander_casts: HashMap::new(),
+            completed_dungeons: Vec::new(),
             has_drawn_from_empty_library: false,
         }
     }
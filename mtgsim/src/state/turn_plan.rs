@@ -0,0 +1,305 @@
+//! The turn plan — what's left of the current turn and whose turns come next.
+//!
+//! A turn normally runs five phases in a fixed order (rule 500.1), but
+//! effects edit that order, so `advance_turn` walks this plan instead of a
+//! fixed state machine:
+//! - additional phases are added directly after the current phase (rule 500.8);
+//! - extra turns are taken directly after the current turn, the most
+//!   recently created first (rule 500.7);
+//! - skip effects remove the next step, phase or turn they name (rule 614.10);
+//! - ending the turn discards everything left except a cleanup step (rule 723.1).
+//!
+//! The plan only stores data; `engine/turns.rs` consults it and applies skips.
+
+use std::collections::VecDeque;
+
+use crate::state::game_state::{next_phase, next_step, Phase, PhaseType, StepType};
+use crate::types::effects::SkipTarget;
+use crate::types::ids::PlayerId;
+
+/// A phase still to come this turn.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PlannedPhase {
+    pub phase_type: PhaseType,
+    /// The phase's steps in order; empty for a main phase.
+    pub steps: Vec<StepType>,
+    /// Added by an effect rather than part of the normal turn (rule 500.8).
+    pub additional: bool,
+}
+
+impl PlannedPhase {
+    /// A phase with its normal steps (rules 501–514).
+    pub fn new(phase_type: PhaseType) -> Self {
+        let mut steps = Vec::new();
+        let mut step = Phase::new(phase_type).step;
+        while let Some(s) = step {
+            steps.push(s);
+            step = next_step(phase_type, s);
+        }
+        PlannedPhase { phase_type, steps, additional: false }
+    }
+
+    /// A phase an effect adds to the turn (rule 500.8).
+    pub fn additional(phase_type: PhaseType) -> Self {
+        PlannedPhase { additional: true, ..PlannedPhase::new(phase_type) }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TurnPlan {
+    /// The phase and step the game last entered through the plan.
+    current: Phase,
+    /// Steps still to come in the current phase.
+    steps: VecDeque<StepType>,
+    /// Phases still to come this turn, after the current one.
+    phases: VecDeque<PlannedPhase>,
+    /// Extra turns not yet begun; the last one is taken first (rule 500.7).
+    extra_turns: Vec<PlayerId>,
+    /// While an extra turn is being taken, the player whose turn came
+    /// before it in normal turn order — turn order resumes after them.
+    resume_after: Option<PlayerId>,
+    /// Skip effects waiting for the step, phase or turn they name.
+    skips: Vec<(PlayerId, SkipTarget)>,
+    /// Set when an effect ends the turn, until the cleanup step (rule 723.1).
+    ending: bool,
+}
+
+impl Default for TurnPlan {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TurnPlan {
+    /// The plan for a game about to begin its first untap step.
+    pub fn new() -> Self {
+        let mut plan = TurnPlan {
+            current: Phase::new(PhaseType::Beginning),
+            steps: VecDeque::new(),
+            phases: VecDeque::new(),
+            extra_turns: Vec::new(),
+            resume_after: None,
+            skips: Vec::new(),
+            ending: false,
+        };
+        // The game starts in the first turn's untap step (`Phase::new`).
+        plan.begin_turn();
+        plan.next_phase();
+        plan.next_step();
+        plan
+    }
+
+    /// Queue the normal phases of a turn (rule 500.1), starting with the
+    /// beginning phase.
+    pub fn begin_turn(&mut self) {
+        self.steps.clear();
+        self.phases.clear();
+        let mut phase_type = PhaseType::Beginning;
+        loop {
+            self.phases.push_back(PlannedPhase::new(phase_type));
+            phase_type = next_phase(phase_type);
+            if phase_type == PhaseType::Beginning {
+                break;
+            }
+        }
+    }
+
+    /// Record that the game has entered `phase`.
+    pub fn entered(&mut self, phase: Phase) {
+        self.current = phase;
+    }
+
+    /// Line the plan up with `phase` if the game got there without going
+    /// through the plan — a test or scenario setting `GameState::phase`
+    /// directly. The rest of the turn becomes the normal remainder from there.
+    pub fn sync_to(&mut self, phase: Phase) {
+        if phase == self.current {
+            return;
+        }
+        self.current = phase;
+        self.steps = PlannedPhase::new(phase.phase_type).steps.into_iter()
+            .skip_while(|&s| Some(s) != phase.step)
+            .skip(1)
+            .collect();
+        self.phases.clear();
+        let mut phase_type = next_phase(phase.phase_type);
+        while phase_type != PhaseType::Beginning {
+            self.phases.push_back(PlannedPhase::new(phase_type));
+            phase_type = next_phase(phase_type);
+        }
+        self.ending = false;
+    }
+
+    /// The next step of the current phase, if any.
+    pub fn next_step(&mut self) -> Option<StepType> {
+        self.steps.pop_front()
+    }
+
+    /// The next phase of the turn, or None when the turn is over. Its steps
+    /// become the steps still to come.
+    pub fn next_phase(&mut self) -> Option<PlannedPhase> {
+        let phase = self.phases.pop_front()?;
+        self.steps = phase.steps.iter().copied().collect();
+        self.ending = false;
+        Some(phase)
+    }
+
+    /// Drop the given steps if they're still to come in the current phase —
+    /// e.g. the blockers and damage steps after no attackers (rule 508.8).
+    pub fn remove_steps(&mut self, steps: &[StepType]) {
+        self.steps.retain(|s| !steps.contains(s));
+    }
+
+    /// Phases still to come this turn, in order.
+    pub fn remaining_phases(&self) -> impl Iterator<Item = &PlannedPhase> {
+        self.phases.iter()
+    }
+
+    /// Add phases directly after the current one, in the given order (rule
+    /// 500.8). Phases added later happen first: they're "after this phase" too.
+    pub fn add_phases_after_current(&mut self, phases: &[PhaseType]) {
+        for &phase_type in phases.iter().rev() {
+            self.phases.push_front(PlannedPhase::additional(phase_type));
+        }
+    }
+
+    /// Give `player` an extra turn after this one (rule 500.7).
+    pub fn add_extra_turn(&mut self, player: PlayerId) {
+        self.extra_turns.push(player);
+    }
+
+    /// Extra turns not yet begun, the next one last.
+    pub fn extra_turns(&self) -> &[PlayerId] {
+        &self.extra_turns
+    }
+
+    /// The most recently created extra turn not yet begun.
+    pub fn take_extra_turn(&mut self) -> Option<PlayerId> {
+        self.extra_turns.pop()
+    }
+
+    /// Where normal turn order picks up after the extra turn being taken.
+    pub fn take_resume_point(&mut self) -> Option<PlayerId> {
+        self.resume_after.take()
+    }
+
+    pub fn set_resume_point(&mut self, player: PlayerId) {
+        self.resume_after = Some(player);
+    }
+
+    /// `player` skips the next `target` (rule 614.10). Two such effects skip
+    /// two of them.
+    pub fn add_skip(&mut self, player: PlayerId, target: SkipTarget) {
+        self.skips.push((player, target));
+    }
+
+    /// Consume a skip of `target` held by a player `matches` accepts.
+    /// Returns the player whose skip was used.
+    pub fn take_skip(
+        &mut self,
+        target: SkipTarget,
+        matches: impl Fn(PlayerId) -> bool,
+    ) -> Option<PlayerId> {
+        let pos = self.skips.iter().position(|&(p, t)| t == target && matches(p))?;
+        Some(self.skips.remove(pos).0)
+    }
+
+    /// Pending skip effects, in the order they were created.
+    pub fn skips(&self) -> &[(PlayerId, SkipTarget)] {
+        &self.skips
+    }
+
+    /// End the turn (rule 723.1d): nothing is left but a cleanup step.
+    pub fn end_turn(&mut self) {
+        self.steps.clear();
+        self.phases.clear();
+        self.phases.push_back(PlannedPhase {
+            phase_type: PhaseType::Ending,
+            steps: vec![StepType::Cleanup],
+            additional: false,
+        });
+        self.ending = true;
+    }
+
+    /// Whether an effect is ending the turn and the cleanup step hasn't
+    /// begun yet — no player gets priority in the meantime (rule 723.1c).
+    pub fn is_ending(&self) -> bool {
+        self.ending
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn phase_types(plan: &TurnPlan) -> Vec<PhaseType> {
+        plan.remaining_phases().map(|p| p.phase_type).collect()
+    }
+
+    #[test]
+    fn test_new_plan_starts_in_untap_step() {
+        let mut plan = TurnPlan::new();
+        assert_eq!(plan.next_step(), Some(StepType::Upkeep));
+        assert_eq!(plan.next_step(), Some(StepType::Draw));
+        assert_eq!(plan.next_step(), None);
+        assert_eq!(phase_types(&plan), vec![
+            PhaseType::Precombat, PhaseType::Combat, PhaseType::Postcombat, PhaseType::Ending,
+        ]);
+    }
+
+    #[test]
+    fn test_additional_phases_come_right_after_current() {
+        let mut plan = TurnPlan::new();
+        plan.next_phase(); // precombat main
+        plan.add_phases_after_current(&[PhaseType::Combat, PhaseType::Postcombat]);
+        assert_eq!(phase_types(&plan), vec![
+            PhaseType::Combat, PhaseType::Postcombat,
+            PhaseType::Combat, PhaseType::Postcombat, PhaseType::Ending,
+        ]);
+        let added = plan.next_phase().unwrap();
+        assert!(added.additional);
+        assert_eq!(added.steps.first(), Some(&StepType::BeginCombat));
+    }
+
+    #[test]
+    fn test_sync_to_directly_set_phase() {
+        let mut plan = TurnPlan::new();
+        plan.sync_to(Phase { phase_type: PhaseType::Combat, step: Some(StepType::CombatDamage) });
+        assert_eq!(plan.next_step(), Some(StepType::EndCombat));
+        assert_eq!(plan.next_step(), None);
+        assert_eq!(phase_types(&plan), vec![PhaseType::Postcombat, PhaseType::Ending]);
+    }
+
+    #[test]
+    fn test_extra_turns_are_last_in_first_out() {
+        let mut plan = TurnPlan::new();
+        plan.add_extra_turn(0);
+        plan.add_extra_turn(1);
+        assert_eq!(plan.take_extra_turn(), Some(1));
+        assert_eq!(plan.take_extra_turn(), Some(0));
+        assert_eq!(plan.take_extra_turn(), None);
+    }
+
+    #[test]
+    fn test_skip_is_used_once() {
+        let mut plan = TurnPlan::new();
+        plan.add_skip(1, SkipTarget::DrawStep);
+        assert_eq!(plan.take_skip(SkipTarget::DrawStep, |p| p == 0), None);
+        assert_eq!(plan.take_skip(SkipTarget::UntapStep, |p| p == 1), None);
+        assert_eq!(plan.take_skip(SkipTarget::DrawStep, |p| p == 1), Some(1));
+        assert!(plan.skips().is_empty());
+    }
+
+    #[test]
+    fn test_end_turn_leaves_only_cleanup() {
+        let mut plan = TurnPlan::new();
+        plan.end_turn();
+        assert!(plan.is_ending());
+        assert_eq!(plan.next_step(), None);
+        let phase = plan.next_phase().unwrap();
+        assert_eq!(phase.phase_type, PhaseType::Ending);
+        assert_eq!(phase.steps, vec![StepType::Cleanup]);
+        assert!(!plan.is_ending());
+        assert!(plan.next_phase().is_none());
+    }
+}
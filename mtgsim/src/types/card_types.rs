@@ -628,6 +628,7 @@ pub enum PlanarType {
 
 #[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
 pub enum DungeonType {
+    LostMineOfPhandelver,
     Undercity,
 }
 
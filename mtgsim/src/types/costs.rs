@@ -3,7 +3,8 @@ use super::mana::ManaCost;
 
 /// Costs that must be paid to activate an ability or cast a spell.
 ///
-/// Only `Tap`, `Mana`, `SacrificeSelf`, and `PayLife` are fully implemented.
+/// `Tap`, `Untap`, `Mana`, `SacrificeSelf`, `PayLife`, `AddCounters`, and
+/// `RemoveCounters` (on the source) are fully implemented.
 /// Other variants exist for forward-compatibility; `can_pay_costs` and
 /// `pay_single_cost` return `Err("not yet implemented")` for them.
 #[derive(Debug, Clone, PartialEq)]
@@ -24,9 +25,11 @@ pub enum Cost {
     Discard(CardFilter, u32),
     /// Exile N cards from your graveyard matching a filter
     ExileFromGraveyard(CardFilter, u32),
-    /// Remove N counters of a type from the source
+    /// Remove N counters of a type from the source.
+    /// With `CounterType::Loyalty` this is a −N loyalty cost (rule 606.4).
     RemoveCounters(CounterType, u32),
-    /// Add N counters of a type to the source (e.g. blight counters)
+    /// Add N counters of a type to the source (e.g. blight counters).
+    /// With `CounterType::Loyalty` this is a +N loyalty cost (rule 606.4).
     AddCounters(CounterType, u32),
 }
 
@@ -43,7 +46,14 @@ pub enum AlternativeCost {
     Escape(Vec<Cost>),
     Evoke(Vec<Cost>),
     Bestow(Vec<Cost>),
+    /// Emerge: these costs and sacrificing a creature, with the total cost
+    /// reduced by that creature's mana value (rule 702.119a).
+    Emerge(Vec<Cost>),
     Custom(String, Vec<Cost>),
+    /// "Without paying its mana cost": nothing is paid in its place, and X
+    /// is 0 (rule 107.3b). Chosen by effects that cast a spell this way, or
+    /// offered by a static ability such as Omniscience.
+    WithoutPayingManaCost,
 }
 
 impl AlternativeCost {
@@ -56,7 +66,9 @@ impl AlternativeCost {
             | AlternativeCost::Escape(c)
             | AlternativeCost::Evoke(c)
             | AlternativeCost::Bestow(c)
+            | AlternativeCost::Emerge(c)
             | AlternativeCost::Custom(_, c) => c,
+            AlternativeCost::WithoutPayingManaCost => &[],
         }
     }
 }
@@ -70,9 +82,15 @@ pub enum AdditionalCost {
     Kicker(Vec<Cost>),
     Buyback(Vec<Cost>),
     Entwine(Vec<Cost>),
+    /// "As you cast this spell, you may sacrifice a creature with power N
+    /// or greater. When you do, copy this spell" (rule 702.153).
     Casualty(u32),
     Bargain,
     Strive(Vec<Cost>),
+    /// Paid any number of times; the spell is copied for each time after
+    /// it's cast (rule 702.56). Each payment is its own entry in
+    /// `StackEntry::additional_costs_paid`.
+    Replicate(Vec<Cost>),
     Custom(String, Vec<Cost>),
 }
 
@@ -82,7 +100,8 @@ impl AdditionalCost {
     /// Variants without an explicit `Vec<Cost>` (e.g. `Casualty`, `Bargain`)
     /// return an empty slice **temporarily**. Both decompose into sacrifice
     /// primitives once `PermanentFilter` supports the required predicates:
-    /// - `Casualty(n)` → `Sacrifice(power_n_or_greater, 1)`
+    /// - `Casualty(n)` → `Sacrifice(power_n_or_greater, 1)` (until then,
+    ///   `cast_spell` asks for the creature and sacrifices it itself)
     /// - `Bargain` → `Sacrifice(artifact_or_enchantment_or_token, 1)`
     /// After cost primitive consolidation, every variant will return a
     /// non-empty slice and the empty-slice fallback can be removed.
@@ -92,6 +111,7 @@ impl AdditionalCost {
             | AdditionalCost::Buyback(c)
             | AdditionalCost::Entwine(c)
             | AdditionalCost::Strive(c)
+            | AdditionalCost::Replicate(c)
             | AdditionalCost::Custom(_, c) => c,
             AdditionalCost::Casualty(_) | AdditionalCost::Bargain => &[],
         }
@@ -87,6 +87,16 @@ pub enum Duration {
     Indefinite,
 }
 
+/// What a "skip your next ..." effect skips (rule 614.10).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SkipTarget {
+    UntapStep,
+    UpkeepStep,
+    DrawStep,
+    CombatPhase,
+    Turn,
+}
+
 /// Conditions for Conditional effects (rule 603.4 intervening "if")
 #[derive(Debug, Clone, PartialEq)]
 pub enum Condition {
@@ -98,6 +108,9 @@ pub enum Condition {
     SpellWasKicked,
     ModeChosen(usize),
     SourceOnBattlefield,
+    /// "If it was a [filter]" — the first target object, as it last existed
+    /// on the battlefield if it's left (rule 608.2h).
+    TargetWas(PermanentFilter),
 }
 
 /// How many modes to choose (rule 700.2)
@@ -125,6 +138,10 @@ pub enum EffectRecipient {
     /// The controller of this spell/ability (e.g. Night's Whisper "you draw",
     /// Angel's Mercy "you gain"). Not targeting.
     Controller,
+    /// "Its controller" — whoever controls the first target object, or
+    /// last controlled it if it's left the battlefield or the stack (rule
+    /// 608.2h). Not targeting.
+    TargetController,
     /// Select with targeting rules — hexproof/shroud/protection apply,
     /// fizzles if all targets become illegal (rule 608.2b).
     Target(SelectionFilter, TargetCount),
@@ -132,11 +149,17 @@ pub enum EffectRecipient {
     /// Hexproof/shroud/protection do NOT apply.  Does not fizzle.
     Choose(SelectionFilter, TargetCount),
     /// Filter-based continuous effect (static abilities / anthems).
-    /// Applies to all permanents matching the filter. Not used at cast/resolution
-    /// time — only read by the ETB hook to register continuous effects.
+    /// Applies to all permanents matching the filter. Mostly read by the ETB
+    /// hook to register continuous effects; one-shot "all permanents ..."
+    /// effects (`PhaseOut`, `Transform`) match it against the battlefield
+    /// at resolution.
     /// Use `ByController(PlayerRef::You)` in the filter to express "you control";
     /// the registration hook resolves `PlayerRef` to a concrete `PlayerId`.
     FilteredPermanents(PermanentFilter),
+    /// The permanent the source is attached to — "equipped creature",
+    /// "enchanted creature" (rules 301.5, 303.4). Static abilities only, like
+    /// `FilteredPermanents`; the effect follows the source to each new host.
+    AttachedPermanent,
 }
 
 /// What kind of object(s) can be selected.
@@ -154,6 +177,10 @@ pub enum SelectionFilter {
     Permanent(PermanentFilter),
     /// Spell on the stack
     Spell,
+    /// Instant or sorcery spell on the stack
+    InstantOrSorcerySpell,
+    /// Spell on the stack with exactly one target
+    SpellWithSingleTarget,
 }
 
 /// How many targets/choices to select
@@ -207,6 +234,10 @@ pub enum CounterType {
     PlusOnePlusOne,
     MinusOneMinusOne,
     Loyalty,
+    /// Battle defense counters (rule 310.4).
+    Defense,
+    /// Saga lore counters (rule 714.3).
+    Lore,
     Charge,
     // Keyword counters (rule 122.1b)
     Flying,
@@ -275,13 +306,18 @@ pub enum Primitive {
     // === Zone movement (rule 701) ===
     /// Destroy a permanent (rule 701.8) — respects indestructible/regenerate
     Destroy,
+    /// Destroy a permanent; it can't be regenerated (rule 701.19c)
+    DestroyNoRegeneration,
+    /// Regenerate a permanent: give it a regeneration shield (rule 701.19)
+    Regenerate,
     /// Exile an object (rule 701.13)
     Exile,
     /// Sacrifice a permanent (rule 701.21)
     Sacrifice,
     /// Return to owner's hand ("bounce")
     ReturnToHand,
-    /// Return to the battlefield (from exile/graveyard)
+    /// Return to the battlefield under its owner's control (from
+    /// exile/graveyard), following the card if this effect just moved it
     ReturnToBattlefield,
     /// Put on top of owner's library
     PutOnTopOfLibrary,
@@ -332,6 +368,43 @@ pub enum Primitive {
     /// Untap a permanent (rule 701.26)
     Untap,
 
+    // === Attachment (rule 701.3) ===
+    /// Attach the source permanent to the target (equip, reconfigure).
+    Attach,
+    /// Unattach the source permanent from whatever it's attached to.
+    Unattach,
+
+    // === Phasing (rule 702.26) ===
+    /// Phase out permanents; they phase in during their controller's next
+    /// untap step.
+    PhaseOut,
+
+    // === Double-faced cards (rule 712) ===
+    /// Transform permanents (rule 701.28); with no targets, the source
+    /// permanent transforms.
+    Transform,
+
+    // === Face-down permanents (rule 708) ===
+    /// Manifest the top N cards of the player's library, one at a time
+    /// (rule 701.40a).
+    Manifest(AmountExpr),
+    /// Cloak the top N cards of the player's library, one at a time (rule
+    /// 701.58a).
+    Cloak(AmountExpr),
+
+    // === Command zone (rules 114, 309, 724, 725) ===
+    /// "You get an emblem with [abilities]" (rule 114.1).
+    CreateEmblem(Vec<crate::objects::card_data::AbilityDef>),
+    /// "You become the monarch" (rule 724.1).
+    BecomeMonarch,
+    /// "You take the initiative" (rule 725.1).
+    TakeInitiative,
+    /// "Venture into the dungeon" (rule 701.49a).
+    Venture,
+    /// "Venture into [dungeon]" — Undercity, for the initiative (rule
+    /// 701.49c).
+    VentureInto(crate::types::card_types::DungeonType),
+
     // === Continuous effect primitives (applied via layer system) ===
     /// Set power/toughness to specific values (layer 7b)
     SetPowerToughness(AmountExpr, AmountExpr, Duration),
@@ -357,6 +430,46 @@ pub enum Primitive {
     /// Counter an activated or triggered ability on the stack (rule 701.6b).
     /// The countered ability ceases to exist — it is simply removed from the stack.
     CounterAbility,
+    /// Copy a spell or ability on the stack N times (rule 707.10) — the
+    /// targets, or with no target the spell a storm, replicate or casualty
+    /// trigger came from. The copies' controller may choose new targets.
+    CopySpell(AmountExpr),
+
+    // === Changing targets (rule 115.7) ===
+    /// Change the target of target spell or ability with a single target to
+    /// another legal one of this effect's controller's choice (rule 115.7a).
+    ChangeTarget,
+    /// Change a target of target spell or ability to this ability's source,
+    /// as Spellskite does — only if it's a legal target (rule 115.7a).
+    ChangeTargetToSource,
+    /// "You may choose new targets for target spell" (rule 115.7d).
+    ChooseNewTargets,
+
+    // === Casting without paying the mana cost (rule 118.9) ===
+    /// Cascade, from the trigger of a spell with mana value N (rule
+    /// 702.85a): exile cards from the top of your library until you exile a
+    /// nonland card with lesser mana value. You may cast it without paying
+    /// its mana cost; the rest go on the bottom in a random order.
+    Cascade(AmountExpr),
+    /// Discover N (rule 701.57a): exile cards from the top of your library
+    /// until you exile a nonland card with mana value N or less. Cast it
+    /// without paying its mana cost or put it into your hand; the rest go on
+    /// the bottom in a random order.
+    Discover(AmountExpr),
+    /// "You may cast spells from your hand without paying their mana
+    /// costs" — a static ability, as on Omniscience.
+    MayCastFromHandWithoutPaying,
+
+    // === Turn structure ===
+    /// "Take an extra turn after this one" (rule 500.7)
+    ExtraTurn,
+    /// "After this phase, there is an additional combat phase", optionally
+    /// "followed by an additional main phase" (rule 500.8)
+    AdditionalCombatPhase { followed_by_main: bool },
+    /// "Skip your next [step, phase or turn]" (rule 614.10)
+    Skip(SkipTarget),
+    /// "End the turn" (rule 723)
+    EndTheTurn,
 }
 
 // ---------------------------------------------------------------------------
@@ -406,3 +519,82 @@ pub enum Effect {
     // CreateDelayedTrigger(TriggerCondition, Box<Effect>, Duration),
     // Custom(CardId),  // escape hatch
 }
+
+// ---------------------------------------------------------------------------
+// Binding "you"
+// ---------------------------------------------------------------------------
+
+impl PermanentFilter {
+    /// Replace `PlayerRef::You` with the concrete player. "You" on a spell or
+    /// ability means its controller (rule 109.5), which is fixed once it is
+    /// put on the stack; target validation has no controller to ask.
+    pub fn bind_controller(&self, controller: PlayerId) -> PermanentFilter {
+        match self {
+            PermanentFilter::ByController(PlayerRef::You) => {
+                PermanentFilter::ByController(PlayerRef::Player(controller))
+            }
+            PermanentFilter::And(a, b) => PermanentFilter::And(
+                Box::new(a.bind_controller(controller)),
+                Box::new(b.bind_controller(controller)),
+            ),
+            PermanentFilter::Not(inner) => {
+                PermanentFilter::Not(Box::new(inner.bind_controller(controller)))
+            }
+            other => other.clone(),
+        }
+    }
+}
+
+impl SelectionFilter {
+    /// See `PermanentFilter::bind_controller`.
+    pub fn bind_controller(&self, controller: PlayerId) -> SelectionFilter {
+        match self {
+            SelectionFilter::Permanent(filter) => {
+                SelectionFilter::Permanent(filter.bind_controller(controller))
+            }
+            other => other.clone(),
+        }
+    }
+}
+
+impl EffectRecipient {
+    /// See `PermanentFilter::bind_controller`.
+    pub fn bind_controller(&self, controller: PlayerId) -> EffectRecipient {
+        match self {
+            EffectRecipient::Target(filter, count) => {
+                EffectRecipient::Target(filter.bind_controller(controller), *count)
+            }
+            EffectRecipient::Choose(filter, count) => {
+                EffectRecipient::Choose(filter.bind_controller(controller), *count)
+            }
+            other => other.clone(),
+        }
+    }
+}
+
+impl Effect {
+    /// Bind `PlayerRef::You` in every recipient of the effect tree. See
+    /// `PermanentFilter::bind_controller`.
+    pub fn bind_controller(&self, controller: PlayerId) -> Effect {
+        match self {
+            Effect::Atom(p, r) => Effect::Atom(p.clone(), r.bind_controller(controller)),
+            Effect::Sequence(effects) => {
+                Effect::Sequence(effects.iter().map(|e| e.bind_controller(controller)).collect())
+            }
+            Effect::Conditional(c, inner) => {
+                Effect::Conditional(c.clone(), Box::new(inner.bind_controller(controller)))
+            }
+            Effect::Optional(inner) => Effect::Optional(Box::new(inner.bind_controller(controller))),
+            Effect::Modal { count, modes } => Effect::Modal {
+                count: *count,
+                modes: modes.iter().map(|e| e.bind_controller(controller)).collect(),
+            },
+            Effect::ForEach(sel, inner) => {
+                Effect::ForEach(sel.clone(), Box::new(inner.bind_controller(controller)))
+            }
+            Effect::Repeat(n, inner) => {
+                Effect::Repeat(n.clone(), Box::new(inner.bind_controller(controller)))
+            }
+        }
+    }
+}
@@ -5,8 +5,25 @@
 /// (via continuous effects like "creatures you control have flying").
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum KeywordAbility {
+    /// When cast, exiles cards from the top of its controller's library
+    /// until a nonland card with lesser mana value, which they may cast
+    /// without paying its mana cost (rule 702.85).
+    Cascade,
+    /// Front face of a day/night double-faced card: transforms as it
+    /// becomes night (rule 702.145b).
+    Daybound,
+    /// Its controller may tap creatures to pay for generic mana or mana of
+    /// their colors in its total cost (rule 702.51).
+    Convoke,
     Deathtouch,
     Defender,
+    /// Its controller may exile cards from their graveyard to pay for
+    /// generic mana in its total cost (rule 702.66).
+    Delve,
+    /// Can be cast face down for {3}, with ward {2} while face down, and
+    /// turned face up for its disguise cost (rule 702.168). The cost is in
+    /// `CardData::morph`.
+    Disguise,
     DoubleStrike,
     Enchant, // parameterized in the ability definition, not here
     Equip,   // parameterized in the ability definition, not here
@@ -15,16 +32,42 @@ pub enum KeywordAbility {
     Flying,
     Haste,
     Hexproof,
+    /// Its controller may tap artifacts to pay for generic mana in its total
+    /// cost (rule 702.126).
+    Improvise,
     Indestructible,
+    /// Damage to creatures is dealt as -1/-1 counters, to players as poison
+    /// counters (rule 702.90).
+    Infect,
     Intimidate,
     Landwalk, // parameterized by land type in ability definition
     Lifelink,
+    /// Morph, with a +1/+1 counter as it's turned face up (rule 702.37b).
+    /// The cost is in `CardData::morph`.
+    Megamorph,
     Menace,
+    /// Can be cast face down for {3} and turned face up for its morph cost
+    /// (rule 702.37a). The cost is in `CardData::morph`.
+    Morph,
+    /// Back face of a day/night double-faced card: transforms as it becomes
+    /// day (rule 702.145d).
+    Nightbound,
+    /// Phases in or out during its controller's untap step (rule 702.26).
+    Phasing,
     Protection, // parameterized by quality in ability definition
     Reach,
+    Reconfigure, // parameterized in the ability definition, not here
     Shroud,
+    /// When cast, it's copied for each other spell cast before it this turn
+    /// (rule 702.40).
+    Storm,
+    /// Toxic N (rule 702.164). Instances are cumulative: a creature's total
+    /// toxic value is the sum of N over all its toxic abilities.
+    Toxic(u32),
     Trample,
     Vigilance,
     Ward, // parameterized by cost in ability definition
+    /// Damage to creatures is dealt as -1/-1 counters (rule 702.80).
+    Wither,
     // Add more as needed — this covers the most common ones
 }
@@ -14,12 +14,15 @@ use std::collections::HashMap;
 
 use crate::engine::resolve::ResolvedTarget;
 use crate::events::event::DamageTarget;
+use crate::objects::card_data::CardFace;
 use crate::state::battlefield::AttackTarget;
-use crate::state::game_state::GameState;
+use crate::state::game_state::{GameState, TriggerSource};
+use crate::types::card_types::DungeonType;
 use crate::types::costs::{AdditionalCost, AlternativeCost};
 use crate::types::effects::EffectRecipient;
 use crate::types::ids::{AbilityId, ObjectId, PlayerId};
 use crate::types::mana::{ManaCost, ManaType};
+use crate::types::zones::Zone;
 
 use super::choice_types::{ChoiceContext, ChoiceKind, ChoiceOption};
 use super::decision::{DecisionProvider, PriorityAction};
@@ -155,7 +158,6 @@ fn validate_allocation(
 /// Checks length, index range, and uniqueness. By the pigeonhole principle,
 /// N unique values each in [0, N) IS a permutation of 0..N, so no explicit
 /// "sequential" check is needed.
-#[allow(dead_code)]
 fn validate_ordering(order: &[usize], items_len: usize, context_desc: &str) {
     assert_eq!(
         order.len(),
@@ -185,6 +187,91 @@ fn validate_ordering(order: &[usize], items_len: usize, context_desc: &str) {
     }
 }
 
+// ===========================================================================
+// Game Setup (103.5)
+// ===========================================================================
+
+/// Whether the player mulligans their current opening hand. Picking the
+/// single option mulligans; an empty pick keeps.
+pub fn ask_mulligan(
+    dp: &dyn DecisionProvider,
+    game: &GameState,
+    player: PlayerId,
+    mulligans_taken: u32,
+) -> bool {
+    let options = vec![ChoiceOption::Player(player)];
+    let ctx = ChoiceContext {
+        kind: ChoiceKind::Mulligan { mulligans_taken },
+    };
+    let indices = dp.pick_n(game, player, &ctx, &options, (0, 1));
+    validate_pick_n(&indices, options.len(), (0, 1), "mulligan");
+    !indices.is_empty()
+}
+
+/// Choose `count` cards from `hand` to put on the bottom of the library
+/// (London mulligan, rule 103.5), then their order. The cards go to the
+/// bottom one at a time in the returned order, so the last is bottom-most.
+pub fn ask_bottom_cards(
+    dp: &dyn DecisionProvider,
+    game: &GameState,
+    player: PlayerId,
+    hand: &[ObjectId],
+    count: usize,
+) -> Vec<ObjectId> {
+    let count = count.min(hand.len());
+    if count == 0 {
+        return Vec::new();
+    }
+    let options: Vec<ChoiceOption> = hand.iter().map(|id| ChoiceOption::Object(*id)).collect();
+    let ctx = ChoiceContext {
+        kind: ChoiceKind::BottomCards { count },
+    };
+    let indices = dp.pick_n(game, player, &ctx, &options, (count, count));
+    validate_pick_n(&indices, options.len(), (count, count), "bottom_cards");
+    let chosen: Vec<ObjectId> = indices.iter().map(|&i| hand[i]).collect();
+    order_objects(dp, game, player, &ctx, chosen, "bottom_cards")
+}
+
+/// Choose which of the cards in `candidates` (cards in the opening hand with
+/// an opening-hand action) to use, in the order the actions are taken
+/// (rule 103.6). Any number may be chosen, including none.
+pub fn ask_opening_hand_actions(
+    dp: &dyn DecisionProvider,
+    game: &GameState,
+    player: PlayerId,
+    candidates: &[ObjectId],
+) -> Vec<ObjectId> {
+    if candidates.is_empty() {
+        return Vec::new();
+    }
+    let options: Vec<ChoiceOption> = candidates.iter().map(|id| ChoiceOption::Object(*id)).collect();
+    let ctx = ChoiceContext {
+        kind: ChoiceKind::OpeningHandActions,
+    };
+    let indices = dp.pick_n(game, player, &ctx, &options, (0, candidates.len()));
+    validate_pick_n(&indices, options.len(), (0, candidates.len()), "opening_hand_actions");
+    let chosen: Vec<ObjectId> = indices.iter().map(|&i| candidates[i]).collect();
+    order_objects(dp, game, player, &ctx, chosen, "opening_hand_actions")
+}
+
+/// Ask the player to order `ids` (no prompt when there's nothing to order).
+fn order_objects(
+    dp: &dyn DecisionProvider,
+    game: &GameState,
+    player: PlayerId,
+    ctx: &ChoiceContext,
+    ids: Vec<ObjectId>,
+    context_desc: &str,
+) -> Vec<ObjectId> {
+    if ids.len() < 2 {
+        return ids;
+    }
+    let items: Vec<ChoiceOption> = ids.iter().map(|id| ChoiceOption::Object(*id)).collect();
+    let order = dp.choose_ordering(game, player, ctx, &items);
+    validate_ordering(&order, items.len(), context_desc);
+    order.into_iter().map(|i| ids[i]).collect()
+}
+
 // ===========================================================================
 // Priority & Turn Structure
 // ===========================================================================
@@ -372,6 +459,83 @@ pub fn ask_choose_x_value(
     value
 }
 
+/// Choose how many times to pay a spell's replicate cost (rule 702.56a).
+/// Like X, affordability is enforced by the casting pipeline's rollback.
+pub fn ask_choose_replicate_count(
+    dp: &dyn DecisionProvider,
+    game: &GameState,
+    player: PlayerId,
+    spell_id: ObjectId,
+) -> u64 {
+    let ctx = ChoiceContext {
+        kind: ChoiceKind::ReplicateCount { spell_id },
+    };
+    let value = dp.pick_number(game, player, &ctx, 1, u64::MAX);
+    validate_pick_number(value, 1, u64::MAX, "choose_replicate_count");
+    value
+}
+
+/// Choose which of `candidates` to sacrifice to pay a spell's casualty
+/// cost (rule 702.153a).
+pub fn ask_choose_sacrifice(
+    dp: &dyn DecisionProvider,
+    game: &GameState,
+    player: PlayerId,
+    spell_id: ObjectId,
+    candidates: &[ObjectId],
+) -> ObjectId {
+    assert!(!candidates.is_empty(), "ask_choose_sacrifice: no candidates provided");
+    let options: Vec<ChoiceOption> = candidates.iter().map(|&id| ChoiceOption::Object(id)).collect();
+    let ctx = ChoiceContext {
+        kind: ChoiceKind::ChooseSacrifice { spell_id },
+    };
+    let index = dp.pick_n(game, player, &ctx, &options, (1, 1));
+    validate_pick_n(&index, options.len(), (1, 1), "choose_sacrifice");
+    candidates[index[0]]
+}
+
+/// Whether `player` casts `card_id` without paying its mana cost, as
+/// cascade, discover and a defeated Siege let them (rule 118.9). Picking the card casts it;
+/// an empty pick doesn't.
+pub fn ask_cast_without_paying(
+    dp: &dyn DecisionProvider,
+    game: &GameState,
+    player: PlayerId,
+    card_id: ObjectId,
+) -> bool {
+    let options = vec![ChoiceOption::Object(card_id)];
+    let ctx = ChoiceContext {
+        kind: ChoiceKind::CastWithoutPaying { card_id },
+    };
+    let indices = dp.pick_n(game, player, &ctx, &options, (0, 1));
+    validate_pick_n(&indices, options.len(), (0, 1), "cast_without_paying");
+    !indices.is_empty()
+}
+
+/// Choose which face of a modal double-faced card to cast or play (rule
+/// 712.12) — or which half of a split or adventurer card to cast (rules
+/// 709.3, 715.3), or whether to cast a morph or disguise card face down
+/// (rules 702.37c, 702.168a) — from the `faces` it can be used as right now.
+pub fn ask_choose_face(
+    dp: &dyn DecisionProvider,
+    game: &GameState,
+    player: PlayerId,
+    card_id: ObjectId,
+    faces: &[CardFace],
+) -> CardFace {
+    assert!(!faces.is_empty(), "ask_choose_face: no faces provided");
+    if faces.len() == 1 {
+        return faces[0];
+    }
+    let options: Vec<ChoiceOption> = faces.iter().map(|&f| ChoiceOption::Face(f)).collect();
+    let ctx = ChoiceContext {
+        kind: ChoiceKind::ChooseFace { card_id },
+    };
+    let index = dp.pick_n(game, player, &ctx, &options, (1, 1));
+    validate_pick_n(&index, options.len(), (1, 1), "choose_face");
+    faces[index[0]]
+}
+
 /// Choose an alternative cost (rule 118.9).
 /// Returns `None` for normal cost, or `Some(index)` for chosen alt cost.
 pub fn ask_choose_alternative_cost(
@@ -443,22 +607,74 @@ pub fn ask_select_recipients(
     min_selections: usize,
     max_selections: usize,
 ) -> Vec<ResolvedTarget> {
-    if legal_selections.is_empty() {
+    let kind = ChoiceKind::SelectRecipients {
+        recipient: recipient.clone(),
+        spell_id,
+    };
+    pick_recipients(dp, game, player, kind, legal_selections, min_selections, max_selections)
+}
+
+/// "You may choose new targets" for a spell, ability or copy (rules 115.7d,
+/// 707.10c). Its `current` targets are offered first and stay selectable
+/// even if they've become illegal; picking all of them keeps the targets
+/// unchanged. The number of targets can't change.
+pub fn ask_choose_new_targets(
+    dp: &dyn DecisionProvider,
+    game: &GameState,
+    player: PlayerId,
+    recipient: &EffectRecipient,
+    spell_id: ObjectId,
+    current: &[ResolvedTarget],
+    legal_selections: &[ResolvedTarget],
+) -> Vec<ResolvedTarget> {
+    let mut options = current.to_vec();
+    options.extend(legal_selections.iter().filter(|t| !current.contains(t)));
+    let kind = ChoiceKind::ChooseNewTargets {
+        recipient: recipient.clone(),
+        spell_id,
+    };
+    pick_recipients(dp, game, player, kind, &options, current.len(), current.len())
+}
+
+/// "Change the target" of a spell or ability (rule 115.7a): pick one of
+/// `options` — the legal new targets, or the targets that could be changed
+/// to a specific object.
+pub fn ask_change_target(
+    dp: &dyn DecisionProvider,
+    game: &GameState,
+    player: PlayerId,
+    recipient: &EffectRecipient,
+    spell_id: ObjectId,
+    options: &[ResolvedTarget],
+) -> ResolvedTarget {
+    assert!(!options.is_empty(), "ask_change_target: no options provided");
+    let kind = ChoiceKind::ChangeTarget {
+        recipient: recipient.clone(),
+        spell_id,
+    };
+    pick_recipients(dp, game, player, kind, options, 1, 1)[0]
+}
+
+fn pick_recipients(
+    dp: &dyn DecisionProvider,
+    game: &GameState,
+    player: PlayerId,
+    kind: ChoiceKind,
+    selections: &[ResolvedTarget],
+    min_selections: usize,
+    max_selections: usize,
+) -> Vec<ResolvedTarget> {
+    if selections.is_empty() {
         return Vec::new();
     }
-    let options: Vec<ChoiceOption> = legal_selections
+    let options: Vec<ChoiceOption> = selections
         .iter()
         .map(|t| match t {
             ResolvedTarget::Object(id) => ChoiceOption::Object(*id),
             ResolvedTarget::Player(id) => ChoiceOption::Player(*id),
         })
         .collect();
-    let ctx = ChoiceContext {
-        kind: ChoiceKind::SelectRecipients {
-            recipient: recipient.clone(),
-            spell_id,
-        },
-    };
+    let ctx = ChoiceContext { kind };
     let indices = dp.pick_n(game, player, &ctx, &options, (min_selections, max_selections));
     validate_pick_n(
         &indices,
@@ -466,7 +682,7 @@ pub fn ask_select_recipients(
         (min_selections, max_selections),
         "select_recipients",
     );
-    indices.iter().map(|&i| legal_selections[i]).collect()
+    indices.iter().map(|&i| selections[i]).collect()
 }
 
 /// Prompt the casting/activating player for a mana ability to activate
@@ -516,6 +732,71 @@ pub fn ask_activate_mana_ability(
     }
 }
 
+/// Convoke (rule 702.51a): choose which of the untapped creatures
+/// `candidates` to tap for spell `spell_id`, at most `max` of them.
+pub fn ask_convoke(
+    dp: &dyn DecisionProvider,
+    game: &GameState,
+    player: PlayerId,
+    spell_id: ObjectId,
+    remaining_cost: &ManaCost,
+    candidates: &[ObjectId],
+    max: usize,
+) -> Vec<ObjectId> {
+    let kind = ChoiceKind::Convoke { spell_id, remaining_cost: remaining_cost.clone() };
+    pick_mana_substitutes(dp, game, player, kind, candidates, max, "convoke")
+}
+
+/// Improvise (rule 702.126a): choose which of the untapped artifacts
+/// `candidates` to tap for spell `spell_id`, at most `max` of them.
+pub fn ask_improvise(
+    dp: &dyn DecisionProvider,
+    game: &GameState,
+    player: PlayerId,
+    spell_id: ObjectId,
+    remaining_cost: &ManaCost,
+    candidates: &[ObjectId],
+    max: usize,
+) -> Vec<ObjectId> {
+    let kind = ChoiceKind::Improvise { spell_id, remaining_cost: remaining_cost.clone() };
+    pick_mana_substitutes(dp, game, player, kind, candidates, max, "improvise")
+}
+
+/// Delve (rule 702.66a): choose which of the graveyard cards `candidates`
+/// to exile for spell `spell_id`, at most `max` of them.
+pub fn ask_delve(
+    dp: &dyn DecisionProvider,
+    game: &GameState,
+    player: PlayerId,
+    spell_id: ObjectId,
+    remaining_cost: &ManaCost,
+    candidates: &[ObjectId],
+    max: usize,
+) -> Vec<ObjectId> {
+    let kind = ChoiceKind::Delve { spell_id, remaining_cost: remaining_cost.clone() };
+    pick_mana_substitutes(dp, game, player, kind, candidates, max, "delve")
+}
+
+fn pick_mana_substitutes(
+    dp: &dyn DecisionProvider,
+    game: &GameState,
+    player: PlayerId,
+    kind: ChoiceKind,
+    candidates: &[ObjectId],
+    max: usize,
+    label: &str,
+) -> Vec<ObjectId> {
+    let max = max.min(candidates.len());
+    if max == 0 {
+        return Vec::new();
+    }
+    let options: Vec<ChoiceOption> = candidates.iter().map(|&id| ChoiceOption::Object(id)).collect();
+    let ctx = ChoiceContext { kind };
+    let indices = dp.pick_n(game, player, &ctx, &options, (0, max));
+    validate_pick_n(&indices, options.len(), (0, max), label);
+    indices.into_iter().map(|i| candidates[i]).collect()
+}
+
 /// Choose how to allocate mana from the pool to pay generic mana.
 /// Returns a map of ManaType → amount.
 pub fn ask_choose_generic_mana_allocation(
@@ -561,6 +842,38 @@ pub fn ask_choose_generic_mana_allocation(
         .collect()
 }
 
+// ===========================================================================
+// Keyword Actions
+// ===========================================================================
+
+/// Scry (rule 701.22): of `cards` (top of library first), choose which go
+/// to the bottom, then order each pile. Returns `(top, bottom)`: `top` is
+/// listed topmost first; `bottom` goes under the library one at a time in
+/// order, so its last card is bottom-most.
+pub fn ask_scry(
+    dp: &dyn DecisionProvider,
+    game: &GameState,
+    player: PlayerId,
+    cards: &[ObjectId],
+) -> (Vec<ObjectId>, Vec<ObjectId>) {
+    if cards.is_empty() {
+        return (Vec::new(), Vec::new());
+    }
+    let options: Vec<ChoiceOption> = cards.iter().map(|id| ChoiceOption::Object(*id)).collect();
+    let ctx = ChoiceContext {
+        kind: ChoiceKind::Scry { count: cards.len() },
+    };
+    let indices = dp.pick_n(game, player, &ctx, &options, (0, cards.len()));
+    validate_pick_n(&indices, options.len(), (0, cards.len()), "scry");
+    let (top, bottom): (Vec<ObjectId>, Vec<ObjectId>) = (
+        (0..cards.len()).filter(|i| !indices.contains(i)).map(|i| cards[i]).collect(),
+        indices.iter().map(|&i| cards[i]).collect(),
+    );
+    let top = order_objects(dp, game, player, &ctx, top, "scry");
+    let bottom = order_objects(dp, game, player, &ctx, bottom, "scry");
+    (top, bottom)
+}
+
 // ===========================================================================
 // State-Based & Cleanup
 // ===========================================================================
@@ -611,6 +924,123 @@ pub fn ask_choose_legend_to_keep(
     legendaries[index[0]]
 }
 
+/// Choose the protector of a battle (rule 310.8a). `candidates` is the
+/// eligible players from `GameState::battle_protector_candidates`.
+/// Skips the prompt when there is only one candidate.
+pub fn ask_choose_protector(
+    dp: &dyn DecisionProvider,
+    game: &GameState,
+    player: PlayerId,
+    battle_id: ObjectId,
+    candidates: &[PlayerId],
+) -> PlayerId {
+    assert!(
+        !candidates.is_empty(),
+        "ask_choose_protector: no candidates provided"
+    );
+    if candidates.len() == 1 {
+        return candidates[0];
+    }
+    let options: Vec<ChoiceOption> = candidates
+        .iter()
+        .map(|pid| ChoiceOption::Player(*pid))
+        .collect();
+    let ctx = ChoiceContext {
+        kind: ChoiceKind::ChooseProtector { battle_id },
+    };
+    let index = dp.pick_n(game, player, &ctx, &options, (1, 1));
+    validate_pick_n(&index, options.len(), (1, 1), "choose_protector");
+    candidates[index[0]]
+}
+
+/// Whether a commander's owner puts it into the command zone rather than
+/// `destination` (rule 903.9). Picking the commander moves it; an empty pick
+/// leaves it where it's going.
+pub fn ask_commander_to_command_zone(
+    dp: &dyn DecisionProvider,
+    game: &GameState,
+    owner: PlayerId,
+    commander_id: ObjectId,
+    destination: Zone,
+) -> bool {
+    let options = vec![ChoiceOption::Object(commander_id)];
+    let ctx = ChoiceContext {
+        kind: ChoiceKind::CommanderToCommandZone { commander_id, destination },
+    };
+    let indices = dp.pick_n(game, owner, &ctx, &options, (0, 1));
+    validate_pick_n(&indices, options.len(), (0, 1), "commander_to_command_zone");
+    !indices.is_empty()
+}
+
+/// Choose a dungeon to venture into (rule 701.49a) from `dungeons`.
+/// Skips the prompt when there is only one.
+pub fn ask_choose_dungeon(
+    dp: &dyn DecisionProvider,
+    game: &GameState,
+    player: PlayerId,
+    dungeons: &[DungeonType],
+) -> DungeonType {
+    assert!(!dungeons.is_empty(), "ask_choose_dungeon: no dungeons provided");
+    if dungeons.len() == 1 {
+        return dungeons[0];
+    }
+    let options: Vec<ChoiceOption> = dungeons.iter().map(|&d| ChoiceOption::Dungeon(d)).collect();
+    let ctx = ChoiceContext { kind: ChoiceKind::ChooseDungeon };
+    let index = dp.pick_n(game, player, &ctx, &options, (1, 1));
+    validate_pick_n(&index, options.len(), (1, 1), "choose_dungeon");
+    dungeons[index[0]]
+}
+
+/// Choose the room a venture marker moves to (rule 701.49b) from `rooms`,
+/// the indices an arrow from its current room leads to. Skips the prompt
+/// when there is only one.
+pub fn ask_choose_dungeon_room(
+    dp: &dyn DecisionProvider,
+    game: &GameState,
+    player: PlayerId,
+    dungeon_id: ObjectId,
+    rooms: &[usize],
+) -> usize {
+    assert!(!rooms.is_empty(), "ask_choose_dungeon_room: no rooms provided");
+    if rooms.len() == 1 {
+        return rooms[0];
+    }
+    let options: Vec<ChoiceOption> = rooms.iter().map(|&r| ChoiceOption::DungeonRoom(r)).collect();
+    let ctx = ChoiceContext { kind: ChoiceKind::ChooseDungeonRoom { dungeon_id } };
+    let index = dp.pick_n(game, player, &ctx, &options, (1, 1));
+    validate_pick_n(&index, options.len(), (1, 1), "choose_dungeon_room");
+    rooms[index[0]]
+}
+
+// ===========================================================================
+// Triggered Abilities (603)
+// ===========================================================================
+
+/// Order the triggered abilities `player` controls that triggered at once,
+/// given by their `sources`, as they put them on the stack (rule 603.3b).
+/// Returns indices into `sources`, the first to go on the stack first. No
+/// prompt when there's nothing to order.
+pub fn ask_order_triggers(
+    dp: &dyn DecisionProvider,
+    game: &GameState,
+    player: PlayerId,
+    sources: &[TriggerSource],
+) -> Vec<usize> {
+    if sources.len() < 2 {
+        return (0..sources.len()).collect();
+    }
+    let items: Vec<ChoiceOption> = sources.iter()
+        .map(|source| match *source {
+            TriggerSource::Object(id) => ChoiceOption::Object(id),
+            TriggerSource::Designation(designation) => ChoiceOption::Designation(designation),
+        })
+        .collect();
+    let ctx = ChoiceContext { kind: ChoiceKind::OrderTriggers };
+    let order = dp.choose_ordering(game, player, &ctx, &items);
+    validate_ordering(&order, items.len(), "order_triggers");
+    order
+}
+
 // ===========================================================================
 // Tests
 // ===========================================================================
@@ -765,6 +1195,33 @@ mod tests {
         assert_eq!(result, 3);
     }
 
+    // --- ask_bottom_cards / ask_scry roundtrip ---
+
+    #[test]
+    fn test_ask_bottom_cards_picks_then_orders() {
+        let dp = ScriptedDecisionProvider::new();
+        let game = test_game_state();
+        let hand: Vec<ObjectId> = (0..4).map(|_| crate::types::ids::new_object_id()).collect();
+        dp.expect_pick_n(ChoiceKind::BottomCards { count: 2 }, vec![1, 3]);
+        dp.expect_ordering(ChoiceKind::BottomCards { count: 2 }, vec![1, 0]);
+        assert_eq!(ask_bottom_cards(&dp, &game, 0, &hand, 2), vec![hand[3], hand[1]]);
+
+        // Nothing to put on the bottom: no prompt.
+        assert!(ask_bottom_cards(&dp, &game, 0, &hand, 0).is_empty());
+    }
+
+    #[test]
+    fn test_ask_scry_splits_piles() {
+        let dp = ScriptedDecisionProvider::new();
+        let game = test_game_state();
+        let cards: Vec<ObjectId> = (0..3).map(|_| crate::types::ids::new_object_id()).collect();
+        dp.expect_pick_n(ChoiceKind::Scry { count: 3 }, vec![1]);
+        dp.expect_ordering(ChoiceKind::Scry { count: 3 }, vec![1, 0]);
+        let (top, bottom) = ask_scry(&dp, &game, 0, &cards);
+        assert_eq!(top, vec![cards[2], cards[0]]);
+        assert_eq!(bottom, vec![cards[1]]);
+    }
+
     // --- Validation tests ---
 
     #[test]
@@ -873,6 +1330,46 @@ mod tests {
         assert!(matches!(result1, PriorityAction::PlayLand(_)));
     }
 
+    #[test]
+    fn test_dispatch_routes_by_team() {
+        use crate::ui::decision::DispatchDecisionProvider;
+
+        let team_a = ScriptedDecisionProvider::new();
+        let team_b = ScriptedDecisionProvider::new();
+        team_a.expect_pick_n(ChoiceKind::PriorityAction, vec![0]);
+        team_a.expect_pick_n(ChoiceKind::PriorityAction, vec![0]);
+        team_b.expect_pick_n(ChoiceKind::PriorityAction, vec![1]);
+
+        let teams = vec![vec![0, 1], vec![2, 3]];
+        let dispatch = DispatchDecisionProvider::for_teams(
+            vec![Box::new(team_a), Box::new(team_b)],
+            &teams,
+        ).unwrap();
+
+        let game = test_game_state();
+        let actions = vec![
+            PriorityAction::Pass,
+            PriorityAction::PlayLand(crate::types::ids::new_object_id()),
+        ];
+
+        // Players 0 and 1 share team A's provider; player 3 uses team B's
+        assert!(matches!(ask_choose_priority_action(&dispatch, &game, 0, &actions), PriorityAction::Pass));
+        assert!(matches!(ask_choose_priority_action(&dispatch, &game, 1, &actions), PriorityAction::Pass));
+        assert!(matches!(ask_choose_priority_action(&dispatch, &game, 3, &actions), PriorityAction::PlayLand(_)));
+    }
+
+    #[test]
+    fn test_dispatch_for_teams_rejects_mismatched_providers() {
+        use crate::ui::decision::DispatchDecisionProvider;
+
+        let teams = vec![vec![0, 1], vec![2, 3]];
+        let result = DispatchDecisionProvider::for_teams(
+            vec![Box::new(ScriptedDecisionProvider::new())],
+            &teams,
+        );
+        assert!(result.is_err());
+    }
+
     // --- RandomDecisionProvider generic trait ---
 
     #[test]
@@ -1,10 +1,14 @@
 use crate::events::event::DamageTarget;
+use crate::objects::card_data::CardFace;
 use crate::state::battlefield::AttackTarget;
+use crate::state::game_state::Designation;
+use crate::types::card_types::DungeonType;
 use crate::types::colors::Color;
 use crate::types::costs::{AdditionalCost, AlternativeCost};
 use crate::types::effects::{CounterType, EffectRecipient};
 use crate::types::ids::{ObjectId, PlayerId};
 use crate::types::mana::{ManaCost, ManaType};
+use crate::types::zones::Zone;
 
 use super::decision::PriorityAction;
 
@@ -21,6 +25,17 @@ use super::decision::PriorityAction;
 /// matching ensures every DP impl gets updated at compile time.
 #[derive(Debug, Clone)]
 pub enum ChoiceKind {
+    // --- Game Setup (103.5) ---
+    /// Keep the current opening hand, or mulligan it; `mulligans_taken`
+    /// counts the player's mulligans so far.
+    Mulligan { mulligans_taken: u32 },
+    /// London mulligan — choose `count` cards from the kept hand to put on
+    /// the bottom of the library, then the order they go there in.
+    BottomCards { count: usize },
+    /// 103.6 — choose which opening-hand actions to take (begin the game
+    /// with a Leyline on the battlefield, reveal a Chancellor), in order.
+    OpeningHandActions,
+
     // --- Priority & Turn Structure ---
     PriorityAction,
 
@@ -31,12 +46,31 @@ pub enum ChoiceKind {
     AssignTrampleDamage { attacker_id: ObjectId, defending_target: DamageTarget },
 
     // --- Casting Pipeline (601.2) ---
+    /// 712.12 — choose which face of a modal double-faced card to cast or
+    /// play; 709.3, 715.3 — which half of a split or adventurer card to
+    /// cast; 702.37c — whether to cast a morph card face down.
+    ChooseFace { card_id: ObjectId },
     ChooseXValue { spell_id: ObjectId, x_count: u64 },
     ChooseAlternativeCost,
     ChooseAdditionalCosts,
     /// Select recipients for an effect (covers both MTG "target" and non-targeting
     /// "choose" — the `EffectRecipient` field distinguishes them).
     SelectRecipients { recipient: EffectRecipient, spell_id: ObjectId },
+    /// 115.7d, 707.10c — "you may choose new targets" for `spell_id`, a
+    /// spell, ability or copy: keep or change each of its targets.
+    ChooseNewTargets { recipient: EffectRecipient, spell_id: ObjectId },
+    /// 115.7a — "change the target" of `spell_id`: the new target, or for a
+    /// change to a specific object, which of its targets is changed.
+    ChangeTarget { recipient: EffectRecipient, spell_id: ObjectId },
+    /// 702.56a — how many times to pay a spell's replicate cost.
+    ReplicateCount { spell_id: ObjectId },
+    /// 702.153a, 702.119a — the creature to sacrifice for a spell's
+    /// casualty or emerge cost.
+    ChooseSacrifice { spell_id: ObjectId },
+    /// 118.9 — whether to cast `card_id` without paying its mana cost, as
+    /// cascade or discover offers; discover puts it into its owner's hand
+    /// if not.
+    CastWithoutPaying { card_id: ObjectId },
     GenericManaAllocation { mana_cost: ManaCost },
     /// 601.2g / 602.1b — "mana ability window" inside spell cast or ability
     /// activation. The player may activate mana abilities (rule 605) to cover
@@ -54,10 +88,48 @@ pub enum ChoiceKind {
     ///   for other spells like improvise or landfall triggers)
     /// - Generic vs colored ordering with mixed mana producers
     ManaAbilityWindow { spell_or_ability_id: ObjectId, remaining_cost: ManaCost },
+    /// 702.51a — convoke: the untapped creatures to tap for `spell_id`,
+    /// each paying for one generic mana or one mana of its color in
+    /// `remaining_cost`. Asked in the 601.2g window, before mana abilities.
+    Convoke { spell_id: ObjectId, remaining_cost: ManaCost },
+    /// 702.126a — improvise: the untapped artifacts to tap, each paying for
+    /// one generic mana.
+    Improvise { spell_id: ObjectId, remaining_cost: ManaCost },
+    /// 702.66a — delve: the cards to exile from the graveyard, each paying
+    /// for one generic mana.
+    Delve { spell_id: ObjectId, remaining_cost: ManaCost },
+
+    // --- Keyword Actions ---
+    /// 701.22 — scry: choose which of the looked-at cards go to the bottom,
+    /// then the order of each pile.
+    Scry { count: usize },
 
     // --- State-Based & Cleanup ---
     DiscardToHandSize,
     LegendRule { legend_name: String },
+
+    // --- Battles ---
+    /// 310.8a — as a battle enters (or per SBA 704.5w/x), its controller
+    /// chooses which player protects it.
+    ChooseProtector { battle_id: ObjectId },
+
+    // --- Dungeons ---
+    /// 701.49a — venturing with no dungeon in the command zone: choose a
+    /// dungeon to put there.
+    ChooseDungeon,
+    /// 701.49b — venturing: choose the room the venture marker moves to,
+    /// among those an arrow from its current room leads to.
+    ChooseDungeonRoom { dungeon_id: ObjectId },
+
+    // --- Triggered Abilities ---
+    /// 603.3b — the order to put the triggered abilities a player controls
+    /// that triggered at once on the stack, first to last.
+    OrderTriggers,
+
+    // --- Commander ---
+    /// 903.9 — a commander would be put into (or was just put into)
+    /// `destination`; its owner may put it into the command zone instead.
+    CommanderToCommandZone { commander_id: ObjectId, destination: Zone },
 }
 
 /// Wrapper carrying the semantic kind. No display text — each DP impl formats
@@ -95,4 +167,13 @@ pub enum ChoiceOption {
     CounterType(CounterType),
     /// A mana type (for generic allocation)
     ManaType(ManaType),
+    /// A face of a double-faced card, a half of a split or adventurer
+    /// card, or face down
+    Face(CardFace),
+    /// A dungeon card to venture into
+    Dungeon(DungeonType),
+    /// A dungeon room, by its index in the dungeon's rooms
+    DungeonRoom(usize),
+    /// A designation, as the source of its inherent triggered abilities
+    Designation(Designation),
 }
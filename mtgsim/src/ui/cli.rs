@@ -90,12 +90,36 @@ impl DecisionProvider for CliDecisionProvider {
         bounds: (usize, usize),
     ) -> Vec<usize> {
         let prompt = match &context.kind {
+            ChoiceKind::Mulligan { mulligans_taken } => {
+                format!(
+                    "Mulligans taken: {}. Pick 0 to mulligan this hand, or leave blank to keep:",
+                    mulligans_taken
+                )
+            }
+            ChoiceKind::BottomCards { count } => {
+                format!("Choose {} card(s) to put on the bottom of your library:", count)
+            }
+            ChoiceKind::Scry { count } => {
+                format!("Scry {}: choose cards to put on the bottom (or none):", count)
+            }
+            ChoiceKind::OpeningHandActions => {
+                "Opening hand: choose cards to use before the game begins (or none):".to_string()
+            }
             ChoiceKind::PriorityAction => "Choose action:".to_string(),
             ChoiceKind::DeclareAttackers => "Choose attackers (indices):".to_string(),
             ChoiceKind::DeclareBlockers => "Choose blockers (indices):".to_string(),
+            ChoiceKind::ChooseFace { .. } => "Choose which face or half to use:".to_string(),
             ChoiceKind::ChooseAlternativeCost => "Choose cost (0=normal, 1+=alternative):".to_string(),
             ChoiceKind::ChooseAdditionalCosts => "Choose additional costs (indices, or none):".to_string(),
             ChoiceKind::SelectRecipients { .. } => "Choose targets (indices):".to_string(),
+            ChoiceKind::ChooseNewTargets { .. } => {
+                "Choose new targets (pick the current ones to keep them):".to_string()
+            }
+            ChoiceKind::ChangeTarget { .. } => "Change the target to:".to_string(),
+            ChoiceKind::ChooseSacrifice { .. } => "Choose a creature to sacrifice:".to_string(),
+            ChoiceKind::CastWithoutPaying { .. } => {
+                "Pick the card to cast it without paying its mana cost, or leave blank:".to_string()
+            }
             ChoiceKind::DiscardToHandSize => "Choose card to discard:".to_string(),
             ChoiceKind::ManaAbilityWindow { remaining_cost, .. } => {
                 format!(
@@ -104,9 +128,29 @@ impl DecisionProvider for CliDecisionProvider {
                     remaining_cost
                 )
             }
+            ChoiceKind::Convoke { remaining_cost, .. } => {
+                format!("Convoke: choose creatures to tap toward {} (or none):", remaining_cost)
+            }
+            ChoiceKind::Improvise { remaining_cost, .. } => {
+                format!("Improvise: choose artifacts to tap toward {} (or none):", remaining_cost)
+            }
+            ChoiceKind::Delve { remaining_cost, .. } => {
+                format!("Delve: choose cards to exile toward {} (or none):", remaining_cost)
+            }
             ChoiceKind::LegendRule { legend_name } => {
                 format!("Legend rule: choose which '{}' to keep:", legend_name)
             }
+            ChoiceKind::ChooseProtector { .. } => "Choose the battle's protector:".to_string(),
+            ChoiceKind::ChooseDungeon => "Choose a dungeon to venture into:".to_string(),
+            ChoiceKind::ChooseDungeonRoom { .. } => "Choose the next room:".to_string(),
+            ChoiceKind::OrderTriggers => "Order your triggered abilities:".to_string(),
+            ChoiceKind::CommanderToCommandZone { destination, .. } => {
+                format!(
+                    "Your commander is going to {:?}: pick it to put it into the \
+                     command zone instead, or leave blank:",
+                    destination
+                )
+            }
             _ => format!("Choose from options ({:?}):", context.kind),
         };
 
@@ -157,6 +201,7 @@ impl DecisionProvider for CliDecisionProvider {
     ) -> u64 {
         let prompt = match &context.kind {
             ChoiceKind::ChooseXValue { .. } => "Choose value for X:".to_string(),
+            ChoiceKind::ReplicateCount { .. } => "Pay the replicate cost how many times?".to_string(),
             _ => format!("Choose a number ({:?}):", context.kind),
         };
 
@@ -255,7 +300,17 @@ impl DecisionProvider for CliDecisionProvider {
         context: &ChoiceContext,
         items: &[ChoiceOption],
     ) -> Vec<usize> {
-        println!("\n--- Order items ({:?}) ---", context.kind);
+        let prompt = match &context.kind {
+            ChoiceKind::BottomCards { .. } => {
+                "Order cards for the bottom of your library (last = bottom-most)".to_string()
+            }
+            ChoiceKind::Scry { .. } => "Order these cards (top pile: first = topmost)".to_string(),
+            ChoiceKind::OrderTriggers => {
+                "Order your triggered abilities (first = put on the stack first, resolves last)".to_string()
+            }
+            _ => format!("Order items ({:?})", context.kind),
+        };
+        println!("\n--- {} ---", prompt);
         for (i, item) in items.iter().enumerate() {
             println!("  [{}] {:?}", i, item);
         }
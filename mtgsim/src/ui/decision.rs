@@ -21,6 +21,8 @@ pub enum PriorityAction {
     ActivateAbility(ObjectId, AbilityId),
     /// Play a land from a zone it could be played from
     PlayLand(ObjectId),
+    /// Turn a face-down permanent face up (rule 116.2b)
+    TurnFaceUp(ObjectId),
 }
 
 
@@ -192,6 +194,12 @@ pub fn is_action_still_valid(game: &GameState, player_id: PlayerId, action: &Pri
                 .map(|o| o.owner == player_id && o.zone == crate::types::zones::Zone::Hand)
                 .unwrap_or(false)
         }
+        PriorityAction::TurnFaceUp(permanent_id) => {
+            // Permanent must still be face down and controlled by player
+            game.battlefield.get(permanent_id)
+                .map(|e| e.controller == player_id && e.face_down.is_some())
+                .unwrap_or(false)
+        }
         PriorityAction::ActivateAbility(permanent_id, _ability_id) => {
             // Permanent must still be on battlefield and controlled by player.
             // Note: we don't check tapped state here because some abilities
@@ -213,18 +221,48 @@ pub fn is_action_still_valid(game: &GameState, player_id: PlayerId, action: &Pri
 /// Enables any combination of human/bot/network players in a single game.
 /// Each player is assigned a `Box<dyn DecisionProvider>` at construction time.
 /// All `DecisionProvider` methods route through `dp_for(player_id)`.
+///
+/// In team games a provider can speak for a whole team (`for_teams`): both
+/// Two-Headed Giant teammates' decisions go to the team's provider.
 pub struct DispatchDecisionProvider {
     providers: Vec<Box<dyn DecisionProvider>>,
+    /// Index into `providers` for each player.
+    routes: Vec<usize>,
 }
 
 impl DispatchDecisionProvider {
     /// Create a new dispatcher from a list of generic providers, one per player.
     pub fn new(providers: Vec<Box<dyn DecisionProvider>>) -> Self {
-        DispatchDecisionProvider { providers }
+        let routes = (0..providers.len()).collect();
+        DispatchDecisionProvider { providers, routes }
+    }
+
+    /// Create a dispatcher with one provider per team: `teams[i]` lists the
+    /// players whose decisions go to `providers[i]` (see `GameState::teams`).
+    pub fn for_teams(
+        providers: Vec<Box<dyn DecisionProvider>>,
+        teams: &[Vec<PlayerId>],
+    ) -> Result<Self, String> {
+        if providers.len() != teams.len() {
+            return Err(format!(
+                "{} providers for {} teams", providers.len(), teams.len()
+            ));
+        }
+        let num_players = teams.iter().flatten().max().map_or(0, |&pid| pid + 1);
+        let mut routes = vec![usize::MAX; num_players];
+        for (team, members) in teams.iter().enumerate() {
+            for &pid in members {
+                routes[pid] = team;
+            }
+        }
+        if routes.contains(&usize::MAX) {
+            return Err("Every player must be on a team".to_string());
+        }
+        Ok(DispatchDecisionProvider { providers, routes })
     }
 
     fn dp_for(&self, player_id: PlayerId) -> &dyn DecisionProvider {
-        &*self.providers[player_id]
+        &*self.providers[self.routes[player_id]]
     }
 }
 
@@ -3,7 +3,7 @@
 // All functions are pure formatters over &GameState — no mutations.
 // Lives in ui/ because these are presentation helpers, not game-state queries.
 
-use crate::objects::card_data::AbilityType;
+use crate::objects::card_data::{AbilityType, CardFace};
 use crate::oracle::characteristics::{
     get_effective_power, get_effective_toughness, has_keyword, has_type, is_creature,
 };
@@ -15,25 +15,47 @@ use crate::types::keywords::KeywordAbility;
 /// Format a card name with its ObjectId (short UUID suffix for disambiguation).
 pub fn card_label(game: &GameState, id: ObjectId) -> String {
     match game.objects.get(&id) {
-        Some(obj) => {
+        Some(_) => {
             let short_id = &format!("{}", id)[..8];
-            format!("{} ({})", obj.card_data.name, short_id)
+            format!("{} ({})", card_name(game, id), short_id)
         }
         None => format!("<unknown {}>", &format!("{}", id)[..8]),
     }
 }
 
-/// Format a card name only (no ID).
+/// Format a card name only (no ID). A face-down spell or permanent has no
+/// name (rule 708.2), and what it really is stays hidden.
 pub fn card_name(game: &GameState, id: ObjectId) -> String {
-    game.objects.get(&id)
-        .map(|obj| obj.card_data.name.clone())
+    if matches!(game.face_up(id), CardFace::FaceDown(_)) {
+        return FACE_DOWN.to_string();
+    }
+    if game.emblems.contains_key(&id) {
+        return "Emblem".to_string();
+    }
+    game.face_up_data(id)
+        .map(|data| data.name.clone())
         .unwrap_or_else(|| "<unknown>".to_string())
 }
 
-/// Format a battlefield permanent for display.
-/// Example: "Grizzly Bears 2/2 [tapped]" or "Forest [tapped]"
-pub fn format_permanent(game: &GameState, id: ObjectId) -> String {
+/// `card_name` as `viewer` sees it: a face-down spell or permanent they
+/// control also shows what it really is (rule 708.5).
+pub fn card_name_for(game: &GameState, id: ObjectId, viewer: PlayerId) -> String {
     let name = card_name(game, id);
+    match game.objects.get(&id) {
+        Some(obj) if name == FACE_DOWN && !game.is_hidden_from(id, viewer) => {
+            format!("{} ({})", name, obj.card_data.name)
+        }
+        _ => name,
+    }
+}
+
+const FACE_DOWN: &str = "Face-down creature";
+
+/// Format a battlefield permanent for display to `viewer`, who sees what a
+/// face-down permanent is only if they control it.
+/// Example: "Grizzly Bears 2/2 [tapped]" or "Forest [tapped]"
+pub fn format_permanent(game: &GameState, id: ObjectId, viewer: PlayerId) -> String {
+    let name = card_name_for(game, id, viewer);
     let entry = match game.battlefield.get(&id) {
         Some(e) => e,
         None => return name,
@@ -53,6 +75,27 @@ pub fn format_permanent(game: &GameState, id: ObjectId) -> String {
         }
     }
 
+    // Loyalty for planeswalkers (rule 306.5b)
+    if crate::oracle::characteristics::has_type(game, id, crate::types::card_types::CardType::Planeswalker) {
+        let loyalty = entry.counter_count(crate::types::effects::CounterType::Loyalty);
+        parts.push(format!("loyalty {}", loyalty));
+    }
+
+    // Defense and protector for battles (rules 310.4, 310.8)
+    if crate::oracle::characteristics::has_type(game, id, crate::types::card_types::CardType::Battle) {
+        let defense = entry.counter_count(crate::types::effects::CounterType::Defense);
+        match entry.protector {
+            Some(p) => parts.push(format!("defense {}, protected by P{}", defense, p)),
+            None => parts.push(format!("defense {}", defense)),
+        }
+    }
+
+    // Lore counters for Sagas (rule 714.3)
+    if game.is_saga(id) {
+        let lore = entry.counter_count(crate::types::effects::CounterType::Lore);
+        parts.push(format!("lore {}", lore));
+    }
+
     // Abilities: keywords shown compact, non-keyword abilities listed individually
     let keywords = collect_keywords(game, id);
     if !keywords.is_empty() {
@@ -65,6 +108,9 @@ pub fn format_permanent(game: &GameState, id: ObjectId) -> String {
 
     // Status flags
     let mut flags = Vec::new();
+    if entry.phased_out {
+        flags.push("phased out");
+    }
     if entry.tapped {
         flags.push("tapped");
     }
@@ -92,6 +138,9 @@ fn collect_keywords(game: &GameState, id: ObjectId) -> Vec<&'static str> {
     [
         check(KeywordAbility::Flying, "flying"),
         check(KeywordAbility::Reach, "reach"),
+        check(KeywordAbility::Phasing, "phasing"),
+        check(KeywordAbility::Daybound, "daybound"),
+        check(KeywordAbility::Nightbound, "nightbound"),
         check(KeywordAbility::Deathtouch, "deathtouch"),
         check(KeywordAbility::Lifelink, "lifelink"),
         check(KeywordAbility::FirstStrike, "first strike"),
@@ -103,6 +152,7 @@ fn collect_keywords(game: &GameState, id: ObjectId) -> Vec<&'static str> {
         check(KeywordAbility::Hexproof, "hexproof"),
         check(KeywordAbility::Indestructible, "indestructible"),
         check(KeywordAbility::Menace, "menace"),
+        check(KeywordAbility::Ward, "ward"),
     ]
     .into_iter()
     .flatten()
@@ -174,6 +224,13 @@ fn format_abilities(game: &GameState, id: ObjectId) -> Vec<String> {
             AbilityType::Static => {
                 lines.push(format!("static({})", i));
             }
+            AbilityType::TriggeredOn(event) => {
+                lines.push(format!("triggered({}, {:?})", i, event));
+            }
+            // Chapter abilities: show their chapter number (rule 714.2b)
+            AbilityType::Chapter(chapter) => {
+                lines.push(format!("chapter {}", chapter));
+            }
             // Spell abilities live on instants/sorceries, not permanents
             AbilityType::Spell => {}
         }
@@ -182,8 +239,10 @@ fn format_abilities(game: &GameState, id: ObjectId) -> Vec<String> {
     // If we have rules_text and no structured ability descriptions, show it
     // as a fallback. Even simple text like "{T}: Add {G}." is fine to display —
     // users reading CLI output can handle the redundancy.
-    if lines.is_empty() && !obj.card_data.rules_text.is_empty() {
-        lines.push(obj.card_data.rules_text.clone());
+    // The face that's up — a face-down permanent has no text (rule 708.2).
+    let rules_text = &obj.face(game.face_up(id)).rules_text;
+    if lines.is_empty() && !rules_text.is_empty() {
+        lines.push(rules_text.clone());
     }
 
     lines
@@ -220,12 +279,13 @@ pub fn format_hand(game: &GameState, player_id: PlayerId) -> String {
         .join("\n")
 }
 
-/// Format a player's battlefield for display, grouped by permanent type.
+/// Format a player's battlefield for display to `viewer`, grouped by
+/// permanent type.
 ///
 /// Groups: Creatures, Lands, Other (artifacts, enchantments, planeswalkers, etc.).
 /// Each group is shown with a sub-header. Permanents are numbered sequentially
 /// across groups so CLI index references remain unambiguous.
-pub fn format_battlefield(game: &GameState, player_id: PlayerId) -> String {
+pub fn format_battlefield(game: &GameState, player_id: PlayerId, viewer: PlayerId) -> String {
     let perms: Vec<ObjectId> = game.battlefield.iter()
         .filter(|(_, e)| e.controller == player_id)
         .map(|(id, _)| *id)
@@ -257,21 +317,21 @@ pub fn format_battlefield(game: &GameState, player_id: PlayerId) -> String {
     if !creatures.is_empty() {
         lines.push("  Creatures:".to_string());
         for &id in &creatures {
-            lines.push(format!("    {}: {}", idx, format_permanent(game, id)));
+            lines.push(format!("    {}: {}", idx, format_permanent(game, id, viewer)));
             idx += 1;
         }
     }
     if !lands.is_empty() {
         lines.push("  Lands:".to_string());
         for &id in &lands {
-            lines.push(format!("    {}: {}", idx, format_permanent(game, id)));
+            lines.push(format!("    {}: {}", idx, format_permanent(game, id, viewer)));
             idx += 1;
         }
     }
     if !other.is_empty() {
         lines.push("  Other:".to_string());
         for &id in &other {
-            lines.push(format!("    {}: {}", idx, format_permanent(game, id)));
+            lines.push(format!("    {}: {}", idx, format_permanent(game, id, viewer)));
             idx += 1;
         }
     }
@@ -279,8 +339,8 @@ pub fn format_battlefield(game: &GameState, player_id: PlayerId) -> String {
     lines.join("\n")
 }
 
-/// Format the stack for display, with top/bottom markers.
-pub fn format_stack(game: &GameState) -> String {
+/// Format the stack for display to `viewer`, with top/bottom markers.
+pub fn format_stack(game: &GameState, viewer: PlayerId) -> String {
     if game.stack.is_empty() {
         return "  (empty)".to_string();
     }
@@ -289,9 +349,9 @@ pub fn format_stack(game: &GameState) -> String {
     game.stack.iter().rev()
         .enumerate()
         .map(|(i, &id)| {
-            let name = card_name(game, id);
+            let name = card_name_for(game, id, viewer);
             let controller = game.stack_entries.get(&id)
-                .map(|e| format!(" (P{})", e.controller))
+                .map(|e| format!(" (P{}{})", e.controller, if e.is_copy { ", copy" } else { "" }))
                 .unwrap_or_default();
             let marker = if count == 1 {
                 " <- top/bottom"
@@ -340,14 +400,33 @@ pub fn format_phase(game: &GameState) -> String {
 /// Format a summary line for a player (life, hand size, library size, graveyard size).
 pub fn format_player_summary(game: &GameState, player_id: PlayerId) -> String {
     match game.players.get(player_id) {
-        Some(p) => format!(
-            "Player {} — Life: {} | Hand: {} | Library: {} | Graveyard: {}",
-            player_id,
-            p.life_total,
-            p.hand.len(),
-            p.library.len(),
-            p.graveyard.len(),
-        ),
+        Some(p) => {
+            let mut summary = format!(
+                "Player {} — Life: {} | Hand: {} | Library: {} | Graveyard: {}",
+                player_id,
+                p.life_total,
+                p.hand.len(),
+                p.library.len(),
+                p.graveyard.len(),
+            );
+            if game.monarch == Some(player_id) {
+                summary.push_str(" | Monarch");
+            }
+            if game.initiative == Some(player_id) {
+                summary.push_str(" | Initiative");
+            }
+            if let Some(dungeon) = game.dungeon_of(player_id)
+                && let Some(kind) = game.dungeon_type(dungeon)
+            {
+                let room = kind.rooms()[game.venture_markers[&dungeon]].name;
+                summary.push_str(&format!(" | {}: {}", kind.name(), room));
+            }
+            let emblems = game.emblems_of(player_id).len();
+            if emblems > 0 {
+                summary.push_str(&format!(" | Emblems: {}", emblems));
+            }
+            summary
+        }
         None => format!("Player {} — invalid", player_id),
     }
 }
@@ -386,10 +465,13 @@ pub fn format_mana_pool(game: &GameState, player_id: PlayerId) -> String {
 
 /// Resolve an ObjectId to "CardName (short-id)" for readable logs.
 fn obj_name(game: &GameState, id: ObjectId) -> String {
-    match game.objects.get(&id) {
-        Some(obj) => {
+    // An object that's changed zones since is named by the card it became
+    // (rule 400.7).
+    let card = game.current_identity(id);
+    match game.objects.get(&card) {
+        Some(_) => {
             let short = &format!("{}", id)[..8];
-            format!("{} ({})", obj.card_data.name, short)
+            format!("{} ({})", card_name(game, card), short)
         }
         None => format!("{}", id),
     }
@@ -422,12 +504,21 @@ pub fn format_event(game: &GameState, event: &crate::events::event::GameEvent) -
         StepEnd { step } => format!("StepEnd: {:?}", step),
         TurnBegin { player, turn_number } => format!("TurnBegin: P{} turn {}", player, turn_number),
         TurnEnd { player, turn_number } => format!("TurnEnd: P{} turn {}", player, turn_number),
+        PhasesAdded { phases } => format!("PhasesAdded: {:?}", phases),
+        ExtraTurnAdded { player } => format!("ExtraTurnAdded: P{}", player),
+        Skipped { player, what } => format!("Skipped: P{} {:?}", player, what),
+        TurnEndedEarly { player } => format!("TurnEndedEarly: P{}'s turn", player),
         PermanentEnteredBattlefield { object_id, controller } => {
             format!("ETB: {} [P{}]", obj_name(game, *object_id), controller)
         }
         PermanentLeftBattlefield { object_id } => {
             format!("LTB: {}", obj_name(game, *object_id))
         }
+        PhasedOut { object_id } => format!("PhasedOut: {}", obj_name(game, *object_id)),
+        PhasedIn { object_id } => format!("PhasedIn: {}", obj_name(game, *object_id)),
+        Transformed { object_id } => format!("Transformed: {}", obj_name(game, *object_id)),
+        TurnedFaceUp { object_id } => format!("TurnedFaceUp: {}", obj_name(game, *object_id)),
+        DayNightChanged { day_night } => format!("It becomes {:?}", day_night),
         LifeChanged { player_id, old, new, source } => {
             let src = match source {
                 Some(id) => format!(" (source: {})", obj_name(game, *id)),
@@ -460,21 +551,69 @@ pub fn format_event(game: &GameState, event: &crate::events::event::GameEvent) -
         SpellFizzled { spell_id } => {
             format!("SpellFizzled: {}", obj_name(game, *spell_id))
         }
+        SpellCopied { original, controller, .. } => {
+            format!("SpellCopied: P{} copies {}", controller, obj_name(game, *original))
+        }
+        TargetsChanged { spell_id, changed_by } => {
+            format!("TargetsChanged: {}'s targets changed by {}", obj_name(game, *spell_id), obj_name(game, *changed_by))
+        }
         CreatureDied { creature_id, owner } => {
             format!("CreatureDied: {} [P{}]", obj_name(game, *creature_id), owner)
         }
+        Regenerated { object_id } => {
+            format!("Regenerated: {}", obj_name(game, *object_id))
+        }
         PlaneswalkerDied { object_id, owner } => {
             format!("PlaneswalkerDied: {} [P{}]", obj_name(game, *object_id), owner)
         }
         LegendRuleSacrificed { object_id, owner } => {
             format!("LegendRuleSacrificed: {} [P{}]", obj_name(game, *object_id), owner)
         }
+        SagaSacrificed { object_id, owner } => {
+            format!("SagaSacrificed: {} [P{}]", obj_name(game, *object_id), owner)
+        }
+        BattleDied { object_id, owner } => {
+            format!("BattleDied: {} [P{}]", obj_name(game, *object_id), owner)
+        }
+        ProtectorChosen { battle_id, protector } => {
+            format!("ProtectorChosen: {} protected by P{}", obj_name(game, *battle_id), protector)
+        }
+        SiegeDefeated { object_id, controller } => {
+            format!("SiegeDefeated: {} [P{}]", obj_name(game, *object_id), controller)
+        }
+        EmblemCreated { emblem_id: _, controller } => {
+            format!("EmblemCreated: [P{}]", controller)
+        }
+        BecameMonarch { player } => {
+            format!("BecameMonarch: P{}", player)
+        }
+        TookInitiative { player } => {
+            format!("TookInitiative: P{}", player)
+        }
+        VenturedInto { player, dungeon_id, room } => {
+            format!("VenturedInto: P{} {} ({})", player, obj_name(game, *dungeon_id), room)
+        }
+        DungeonCompleted { player, dungeon } => {
+            format!("DungeonCompleted: P{} {}", player, dungeon.name())
+        }
         PlayerLost { player_id, reason } => {
             format!("PlayerLost: P{} ({:?})", player_id, reason)
         }
+        PlayerLeftGame { player_id } => {
+            format!("PlayerLeftGame: P{}", player_id)
+        }
         CountersAnnihilated { object_id, pairs_removed } => {
             format!("CountersAnnihilated: {} ({} pairs)", obj_name(game, *object_id), pairs_removed)
         }
+        PoisonCountersAdded { player_id, amount, source } => {
+            format!("PoisonCountersAdded: P{} +{} from {}", player_id, amount, obj_name(game, *source))
+        }
+        Attached { attachment, host } => {
+            format!("Attached: {} to {}", obj_name(game, *attachment), obj_name(game, *host))
+        }
+        Unattached { attachment, former_host } => {
+            format!("Unattached: {} from {}", obj_name(game, *attachment), obj_name(game, *former_host))
+        }
         AuraDied { object_id, owner } => {
             format!("AuraDied: {} [P{}]", obj_name(game, *object_id), owner)
         }
@@ -484,6 +623,9 @@ pub fn format_event(game: &GameState, event: &crate::events::event::GameEvent) -
         TokenCeasedToExist { object_id } => {
             format!("TokenCeasedToExist: {}", obj_name(game, *object_id))
         }
+        CopyCeasedToExist { object_id } => {
+            format!("CopyCeasedToExist: {}", obj_name(game, *object_id))
+        }
         StateBasedActionPerformed => "StateBasedActionPerformed".to_string(),
     }
 }
@@ -498,7 +640,7 @@ pub fn format_event_log(game: &GameState) -> Vec<String> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::objects::card_data::CardDataBuilder;
+    use crate::objects::card_data::{CardDataBuilder, CardFace};
     use crate::objects::object::GameObject;
     use crate::state::battlefield::BattlefieldEntity;
     use crate::state::game_state::{GameState, Phase};
@@ -530,7 +672,7 @@ mod tests {
         let entry = BattlefieldEntity::new(id, 0, ts, 0);
         game.battlefield.insert(id, entry);
 
-        let display = format_permanent(&game, id);
+        let display = format_permanent(&game, id, 0);
         assert!(display.contains("Grizzly Bears"));
         assert!(display.contains("2/2"));
     }
@@ -549,7 +691,7 @@ mod tests {
         entry.tapped = true;
         game.battlefield.insert(id, entry);
 
-        let display = format_permanent(&game, id);
+        let display = format_permanent(&game, id, 0);
         assert!(display.contains("tapped"));
     }
 
@@ -574,7 +716,7 @@ mod tests {
     #[test]
     fn test_format_stack_empty() {
         let game = GameState::new(2, 20);
-        assert_eq!(format_stack(&game), "  (empty)");
+        assert_eq!(format_stack(&game, 0), "  (empty)");
     }
 
     #[test]
@@ -609,7 +751,7 @@ mod tests {
         let entry = BattlefieldEntity::new(forest_id, 0, ts, 0);
         game.battlefield.insert(forest_id, entry);
 
-        let output = format_battlefield(&game, 0);
+        let output = format_battlefield(&game, 0, 0);
         assert!(output.contains("Creatures:"), "Should have Creatures header");
         assert!(output.contains("Lands:"), "Should have Lands header");
         assert!(output.contains("Grizzly Bears"));
@@ -637,11 +779,16 @@ mod tests {
             x_value: None,
             effect: Effect::Atom(Primitive::DealDamage(AmountExpr::Fixed(3)), EffectRecipient::Target(SelectionFilter::Any, TargetCount::Exactly(1))),
             is_spell: true,
+            ability_source: None,
             chosen_alternative_cost: None,
             additional_costs_paid: Vec::new(),
+            cast_face: CardFace::Front,
+            fused_half: None,
+            is_copy: false,
+            is_triggered: false,
         });
 
-        let output = format_stack(&game);
+        let output = format_stack(&game, 0);
         assert!(output.contains("top/bottom"), "Single item should show top/bottom marker");
     }
 
@@ -667,8 +814,13 @@ mod tests {
             x_value: None,
             effect: Effect::Atom(Primitive::DealDamage(AmountExpr::Fixed(3)), EffectRecipient::Target(SelectionFilter::Any, TargetCount::Exactly(1))),
             is_spell: true,
+            ability_source: None,
             chosen_alternative_cost: None,
             additional_costs_paid: Vec::new(),
+            cast_face: CardFace::Front,
+            fused_half: None,
+            is_copy: false,
+            is_triggered: false,
         });
 
         let recall = CardDataBuilder::new("Ancestral Recall")
@@ -686,11 +838,16 @@ mod tests {
             x_value: None,
             effect: Effect::Atom(Primitive::DealDamage(AmountExpr::Fixed(3)), EffectRecipient::Target(SelectionFilter::Any, TargetCount::Exactly(1))),
             is_spell: true,
+            ability_source: None,
             chosen_alternative_cost: None,
             additional_costs_paid: Vec::new(),
+            cast_face: CardFace::Front,
+            fused_half: None,
+            is_copy: false,
+            is_triggered: false,
         });
 
-        let output = format_stack(&game);
+        let output = format_stack(&game, 0);
         assert!(output.contains("top (resolves next)"), "Top item should have resolves-next marker");
         assert!(output.contains("bottom"), "Bottom item should have bottom marker");
     }
@@ -713,7 +870,7 @@ mod tests {
         let entry = BattlefieldEntity::new(id, 0, ts, 0);
         game.battlefield.insert(id, entry);
 
-        let display = format_permanent(&game, id);
+        let display = format_permanent(&game, id, 0);
         assert!(display.contains("mana: Add"), "Should show mana ability");
         assert!(display.contains("{G}"), "Should show green mana");
     }
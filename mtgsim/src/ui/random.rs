@@ -36,11 +36,12 @@ use crate::ui::decision::DecisionProvider;
 ///
 /// Implements the 4-primitive `DecisionProvider` trait. The `ask_*` functions
 /// in `ui::ask` handle semantic context; this provider just picks randomly
-/// among the options presented to it — with one exception: during a
+/// among the options presented to it — with two exceptions: during a
 /// `ChoiceKind::ManaAbilityWindow`, it always activates (never randomly
 /// declines) until the per-window activation cap is hit, at which point it
 /// declines so the 601.2g / 602.1b loop exits and the engine rolls back any
-/// unpayable cost. See `pick_n` for details.
+/// unpayable cost; and for `ChoiceKind::Mulligan` it keeps any hand with a
+/// workable land count. See `pick_n` for details.
 pub struct RandomDecisionProvider {
     /// Current mana-ability window tracker: `(spell_or_ability_id, activations_so_far)`.
     /// Resets when a new window id is seen. See `pick_n` for the rationale.
@@ -67,8 +68,8 @@ impl Default for RandomDecisionProvider {
 impl DecisionProvider for RandomDecisionProvider {
     fn pick_n(
         &self,
-        _game: &GameState,
-        _player: PlayerId,
+        game: &GameState,
+        player: PlayerId,
         context: &ChoiceContext,
         options: &[ChoiceOption],
         bounds: (usize, usize),
@@ -101,6 +102,20 @@ impl DecisionProvider for RandomDecisionProvider {
             return vec![idx];
         }
 
+        // A coin flip would mulligan half of all hands. Instead keep unless
+        // the hand is land-light or land-heavy (fewer than two lands or
+        // fewer than two spells), and keep whatever comes after two
+        // mulligans.
+        if let ChoiceKind::Mulligan { mulligans_taken } = context.kind {
+            let hand = &game.players[player].hand;
+            let lands = hand.iter()
+                .filter(|id| crate::oracle::characteristics::has_type(
+                    game, **id, crate::types::card_types::CardType::Land))
+                .count();
+            let unplayable = lands < 2 || lands + 2 > hand.len();
+            return if unplayable && mulligans_taken < 2 { vec![0] } else { Vec::new() };
+        }
+
         let count = if bounds.0 == bounds.1 {
             bounds.0
         } else {
@@ -153,15 +168,16 @@ impl DecisionProvider for RandomDecisionProvider {
     ) -> u64 {
         let mut rng = rand::rng();
 
-        // For ChooseXValue, self-limit based on available mana to avoid
-        // degenerate rollback loops in fuzz testing. The ask function passes
-        // (0, u64::MAX) — we inspect game state for a reasonable upper bound.
-        if let ChoiceKind::ChooseXValue { .. } = &context.kind {
+        // For ChooseXValue and the replicate count, self-limit based on
+        // available mana to avoid degenerate rollback loops in fuzz testing.
+        // The ask function passes (0, u64::MAX) — we inspect game state for
+        // a reasonable upper bound.
+        if let ChoiceKind::ChooseXValue { .. } | ChoiceKind::ReplicateCount { .. } = &context.kind {
             let pool_total: u64 = game.players.get(player)
                 .map(|p| p.mana_pool.total())
                 .unwrap_or(0);
             // Count untapped lands as potential mana sources
-            let untapped_lands: u64 = game.battlefield.iter()
+            let untapped_lands: u64 = game.permanents()
                 .filter(|(_, e)| {
                     e.controller == player && !e.tapped
                 })
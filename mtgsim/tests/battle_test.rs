@@ -0,0 +1,200 @@
+//! Battle integration tests — defense counters (rule 310.4b), protectors
+//! (rule 310.8), attacking battles (rule 508.1b), damage to battles
+//! (rule 120.3h), and the zero-defense SBA / Siege defeat (rules 704.5v,
+//! 310.11b).
+
+mod common;
+
+use common::{bolt_source, deal_damage, put_in_hand, put_on_battlefield, setup_two_player_game};
+
+use mtgsim::cards::battles;
+use mtgsim::cards::creatures;
+use mtgsim::engine::resolve::ResolvedTarget;
+use mtgsim::events::event::GameEvent;
+use mtgsim::oracle::characteristics::get_mana_value;
+use mtgsim::oracle::legality::{enumerate_legal_selections, legal_attack_targets};
+use mtgsim::state::battlefield::AttackTarget;
+use mtgsim::state::game_state::{GameState, Phase, PhaseType};
+use mtgsim::types::effects::{CounterType, SelectionFilter};
+use mtgsim::types::ids::ObjectId;
+use mtgsim::types::mana::ManaType;
+use mtgsim::types::zones::Zone;
+use mtgsim::ui::choice_types::ChoiceKind;
+use mtgsim::ui::decision::ScriptedDecisionProvider;
+
+fn defense(game: &GameState, id: ObjectId) -> u32 {
+    game.battlefield.get(&id).unwrap().counter_count(CounterType::Defense)
+}
+
+#[test]
+fn test_siege_enters_with_defense_and_opponent_protector() {
+    let mut game = setup_two_player_game();
+    let siege = put_on_battlefield(&mut game, battles::invasion_of_regatha(), 0);
+    assert_eq!(defense(&game, siege), 5);
+    assert_eq!(game.battlefield.get(&siege).unwrap().protector, Some(1));
+}
+
+#[test]
+fn test_cast_siege_controller_chooses_protector() {
+    let mut game = GameState::new(3, 20);
+    game.phase = Phase::new(PhaseType::Precombat);
+    let siege = put_in_hand(&mut game, battles::invasion_of_regatha(), 0);
+    game.players[0].mana_pool.add(ManaType::Red, 3);
+
+    let dp = ScriptedDecisionProvider::new();
+    // {2}{R} from a pool of RRR: the two generic go to red.
+    dp.expect_allocation(
+        ChoiceKind::GenericManaAllocation { mana_cost: mtgsim::types::mana::ManaCost::zero() },
+        vec![2],
+    );
+    let siege = game.cast_spell(0, siege, &dp).unwrap();
+
+    // Candidates in turn order after the controller: [P1, P2]
+    dp.expect_pick_n(ChoiceKind::ChooseProtector { battle_id: siege }, vec![1]);
+    game.resolve_top_of_stack(&dp).unwrap();
+    let siege = game.current_identity(siege);
+
+    let entry = game.battlefield.get(&siege).unwrap();
+    assert_eq!(entry.controller, 0);
+    assert_eq!(entry.protector, Some(2));
+    assert_eq!(defense(&game, siege), 5);
+}
+
+#[test]
+fn test_siege_attackable_by_non_protector_only() {
+    let mut game = setup_two_player_game();
+    let siege = put_on_battlefield(&mut game, battles::invasion_of_zendikar(), 0);
+
+    // The Siege's controller attacks it; its protector can't.
+    assert!(legal_attack_targets(&game, 0).contains(&AttackTarget::Battle(siege)));
+    assert!(!legal_attack_targets(&game, 1).contains(&AttackTarget::Battle(siege)));
+    assert_eq!(game.defending_player_for(&AttackTarget::Battle(siege)), Some(1));
+}
+
+#[test]
+fn test_damage_removes_defense_counters() {
+    let mut game = setup_two_player_game();
+    let siege = put_on_battlefield(&mut game, battles::invasion_of_regatha(), 0);
+
+    let bolt = bolt_source(&mut game, 0);
+    deal_damage(&mut game, bolt, siege, 3);
+    assert_eq!(defense(&game, siege), 2);
+    assert_eq!(game.battlefield.get(&siege).unwrap().damage_marked, 0);
+
+    // Battles are legal for "any target" (rule 115.4).
+    let legal = enumerate_legal_selections(&game, &SelectionFilter::Any, None);
+    assert!(legal.contains(&ResolvedTarget::Object(siege)));
+}
+
+#[test]
+fn test_siege_defeated_is_exiled() {
+    let mut game = setup_two_player_game();
+    let siege = put_on_battlefield(&mut game, battles::invasion_of_zendikar(), 0);
+
+    let bolt = bolt_source(&mut game, 0);
+    deal_damage(&mut game, bolt, siege, 5);
+    assert_eq!(defense(&game, siege), 0);
+
+    assert!(game.check_state_based_actions(&ScriptedDecisionProvider::new()).unwrap());
+    let exiled = game.current_identity(siege);
+    assert_eq!(game.get_object(exiled).unwrap().zone, Zone::Exile);
+    // The event names the card in exile, not the battle it was.
+    assert!(game.events.events().iter().any(|e| matches!(
+        e,
+        GameEvent::SiegeDefeated { object_id, controller: 0 } if *object_id == exiled
+    )));
+}
+
+/// Defeat Invasion of Emberfall under player 0, who casts it transformed
+/// if `cast`. Returns the card in exile or the spell on the stack.
+fn defeat_emberfall(game: &mut GameState, cast: bool) -> ObjectId {
+    let siege = put_on_battlefield(game, battles::invasion_of_emberfall(), 0);
+    let bolt = bolt_source(game, 0);
+    deal_damage(game, bolt, siege, 2);
+    let dp = ScriptedDecisionProvider::new();
+    let pick = if cast { vec![0] } else { vec![] };
+    dp.expect_pick_n(ChoiceKind::CastWithoutPaying { card_id: ObjectId::nil() }, pick);
+    assert!(game.check_state_based_actions(&dp).unwrap());
+    game.current_identity(siege)
+}
+
+#[test]
+fn test_defeated_siege_is_cast_transformed() {
+    let mut game = setup_two_player_game();
+    let spell = defeat_emberfall(&mut game, true);
+    assert_eq!(game.stack, vec![spell]);
+    assert_eq!(game.face_up_data(spell).unwrap().name, "Emberfall Raider");
+    assert_eq!(get_mana_value(&game, spell), 2, "its front face's");
+    assert!(game.exile.is_empty());
+
+    game.resolve_top_of_stack(&ScriptedDecisionProvider::new()).unwrap();
+    let raider = game.current_identity(spell);
+    assert!(game.battlefield[&raider].transformed);
+    assert_eq!(game.face_up_data(raider).unwrap().name, "Emberfall Raider");
+}
+
+#[test]
+fn test_defeated_siege_not_cast_stays_in_exile() {
+    let mut game = setup_two_player_game();
+    let exiled = defeat_emberfall(&mut game, false);
+    assert!(game.stack.is_empty());
+    assert_eq!(game.get_object(exiled).unwrap().zone, Zone::Exile);
+}
+
+#[test]
+fn test_attack_siege_and_defeat_it_in_combat() {
+    let mut game = setup_two_player_game();
+    game.turn_number = 2;
+    let bears = put_on_battlefield(&mut game, creatures::grizzly_bears(), 0);
+    let siege = put_on_battlefield(&mut game, battles::invasion_of_zendikar(), 0);
+    game.battlefield.get_mut(&siege).unwrap().remove_counters(CounterType::Defense, 1);
+
+    // Legal pairs: [(bears, Player(1)), (bears, Battle(siege))]
+    let dp = ScriptedDecisionProvider::new();
+    dp.expect_pick_n(ChoiceKind::DeclareAttackers, vec![1]);
+    assert!(game.process_declare_attackers(&dp).unwrap());
+    assert_eq!(
+        game.battlefield.get(&bears).unwrap().attacking.as_ref().unwrap().target,
+        AttackTarget::Battle(siege),
+    );
+
+    // The protector has no creatures to block with.
+    game.process_declare_blockers(&dp).unwrap();
+    game.process_combat_damage(&dp, false).unwrap();
+    assert_eq!(game.players[1].life_total, 20);
+
+    game.check_state_based_actions(&dp).unwrap();
+    assert_eq!(game.get_object(game.current_identity(siege)).unwrap().zone, Zone::Exile);
+}
+
+#[test]
+fn test_protector_blocks_attacker_of_battle() {
+    let mut game = setup_two_player_game();
+    game.turn_number = 2;
+    put_on_battlefield(&mut game, creatures::grizzly_bears(), 0);
+    let siege = put_on_battlefield(&mut game, battles::invasion_of_zendikar(), 0);
+    let blocker = put_on_battlefield(&mut game, creatures::grizzly_bears(), 1);
+
+    let dp = ScriptedDecisionProvider::new();
+    dp.expect_pick_n(ChoiceKind::DeclareAttackers, vec![1]);
+    game.process_declare_attackers(&dp).unwrap();
+
+    dp.expect_pick_n(ChoiceKind::DeclareBlockers, vec![0]);
+    game.process_declare_blockers(&dp).unwrap();
+    assert!(game.battlefield.get(&blocker).unwrap().blocking.is_some());
+
+    game.process_combat_damage(&dp, false).unwrap();
+    assert_eq!(defense(&game, siege), 3);
+}
+
+#[test]
+fn test_siege_controlled_by_protector_gets_new_protector() {
+    // SBA 704.5x: after a control change hands the Siege to its protector,
+    // the new controller chooses an opponent to protect it.
+    let mut game = setup_two_player_game();
+    let siege = put_on_battlefield(&mut game, battles::invasion_of_regatha(), 0);
+    game.battlefield.get_mut(&siege).unwrap().controller = 1;
+
+    game.check_state_based_actions(&ScriptedDecisionProvider::new()).unwrap();
+    assert_eq!(game.battlefield.get(&siege).unwrap().protector, Some(0));
+}
@@ -0,0 +1,202 @@
+//! Command zone integration tests — emblems from planeswalker ultimates
+//! (rule 114), the monarch (rule 724), the initiative (rule 725), and
+//! venturing into dungeons (rules 309, 701.49, SBA 704.5t).
+
+mod common;
+
+use common::{fill_library, put_in_hand, put_on_battlefield, setup_multiplayer_game, setup_two_player_game};
+
+use mtgsim::cards::command_zone;
+use mtgsim::cards::creatures;
+use mtgsim::cards::planeswalkers;
+use mtgsim::engine::actions::GameAction;
+use mtgsim::events::event::{DamageTarget, GameEvent};
+use mtgsim::state::game_state::{GameState, StepType};
+use mtgsim::types::card_types::DungeonType;
+use mtgsim::types::effects::{CounterType, EffectRecipient};
+use mtgsim::types::ids::ObjectId;
+use mtgsim::types::mana::{ManaCost, ManaType};
+use mtgsim::types::zones::Zone;
+use mtgsim::ui::choice_types::ChoiceKind;
+use mtgsim::ui::decision::ScriptedDecisionProvider;
+use mtgsim::ui::display::{card_name, format_player_summary};
+
+/// Cast a sorcery from player 0's hand and resolve it, paying its generic
+/// part with mana of `pip`.
+fn cast_and_resolve(game: &mut GameState, card: ObjectId, pip: ManaType, generic: u64) {
+    game.players[0].mana_pool.add(pip, 1 + generic);
+    let dp = ScriptedDecisionProvider::new();
+    if generic > 0 {
+        dp.expect_allocation(ChoiceKind::GenericManaAllocation { mana_cost: ManaCost::zero() }, vec![generic]);
+    }
+    game.cast_spell(0, card, &dp).unwrap();
+    game.resolve_top_of_stack(&dp).unwrap();
+}
+
+/// Put pending triggers on the stack and resolve it, until neither is left.
+fn resolve_triggers(game: &mut GameState, dp: &ScriptedDecisionProvider) {
+    while game.put_pending_triggers_on_stack(dp).unwrap() || !game.stack.is_empty() {
+        game.resolve_top_of_stack(dp).unwrap();
+    }
+}
+
+/// Advance turns until player `player`'s `step` begins.
+fn advance_to_step(game: &mut GameState, player: usize, step: StepType) {
+    game.advance_turn().unwrap();
+    while !(game.active_player == player && game.phase.step == Some(step)) {
+        game.advance_turn().unwrap();
+    }
+}
+
+fn combat_damage(game: &mut GameState, source: ObjectId, player: usize) {
+    game.execute_action(GameAction::DealDamage {
+        source,
+        target: DamageTarget::Player(player),
+        amount: 2,
+        is_combat: true,
+    }).unwrap();
+}
+
+#[test]
+fn test_chandra_emblem_deals_damage_whenever_you_cast_a_spell() {
+    let mut game = setup_two_player_game();
+    game.turn_number = 1;
+    let chandra = put_on_battlefield(&mut game, planeswalkers::chandra_torch_of_defiance(), 0);
+    game.battlefield.get_mut(&chandra).unwrap().add_counters(CounterType::Loyalty, 3);
+
+    let dp = ScriptedDecisionProvider::new();
+    game.activate_ability(0, chandra, 2, &dp).unwrap();
+    game.resolve_top_of_stack(&dp).unwrap();
+    let emblem = game.emblems_of(0)[0];
+    assert_eq!(game.objects[&game.current_identity(emblem)].zone, Zone::Command);
+    assert_eq!(card_name(&game, emblem), "Emblem");
+    assert!(format_player_summary(&game, 0).contains("Emblems: 1"));
+
+    let spell = put_in_hand(&mut game, command_zone::delve_the_depths(), 0);
+    game.players[0].mana_pool.add(ManaType::Blue, 1);
+    let spell = game.cast_spell(0, spell, &dp).unwrap();
+    assert_eq!(game.pending_triggers.len(), 1);
+
+    dp.expect_pick_n(
+        ChoiceKind::SelectRecipients { recipient: EffectRecipient::Implicit, spell_id: emblem },
+        vec![1],
+    );
+    game.put_pending_triggers_on_stack(&dp).unwrap();
+    game.resolve_top_of_stack(&dp).unwrap();
+    assert_eq!(game.players[1].life_total, 15);
+    assert_eq!(game.stack, vec![spell], "the trigger resolved before the spell");
+}
+
+#[test]
+fn test_monarch_draws_at_end_step_and_is_stolen_by_combat_damage() {
+    let mut game = setup_two_player_game();
+    fill_library(&mut game, 0, 5);
+    fill_library(&mut game, 1, 5);
+    let throne = put_in_hand(&mut game, command_zone::claim_the_throne(), 0);
+    cast_and_resolve(&mut game, throne, ManaType::White, 1);
+    assert_eq!(game.monarch, Some(0));
+    assert!(game.events.events().iter().any(|e| matches!(e, GameEvent::BecameMonarch { player: 0 })));
+
+    advance_to_step(&mut game, 0, StepType::End);
+    let hand = game.players[0].hand.len();
+    resolve_triggers(&mut game, &ScriptedDecisionProvider::new());
+    assert_eq!(game.players[0].hand.len(), hand + 1);
+
+    let bears = put_on_battlefield(&mut game, creatures::grizzly_bears(), 1);
+    combat_damage(&mut game, bears, 0);
+    resolve_triggers(&mut game, &ScriptedDecisionProvider::new());
+    assert_eq!(game.monarch, Some(1));
+    assert!(format_player_summary(&game, 1).contains("Monarch"));
+}
+
+#[test]
+fn test_noncombat_damage_does_not_steal_the_monarch() {
+    let mut game = setup_two_player_game();
+    game.become_monarch(0);
+    let bears = put_on_battlefield(&mut game, creatures::grizzly_bears(), 1);
+    game.execute_action(GameAction::DealDamage {
+        source: bears,
+        target: DamageTarget::Player(0),
+        amount: 2,
+        is_combat: false,
+    }).unwrap();
+    assert!(game.pending_triggers.is_empty());
+}
+
+#[test]
+fn test_taking_the_initiative_ventures_into_undercity() {
+    let mut game = setup_two_player_game();
+    fill_library(&mut game, 0, 5);
+    fill_library(&mut game, 1, 5);
+    let seize = put_in_hand(&mut game, command_zone::seize_the_initiative(), 0);
+    cast_and_resolve(&mut game, seize, ManaType::Black, 2);
+    assert_eq!(game.initiative, Some(0));
+
+    let dp = ScriptedDecisionProvider::new();
+    resolve_triggers(&mut game, &dp);
+    let dungeon = game.dungeon_of(0).unwrap();
+    assert_eq!(game.dungeon_type(dungeon), Some(DungeonType::Undercity));
+    assert_eq!(game.venture_markers[&dungeon], 0);
+    assert!(format_player_summary(&game, 0).contains("Undercity: Secret Entrance"));
+
+    // At the beginning of their next upkeep they venture again, choosing
+    // between Forge and Lost Well.
+    advance_to_step(&mut game, 0, StepType::Upkeep);
+    dp.expect_pick_n(ChoiceKind::ChooseDungeonRoom { dungeon_id: dungeon }, vec![1]);
+    resolve_triggers(&mut game, &dp);
+    assert_eq!(game.venture_markers[&dungeon], 2, "Lost Well");
+}
+
+#[test]
+fn test_combat_damage_takes_the_initiative_once_per_player() {
+    let mut game = setup_two_player_game();
+    game.initiative = Some(0);
+    let first = put_on_battlefield(&mut game, creatures::grizzly_bears(), 1);
+    let second = put_on_battlefield(&mut game, creatures::grizzly_bears(), 1);
+    combat_damage(&mut game, first, 0);
+    combat_damage(&mut game, second, 0);
+    assert_eq!(game.pending_triggers.len(), 1);
+
+    resolve_triggers(&mut game, &ScriptedDecisionProvider::new());
+    assert_eq!(game.initiative, Some(1));
+    assert_eq!(game.dungeon_of(1).and_then(|id| game.dungeon_type(id)), Some(DungeonType::Undercity));
+}
+
+#[test]
+fn test_venture_into_the_dungeon_and_complete_it() {
+    let mut game = setup_two_player_game();
+    fill_library(&mut game, 0, 5);
+    let delve = put_in_hand(&mut game, command_zone::delve_the_depths(), 0);
+    cast_and_resolve(&mut game, delve, ManaType::Blue, 0);
+    let dungeon = game.dungeon_of(0).unwrap();
+    assert_eq!(game.dungeon_type(dungeon), Some(DungeonType::LostMineOfPhandelver));
+
+    // Cave Entrance → Mine Tunnels → Fungi Cavern → Temple of Dumathoin.
+    let dp = ScriptedDecisionProvider::new();
+    dp.expect_pick_n(ChoiceKind::ChooseDungeonRoom { dungeon_id: dungeon }, vec![1]);
+    game.venture(0, None, &dp).unwrap();
+    dp.expect_pick_n(ChoiceKind::ChooseDungeonRoom { dungeon_id: dungeon }, vec![1]);
+    game.venture(0, None, &dp).unwrap();
+    game.venture(0, None, &dp).unwrap();
+    assert_eq!(game.venture_markers[&dungeon], 6);
+
+    // The four room abilities go on the stack in the order they triggered.
+    dp.expect_ordering(ChoiceKind::OrderTriggers, vec![0, 1, 2, 3]);
+    let hand = game.players[0].hand.len();
+    resolve_triggers(&mut game, &dp);
+    assert_eq!(game.players[0].hand.len(), hand + 1, "Temple of Dumathoin draws");
+
+    game.check_state_based_actions_loop(&dp).unwrap();
+    assert!(!game.objects.contains_key(&dungeon));
+    assert!(game.command.is_empty());
+    assert_eq!(game.players[0].completed_dungeons, vec![DungeonType::LostMineOfPhandelver]);
+}
+
+#[test]
+fn test_monarch_passes_to_active_player_when_monarch_leaves() {
+    let mut game = setup_multiplayer_game(3);
+    game.become_monarch(2);
+    game.player_lost[2] = true;
+    game.remove_player_from_game(2).unwrap();
+    assert_eq!(game.monarch, Some(0));
+}
@@ -0,0 +1,200 @@
+//! Commander integration tests (rule 903) — game setup with commanders in
+//! the command zone, casting from the command zone with commander tax, the
+//! command-zone choice when a commander would leave for another zone, and
+//! color-identity deck validation.
+
+mod common;
+
+use std::sync::Arc;
+
+use common::{put_in_hand, put_on_battlefield, setup_two_player_game};
+
+use mtgsim::cards::basic_lands;
+use mtgsim::cards::creatures;
+use mtgsim::cards::phase5_pre_cards::isamaru_hound_of_konda;
+use mtgsim::objects::card_data::{AbilityDef, AbilityType, CardData, CardDataBuilder, CardFace};
+use mtgsim::oracle::legality::candidate_priority_actions;
+use mtgsim::state::game::Game;
+use mtgsim::state::game_config::GameConfig;
+use mtgsim::state::game_state::GameState;
+use mtgsim::types::card_types::CardType;
+use mtgsim::types::effects::{Effect, EffectRecipient, PermanentFilter, Primitive, SelectionFilter, TargetCount};
+use mtgsim::types::ids::{new_ability_id, ObjectId};
+use mtgsim::types::mana::{ManaCost, ManaType};
+use mtgsim::types::zones::Zone;
+use mtgsim::ui::choice_types::ChoiceKind;
+use mtgsim::ui::decision::{PriorityAction, ScriptedDecisionProvider};
+
+const ISAMARU: &str = "Isamaru, Hound of Konda";
+
+/// Isamaru and 99 Plains.
+fn isamaru_deck() -> Vec<Arc<CardData>> {
+    let mut deck = vec![isamaru_hound_of_konda()];
+    deck.extend((0..99).map(|_| basic_lands::plains()));
+    deck
+}
+
+/// Player 0's Isamaru, designated and in the command zone.
+fn setup_with_commander() -> (GameState, ObjectId) {
+    let mut game = setup_two_player_game();
+    let id = put_in_hand(&mut game, isamaru_hound_of_konda(), 0);
+    let id = game.designate_commander(0, id).unwrap();
+    (game, id)
+}
+
+/// "Return target creature to its owner's hand."
+fn unsummon() -> Arc<CardData> {
+    CardDataBuilder::new("Unsummon")
+        .mana_cost(ManaCost::build(&[ManaType::Blue], 0))
+        .card_type(CardType::Instant)
+        .ability(AbilityDef {
+            id: new_ability_id(),
+            ability_type: AbilityType::Spell,
+            costs: Vec::new(),
+            effect: Effect::Atom(
+                Primitive::ReturnToHand,
+                EffectRecipient::Target(
+                    SelectionFilter::Permanent(PermanentFilter::ByType(CardType::Creature)),
+                    TargetCount::Exactly(1),
+                ),
+            ),
+        })
+        .build()
+}
+
+#[test]
+fn test_new_commander_game_starts_commanders_in_command_zone() {
+    let game = Game::new_commander(
+        GameConfig::commander(),
+        vec![isamaru_deck(), isamaru_deck()],
+        vec![vec![ISAMARU], vec![ISAMARU]],
+    ).unwrap();
+
+    assert_eq!(game.state.command.len(), 2);
+    for (pid, &id) in game.state.command.iter().enumerate() {
+        let obj = game.state.get_object(id).unwrap();
+        assert!(obj.is_commander);
+        assert_eq!(obj.owner, pid);
+        assert_eq!(game.state.players[pid].library.len(), 99);
+        assert_eq!(game.state.players[pid].life_total, 40);
+    }
+    assert!(!game.state.skip_first_draw);
+}
+
+#[test]
+fn test_new_commander_game_validates_decks() {
+    let mut off_color = isamaru_deck();
+    off_color[1] = creatures::grizzly_bears();
+    let err = Game::new_commander(
+        GameConfig::commander(),
+        vec![isamaru_deck(), off_color],
+        vec![vec![ISAMARU], vec![ISAMARU]],
+    ).err().unwrap();
+    assert!(err.contains("Player 1"), "{}", err);
+    assert!(err.contains("Grizzly Bears"), "{}", err);
+
+    let mut short = isamaru_deck();
+    short.pop();
+    assert!(Game::new_commander(
+        GameConfig::commander(),
+        vec![isamaru_deck(), short],
+        vec![vec![ISAMARU], vec![ISAMARU]],
+    ).is_err());
+}
+
+#[test]
+fn test_cast_commander_from_command_zone_then_pay_tax() {
+    let (mut game, cmdr) = setup_with_commander();
+    let dp = ScriptedDecisionProvider::new();
+
+    // First cast: just {W}.
+    game.players[0].mana_pool.add(ManaType::White, 1);
+    let cmdr = game.cast_spell(0, cmdr, &dp).unwrap();
+    game.resolve_top_of_stack(&dp).unwrap();
+    let cmdr = game.current_identity(cmdr);
+    assert!(game.battlefield.contains_key(&cmdr));
+    // Tax follows the card, not the object (rule 400.7).
+    let card = game.original_identity(cmdr);
+    assert_eq!(game.players[0].commander_casts.get(&card).copied(), Some(1));
+
+    // It dies; its owner puts it back into the command zone.
+    let cmdr = game.change_zone(cmdr, Zone::Graveyard).unwrap();
+    dp.expect_pick_n(
+        ChoiceKind::CommanderToCommandZone { commander_id: cmdr, destination: Zone::Graveyard },
+        vec![0],
+    );
+    assert!(game.check_state_based_actions(&dp).unwrap());
+    let cmdr = game.current_identity(cmdr);
+    assert_eq!(game.get_object(cmdr).unwrap().zone, Zone::Command);
+
+    // Second cast costs {2}{W}: two mana isn't enough, and the failed cast
+    // leaves it in the command zone without counting toward tax.
+    game.players[0].mana_pool.add(ManaType::White, 2);
+    assert!(game.cast_spell(0, cmdr, &dp).is_err());
+    assert_eq!(game.get_object(cmdr).unwrap().zone, Zone::Command);
+    assert_eq!(game.commander_tax(cmdr), 2);
+
+    game.players[0].mana_pool.add(ManaType::White, 1);
+    dp.expect_allocation(ChoiceKind::GenericManaAllocation { mana_cost: ManaCost::zero() }, vec![2]);
+    let cmdr = game.cast_spell(0, cmdr, &dp).unwrap();
+    assert_eq!(game.players[0].mana_pool.total(), 0);
+    assert_eq!(game.commander_tax(cmdr), 4);
+}
+
+#[test]
+fn test_commander_offered_as_castable_only_when_tax_is_affordable() {
+    let (mut game, cmdr) = setup_with_commander();
+    game.players[0].mana_pool.add(ManaType::White, 1);
+    assert!(candidate_priority_actions(&game, 0).contains(&PriorityAction::CastSpell(cmdr)));
+
+    let card = game.original_identity(cmdr);
+    game.players[0].commander_casts.insert(card, 1);
+    assert!(!candidate_priority_actions(&game, 0).contains(&PriorityAction::CastSpell(cmdr)));
+    // The opponent can't cast it.
+    assert!(!candidate_priority_actions(&game, 1).contains(&PriorityAction::CastSpell(cmdr)));
+}
+
+#[test]
+fn test_bounced_commander_may_go_to_command_zone_instead() {
+    let mut game = setup_two_player_game();
+    let cmdr = put_on_battlefield(&mut game, isamaru_hound_of_konda(), 1);
+    game.get_object_mut(cmdr).unwrap().is_commander = true;
+    let spell = put_in_hand(&mut game, unsummon(), 0);
+    game.players[0].mana_pool.add(ManaType::Blue, 1);
+
+    let dp = ScriptedDecisionProvider::new();
+    dp.expect_pick_n(
+        ChoiceKind::SelectRecipients {
+            recipient: EffectRecipient::Implicit,
+            spell_id: spell,
+        },
+        vec![0],
+    );
+    game.cast_spell(0, spell, &dp).unwrap();
+
+    // The commander's owner, not the caster, decides (rule 903.9b).
+    dp.expect_pick_n(
+        ChoiceKind::CommanderToCommandZone { commander_id: cmdr, destination: Zone::Hand },
+        vec![0],
+    );
+    game.resolve_top_of_stack(&dp).unwrap();
+    assert_eq!(game.get_object(game.current_identity(cmdr)).unwrap().zone, Zone::Command);
+    assert!(game.players[1].hand.is_empty());
+}
+
+#[test]
+fn test_commander_declining_command_zone_stays_in_hand() {
+    let mut game = setup_two_player_game();
+    let cmdr = put_on_battlefield(&mut game, isamaru_hound_of_konda(), 0);
+    game.get_object_mut(cmdr).unwrap().is_commander = true;
+
+    let dp = ScriptedDecisionProvider::new();
+    dp.expect_pick_n(
+        ChoiceKind::CommanderToCommandZone { commander_id: cmdr, destination: Zone::Hand },
+        vec![],
+    );
+    let cmdr = game.change_zone_with_commander_choice(cmdr, Zone::Hand, &dp).unwrap();
+    assert_eq!(game.players[0].hand, vec![cmdr]);
+    // Still a commander: it can be cast from hand without tax.
+    assert_eq!(game.mana_cost_to_cast(cmdr, CardFace::Front).unwrap().mana_value(), 1);
+}
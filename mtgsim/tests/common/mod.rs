@@ -6,6 +6,9 @@
 
 use std::sync::Arc;
 
+use mtgsim::cards::alpha;
+use mtgsim::engine::actions::GameAction;
+use mtgsim::events::event::DamageTarget;
 use mtgsim::objects::card_data::{CardData, CardDataBuilder};
 use mtgsim::objects::object::GameObject;
 use mtgsim::state::game_state::{GameState, PhaseType};
@@ -21,6 +24,16 @@ pub fn setup_two_player_game() -> GameState {
     game
 }
 
+/// Create an `n`-player free-for-all game in precombat main phase with
+/// player 0 active.
+#[allow(dead_code)]
+pub fn setup_multiplayer_game(num_players: usize) -> GameState {
+    let mut game = GameState::new(num_players, 20);
+    game.phase = mtgsim::state::game_state::Phase::new(PhaseType::Precombat);
+    game.active_player = 0;
+    game
+}
+
 /// Put a card into a player's hand and register it in the game.
 #[allow(dead_code)]
 pub fn put_in_hand(game: &mut GameState, card_data: Arc<CardData>, player: usize) -> ObjectId {
@@ -73,3 +86,23 @@ pub fn fill_library(game: &mut GameState, player: usize, count: usize) {
         game.players[player].library.push(id);
     }
 }
+
+/// Put a Lightning Bolt into a player's graveyard to serve as a damage source.
+#[allow(dead_code)]
+pub fn bolt_source(game: &mut GameState, player: usize) -> ObjectId {
+    let bolt = GameObject::new(alpha::lightning_bolt(), player, Zone::Graveyard);
+    let id = bolt.id;
+    game.add_object(bolt);
+    id
+}
+
+/// Have `source` deal noncombat damage to a permanent.
+#[allow(dead_code)]
+pub fn deal_damage(game: &mut GameState, source: ObjectId, target: ObjectId, amount: u64) {
+    game.execute_action(GameAction::DealDamage {
+        source,
+        target: DamageTarget::Object(target),
+        amount,
+        is_combat: false,
+    }).unwrap();
+}
@@ -0,0 +1,208 @@
+//! Copy integration tests — copying a target spell (rule 707.10), storm
+//! (rule 702.40), replicate (rule 702.56) and casualty (rule 702.153),
+//! choosing new targets for copies (rule 707.10c), and copies ceasing to
+//! exist once they leave the stack (rule 704.5e).
+
+mod common;
+
+use common::{put_in_hand, put_on_battlefield, setup_two_player_game};
+
+use mtgsim::cards::alpha;
+use mtgsim::cards::copies;
+use mtgsim::cards::creatures;
+use mtgsim::engine::resolve::ResolvedTarget;
+use mtgsim::events::event::GameEvent;
+use mtgsim::state::game_state::GameState;
+use mtgsim::types::effects::EffectRecipient;
+use mtgsim::types::ids::ObjectId;
+use mtgsim::types::mana::{ManaCost, ManaType};
+use mtgsim::ui::choice_types::ChoiceKind;
+use mtgsim::ui::decision::ScriptedDecisionProvider;
+
+/// Expectations are matched by kind only, so their ids are placeholders.
+const ANY: ObjectId = ObjectId::nil();
+
+fn pick_target(dp: &ScriptedDecisionProvider, index: usize) {
+    dp.expect_pick_n(
+        ChoiceKind::SelectRecipients { recipient: EffectRecipient::Implicit, spell_id: ANY },
+        vec![index],
+    );
+}
+
+/// Each copy keeps its target (options list the current one first).
+fn keep_targets(dp: &ScriptedDecisionProvider, copies: usize) {
+    for _ in 0..copies {
+        dp.expect_pick_n(
+            ChoiceKind::ChooseNewTargets { recipient: EffectRecipient::Implicit, spell_id: ANY },
+            vec![0],
+        );
+    }
+}
+
+fn pay_generic(dp: &ScriptedDecisionProvider, allocation: Vec<u64>) {
+    dp.expect_allocation(ChoiceKind::GenericManaAllocation { mana_cost: ManaCost::zero() }, allocation);
+}
+
+fn copies_on_stack(game: &GameState) -> usize {
+    game.stack.iter().filter(|id| game.stack_entries[id].is_copy).count()
+}
+
+fn resolve_all(game: &mut GameState, dp: &ScriptedDecisionProvider) {
+    while game.put_pending_triggers_on_stack(dp).unwrap() || !game.stack.is_empty() {
+        if !game.stack.is_empty() {
+            game.resolve_top_of_stack(dp).unwrap();
+        }
+        game.check_state_based_actions(dp).unwrap();
+    }
+}
+
+/// Player 0 casts Lightning Bolt at player 1.
+fn cast_bolt_at_opponent(game: &mut GameState, dp: &ScriptedDecisionProvider) -> ObjectId {
+    let bolt = put_in_hand(game, alpha::lightning_bolt(), 0);
+    game.players[0].mana_pool.add(ManaType::Red, 1);
+    // Any target: [Player(0), Player(1)]
+    pick_target(dp, 1);
+    game.cast_spell(0, bolt, dp).unwrap()
+}
+
+#[test]
+fn test_twincast_copies_a_spell_with_new_targets() {
+    let mut game = setup_two_player_game();
+    let dp = ScriptedDecisionProvider::new();
+    let bolt = cast_bolt_at_opponent(&mut game, &dp);
+
+    let twincast = put_in_hand(&mut game, copies::twincast(), 1);
+    game.players[1].mana_pool.add(ManaType::Blue, 2);
+    pick_target(&dp, 0);
+    game.cast_spell(1, twincast, &dp).unwrap();
+
+    // The copy's options: [Player(1) (current), Player(0)]
+    dp.expect_pick_n(
+        ChoiceKind::ChooseNewTargets { recipient: EffectRecipient::Implicit, spell_id: ANY },
+        vec![1],
+    );
+    game.resolve_top_of_stack(&dp).unwrap();
+    let copy = *game.stack.last().unwrap();
+    assert_ne!(copy, bolt);
+    assert_eq!(game.stack_entries[&copy].controller, 1);
+    assert_eq!(game.stack_entries[&copy].chosen_targets, vec![ResolvedTarget::Player(0)]);
+    assert!(game.events.events().iter().any(|e| matches!(
+        e, GameEvent::SpellCopied { original, copy: c, controller: 1 } if *original == bolt && *c == copy
+    )));
+
+    resolve_all(&mut game, &dp);
+    assert_eq!(game.players[0].life_total, 17);
+    assert_eq!(game.players[1].life_total, 17);
+    assert!(!game.objects.contains_key(&game.current_identity(copy)), "the copy ceased to exist");
+    assert!(game.players[1].graveyard.iter().all(|&id| game.objects[&id].card_data.name == "Twincast"));
+    assert!(game.events.events().iter().any(|e| matches!(e, GameEvent::CopyCeasedToExist { .. })));
+}
+
+#[test]
+fn test_storm_copies_for_each_spell_cast_before_it_this_turn() {
+    let mut game = setup_two_player_game();
+    let dp = ScriptedDecisionProvider::new();
+    cast_bolt_at_opponent(&mut game, &dp);
+    game.resolve_top_of_stack(&dp).unwrap();
+    cast_bolt_at_opponent(&mut game, &dp);
+    game.resolve_top_of_stack(&dp).unwrap();
+
+    let grapeshot = put_in_hand(&mut game, copies::grapeshot(), 0);
+    game.players[0].mana_pool.add(ManaType::Red, 2);
+    pick_target(&dp, 1);
+    pay_generic(&dp, vec![1]);
+    game.cast_spell(0, grapeshot, &dp).unwrap();
+    assert_eq!(game.spells_cast_this_turn, 3);
+
+    game.put_pending_triggers_on_stack(&dp).unwrap();
+    keep_targets(&dp, 2);
+    game.resolve_top_of_stack(&dp).unwrap();
+    assert_eq!(copies_on_stack(&game), 2);
+
+    resolve_all(&mut game, &dp);
+    assert_eq!(game.players[1].life_total, 20 - 3 - 3 - 1 - 1 - 1);
+}
+
+#[test]
+fn test_storm_gets_no_copies_once_its_spell_is_countered() {
+    let mut game = setup_two_player_game();
+    let dp = ScriptedDecisionProvider::new();
+    cast_bolt_at_opponent(&mut game, &dp);
+    game.resolve_top_of_stack(&dp).unwrap();
+
+    let grapeshot = put_in_hand(&mut game, copies::grapeshot(), 0);
+    game.players[0].mana_pool.add(ManaType::Red, 2);
+    pick_target(&dp, 1);
+    pay_generic(&dp, vec![1]);
+    let grapeshot = game.cast_spell(0, grapeshot, &dp).unwrap();
+    game.put_pending_triggers_on_stack(&dp).unwrap();
+
+    let counterspell = put_in_hand(&mut game, alpha::counterspell(), 1);
+    game.players[1].mana_pool.add(ManaType::Blue, 2);
+    // Spells on the stack: [Grapeshot]
+    pick_target(&dp, 0);
+    game.cast_spell(1, counterspell, &dp).unwrap();
+    game.resolve_top_of_stack(&dp).unwrap();
+    assert!(!game.stack_entries.contains_key(&grapeshot));
+
+    game.resolve_top_of_stack(&dp).unwrap();
+    assert!(game.stack.is_empty());
+    assert_eq!(game.players[1].life_total, 17);
+}
+
+#[test]
+fn test_replicate_copies_once_per_extra_payment() {
+    let mut game = setup_two_player_game();
+    let pyromatics = put_in_hand(&mut game, copies::pyromatics(), 0);
+    game.players[0].mana_pool.add(ManaType::Red, 6);
+    let dp = ScriptedDecisionProvider::new();
+    dp.expect_pick_n(ChoiceKind::ChooseAdditionalCosts, vec![0]);
+    dp.expect_number(ChoiceKind::ReplicateCount { spell_id: ANY }, 2);
+    pick_target(&dp, 1);
+    pay_generic(&dp, vec![3]);
+    let spell = game.cast_spell(0, pyromatics, &dp).unwrap();
+    assert_eq!(game.stack_entries[&spell].additional_costs_paid.len(), 2);
+    assert_eq!(game.players[0].mana_pool.total(), 0);
+
+    game.put_pending_triggers_on_stack(&dp).unwrap();
+    keep_targets(&dp, 2);
+    game.resolve_top_of_stack(&dp).unwrap();
+    assert_eq!(copies_on_stack(&game), 2);
+
+    resolve_all(&mut game, &dp);
+    assert_eq!(game.players[1].life_total, 17);
+}
+
+#[test]
+fn test_casualty_sacrifices_a_creature_and_copies() {
+    let mut game = setup_two_player_game();
+    let bears = put_on_battlefield(&mut game, creatures::grizzly_bears(), 0);
+    let volley = put_in_hand(&mut game, copies::ruthless_volley(), 0);
+    game.players[0].mana_pool.add(ManaType::Red, 2);
+    let dp = ScriptedDecisionProvider::new();
+    dp.expect_pick_n(ChoiceKind::ChooseAdditionalCosts, vec![0]);
+    pick_target(&dp, 1);
+    pay_generic(&dp, vec![1]);
+    dp.expect_pick_n(ChoiceKind::ChooseSacrifice { spell_id: ANY }, vec![0]);
+    game.cast_spell(0, volley, &dp).unwrap();
+    assert!(!game.battlefield.contains_key(&bears));
+
+    keep_targets(&dp, 1);
+    resolve_all(&mut game, &dp);
+    assert_eq!(game.players[1].life_total, 16);
+}
+
+#[test]
+fn test_casualty_needs_a_creature_to_sacrifice() {
+    let mut game = setup_two_player_game();
+    let volley = put_in_hand(&mut game, copies::ruthless_volley(), 0);
+    game.players[0].mana_pool.add(ManaType::Red, 2);
+    let dp = ScriptedDecisionProvider::new();
+    dp.expect_pick_n(ChoiceKind::ChooseAdditionalCosts, vec![0]);
+    pick_target(&dp, 1);
+
+    assert!(game.cast_spell(0, volley, &dp).is_err());
+    assert!(game.stack.is_empty());
+    assert_eq!(game.players[0].hand.len(), 1);
+    assert_eq!(game.players[0].mana_pool.total(), 2);
+}
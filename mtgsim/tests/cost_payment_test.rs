@@ -0,0 +1,225 @@
+//! Paying mana costs without mana integration tests — convoke (rule
+//! 702.51), improvise (rule 702.126) and delve (rule 702.66) in the 601.2g
+//! window, emerge's sacrifice and reduction (rule 702.119a), and
+//! `castable_spells` counting all of them.
+
+mod common;
+
+use std::sync::Arc;
+
+use common::{fill_library, put_in_hand, put_on_battlefield, setup_two_player_game};
+
+use mtgsim::cards::cost_payment;
+use mtgsim::cards::creatures;
+use mtgsim::cards::equipment;
+use mtgsim::objects::card_data::CardData;
+use mtgsim::objects::object::GameObject;
+use mtgsim::oracle::mana_helpers::castable_spells;
+use mtgsim::state::game_state::GameState;
+use mtgsim::types::effects::EffectRecipient;
+use mtgsim::types::ids::ObjectId;
+use mtgsim::types::mana::{ManaCost, ManaType};
+use mtgsim::types::zones::Zone;
+use mtgsim::ui::choice_types::ChoiceKind;
+use mtgsim::ui::decision::ScriptedDecisionProvider;
+
+/// Expectations are matched by kind only, so their ids are placeholders.
+const ANY: ObjectId = ObjectId::nil();
+
+fn put_in_graveyard(game: &mut GameState, card_data: Arc<CardData>, player: usize) -> ObjectId {
+    let obj = GameObject::new(card_data, player, Zone::Graveyard);
+    let id = obj.id;
+    game.add_object(obj);
+    game.players[player].graveyard.push(id);
+    id
+}
+
+/// Give player 0 Stoke the Flames, to cast at player 1 convoking the
+/// creatures at `convoked` among those they control.
+fn stoke_the_flames_at_player_one(
+    game: &mut GameState,
+    dp: &ScriptedDecisionProvider,
+    convoked: Vec<usize>,
+) -> ObjectId {
+    let stoke = put_in_hand(game, cost_payment::stoke_the_flames(), 0);
+    // Any target: [Player(0), Player(1), creatures...]
+    dp.expect_pick_n(ChoiceKind::SelectRecipients { recipient: EffectRecipient::Implicit, spell_id: ANY }, vec![1]);
+    dp.expect_pick_n(ChoiceKind::Convoke { spell_id: ANY, remaining_cost: ManaCost::zero() }, convoked);
+    stoke
+}
+
+#[test]
+fn test_convoke_taps_creatures_for_generic_and_colored_mana() {
+    let mut game = setup_two_player_game();
+    let bears = put_on_battlefield(&mut game, creatures::grizzly_bears(), 0);
+    let giant = put_on_battlefield(&mut game, creatures::hill_giant(), 0);
+    let elemental = put_on_battlefield(&mut game, creatures::earth_elemental(), 0);
+    game.players[0].mana_pool.add(ManaType::Red, 1);
+    let dp = ScriptedDecisionProvider::new();
+
+    // {2}{R}{R}: the red creatures pay {R}{R}, the Bears {1}, and the pool
+    // the other {1}.
+    let stoke = stoke_the_flames_at_player_one(&mut game, &dp, vec![0, 1, 2]);
+    dp.expect_allocation(ChoiceKind::GenericManaAllocation { mana_cost: ManaCost::zero() }, vec![1]);
+    game.cast_spell(0, stoke, &dp).unwrap();
+    for creature in [bears, giant, elemental] {
+        assert!(game.battlefield[&creature].tapped);
+    }
+    assert_eq!(game.players[0].mana_pool.total(), 0);
+
+    game.resolve_top_of_stack(&dp).unwrap();
+    assert_eq!(game.players[1].life_total, 16);
+}
+
+#[test]
+fn test_convoking_a_creature_with_nothing_to_pay_for_rolls_back() {
+    let mut game = setup_two_player_game();
+    for _ in 0..3 {
+        put_on_battlefield(&mut game, creatures::grizzly_bears(), 0);
+    }
+    game.players[0].mana_pool.add(ManaType::Red, 2);
+    let dp = ScriptedDecisionProvider::new();
+
+    // Green creatures can only pay for the {2}: the third has nothing.
+    let stoke = stoke_the_flames_at_player_one(&mut game, &dp, vec![0, 1, 2]);
+    assert!(game.cast_spell(0, stoke, &dp).is_err());
+    assert_eq!(game.players[0].hand.len(), 1);
+    assert!(game.permanents().all(|(_, entry)| !entry.tapped));
+    assert_eq!(game.players[0].mana_pool.total(), 2);
+}
+
+#[test]
+fn test_convoked_creature_cannot_also_tap_for_mana() {
+    let mut game = setup_two_player_game();
+    let elves = put_on_battlefield(&mut game, creatures::llanowar_elves(), 0);
+    game.players[0].mana_pool.add(ManaType::Red, 2);
+    let dp = ScriptedDecisionProvider::new();
+
+    // Convoking the Elves pays {1}; they're tapped before the mana ability
+    // window, so their {G} can't pay the other {1}.
+    let stoke = stoke_the_flames_at_player_one(&mut game, &dp, vec![0]);
+    assert!(game.cast_spell(0, stoke, &dp).is_err());
+    assert!(!game.battlefield[&elves].tapped);
+    assert_eq!(game.players[0].mana_pool.total(), 2);
+    assert_eq!(game.players[0].hand.len(), 1);
+}
+
+#[test]
+fn test_castable_spells_counts_creatures_to_convoke() {
+    let mut game = setup_two_player_game();
+    put_in_hand(&mut game, cost_payment::stoke_the_flames(), 0);
+    game.players[0].mana_pool.add(ManaType::Red, 2);
+    assert!(castable_spells(&game, 0).is_empty());
+
+    put_on_battlefield(&mut game, creatures::grizzly_bears(), 0);
+    assert!(castable_spells(&game, 0).is_empty());
+    put_on_battlefield(&mut game, creatures::grizzly_bears(), 0);
+    assert_eq!(castable_spells(&game, 0).len(), 1);
+}
+
+#[test]
+fn test_delve_exiles_graveyard_cards_for_generic_mana() {
+    let mut game = setup_two_player_game();
+    fill_library(&mut game, 0, 3);
+    let cruise = put_in_hand(&mut game, cost_payment::treasure_cruise(), 0);
+    for _ in 0..5 {
+        put_in_graveyard(&mut game, creatures::grizzly_bears(), 0);
+    }
+    game.players[0].mana_pool.add(ManaType::Blue, 3);
+    assert_eq!(castable_spells(&game, 0).len(), 1);
+
+    let dp = ScriptedDecisionProvider::new();
+    dp.expect_pick_n(
+        ChoiceKind::Delve { spell_id: ANY, remaining_cost: ManaCost::zero() },
+        vec![0, 1, 2, 3, 4],
+    );
+    dp.expect_allocation(ChoiceKind::GenericManaAllocation { mana_cost: ManaCost::zero() }, vec![2]);
+    game.cast_spell(0, cruise, &dp).unwrap();
+    assert!(game.players[0].graveyard.is_empty());
+    assert_eq!(game.exile.len(), 5);
+    assert_eq!(game.players[0].mana_pool.total(), 0);
+
+    game.resolve_top_of_stack(&dp).unwrap();
+    assert_eq!(game.players[0].hand.len(), 3);
+}
+
+#[test]
+fn test_improvise_taps_artifacts_for_generic_mana_only() {
+    let mut game = setup_two_player_game();
+    fill_library(&mut game, 0, 3);
+    let engineer = put_in_hand(&mut game, cost_payment::reverse_engineer(), 0);
+    let artifacts: Vec<ObjectId> = (0..3)
+        .map(|_| put_on_battlefield(&mut game, equipment::bonesplitter(), 0))
+        .collect();
+    game.players[0].mana_pool.add(ManaType::Blue, 1);
+    // Improvise can't pay for {U}{U}.
+    assert!(castable_spells(&game, 0).is_empty());
+    game.players[0].mana_pool.add(ManaType::Blue, 1);
+    assert_eq!(castable_spells(&game, 0).len(), 1);
+
+    let dp = ScriptedDecisionProvider::new();
+    dp.expect_pick_n(
+        ChoiceKind::Improvise { spell_id: ANY, remaining_cost: ManaCost::zero() },
+        vec![0, 1, 2],
+    );
+    game.cast_spell(0, engineer, &dp).unwrap();
+    for artifact in artifacts {
+        assert!(game.battlefield[&artifact].tapped);
+    }
+    assert_eq!(game.players[0].mana_pool.total(), 0);
+}
+
+#[test]
+fn test_emerge_sacrifices_a_creature_and_reduces_the_cost() {
+    let mut game = setup_two_player_game();
+    let gryff = put_in_hand(&mut game, cost_payment::wretched_gryff(), 0);
+    game.players[0].mana_pool.add(ManaType::Blue, 4);
+    assert!(castable_spells(&game, 0).is_empty());
+    let bears = put_on_battlefield(&mut game, creatures::grizzly_bears(), 0);
+    assert_eq!(castable_spells(&game, 0).len(), 1);
+
+    // Costs: [normal, emerge]. {5}{U} less the Bears' mana value 2.
+    let dp = ScriptedDecisionProvider::new();
+    dp.expect_pick_n(ChoiceKind::ChooseAlternativeCost, vec![1]);
+    dp.expect_pick_n(ChoiceKind::ChooseSacrifice { spell_id: ANY }, vec![0]);
+    dp.expect_allocation(ChoiceKind::GenericManaAllocation { mana_cost: ManaCost::zero() }, vec![3]);
+    game.cast_spell(0, gryff, &dp).unwrap();
+    assert!(!game.battlefield.contains_key(&bears));
+    assert_eq!(game.players[0].graveyard.len(), 1);
+    assert_eq!(game.players[0].mana_pool.total(), 0);
+
+    game.resolve_top_of_stack(&dp).unwrap();
+    let names: Vec<&str> = game.battlefield.keys().map(|id| game.objects[id].card_data.name.as_str()).collect();
+    assert_eq!(names, vec!["Wretched Gryff"]);
+}
+
+#[test]
+fn test_emerge_needs_a_creature_to_sacrifice() {
+    let mut game = setup_two_player_game();
+    let gryff = put_in_hand(&mut game, cost_payment::wretched_gryff(), 0);
+    game.players[0].mana_pool.add(ManaType::Blue, 6);
+    let dp = ScriptedDecisionProvider::new();
+    dp.expect_pick_n(ChoiceKind::ChooseAlternativeCost, vec![1]);
+    assert!(game.cast_spell(0, gryff, &dp).is_err());
+    assert!(game.players[0].hand.contains(&gryff));
+    assert_eq!(game.players[0].mana_pool.total(), 6);
+}
+
+#[test]
+fn test_emerge_and_casualty_cannot_sacrifice_the_same_creature() {
+    let mut game = setup_two_player_game();
+    let larva = put_in_hand(&mut game, cost_payment::gorging_larva(), 0);
+    let bears = put_on_battlefield(&mut game, creatures::grizzly_bears(), 0);
+    game.players[0].mana_pool.add(ManaType::Blue, 4);
+
+    // Costs: [normal, emerge]. The Bears are sacrificed for emerge, so
+    // there's nothing left for casualty.
+    let dp = ScriptedDecisionProvider::new();
+    dp.expect_pick_n(ChoiceKind::ChooseAlternativeCost, vec![1]);
+    dp.expect_pick_n(ChoiceKind::ChooseAdditionalCosts, vec![0]);
+    dp.expect_pick_n(ChoiceKind::ChooseSacrifice { spell_id: ANY }, vec![0]);
+    assert!(game.cast_spell(0, larva, &dp).is_err());
+    assert!(game.battlefield.contains_key(&bears));
+    assert!(game.players[0].hand.contains(&larva));
+    assert_eq!(game.players[0].mana_pool.total(), 4);
+}
@@ -0,0 +1,219 @@
+//! Double-faced card integration tests — transforming (rule 701.28), modal
+//! double-faced cards cast or played as either face (rule 712.12), and
+//! day/night with daybound and nightbound (rules 702.145, 726).
+
+mod common;
+
+use common::{fill_library, put_in_hand, put_on_battlefield, setup_two_player_game};
+
+use mtgsim::cards::creatures;
+use mtgsim::cards::double_faced;
+use mtgsim::events::event::GameEvent;
+use mtgsim::objects::card_data::CardFace;
+use mtgsim::oracle::characteristics::{
+    get_effective_name, get_effective_power, get_effective_toughness, get_mana_value, has_keyword,
+    has_type, is_creature,
+};
+use mtgsim::oracle::legality::playable_lands;
+use mtgsim::oracle::mana_helpers::castable_spells;
+use mtgsim::state::game_state::{DayNight, GameState, Phase, PhaseType};
+use mtgsim::types::card_types::CardType;
+use mtgsim::types::effects::CounterType;
+use mtgsim::types::keywords::KeywordAbility;
+use mtgsim::types::mana::{ManaCost, ManaType};
+use mtgsim::types::zones::Zone;
+use mtgsim::ui::choice_types::ChoiceKind;
+use mtgsim::ui::decision::ScriptedDecisionProvider;
+
+fn main_phase_game() -> GameState {
+    let mut game = setup_two_player_game();
+    game.phase = Phase::new(PhaseType::Precombat);
+    game
+}
+
+/// Advance until the next turn has begun.
+fn advance_to_next_turn(game: &mut GameState) {
+    let turn = game.turn_number;
+    while game.turn_number == turn {
+        game.advance_turn().unwrap();
+    }
+}
+
+#[test]
+fn test_transform_keeps_the_object_with_a_new_timestamp() {
+    let mut game = setup_two_player_game();
+    let delver = put_on_battlefield(&mut game, double_faced::delver_of_secrets(), 0);
+    game.battlefield.get_mut(&delver).unwrap().add_counters(CounterType::PlusOnePlusOne, 1);
+    let timestamp = game.battlefield[&delver].timestamp;
+
+    assert!(game.transform(delver));
+
+    assert_eq!(get_effective_name(&game, delver), "Insectile Aberration");
+    assert_eq!(get_effective_power(&game, delver), Some(4), "counters stay on");
+    assert_eq!(get_effective_toughness(&game, delver), Some(3));
+    assert!(has_keyword(&game, delver, KeywordAbility::Flying));
+    assert!(game.battlefield[&delver].timestamp > timestamp);
+    assert!(game.events.events().iter().any(|e| matches!(
+        e,
+        GameEvent::Transformed { object_id } if *object_id == delver
+    )));
+
+    assert!(game.transform(delver));
+    assert_eq!(get_effective_name(&game, delver), "Delver of Secrets");
+    assert!(!has_keyword(&game, delver, KeywordAbility::Flying));
+}
+
+#[test]
+fn test_transformed_permanent_has_its_front_faces_mana_value() {
+    let mut game = setup_two_player_game();
+    let delver = put_on_battlefield(&mut game, double_faced::delver_of_secrets(), 0);
+    assert!(game.transform(delver));
+    assert_eq!(get_mana_value(&game, delver), 1);
+}
+
+#[test]
+fn test_transformed_card_is_front_face_up_after_leaving_the_battlefield() {
+    let mut game = setup_two_player_game();
+    let delver = put_on_battlefield(&mut game, double_faced::delver_of_secrets(), 0);
+    game.transform(delver);
+
+    let delver = game.change_zone(delver, Zone::Graveyard).unwrap();
+    assert_eq!(game.face_up(delver), CardFace::Front);
+    assert_eq!(get_effective_name(&game, delver), "Delver of Secrets");
+}
+
+#[test]
+fn test_moonmist_transforms_only_humans_that_can_transform() {
+    let mut game = setup_two_player_game();
+    let delver = put_on_battlefield(&mut game, double_faced::delver_of_secrets(), 0);
+    let liberator = put_on_battlefield(&mut game, double_faced::outland_liberator(), 1);
+    let bears = put_on_battlefield(&mut game, creatures::grizzly_bears(), 0);
+
+    let moonmist = put_in_hand(&mut game, double_faced::moonmist(), 0);
+    game.players[0].mana_pool.add(ManaType::Green, 2);
+    let dp = ScriptedDecisionProvider::new();
+    dp.expect_allocation(ChoiceKind::GenericManaAllocation { mana_cost: ManaCost::zero() }, vec![1]);
+    game.cast_spell(0, moonmist, &dp).unwrap();
+    game.resolve_top_of_stack(&dp).unwrap();
+
+    assert_eq!(get_effective_name(&game, delver), "Insectile Aberration");
+    assert!(!game.battlefield[&liberator].transformed, "daybound only transforms with day/night");
+    assert!(!game.battlefield[&bears].transformed);
+}
+
+#[test]
+fn test_modal_dfc_cannot_transform() {
+    let mut game = setup_two_player_game();
+    let halvar = put_on_battlefield(&mut game, double_faced::halvar_god_of_battle(), 0);
+    assert!(!game.transform(halvar));
+    assert_eq!(get_effective_name(&game, halvar), "Halvar, God of Battle");
+}
+
+#[test]
+fn test_spell_land_mdfc_is_both_castable_and_playable() {
+    let mut game = main_phase_game();
+    let hazard = put_in_hand(&mut game, double_faced::spikefield_hazard(), 0);
+    game.players[0].mana_pool.add(ManaType::Red, 1);
+
+    assert_eq!(playable_lands(&game, 0), vec![hazard]);
+    assert!(castable_spells(&game, 0).iter().any(|(id, _)| *id == hazard));
+}
+
+#[test]
+fn test_playing_spell_land_mdfc_puts_its_land_face_onto_the_battlefield() {
+    let mut game = main_phase_game();
+    let hazard = put_in_hand(&mut game, double_faced::spikefield_hazard(), 0);
+
+    let hazard = game.play_land(0, hazard, Zone::Hand).unwrap();
+
+    assert_eq!(game.face_up(hazard), CardFace::Back);
+    assert_eq!(get_effective_name(&game, hazard), "Spikefield Cave");
+    assert!(has_type(&game, hazard, CardType::Land));
+    assert!(!has_type(&game, hazard, CardType::Instant));
+    assert_eq!(game.players[0].lands_played_this_turn, 1);
+}
+
+#[test]
+fn test_land_land_mdfc_is_played_as_the_chosen_face() {
+    let mut game = main_phase_game();
+    let pathway = put_in_hand(&mut game, double_faced::brightclimb_pathway(), 0);
+
+    let pathway = game.play_land_as(0, pathway, Zone::Hand, CardFace::Back).unwrap();
+    assert_eq!(get_effective_name(&game, pathway), "Grimclimb Pathway");
+}
+
+#[test]
+fn test_casting_mdfc_back_face_uses_its_cost_and_characteristics() {
+    let mut game = main_phase_game();
+    let halvar = put_in_hand(&mut game, double_faced::halvar_god_of_battle(), 0);
+    game.players[0].mana_pool.add(ManaType::White, 2);
+
+    let dp = ScriptedDecisionProvider::new();
+    dp.expect_pick_n(ChoiceKind::ChooseFace { card_id: halvar }, vec![1]);
+    dp.expect_allocation(ChoiceKind::GenericManaAllocation { mana_cost: ManaCost::zero() }, vec![1]);
+    let halvar = game.cast_spell(0, halvar, &dp).unwrap();
+
+    assert_eq!(game.stack_entries[&halvar].cast_face, CardFace::Back);
+    assert_eq!(get_effective_name(&game, halvar), "Sword of the Realms");
+    assert_eq!(game.players[0].mana_pool.total(), 0, "paid {{1}}{{W}}, not {{2}}{{W}}{{W}}");
+
+    game.resolve_top_of_stack(&dp).unwrap();
+    let halvar = game.current_identity(halvar);
+    assert_eq!(game.face_up(halvar), CardFace::Back);
+    assert!(has_type(&game, halvar, CardType::Artifact));
+    assert!(!is_creature(&game, halvar));
+}
+
+#[test]
+fn test_mdfc_that_cannot_be_cast_does_not_ask_for_a_face() {
+    let mut game = main_phase_game();
+    game.phase = Phase::new(PhaseType::Beginning);
+    let halvar = put_in_hand(&mut game, double_faced::halvar_god_of_battle(), 0);
+    game.players[0].mana_pool.add(ManaType::White, 4);
+
+    // Neither face can be cast in the beginning phase, so no face choice is
+    // queued; asking for one would panic.
+    let dp = ScriptedDecisionProvider::new();
+    assert!(game.cast_spell(0, halvar, &dp).is_err());
+    assert_eq!(game.objects[&halvar].zone, Zone::Hand);
+}
+
+#[test]
+fn test_daybound_werewolf_transforms_as_night_falls_and_back_at_day() {
+    let mut game = setup_two_player_game();
+    for pid in 0..2 {
+        fill_library(&mut game, pid, 10);
+    }
+    let liberator = put_on_battlefield(&mut game, double_faced::outland_liberator(), 0);
+    assert_eq!(game.day_night, Some(DayNight::Day));
+
+    // No spells this turn: it becomes night as the next turn begins.
+    advance_to_next_turn(&mut game);
+    assert_eq!(game.day_night, Some(DayNight::Night));
+    assert_eq!(get_effective_name(&game, liberator), "Frenzied Trapbreaker");
+    assert_eq!(get_effective_power(&game, liberator), Some(3));
+
+    // Two spells from the active player turn it back to day.
+    game.active_player_spells_this_turn = 2;
+    advance_to_next_turn(&mut game);
+    assert_eq!(game.day_night, Some(DayNight::Day));
+    assert_eq!(get_effective_name(&game, liberator), "Outland Liberator");
+}
+
+#[test]
+fn test_daybound_werewolf_cast_at_night_enters_transformed() {
+    let mut game = main_phase_game();
+    game.set_day_night(DayNight::Night);
+    let liberator = put_in_hand(&mut game, double_faced::outland_liberator(), 0);
+    game.players[0].mana_pool.add(ManaType::Green, 2);
+
+    let dp = ScriptedDecisionProvider::new();
+    dp.expect_allocation(ChoiceKind::GenericManaAllocation { mana_cost: ManaCost::zero() }, vec![1]);
+    let liberator = game.cast_spell(0, liberator, &dp).unwrap();
+    assert_eq!(game.active_player_spells_this_turn, 1);
+    game.resolve_top_of_stack(&dp).unwrap();
+    let liberator = game.current_identity(liberator);
+
+    assert_eq!(get_effective_name(&game, liberator), "Frenzied Trapbreaker");
+    assert!(has_keyword(&game, liberator, KeywordAbility::Nightbound));
+}
@@ -0,0 +1,201 @@
+//! Equipment integration tests — equip (rule 702.6), equipment static
+//! abilities following the host (rules 301.5, 611.3a), and reconfigure
+//! (rule 702.151).
+
+mod common;
+
+use common::{put_on_battlefield, setup_two_player_game};
+
+use mtgsim::cards::creatures;
+use mtgsim::cards::equipment;
+use mtgsim::engine::resolve::ResolvedTarget;
+use mtgsim::objects::card_data::{AbilityDef, AbilityType};
+use mtgsim::oracle::characteristics::{
+    get_effective_abilities, get_effective_power, get_effective_subtypes, get_effective_toughness, has_keyword, is_creature,
+};
+use mtgsim::oracle::legality::enumerate_legal_selections;
+use mtgsim::state::game_state::{GameState, Phase, PhaseType, StepType};
+use mtgsim::types::card_types::{CardType, CreatureType, Subtype};
+use mtgsim::types::effects::{
+    Effect, EffectRecipient, PermanentFilter, Primitive, PlayerRef, SelectionFilter, TargetCount,
+};
+use mtgsim::types::ids::ObjectId;
+use mtgsim::types::keywords::KeywordAbility;
+use mtgsim::types::mana::{ManaCost, ManaType};
+use mtgsim::types::zones::Zone;
+use mtgsim::ui::choice_types::ChoiceKind;
+use mtgsim::ui::decision::ScriptedDecisionProvider;
+
+/// "Target creature you control" from player 0's point of view.
+fn creature_you_control() -> SelectionFilter {
+    SelectionFilter::Permanent(PermanentFilter::And(
+        Box::new(PermanentFilter::ByType(CardType::Creature)),
+        Box::new(PermanentFilter::ByController(PlayerRef::You)),
+    ))
+    .bind_controller(0)
+}
+
+/// Index of the first activated ability of `id` matching `pred`.
+fn ability_index(game: &GameState, id: ObjectId, pred: impl Fn(&AbilityDef) -> bool) -> usize {
+    get_effective_abilities(game, id)
+        .iter()
+        .position(|a| a.ability_type == AbilityType::Activated && pred(a))
+        .unwrap()
+}
+
+/// Activate the attach ability of `equipment` targeting `host`,
+/// with `mana` colorless in player 0's pool, and resolve it.
+fn equip(game: &mut GameState, equipment: ObjectId, host: ObjectId, mana: u64) {
+    let legal = enumerate_legal_selections(game, &creature_you_control(), None);
+    let index = legal.iter().position(|t| *t == ResolvedTarget::Object(host)).unwrap();
+    game.players[0].mana_pool.add(ManaType::Colorless, mana);
+    let dp = ScriptedDecisionProvider::new();
+    dp.expect_pick_n(
+        ChoiceKind::SelectRecipients {
+            recipient: EffectRecipient::Target(SelectionFilter::Creature, TargetCount::Exactly(1)),
+            spell_id: equipment,
+        },
+        vec![index],
+    );
+    if mana > 0 {
+        dp.expect_allocation(
+            ChoiceKind::GenericManaAllocation { mana_cost: ManaCost::zero() },
+            vec![mana],
+        );
+    }
+    let attach = ability_index(game, equipment, AbilityDef::is_attach_ability);
+    game.activate_ability(0, equipment, attach, &dp).unwrap();
+    game.resolve_top_of_stack(&dp).unwrap();
+}
+
+fn attached_to(game: &GameState, id: ObjectId) -> Option<ObjectId> {
+    game.battlefield.get(&id).unwrap().attached_to
+}
+
+#[test]
+fn test_equip_bonesplitter_pumps_host() {
+    let mut game = setup_two_player_game();
+    let bears = put_on_battlefield(&mut game, creatures::grizzly_bears(), 0);
+    let bonesplitter = put_on_battlefield(&mut game, equipment::bonesplitter(), 0);
+    assert_eq!(get_effective_power(&game, bears), Some(2));
+
+    equip(&mut game, bonesplitter, bears, 1);
+
+    assert_eq!(attached_to(&game, bonesplitter), Some(bears));
+    assert!(game.battlefield.get(&bears).unwrap().attached_by.contains(&bonesplitter));
+    assert_eq!(get_effective_power(&game, bears), Some(4));
+    assert_eq!(get_effective_toughness(&game, bears), Some(2));
+    assert_eq!(game.players[0].mana_pool.total(), 0);
+}
+
+#[test]
+fn test_reequip_moves_bonus_to_new_host() {
+    let mut game = setup_two_player_game();
+    let first = put_on_battlefield(&mut game, creatures::grizzly_bears(), 0);
+    let sword = put_on_battlefield(&mut game, equipment::sword_of_fire_and_ice(), 0);
+    let second = put_on_battlefield(&mut game, creatures::grizzly_bears(), 0);
+    equip(&mut game, sword, first, 2);
+    assert_eq!(get_effective_power(&game, first), Some(4));
+
+    equip(&mut game, sword, second, 2);
+
+    assert_eq!(attached_to(&game, sword), Some(second));
+    assert!(game.battlefield.get(&first).unwrap().attached_by.is_empty());
+    assert_eq!(get_effective_power(&game, first), Some(2));
+    assert_eq!(get_effective_power(&game, second), Some(4));
+    assert_eq!(get_effective_toughness(&game, second), Some(4));
+}
+
+#[test]
+fn test_equip_only_as_a_sorcery() {
+    let mut game = setup_two_player_game();
+    put_on_battlefield(&mut game, creatures::grizzly_bears(), 0);
+    let bonesplitter = put_on_battlefield(&mut game, equipment::bonesplitter(), 0);
+    game.players[0].mana_pool.add(ManaType::Colorless, 1);
+    let attach = ability_index(&game, bonesplitter, AbilityDef::is_attach_ability);
+    let dp = ScriptedDecisionProvider::new();
+
+    game.phase = Phase { phase_type: PhaseType::Combat, step: Some(StepType::DeclareAttackers) };
+    assert!(game.activate_ability(0, bonesplitter, attach, &dp).is_err());
+
+    game.phase = Phase::new(PhaseType::Precombat);
+    game.active_player = 1;
+    assert!(game.activate_ability(0, bonesplitter, attach, &dp).is_err());
+    assert!(game.stack.is_empty());
+}
+
+#[test]
+fn test_equip_targets_only_creatures_you_control() {
+    let mut game = setup_two_player_game();
+    let opposing = put_on_battlefield(&mut game, creatures::grizzly_bears(), 1);
+    let mine = put_on_battlefield(&mut game, creatures::grizzly_bears(), 0);
+
+    // "You" is bound to the activating player when the ability goes on the
+    // stack, so only player 0's creature is a legal target.
+    let legal = enumerate_legal_selections(&game, &creature_you_control(), None);
+    assert_eq!(legal, vec![ResolvedTarget::Object(mine)]);
+    assert!(!legal.contains(&ResolvedTarget::Object(opposing)));
+}
+
+#[test]
+fn test_equipment_stays_when_host_dies() {
+    let mut game = setup_two_player_game();
+    let bears = put_on_battlefield(&mut game, creatures::grizzly_bears(), 0);
+    let bonesplitter = put_on_battlefield(&mut game, equipment::bonesplitter(), 0);
+    equip(&mut game, bonesplitter, bears, 1);
+
+    game.change_zone(bears, Zone::Graveyard).unwrap();
+    game.check_state_based_actions(&ScriptedDecisionProvider::new()).unwrap();
+
+    assert!(game.battlefield.contains_key(&bonesplitter));
+    assert_eq!(attached_to(&game, bonesplitter), None);
+}
+
+#[test]
+fn test_bonus_ends_when_equipment_leaves() {
+    let mut game = setup_two_player_game();
+    let bears = put_on_battlefield(&mut game, creatures::grizzly_bears(), 0);
+    let hammer = put_on_battlefield(&mut game, equipment::loxodon_warhammer(), 0);
+    equip(&mut game, hammer, bears, 3);
+    assert_eq!(get_effective_power(&game, bears), Some(5));
+    assert!(has_keyword(&game, bears, KeywordAbility::Trample));
+    assert!(has_keyword(&game, bears, KeywordAbility::Lifelink));
+
+    game.change_zone(hammer, Zone::Graveyard).unwrap();
+    assert_eq!(get_effective_power(&game, bears), Some(2));
+    assert!(!has_keyword(&game, bears, KeywordAbility::Lifelink));
+    assert!(game.battlefield.get(&bears).unwrap().attached_by.is_empty());
+}
+
+#[test]
+fn test_reconfigure_attach_and_unattach() {
+    let mut game = setup_two_player_game();
+    let bears = put_on_battlefield(&mut game, creatures::grizzly_bears(), 0);
+    let blades = put_on_battlefield(&mut game, equipment::lizard_blades(), 0);
+    let dp = ScriptedDecisionProvider::new();
+
+    // Unattach needs the Blades to be attached.
+    let unattach = ability_index(&game, blades, |a| {
+        matches!(a.effect, Effect::Atom(Primitive::Unattach, _))
+    });
+    assert!(game.activate_ability(0, blades, unattach, &dp).is_err());
+
+    equip(&mut game, blades, bears, 2);
+
+    assert_eq!(attached_to(&game, blades), Some(bears));
+    assert!(!is_creature(&game, blades));
+    assert!(!get_effective_subtypes(&game, blades).contains(&Subtype::Creature(CreatureType::Lizard)));
+    assert!(has_keyword(&game, bears, KeywordAbility::DoubleStrike));
+
+    game.players[0].mana_pool.add(ManaType::Colorless, 2);
+    dp.expect_allocation(
+        ChoiceKind::GenericManaAllocation { mana_cost: ManaCost::zero() },
+        vec![2],
+    );
+    game.activate_ability(0, blades, unattach, &dp).unwrap();
+    game.resolve_top_of_stack(&dp).unwrap();
+
+    assert_eq!(attached_to(&game, blades), None);
+    assert!(is_creature(&game, blades));
+    assert!(!has_keyword(&game, bears, KeywordAbility::DoubleStrike));
+}
@@ -0,0 +1,234 @@
+//! Face-down integration tests — casting with morph, megamorph and
+//! disguise (rules 702.37, 702.168), turning face up as a special action
+//! (rule 116.2b), manifest and cloak (rules 701.40, 701.58), and the
+//! hidden-information rules for face-down objects (rule 708).
+
+mod common;
+
+use common::{put_in_hand, setup_two_player_game};
+
+use mtgsim::cards::creatures;
+use mtgsim::cards::face_down;
+use mtgsim::events::event::GameEvent;
+use mtgsim::objects::card_data::{CardFace, FaceDownKind};
+use mtgsim::objects::object::GameObject;
+use mtgsim::oracle::characteristics::{
+    get_effective_name, get_effective_power, get_effective_toughness, has_keyword,
+};
+use mtgsim::oracle::legality::candidate_priority_actions;
+use mtgsim::state::game_state::GameState;
+use mtgsim::types::ids::ObjectId;
+use mtgsim::types::keywords::KeywordAbility;
+use mtgsim::types::mana::{ManaCost, ManaType};
+use mtgsim::types::zones::Zone;
+use mtgsim::ui::choice_types::ChoiceKind;
+use mtgsim::ui::decision::{PriorityAction, ScriptedDecisionProvider};
+use mtgsim::ui::display::{format_permanent, format_stack};
+
+fn pay_generic(dp: &ScriptedDecisionProvider, allocation: Vec<u64>) {
+    dp.expect_allocation(ChoiceKind::GenericManaAllocation { mana_cost: ManaCost::zero() }, allocation);
+}
+
+/// Cast `card` from player 0's hand face down for {3}, returning the spell.
+fn cast_face_down(game: &mut GameState, card: ObjectId) -> ObjectId {
+    game.players[0].mana_pool.add(ManaType::Colorless, 3);
+    let dp = ScriptedDecisionProvider::new();
+    dp.expect_pick_n(ChoiceKind::ChooseFace { card_id: card }, vec![1]);
+    pay_generic(&dp, vec![3]);
+    game.cast_spell(0, card, &dp).unwrap()
+}
+
+fn resolve(game: &mut GameState) {
+    game.resolve_top_of_stack(&ScriptedDecisionProvider::new()).unwrap();
+}
+
+fn put_on_top_of_library(game: &mut GameState, card: ObjectId) {
+    let obj = game.objects.get_mut(&card).unwrap();
+    obj.zone = Zone::Library;
+    game.players[0].hand.retain(|&id| id != card);
+    game.players[0].library.push(card);
+}
+
+fn cast_manifest_spell(game: &mut GameState, spell: ObjectId, mana: ManaType) {
+    game.players[0].mana_pool.add(mana, 2);
+    let dp = ScriptedDecisionProvider::new();
+    pay_generic(&dp, vec![1]);
+    game.cast_spell(0, spell, &dp).unwrap();
+    resolve(game);
+}
+
+#[test]
+fn test_morph_card_is_cast_face_down_for_three() {
+    let mut game = setup_two_player_game();
+    let angel = put_in_hand(&mut game, face_down::exalted_angel(), 0);
+
+    let angel = cast_face_down(&mut game, angel);
+    assert_eq!(game.face_up(angel), CardFace::FaceDown(FaceDownKind::Morph));
+    assert!(format_stack(&game, 1).contains("Face-down creature"));
+    assert!(!format_stack(&game, 1).contains("Exalted Angel"));
+    assert!(format_stack(&game, 0).contains("Exalted Angel"), "its controller may look at it");
+
+    resolve(&mut game);
+    let angel = game.current_identity(angel);
+    assert_eq!(game.battlefield[&angel].face_down, Some(FaceDownKind::Morph));
+    assert_eq!(get_effective_name(&game, angel), "");
+    assert_eq!(get_effective_power(&game, angel), Some(2));
+    assert_eq!(get_effective_toughness(&game, angel), Some(2));
+    assert!(!has_keyword(&game, angel, KeywordAbility::Flying));
+}
+
+#[test]
+fn test_opponents_see_only_a_2_2() {
+    let mut game = setup_two_player_game();
+    let angel = put_in_hand(&mut game, face_down::exalted_angel(), 0);
+    let angel = cast_face_down(&mut game, angel);
+    resolve(&mut game);
+    let angel = game.current_identity(angel);
+
+    let opponent_view = format_permanent(&game, angel, 1);
+    assert!(opponent_view.starts_with("Face-down creature 2/2"), "{}", opponent_view);
+    assert!(!opponent_view.contains("Exalted Angel"));
+
+    let controller_view = format_permanent(&game, angel, 0);
+    assert!(controller_view.starts_with("Face-down creature (Exalted Angel) 2/2"), "{}", controller_view);
+}
+
+#[test]
+fn test_turning_face_up_is_a_special_action() {
+    let mut game = setup_two_player_game();
+    let angel = put_in_hand(&mut game, face_down::exalted_angel(), 0);
+    let angel = cast_face_down(&mut game, angel);
+    resolve(&mut game);
+    let angel = game.current_identity(angel);
+
+    assert!(candidate_priority_actions(&game, 0).contains(&PriorityAction::TurnFaceUp(angel)));
+    assert!(!candidate_priority_actions(&game, 1).contains(&PriorityAction::TurnFaceUp(angel)));
+
+    game.players[0].mana_pool.add(ManaType::White, 4);
+    let dp = ScriptedDecisionProvider::new();
+    pay_generic(&dp, vec![2]);
+    game.turn_face_up(0, angel, &dp).unwrap();
+
+    assert!(game.stack.is_empty(), "turning face up doesn't use the stack");
+    assert_eq!(game.battlefield[&angel].face_down, None);
+    assert_eq!(get_effective_name(&game, angel), "Exalted Angel");
+    assert_eq!(get_effective_power(&game, angel), Some(4));
+    assert!(has_keyword(&game, angel, KeywordAbility::Flying));
+    assert!(game.events.events().iter().any(|e| matches!(
+        e, GameEvent::TurnedFaceUp { object_id } if *object_id == angel
+    )));
+    assert!(format_permanent(&game, angel, 1).starts_with("Exalted Angel 4/5"));
+}
+
+#[test]
+fn test_turning_face_up_requires_its_cost() {
+    let mut game = setup_two_player_game();
+    let angel = put_in_hand(&mut game, face_down::exalted_angel(), 0);
+    let angel = cast_face_down(&mut game, angel);
+    resolve(&mut game);
+    let angel = game.current_identity(angel);
+
+    game.players[0].mana_pool.add(ManaType::White, 2);
+    let dp = ScriptedDecisionProvider::new();
+    assert!(game.turn_face_up(0, angel, &dp).is_err());
+    assert!(game.turn_face_up(1, angel, &dp).is_err(), "only its controller can");
+    assert_eq!(game.battlefield[&angel].face_down, Some(FaceDownKind::Morph));
+}
+
+#[test]
+fn test_megamorph_gets_a_counter_as_it_turns_face_up() {
+    let mut game = setup_two_player_game();
+    let protector = put_in_hand(&mut game, face_down::den_protector(), 0);
+    let protector = cast_face_down(&mut game, protector);
+    resolve(&mut game);
+    let protector = game.current_identity(protector);
+
+    game.players[0].mana_pool.add(ManaType::Green, 2);
+    let dp = ScriptedDecisionProvider::new();
+    pay_generic(&dp, vec![1]);
+    game.turn_face_up(0, protector, &dp).unwrap();
+
+    assert_eq!(get_effective_power(&game, protector), Some(3));
+    assert_eq!(get_effective_toughness(&game, protector), Some(2));
+}
+
+#[test]
+fn test_disguised_permanent_has_ward_while_face_down() {
+    let mut game = setup_two_player_game();
+    let vagrant = put_in_hand(&mut game, face_down::masked_vagrant(), 0);
+    let vagrant = cast_face_down(&mut game, vagrant);
+    resolve(&mut game);
+    let vagrant = game.current_identity(vagrant);
+
+    assert_eq!(game.face_up(vagrant), CardFace::FaceDown(FaceDownKind::Disguise));
+    assert!(has_keyword(&game, vagrant, KeywordAbility::Ward));
+    assert!(format_permanent(&game, vagrant, 1).contains("[ward]"));
+}
+
+#[test]
+fn test_manifested_creature_card_turns_face_up_for_its_mana_cost() {
+    let mut game = setup_two_player_game();
+    let bears = put_in_hand(&mut game, creatures::grizzly_bears(), 0);
+    put_on_top_of_library(&mut game, bears);
+    let summons = put_in_hand(&mut game, face_down::soul_summons(), 0);
+
+    cast_manifest_spell(&mut game, summons, ManaType::White);
+    let bears = game.current_identity(bears);
+    assert_eq!(game.face_up(bears), CardFace::FaceDown(FaceDownKind::Manifest));
+    assert!(game.is_hidden_from(bears, 1));
+
+    game.players[0].mana_pool.add(ManaType::Green, 2);
+    let dp = ScriptedDecisionProvider::new();
+    pay_generic(&dp, vec![1]);
+    game.turn_face_up(0, bears, &dp).unwrap();
+    assert_eq!(get_effective_name(&game, bears), "Grizzly Bears");
+}
+
+#[test]
+fn test_manifested_noncreature_card_cannot_turn_face_up() {
+    let mut game = setup_two_player_game();
+    let sorcery = put_in_hand(&mut game, face_down::soul_summons(), 0);
+    put_on_top_of_library(&mut game, sorcery);
+    let summons = put_in_hand(&mut game, face_down::soul_summons(), 0);
+
+    cast_manifest_spell(&mut game, summons, ManaType::White);
+    let sorcery = game.current_identity(sorcery);
+    assert!(game.battlefield.contains_key(&sorcery));
+    assert_eq!(game.face_up_cost(sorcery), None);
+    assert!(game.turnable_face_up(0).is_empty());
+}
+
+#[test]
+fn test_cloaked_card_is_a_2_2_with_ward() {
+    let mut game = setup_two_player_game();
+    let angel = put_in_hand(&mut game, face_down::exalted_angel(), 0);
+    put_on_top_of_library(&mut game, angel);
+    let secret = put_in_hand(&mut game, face_down::shrouded_secret(), 0);
+
+    cast_manifest_spell(&mut game, secret, ManaType::Blue);
+    let angel = game.current_identity(angel);
+    assert_eq!(game.face_up(angel), CardFace::FaceDown(FaceDownKind::Cloak));
+    assert!(has_keyword(&game, angel, KeywordAbility::Ward));
+    assert_eq!(get_effective_power(&game, angel), Some(2));
+    assert_eq!(game.face_up_cost(angel).unwrap().mana_value(), 4, "its morph cost");
+}
+
+#[test]
+fn test_face_down_permanent_is_revealed_as_it_leaves() {
+    let mut game = setup_two_player_game();
+    let angel = put_in_hand(&mut game, face_down::exalted_angel(), 0);
+    let angel = cast_face_down(&mut game, angel);
+    resolve(&mut game);
+    let angel = game.current_identity(angel);
+
+    let angel = game.change_zone(angel, Zone::Graveyard).unwrap();
+    assert_eq!(game.face_up(angel), CardFace::Front);
+    assert_eq!(get_effective_name(&game, angel), "Exalted Angel");
+    assert!(!game.is_hidden_from(angel, 1));
+
+    // Played again, it's a new permanent, face up unless cast face down.
+    let fresh = GameObject::new(face_down::exalted_angel(), 0, Zone::Battlefield);
+    let fresh_id = game.add_object(fresh);
+    game.place_on_battlefield(fresh_id, 0);
+    assert_eq!(game.battlefield[&fresh_id].face_down, None);
+}
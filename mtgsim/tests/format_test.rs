@@ -0,0 +1,97 @@
+//! Format integration tests (rule 100.6) — `Game::new` rejecting illegal
+//! decks, `Game::with_format` validation and setup hooks, and a custom
+//! format overriding how the game is won.
+
+use std::sync::Arc;
+
+use mtgsim::cards::{alpha, basic_lands};
+use mtgsim::cards::phase5_pre_cards::isamaru_hound_of_konda;
+use mtgsim::objects::card_data::CardData;
+use mtgsim::state::format::{BanList, Brawl, Constructed, DeckSubmission, Format, Limited};
+use mtgsim::state::game::{Game, GameResult};
+use mtgsim::state::game_config::GameConfig;
+use mtgsim::state::game_state::GameState;
+use mtgsim::types::zones::Zone;
+
+fn forests(n: usize) -> Vec<Arc<CardData>> {
+    (0..n).map(|_| basic_lands::forest()).collect()
+}
+
+/// Constructed rules, but a player wins by reaching 30 life.
+struct ThirtyLife {
+    inner: Constructed,
+}
+
+impl Format for ThirtyLife {
+    fn name(&self) -> &str {
+        "Thirty Life"
+    }
+
+    fn config(&self) -> &GameConfig {
+        &self.inner.config
+    }
+
+    fn ban_list(&self) -> &BanList {
+        &self.inner.bans
+    }
+
+    fn check_game_over(&self, state: &GameState) -> Option<GameResult> {
+        state.players.iter()
+            .position(|p| p.life_total >= 30)
+            .map(GameResult::Winner)
+    }
+}
+
+#[test]
+fn test_game_new_rejects_deck_below_minimum() {
+    let err = Game::new(GameConfig::standard(), vec![forests(60), forests(40)]).err().unwrap();
+    assert!(err.contains("Player 1"), "{}", err);
+    assert!(Game::new(GameConfig::limited(), vec![forests(40), forests(40)]).is_ok());
+}
+
+#[test]
+fn test_with_format_rejects_banned_card_in_sideboard() {
+    let format = Constructed {
+        bans: BanList::new().ban(&["Ancestral Recall"]),
+        ..Constructed::standard()
+    };
+    let clean = DeckSubmission::new(forests(60));
+    let tainted = DeckSubmission::new(forests(60)).with_sideboard(vec![alpha::ancestral_recall()]);
+    let err = Game::with_format(Box::new(format), vec![clean, tainted]).err().unwrap();
+    assert!(err.contains("Player 1") && err.contains("banned"), "{}", err);
+}
+
+#[test]
+fn test_with_format_uses_format_config() {
+    let decks = vec![DeckSubmission::new(forests(40)), DeckSubmission::new(forests(45))];
+    let game = Game::with_format(Box::new(Limited::new()), decks).unwrap();
+    assert_eq!(game.config.deck_limits.min_deck_size, 40);
+    assert_eq!(game.state.players[1].library.len(), 45);
+    assert_eq!(game.format.as_ref().unwrap().name(), "Limited");
+}
+
+#[test]
+fn test_brawl_setup_puts_commanders_in_command_zone() {
+    let deck = || {
+        let mut main = vec![isamaru_hound_of_konda()];
+        main.extend((0..59).map(|_| basic_lands::plains()));
+        DeckSubmission::new(main).with_commanders(&["Isamaru, Hound of Konda"])
+    };
+    let game = Game::with_format(Box::new(Brawl::new()), vec![deck(), deck()]).unwrap();
+    assert_eq!(game.state.command.len(), 2);
+    for &id in &game.state.command {
+        assert_eq!(game.state.get_object(id).unwrap().zone, Zone::Command);
+    }
+    assert_eq!(game.state.players[0].life_total, 25);
+}
+
+#[test]
+fn test_custom_format_overrides_win_condition() {
+    let format = ThirtyLife { inner: Constructed::standard() };
+    let decks = vec![DeckSubmission::new(forests(60)), DeckSubmission::new(forests(60))];
+    let mut game = Game::with_format(Box::new(format), decks).unwrap();
+    assert!(game.check_game_over().is_none());
+
+    game.state.players[1].life_total = 30;
+    assert_eq!(game.check_game_over(), Some(GameResult::Winner(1)));
+}
@@ -0,0 +1,187 @@
+//! Casting without paying the mana cost integration tests — cascade (rule
+//! 702.85), discover (rule 701.57), Omniscience's static ability, X being
+//! 0 (rule 107.3b), and sorceries cast while another spell resolves.
+
+mod common;
+
+use std::sync::Arc;
+
+use common::{fill_library, put_in_hand, put_on_battlefield, setup_two_player_game};
+
+use mtgsim::cards::alpha;
+use mtgsim::cards::basic_lands;
+use mtgsim::cards::creatures;
+use mtgsim::cards::free_spells;
+use mtgsim::cards::phase5_pre_cards;
+use mtgsim::engine::cast::CastPermission;
+use mtgsim::objects::card_data::{AbilityDef, AbilityType, CardData, CardDataBuilder};
+use mtgsim::objects::object::GameObject;
+use mtgsim::oracle::mana_helpers::castable_spells;
+use mtgsim::state::game_state::{GameState, Phase, PhaseType};
+use mtgsim::types::card_types::CardType;
+use mtgsim::types::costs::AlternativeCost;
+use mtgsim::types::effects::{AmountExpr, Effect, EffectRecipient, Primitive, SelectionFilter, TargetCount};
+use mtgsim::types::ids::{new_ability_id, ObjectId};
+use mtgsim::types::mana::{ManaCost, ManaSymbol, ManaType};
+use mtgsim::types::zones::Zone;
+use mtgsim::ui::choice_types::ChoiceKind;
+use mtgsim::ui::decision::ScriptedDecisionProvider;
+
+/// Expectations are matched by kind only, so their ids are placeholders.
+const ANY: ObjectId = ObjectId::nil();
+
+/// Put a card on top of a player's library.
+fn put_on_top_of_library(game: &mut GameState, card_data: Arc<CardData>, player: usize) -> ObjectId {
+    let obj = GameObject::new(card_data, player, Zone::Library);
+    let id = obj.id;
+    game.add_object(obj);
+    game.players[player].library.push(id);
+    id
+}
+
+fn library_names(game: &GameState, player: usize) -> Vec<String> {
+    game.players[player].library.iter()
+        .map(|id| game.objects[id].card_data.name.clone())
+        .collect()
+}
+
+fn cast_for_free(dp: &ScriptedDecisionProvider, cast: bool) {
+    let pick = if cast { vec![0] } else { vec![] };
+    dp.expect_pick_n(ChoiceKind::CastWithoutPaying { card_id: ANY }, pick);
+}
+
+/// Player 0 casts Bloodbraid Elf and its cascade trigger goes on the stack.
+fn cast_bloodbraid_elf(game: &mut GameState, dp: &ScriptedDecisionProvider) -> ObjectId {
+    let elf = put_in_hand(game, free_spells::bloodbraid_elf(), 0);
+    game.players[0].mana_pool.add(ManaType::Red, 3);
+    game.players[0].mana_pool.add(ManaType::Green, 1);
+    dp.expect_allocation(ChoiceKind::GenericManaAllocation { mana_cost: ManaCost::zero() }, vec![2, 0]);
+    let elf = game.cast_spell(0, elf, dp).unwrap();
+    assert!(game.put_pending_triggers_on_stack(dp).unwrap());
+    elf
+}
+
+#[test]
+fn test_cascade_casts_a_sorcery_for_free_while_resolving() {
+    let mut game = setup_two_player_game();
+    fill_library(&mut game, 0, 1);
+    put_on_top_of_library(&mut game, phase5_pre_cards::nights_whisper(), 0);
+    put_on_top_of_library(&mut game, basic_lands::forest(), 0);
+    let dp = ScriptedDecisionProvider::new();
+    let elf = cast_bloodbraid_elf(&mut game, &dp);
+
+    // The Forest is passed over; Night's Whisper is cast, with the elf
+    // still on the stack.
+    cast_for_free(&dp, true);
+    game.resolve_top_of_stack(&dp).unwrap();
+    assert_eq!(game.stack.len(), 2);
+    assert_eq!(game.stack[0], elf);
+    assert_eq!(library_names(&game, 0), vec!["Forest", "Dummy Card"]);
+
+    game.resolve_top_of_stack(&dp).unwrap();
+    assert_eq!(game.players[0].life_total, 18);
+    assert_eq!(game.players[0].hand.len(), 2);
+    assert_eq!(game.spells_cast_this_turn, 2);
+}
+
+#[test]
+fn test_cascade_skips_cards_with_equal_mana_value_and_bottoms_a_declined_card() {
+    let mut game = setup_two_player_game();
+    fill_library(&mut game, 0, 1);
+    put_on_top_of_library(&mut game, alpha::lightning_bolt(), 0);
+    put_on_top_of_library(&mut game, free_spells::bloodbraid_elf(), 0);
+    let dp = ScriptedDecisionProvider::new();
+    cast_bloodbraid_elf(&mut game, &dp);
+
+    cast_for_free(&dp, false);
+    game.resolve_top_of_stack(&dp).unwrap();
+    assert_eq!(game.stack.len(), 1);
+    assert!(game.exile.is_empty());
+    let mut bottom = library_names(&game, 0)[..2].to_vec();
+    bottom.sort();
+    assert_eq!(bottom, vec!["Bloodbraid Elf", "Lightning Bolt"]);
+    assert_eq!(library_names(&game, 0)[2], "Dummy Card");
+}
+
+#[test]
+fn test_discover_casts_the_card_or_puts_it_into_hand() {
+    let mut game = setup_two_player_game();
+    put_on_top_of_library(&mut game, creatures::grizzly_bears(), 0);
+    put_on_top_of_library(&mut game, creatures::grizzly_bears(), 0);
+    let dp = ScriptedDecisionProvider::new();
+
+    let depths = put_in_hand(&mut game, free_spells::uncharted_depths(), 0);
+    game.players[0].mana_pool.add(ManaType::Red, 3);
+    dp.expect_allocation(ChoiceKind::GenericManaAllocation { mana_cost: ManaCost::zero() }, vec![2]);
+    game.cast_spell(0, depths, &dp).unwrap();
+    cast_for_free(&dp, true);
+    game.resolve_top_of_stack(&dp).unwrap();
+    let bears = game.stack[0];
+    assert_eq!(game.objects[&bears].card_data.name, "Grizzly Bears");
+    game.resolve_top_of_stack(&dp).unwrap();
+
+    let depths = put_in_hand(&mut game, free_spells::uncharted_depths(), 0);
+    game.players[0].mana_pool.add(ManaType::Red, 3);
+    dp.expect_allocation(ChoiceKind::GenericManaAllocation { mana_cost: ManaCost::zero() }, vec![2]);
+    game.cast_spell(0, depths, &dp).unwrap();
+    cast_for_free(&dp, false);
+    game.resolve_top_of_stack(&dp).unwrap();
+    assert!(game.stack.is_empty());
+    assert_eq!(game.players[0].hand.len(), 1);
+    assert!(game.players[0].library.is_empty());
+}
+
+#[test]
+fn test_x_is_zero_without_paying_the_mana_cost() {
+    let mut game = setup_two_player_game();
+    let blaze = CardDataBuilder::new("Blaze")
+        .card_type(CardType::Sorcery)
+        .mana_cost(ManaCost::from_symbols(vec![ManaSymbol::X, ManaSymbol::Colored(ManaType::Red)]))
+        .ability(AbilityDef {
+            id: new_ability_id(),
+            ability_type: AbilityType::Spell,
+            costs: Vec::new(),
+            effect: Effect::Atom(
+                Primitive::DealDamage(AmountExpr::Variable),
+                EffectRecipient::Target(SelectionFilter::Player, TargetCount::Exactly(1)),
+            ),
+        })
+        .build();
+    let blaze = game.add_object(GameObject::new(blaze, 0, Zone::Exile));
+    game.exile.push(blaze);
+    game.phase = Phase::new(PhaseType::Combat);
+
+    let dp = ScriptedDecisionProvider::new();
+    dp.expect_pick_n(ChoiceKind::SelectRecipients { recipient: EffectRecipient::Implicit, spell_id: ANY }, vec![1]);
+    let spell = game.cast_spell_with(0, blaze, CastPermission::free_from(Zone::Exile), &dp).unwrap();
+    let entry = &game.stack_entries[&spell];
+    assert_eq!(entry.x_value, Some(0));
+    assert_eq!(entry.chosen_alternative_cost, Some(AlternativeCost::WithoutPayingManaCost));
+}
+
+#[test]
+fn test_a_card_in_exile_needs_a_permission_to_be_cast() {
+    let mut game = setup_two_player_game();
+    let bolt = game.add_object(GameObject::new(alpha::lightning_bolt(), 0, Zone::Exile));
+    game.exile.push(bolt);
+    let dp = ScriptedDecisionProvider::new();
+    assert!(game.cast_spell(0, bolt, &dp).is_err());
+    assert!(game.exile.contains(&bolt));
+}
+
+#[test]
+fn test_omniscience_offers_casting_from_hand_for_free() {
+    let mut game = setup_two_player_game();
+    let bolt = put_in_hand(&mut game, alpha::lightning_bolt(), 0);
+    assert!(castable_spells(&game, 0).is_empty());
+    put_on_battlefield(&mut game, free_spells::omniscience(), 0);
+    assert_eq!(castable_spells(&game, 0).len(), 1);
+
+    // Costs: [normal, without paying its mana cost]
+    let dp = ScriptedDecisionProvider::new();
+    dp.expect_pick_n(ChoiceKind::ChooseAlternativeCost, vec![1]);
+    dp.expect_pick_n(ChoiceKind::SelectRecipients { recipient: EffectRecipient::Implicit, spell_id: ANY }, vec![1]);
+    game.cast_spell(0, bolt, &dp).unwrap();
+    game.resolve_top_of_stack(&dp).unwrap();
+    assert_eq!(game.players[1].life_total, 17);
+}
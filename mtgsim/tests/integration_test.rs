@@ -62,10 +62,10 @@ fn test_full_opening_sequence() {
 
     // -- Play a land from hand --
     let land_id = game.players[0].hand[0];
-    game.play_land(0, land_id, Zone::Hand).unwrap();
+    let land_id = game.play_land(0, land_id, Zone::Hand).unwrap();
 
     assert!(game.battlefield.contains_key(&land_id));
-    assert_eq!(game.get_object(land_id).unwrap().zone, Zone::Battlefield);
+    assert_eq!(game.get_object(game.current_identity(land_id)).unwrap().zone, Zone::Battlefield);
     assert!(game.players[0].hand.is_empty());
     assert_eq!(game.players[0].lands_played_this_turn, 1);
 
@@ -103,7 +103,7 @@ fn test_two_turn_land_and_mana_cycle() {
     assert_eq!(game.phase.phase_type, PhaseType::Precombat);
 
     let land1_id = game.players[0].hand[0];
-    game.play_land(0, land1_id, Zone::Hand).unwrap();
+    let land1_id = game.play_land(0, land1_id, Zone::Hand).unwrap();
     let ability1 = get_mana_ability_id(&game, land1_id);
     game.activate_mana_ability(0, land1_id, ability1).unwrap();
     assert_eq!(game.players[0].mana_pool.amount(ManaType::Green), 1);
@@ -121,7 +121,7 @@ fn test_two_turn_land_and_mana_cycle() {
     }
 
     let land2_id = game.players[1].hand[0];
-    game.play_land(1, land2_id, Zone::Hand).unwrap();
+    let land2_id = game.play_land(1, land2_id, Zone::Hand).unwrap();
     let ability2 = get_mana_ability_id(&game, land2_id);
     game.activate_mana_ability(1, land2_id, ability2).unwrap();
     assert_eq!(game.players[1].mana_pool.amount(ManaType::Red), 1);
@@ -208,5 +208,5 @@ fn test_sba_integration_with_turn_structure() {
 
     assert!(!game.battlefield.contains_key(&bears_id));
     assert_eq!(game.players[0].graveyard.len(), 1);
-    assert_eq!(game.get_object(bears_id).unwrap().zone, Zone::Graveyard);
+    assert_eq!(game.get_object(game.current_identity(bears_id)).unwrap().zone, Zone::Graveyard);
 }
@@ -0,0 +1,148 @@
+//! Last known information integration tests — effects that refer to an
+//! object after it's left the battlefield or the stack read it as it last
+//! existed there (rule 608.2h), and dies triggers look back in time (rule
+//! 603.10a).
+
+mod common;
+
+use common::{fill_library, put_in_hand, put_on_battlefield, setup_two_player_game};
+
+use mtgsim::cards::alpha;
+use mtgsim::cards::creatures;
+use mtgsim::cards::basic_lands;
+use mtgsim::cards::last_known;
+use mtgsim::engine::actions::GameAction;
+use mtgsim::engine::resolve::{ResolutionContext, ResolvedTarget};
+use mtgsim::objects::object::GameObject;
+use mtgsim::state::game_state::GameState;
+use mtgsim::types::card_types::CardType;
+use mtgsim::types::effects::*;
+use mtgsim::types::ids::ObjectId;
+use mtgsim::types::mana::ManaType;
+use mtgsim::types::zones::Zone;
+use mtgsim::ui::choice_types::ChoiceKind;
+use mtgsim::ui::decision::ScriptedDecisionProvider;
+
+fn pick_target(dp: &ScriptedDecisionProvider, spell_id: ObjectId, index: usize) {
+    dp.expect_pick_n(
+        ChoiceKind::SelectRecipients { recipient: EffectRecipient::Implicit, spell_id },
+        vec![index],
+    );
+}
+
+#[test]
+fn test_swords_to_plowshares_uses_exiled_creatures_last_known_power() {
+    let mut game = setup_two_player_game();
+    let giant = put_on_battlefield(&mut game, creatures::hill_giant(), 1);
+    game.battlefield.get_mut(&giant).unwrap().add_counters(CounterType::PlusOnePlusOne, 2);
+
+    let swords = put_in_hand(&mut game, last_known::swords_to_plowshares(), 0);
+    game.players[0].mana_pool.add(ManaType::White, 1);
+    let dp = ScriptedDecisionProvider::new();
+    pick_target(&dp, swords, 0);
+    game.cast_spell(0, swords, &dp).unwrap();
+    game.resolve_top_of_stack(&dp).unwrap();
+
+    assert_eq!(game.objects[&game.current_identity(giant)].zone, Zone::Exile);
+    assert_eq!(game.players[1].life_total, 25, "its controller gains its last known power");
+    assert_eq!(game.players[0].life_total, 20);
+    let lki = game.last_known_information(giant).unwrap();
+    assert_eq!(lki.zone, Zone::Battlefield);
+    assert_eq!(lki.characteristics.power, Some(5));
+}
+
+#[test]
+fn test_perilous_myr_dies_trigger() {
+    let mut game = setup_two_player_game();
+    let myr = put_on_battlefield(&mut game, last_known::perilous_myr(), 0);
+    game.execute_action(GameAction::Destroy { object: myr, can_regenerate: true }).unwrap();
+    assert_eq!(game.pending_triggers.len(), 1);
+
+    // Players come first among "any target".
+    let dp = ScriptedDecisionProvider::new();
+    pick_target(&dp, myr, 1);
+    game.put_pending_triggers_on_stack(&dp).unwrap();
+    game.resolve_top_of_stack(&dp).unwrap();
+    assert_eq!(game.players[1].life_total, 18);
+}
+
+#[test]
+fn test_token_dies_trigger_outlives_the_token() {
+    let mut game = setup_two_player_game();
+    let myr = put_on_battlefield(&mut game, last_known::perilous_myr(), 0);
+    game.objects.get_mut(&myr).unwrap().is_token = true;
+    game.battlefield.get_mut(&myr).unwrap().damage_marked = 1;
+
+    // It dies, then ceases to exist (rule 704.5d) before its trigger goes on
+    // the stack.
+    let dp = ScriptedDecisionProvider::new();
+    game.check_state_based_actions_loop(&dp).unwrap();
+    assert!(!game.objects.contains_key(&game.current_identity(myr)));
+    pick_target(&dp, myr, 1);
+    assert!(game.put_pending_triggers_on_stack(&dp).unwrap());
+    game.resolve_top_of_stack(&dp).unwrap();
+    assert_eq!(game.players[1].life_total, 18);
+}
+
+#[test]
+fn test_exiled_creature_does_not_die() {
+    let mut game = setup_two_player_game();
+    let myr = put_on_battlefield(&mut game, last_known::perilous_myr(), 0);
+    game.change_zone(myr, Zone::Exile).unwrap();
+    assert!(game.pending_triggers.is_empty());
+}
+
+/// "Destroy target permanent. If it was a creature, draw a card."
+fn destroy_then_draw_if_creature(game: &mut GameState, target: ObjectId) {
+    let effect = Effect::Sequence(vec![
+        Effect::Atom(
+            Primitive::Destroy,
+            EffectRecipient::Target(SelectionFilter::Permanent(PermanentFilter::All), TargetCount::Exactly(1)),
+        ),
+        Effect::Conditional(
+            Condition::TargetWas(PermanentFilter::ByType(CardType::Creature)),
+            Box::new(Effect::Atom(Primitive::DrawCards(AmountExpr::Fixed(1)), EffectRecipient::Controller)),
+        ),
+    ]);
+    let source = game.add_object(GameObject::new(alpha::lightning_bolt(), 0, Zone::Stack));
+    let ctx = ResolutionContext {
+        source,
+        controller: 0,
+        targets: vec![ResolvedTarget::Object(target)],
+        ability_source: None,
+    };
+    game.resolve_effect(&effect, &ctx, &ScriptedDecisionProvider::new()).unwrap();
+}
+
+#[test]
+fn test_condition_reads_what_the_destroyed_permanent_was() {
+    let mut game = setup_two_player_game();
+    fill_library(&mut game, 0, 5);
+    let bears = put_on_battlefield(&mut game, creatures::grizzly_bears(), 1);
+    let forest = put_on_battlefield(&mut game, basic_lands::forest(), 1);
+
+    let hand = game.players[0].hand.len();
+    destroy_then_draw_if_creature(&mut game, forest);
+    assert_eq!(game.objects[&game.current_identity(forest)].zone, Zone::Graveyard);
+    assert_eq!(game.players[0].hand.len(), hand);
+
+    destroy_then_draw_if_creature(&mut game, bears);
+    assert_eq!(game.objects[&game.current_identity(bears)].zone, Zone::Graveyard);
+    assert_eq!(game.players[0].hand.len(), hand + 1);
+}
+
+#[test]
+fn test_resolved_spell_records_last_known_information_from_the_stack() {
+    let mut game = setup_two_player_game();
+    let bolt = put_in_hand(&mut game, alpha::lightning_bolt(), 0);
+    game.players[0].mana_pool.add(ManaType::Red, 1);
+    let dp = ScriptedDecisionProvider::new();
+    pick_target(&dp, bolt, 1);
+    let bolt = game.cast_spell(0, bolt, &dp).unwrap();
+    game.resolve_top_of_stack(&dp).unwrap();
+
+    let lki = game.last_known_information(bolt).unwrap();
+    assert_eq!(lki.zone, Zone::Stack);
+    assert_eq!(lki.controller, 0);
+    assert!(lki.characteristics.types.contains(&CardType::Instant));
+}
@@ -0,0 +1,281 @@
+//! Multi-part card integration tests — split cards cast as either half
+//! (rule 709.3) or fused (rule 702.102), combined characteristics off the
+//! stack (rule 709.4), aftermath halves cast from the graveyard (rule
+//! 702.127), and adventurers (rule 715).
+
+mod common;
+
+use common::{put_in_hand, put_on_battlefield, setup_two_player_game};
+
+use mtgsim::cards::creatures;
+use mtgsim::cards::equipment;
+use mtgsim::cards::multipart;
+use mtgsim::objects::card_data::{CardDataBuilder, CardFace};
+use mtgsim::oracle::characteristics::{
+    get_effective_name, get_effective_names, get_effective_power, get_mana_value, has_keyword,
+    has_name,
+};
+use mtgsim::oracle::mana_helpers::castable_spells;
+use mtgsim::state::game_state::GameState;
+use mtgsim::types::card_types::CardType;
+use mtgsim::types::effects::{EffectRecipient, SelectionFilter, TargetCount};
+use mtgsim::types::ids::ObjectId;
+use mtgsim::types::keywords::KeywordAbility;
+use mtgsim::types::mana::{ManaCost, ManaType};
+use mtgsim::types::zones::Zone;
+use mtgsim::ui::choice_types::ChoiceKind;
+use mtgsim::ui::decision::ScriptedDecisionProvider;
+
+fn choose_face(dp: &ScriptedDecisionProvider, card_id: ObjectId, index: usize) {
+    dp.expect_pick_n(ChoiceKind::ChooseFace { card_id }, vec![index]);
+}
+
+fn choose_target(dp: &ScriptedDecisionProvider, spell_id: ObjectId, index: usize) {
+    dp.expect_pick_n(ChoiceKind::SelectRecipients {
+        recipient: EffectRecipient::Target(SelectionFilter::Creature, TargetCount::Exactly(1)),
+        spell_id,
+    }, vec![index]);
+}
+
+fn pay_generic(dp: &ScriptedDecisionProvider, allocation: Vec<u64>) {
+    dp.expect_allocation(ChoiceKind::GenericManaAllocation { mana_cost: ManaCost::zero() }, allocation);
+}
+
+/// Cast Onward from hand on `creature` and resolve it. Returns the card,
+/// now in the graveyard.
+fn cast_onward(game: &mut GameState, card: ObjectId, creature: ObjectId) -> ObjectId {
+    game.players[0].mana_pool.add(ManaType::Red, 3);
+    let dp = ScriptedDecisionProvider::new();
+    choose_target(&dp, card, 0);
+    pay_generic(&dp, vec![2]);
+    let spell = game.cast_spell(0, card, &dp).unwrap();
+    game.resolve_top_of_stack(&dp).unwrap();
+    assert_eq!(game.get_object(creature).unwrap().zone, Zone::Battlefield);
+    game.current_identity(spell)
+}
+
+#[test]
+fn test_split_card_off_the_stack_has_both_names_and_total_mana_value() {
+    let mut game = setup_two_player_game();
+    let card = put_in_hand(&mut game, multipart::dead_gone(), 0);
+
+    assert_eq!(get_effective_names(&game, card), vec!["Dead", "Gone"]);
+    assert!(has_name(&game, card, "Dead"));
+    assert!(has_name(&game, card, "Gone"));
+    assert!(!has_name(&game, card, "Dead // Gone"));
+    assert_eq!(get_mana_value(&game, card), 4);
+}
+
+#[test]
+fn test_split_half_on_the_stack_has_only_its_own_characteristics() {
+    let mut game = setup_two_player_game();
+    let bears = put_on_battlefield(&mut game, creatures::grizzly_bears(), 1);
+    let card = put_in_hand(&mut game, multipart::dead_gone(), 0);
+    game.players[0].mana_pool.add(ManaType::Red, 1);
+
+    let dp = ScriptedDecisionProvider::new();
+    choose_face(&dp, card, 0);
+    choose_target(&dp, card, 0);
+    let card = game.cast_spell(0, card, &dp).unwrap();
+
+    assert_eq!(get_effective_names(&game, card), vec!["Dead"]);
+    assert_eq!(get_mana_value(&game, card), 1);
+
+    game.resolve_top_of_stack(&dp).unwrap();
+    let card = game.current_identity(card);
+    assert_eq!(game.battlefield[&bears].damage_marked, 2);
+    assert_eq!(game.get_object(card).unwrap().zone, Zone::Graveyard);
+    assert_eq!(get_effective_name(&game, card), "Dead // Gone");
+}
+
+#[test]
+fn test_split_card_right_half_uses_its_own_cost() {
+    let mut game = setup_two_player_game();
+    let giant = put_on_battlefield(&mut game, creatures::hill_giant(), 1);
+    let card = put_in_hand(&mut game, multipart::dead_gone(), 0);
+    game.players[0].mana_pool.add(ManaType::Red, 3);
+
+    let dp = ScriptedDecisionProvider::new();
+    choose_face(&dp, card, 1);
+    choose_target(&dp, card, 0);
+    pay_generic(&dp, vec![2]);
+    let card = game.cast_spell(0, card, &dp).unwrap();
+    assert_eq!(game.stack_entries[&card].cast_face, CardFace::Back);
+    assert_eq!(game.players[0].mana_pool.total(), 0);
+
+    game.resolve_top_of_stack(&dp).unwrap();
+    assert_eq!(game.get_object(game.current_identity(giant)).unwrap().zone, Zone::Hand);
+}
+
+#[test]
+fn test_fused_split_spell_resolves_both_halves_with_their_own_targets() {
+    let mut game = setup_two_player_game();
+    let artifact = put_on_battlefield(&mut game, equipment::bonesplitter(), 1);
+    let enchantment = put_on_battlefield(
+        &mut game,
+        CardDataBuilder::new("Test Enchantment").card_type(CardType::Enchantment).build(),
+        1,
+    );
+    let card = put_in_hand(&mut game, multipart::wear_tear(), 0);
+    game.players[0].mana_pool.add(ManaType::Red, 2);
+    game.players[0].mana_pool.add(ManaType::White, 1);
+
+    let dp = ScriptedDecisionProvider::new();
+    choose_face(&dp, card, 2);
+    choose_target(&dp, card, 0);
+    choose_target(&dp, card, 0);
+    pay_generic(&dp, vec![0, 1]);
+    let card = game.cast_spell(0, card, &dp).unwrap();
+
+    assert_eq!(game.stack_entries[&card].cast_face, CardFace::Combined);
+    assert_eq!(get_effective_names(&game, card), vec!["Wear", "Tear"]);
+    assert_eq!(get_mana_value(&game, card), 3);
+    assert_eq!(game.players[0].mana_pool.total(), 0, "paid {{1}}{{R}} and {{W}}");
+
+    game.resolve_top_of_stack(&dp).unwrap();
+    assert_eq!(game.get_object(game.current_identity(artifact)).unwrap().zone, Zone::Graveyard);
+    assert_eq!(game.get_object(game.current_identity(enchantment)).unwrap().zone, Zone::Graveyard);
+}
+
+#[test]
+fn test_fused_split_spell_resolves_a_half_whose_target_is_still_legal() {
+    let mut game = setup_two_player_game();
+    let artifact = put_on_battlefield(&mut game, equipment::bonesplitter(), 1);
+    let enchantment = put_on_battlefield(
+        &mut game,
+        CardDataBuilder::new("Test Enchantment").card_type(CardType::Enchantment).build(),
+        1,
+    );
+    let card = put_in_hand(&mut game, multipart::wear_tear(), 0);
+    game.players[0].mana_pool.add(ManaType::Red, 2);
+    game.players[0].mana_pool.add(ManaType::White, 1);
+
+    let dp = ScriptedDecisionProvider::new();
+    choose_face(&dp, card, 2);
+    choose_target(&dp, card, 0);
+    choose_target(&dp, card, 0);
+    pay_generic(&dp, vec![0, 1]);
+    let card = game.cast_spell(0, card, &dp).unwrap();
+    game.change_zone(artifact, Zone::Exile).unwrap();
+
+    game.resolve_top_of_stack(&dp).unwrap();
+    assert_eq!(game.get_object(game.current_identity(enchantment)).unwrap().zone, Zone::Graveyard);
+    assert_eq!(game.get_object(game.current_identity(card)).unwrap().zone, Zone::Graveyard, "resolved, not countered");
+}
+
+#[test]
+fn test_fuse_is_only_offered_from_hand() {
+    let mut game = setup_two_player_game();
+    let card = put_in_hand(&mut game, multipart::wear_tear(), 0);
+    assert_eq!(
+        game.faces_castable_from_zone(card),
+        vec![CardFace::Front, CardFace::Back, CardFace::Combined],
+    );
+
+    let card = game.change_zone(card, Zone::Graveyard).unwrap();
+    assert!(game.faces_castable_from_zone(card).is_empty());
+}
+
+#[test]
+fn test_aftermath_half_is_cast_from_the_graveyard_and_then_exiled() {
+    let mut game = setup_two_player_game();
+    let bears = put_on_battlefield(&mut game, creatures::grizzly_bears(), 0);
+    let card = put_in_hand(&mut game, multipart::onward_victory(), 0);
+    assert_eq!(game.faces_castable_from_zone(card), vec![CardFace::Front]);
+
+    let card = cast_onward(&mut game, card, bears);
+    assert_eq!(get_effective_power(&game, bears), Some(4), "+X/+0 where X is its power");
+    assert!(has_keyword(&game, bears, KeywordAbility::FirstStrike));
+    assert_eq!(game.get_object(card).unwrap().zone, Zone::Graveyard);
+
+    game.players[0].mana_pool.add(ManaType::White, 3);
+    assert_eq!(game.faces_castable_from_zone(card), vec![CardFace::Back]);
+    assert!(castable_spells(&game, 0).iter().any(|(id, _)| *id == card));
+
+    let dp = ScriptedDecisionProvider::new();
+    choose_target(&dp, card, 0);
+    pay_generic(&dp, vec![2]);
+    let card = game.cast_spell(0, card, &dp).unwrap();
+    assert_eq!(get_effective_names(&game, card), vec!["Victory"]);
+    game.resolve_top_of_stack(&dp).unwrap();
+
+    assert!(has_keyword(&game, bears, KeywordAbility::DoubleStrike));
+    assert_eq!(game.get_object(game.current_identity(card)).unwrap().zone, Zone::Exile);
+}
+
+#[test]
+fn test_aftermath_half_that_fizzles_is_exiled() {
+    let mut game = setup_two_player_game();
+    let bears = put_on_battlefield(&mut game, creatures::grizzly_bears(), 0);
+    let card = put_in_hand(&mut game, multipart::onward_victory(), 0);
+    let card = cast_onward(&mut game, card, bears);
+
+    game.players[0].mana_pool.add(ManaType::White, 3);
+    let dp = ScriptedDecisionProvider::new();
+    choose_target(&dp, card, 0);
+    pay_generic(&dp, vec![2]);
+    let card = game.cast_spell(0, card, &dp).unwrap();
+    game.change_zone(bears, Zone::Graveyard).unwrap();
+    game.resolve_top_of_stack(&dp).unwrap();
+
+    assert_eq!(game.get_object(game.current_identity(card)).unwrap().zone, Zone::Exile);
+}
+
+#[test]
+fn test_aftermath_half_cannot_be_cast_from_hand() {
+    let mut game = setup_two_player_game();
+    let card = put_in_hand(&mut game, multipart::onward_victory(), 0);
+    game.players[0].mana_pool.add(ManaType::White, 3);
+
+    assert!(castable_spells(&game, 0).is_empty(), "no creature for Onward to target");
+    assert!(!game.faces_castable_from_zone(card).contains(&CardFace::Back));
+}
+
+#[test]
+fn test_adventure_goes_on_an_adventure_and_creature_is_cast_from_exile() {
+    let mut game = setup_two_player_game();
+    let card = put_in_hand(&mut game, multipart::bonecrusher_giant(), 0);
+    assert_eq!(get_effective_names(&game, card), vec!["Bonecrusher Giant"]);
+    assert_eq!(get_mana_value(&game, card), 3, "an adventurer has only its creature's characteristics");
+
+    game.players[0].mana_pool.add(ManaType::Red, 2);
+    let dp = ScriptedDecisionProvider::new();
+    choose_face(&dp, card, 1);
+    dp.expect_pick_n(ChoiceKind::SelectRecipients {
+        recipient: EffectRecipient::Target(SelectionFilter::Any, TargetCount::Exactly(1)),
+        spell_id: card,
+    }, vec![1]);
+    pay_generic(&dp, vec![1]);
+    let card = game.cast_spell(0, card, &dp).unwrap();
+    assert_eq!(get_effective_name(&game, card), "Stomp");
+    game.resolve_top_of_stack(&dp).unwrap();
+    let card = game.current_identity(card);
+
+    assert_eq!(game.players[1].life_total, 18);
+    assert_eq!(game.get_object(card).unwrap().zone, Zone::Exile);
+    assert!(game.on_adventure.contains(&card));
+
+    game.players[0].mana_pool.add(ManaType::Red, 3);
+    assert!(castable_spells(&game, 0).iter().any(|(id, _)| *id == card));
+    let dp = ScriptedDecisionProvider::new();
+    pay_generic(&dp, vec![2]);
+    let card = game.cast_spell(0, card, &dp).unwrap();
+    game.resolve_top_of_stack(&dp).unwrap();
+    let card = game.current_identity(card);
+
+    assert_eq!(game.get_object(card).unwrap().zone, Zone::Battlefield);
+    assert_eq!(get_effective_power(&game, card), Some(4));
+    assert!(!game.on_adventure.contains(&card));
+}
+
+#[test]
+fn test_adventurer_exiled_another_way_cannot_be_cast() {
+    let mut game = setup_two_player_game();
+    let card = put_in_hand(&mut game, multipart::bonecrusher_giant(), 0);
+    let card = game.change_zone(card, Zone::Exile).unwrap();
+    game.players[0].mana_pool.add(ManaType::Red, 3);
+
+    assert!(game.faces_castable_from_zone(card).is_empty());
+    let dp = ScriptedDecisionProvider::new();
+    assert!(game.cast_spell(0, card, &dp).is_err());
+}
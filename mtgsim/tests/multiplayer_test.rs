@@ -0,0 +1,175 @@
+//! Multiplayer free-for-all integration tests — turn order and priority
+//! skipping departed players (rule 800.4a), attacking any opponent
+//! (rule 506.2), and a losing player's objects leaving the game.
+
+mod common;
+
+use common::{fill_library, put_in_hand, put_on_battlefield, setup_multiplayer_game};
+
+use mtgsim::cards::creatures;
+use mtgsim::cards::planeswalkers;
+use mtgsim::engine::priority::PriorityResult;
+use mtgsim::events::event::GameEvent;
+use mtgsim::objects::object::GameObject;
+use mtgsim::oracle::legality::legal_attack_targets;
+use mtgsim::state::battlefield::AttackTarget;
+use mtgsim::types::zones::Zone;
+use mtgsim::ui::choice_types::ChoiceKind;
+use mtgsim::ui::decision::ScriptedDecisionProvider;
+
+#[test]
+fn test_turns_rotate_through_all_four_players() {
+    let mut game = setup_multiplayer_game(4);
+    for pid in 0..4 {
+        fill_library(&mut game, pid, 5);
+    }
+
+    let mut order = vec![game.active_player];
+    while order.len() < 5 {
+        let turn = game.turn_number;
+        game.advance_turn().unwrap();
+        if game.turn_number != turn {
+            order.push(game.active_player);
+        }
+    }
+    assert_eq!(order, vec![0, 1, 2, 3, 0]);
+}
+
+#[test]
+fn test_turn_order_skips_player_who_left() {
+    let mut game = setup_multiplayer_game(4);
+    for pid in 0..4 {
+        fill_library(&mut game, pid, 5);
+    }
+    game.active_player = 1;
+    game.player_lost[2] = true;
+
+    let turn = game.turn_number;
+    while game.turn_number == turn {
+        game.advance_turn().unwrap();
+    }
+    assert_eq!(game.active_player, 3);
+}
+
+#[test]
+fn test_priority_passes_through_remaining_players_only() {
+    let mut game = setup_multiplayer_game(4);
+    game.player_lost[2] = true;
+
+    // Three passes (players 0, 1 and 3) end the round; a fourth request
+    // would panic the scripted provider.
+    let dp = ScriptedDecisionProvider::new();
+    for _ in 0..3 {
+        dp.expect_pick_n(ChoiceKind::PriorityAction, vec![0]);
+    }
+    let result = game.run_priority_round(&dp).unwrap();
+    assert_eq!(result, PriorityResult::PhaseEnds);
+    assert_eq!(game.priority_player, 3);
+}
+
+#[test]
+fn test_can_attack_any_opponent_and_their_planeswalkers() {
+    let mut game = setup_multiplayer_game(4);
+    let garruk = put_on_battlefield(&mut game, planeswalkers::garruk_wildspeaker(), 2);
+
+    assert_eq!(
+        legal_attack_targets(&game, 0),
+        vec![
+            AttackTarget::Player(1),
+            AttackTarget::Player(2),
+            AttackTarget::Player(3),
+            AttackTarget::Planeswalker(garruk),
+        ],
+    );
+
+    game.player_lost[3] = true;
+    assert!(!legal_attack_targets(&game, 0).contains(&AttackTarget::Player(3)));
+}
+
+#[test]
+fn test_attack_across_the_table() {
+    let mut game = setup_multiplayer_game(4);
+    game.turn_number = 2;
+    let bears = put_on_battlefield(&mut game, creatures::grizzly_bears(), 0);
+
+    // Legal pairs: [(bears, P1), (bears, P2), (bears, P3)]
+    let dp = ScriptedDecisionProvider::new();
+    dp.expect_pick_n(ChoiceKind::DeclareAttackers, vec![1]);
+    game.process_declare_attackers(&dp).unwrap();
+    assert_eq!(
+        game.battlefield.get(&bears).unwrap().attacking.as_ref().unwrap().target,
+        AttackTarget::Player(2),
+    );
+    game.process_declare_blockers(&dp).unwrap();
+    game.process_combat_damage(&dp, false).unwrap();
+
+    assert_eq!(game.players[2].life_total, 18);
+    assert_eq!(game.players[1].life_total, 20);
+    assert_eq!(game.players[3].life_total, 20);
+}
+
+#[test]
+fn test_losing_player_leaves_with_their_objects() {
+    let mut game = setup_multiplayer_game(4);
+    fill_library(&mut game, 2, 3);
+    let bears = put_on_battlefield(&mut game, creatures::grizzly_bears(), 2);
+    let in_hand = put_in_hand(&mut game, creatures::grizzly_bears(), 2);
+    let survivor = put_on_battlefield(&mut game, creatures::grizzly_bears(), 1);
+
+    // A permanent player 3 owns but player 2 controls is exiled.
+    let borrowed = GameObject::new(creatures::hill_giant(), 3, Zone::Battlefield);
+    let borrowed_id = borrowed.id;
+    game.add_object(borrowed);
+    game.place_on_battlefield(borrowed_id, 2);
+
+    game.players[2].life_total = 0;
+    game.check_state_based_actions(&ScriptedDecisionProvider::new()).unwrap();
+
+    assert!(game.player_lost[2]);
+    assert!(game.get_object(bears).is_err());
+    assert!(game.get_object(in_hand).is_err());
+    assert!(!game.battlefield.contains_key(&bears));
+    assert!(game.players[2].library.is_empty());
+    assert!(game.players[2].hand.is_empty());
+    assert!(!game.objects.values().any(|obj| obj.owner == 2));
+
+    let borrowed_id = game.current_identity(borrowed_id);
+    assert_eq!(game.get_object(borrowed_id).unwrap().zone, Zone::Exile);
+    assert!(game.exile.contains(&borrowed_id));
+    assert!(game.battlefield.contains_key(&survivor));
+    assert!(game.events.events().iter().any(|e| matches!(
+        e,
+        GameEvent::PlayerLeftGame { player_id: 2 }
+    )));
+}
+
+#[test]
+fn test_attackers_of_departed_player_deal_no_damage() {
+    let mut game = setup_multiplayer_game(4);
+    game.turn_number = 2;
+    put_on_battlefield(&mut game, creatures::grizzly_bears(), 0);
+
+    let dp = ScriptedDecisionProvider::new();
+    dp.expect_pick_n(ChoiceKind::DeclareAttackers, vec![2]);
+    game.process_declare_attackers(&dp).unwrap();
+
+    game.players[3].life_total = 0;
+    game.check_state_based_actions(&dp).unwrap();
+    game.process_declare_blockers(&dp).unwrap();
+    game.process_combat_damage(&dp, false).unwrap();
+
+    assert_eq!(game.players[3].life_total, 0);
+    assert!(!game.events.events().iter().any(|e| matches!(e, GameEvent::DamageDealt { .. })));
+}
+
+#[test]
+fn test_two_player_loss_leaves_objects_in_place() {
+    // Rule 104.2a: the game simply ends; nobody leaves.
+    let mut game = setup_multiplayer_game(2);
+    let bears = put_on_battlefield(&mut game, creatures::grizzly_bears(), 1);
+    game.players[1].life_total = 0;
+    game.check_state_based_actions(&ScriptedDecisionProvider::new()).unwrap();
+
+    assert!(game.player_lost[1]);
+    assert!(game.battlefield.contains_key(&bears));
+}
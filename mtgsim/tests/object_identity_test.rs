@@ -0,0 +1,141 @@
+//! Object identity integration tests — an object that changes zones
+//! becomes a new object with no memory of its previous existence (rule
+//! 400.7): effects, targets and attachments that referred to the old one
+//! stop applying, while an effect that moved it can still find it (rule
+//! 400.7 exceptions) and last known information stays with the old one.
+
+mod common;
+
+use common::{put_in_hand, put_on_battlefield, setup_two_player_game};
+
+use mtgsim::cards::alpha;
+use mtgsim::cards::creatures;
+use mtgsim::cards::equipment;
+use mtgsim::engine::actions::GameAction;
+use mtgsim::engine::resolve::{ResolutionContext, ResolvedTarget};
+use mtgsim::events::event::GameEvent;
+use mtgsim::objects::object::GameObject;
+use mtgsim::oracle::characteristics::get_effective_power;
+use mtgsim::state::game_state::GameState;
+use mtgsim::types::effects::*;
+use mtgsim::types::ids::ObjectId;
+use mtgsim::types::mana::ManaType;
+use mtgsim::types::zones::Zone;
+use mtgsim::ui::choice_types::ChoiceKind;
+use mtgsim::ui::decision::ScriptedDecisionProvider;
+
+fn pick_target(dp: &ScriptedDecisionProvider, spell_id: ObjectId, index: usize) {
+    dp.expect_pick_n(
+        ChoiceKind::SelectRecipients { recipient: EffectRecipient::Implicit, spell_id },
+        vec![index],
+    );
+}
+
+/// "Exile target creature, then return it to the battlefield under its
+/// owner's control."
+fn flicker(game: &mut GameState, target: ObjectId) {
+    let effect = Effect::Sequence(vec![
+        Effect::Atom(
+            Primitive::Exile,
+            EffectRecipient::Target(SelectionFilter::Creature, TargetCount::Exactly(1)),
+        ),
+        Effect::Atom(
+            Primitive::ReturnToBattlefield,
+            EffectRecipient::Target(SelectionFilter::Creature, TargetCount::Exactly(1)),
+        ),
+    ]);
+    let source = game.add_object(GameObject::new(alpha::lightning_bolt(), 0, Zone::Stack));
+    let ctx = ResolutionContext {
+        source,
+        controller: 0,
+        targets: vec![ResolvedTarget::Object(target)],
+        ability_source: None,
+    };
+    game.resolve_effect(&effect, &ctx, &ScriptedDecisionProvider::new()).unwrap();
+}
+
+#[test]
+fn test_reanimated_creature_loses_until_end_of_turn_effects() {
+    let mut game = setup_two_player_game();
+    let bears = put_on_battlefield(&mut game, creatures::grizzly_bears(), 0);
+    let growth = put_in_hand(&mut game, alpha::giant_growth(), 0);
+    game.players[0].mana_pool.add(ManaType::Green, 1);
+    let dp = ScriptedDecisionProvider::new();
+    pick_target(&dp, growth, 0);
+    game.cast_spell(0, growth, &dp).unwrap();
+    game.resolve_top_of_stack(&dp).unwrap();
+    assert_eq!(get_effective_power(&game, bears), Some(5));
+
+    let in_graveyard = game.change_zone(bears, Zone::Graveyard).unwrap();
+    let returned = game.change_zone(in_graveyard, Zone::Battlefield).unwrap();
+    assert_ne!(returned, bears);
+    assert_eq!(get_effective_power(&game, returned), Some(2), "Giant Growth applied to the old object");
+}
+
+#[test]
+fn test_spell_targeting_a_flickered_creature_fizzles() {
+    let mut game = setup_two_player_game();
+    let bears = put_on_battlefield(&mut game, creatures::grizzly_bears(), 1);
+    let bolt = put_in_hand(&mut game, alpha::lightning_bolt(), 0);
+    game.players[0].mana_pool.add(ManaType::Red, 1);
+    let dp = ScriptedDecisionProvider::new();
+    // Any target: [Player(0), Player(1), Object(bears)]
+    pick_target(&dp, bolt, 2);
+    let bolt = game.cast_spell(0, bolt, &dp).unwrap();
+
+    flicker(&mut game, bears);
+    let bears = game.current_identity(bears);
+    assert!(game.battlefield.contains_key(&bears));
+
+    game.resolve_top_of_stack(&dp).unwrap();
+    assert!(game.events.events().iter().any(|e| matches!(
+        e, GameEvent::SpellFizzled { spell_id } if *spell_id == bolt
+    )));
+    assert_eq!(game.battlefield[&bears].damage_marked, 0);
+    assert_eq!(game.players[1].life_total, 20);
+}
+
+#[test]
+fn test_equipment_falls_off_a_host_that_changes_zones() {
+    let mut game = setup_two_player_game();
+    let bears = put_on_battlefield(&mut game, creatures::grizzly_bears(), 0);
+    let bonesplitter = put_on_battlefield(&mut game, equipment::bonesplitter(), 0);
+    game.execute_action(GameAction::Attach { attachment: bonesplitter, host: bears }).unwrap();
+    assert_eq!(get_effective_power(&game, bears), Some(4));
+
+    flicker(&mut game, bears);
+    game.check_state_based_actions(&ScriptedDecisionProvider::new()).unwrap();
+    let bears = game.current_identity(bears);
+    assert_eq!(game.battlefield[&bonesplitter].attached_to, None);
+    assert!(game.battlefield[&bears].attached_by.is_empty());
+    assert_eq!(get_effective_power(&game, bears), Some(2));
+}
+
+#[test]
+fn test_exile_then_return_finds_the_new_object() {
+    let mut game = setup_two_player_game();
+    let bears = put_on_battlefield(&mut game, creatures::grizzly_bears(), 1);
+
+    flicker(&mut game, bears);
+    let returned = game.current_identity(bears);
+    assert!(game.battlefield.contains_key(&returned));
+    assert_eq!(game.battlefield[&returned].controller, 1, "under its owner's control");
+    assert!(game.exile.is_empty());
+
+    let exiled = game.previous_identity(returned).unwrap();
+    assert_eq!(game.previous_identity(exiled), Some(bears));
+    assert_eq!(game.original_identity(returned), bears);
+    assert_eq!(game.last_known_information(bears).unwrap().zone, Zone::Battlefield);
+}
+
+#[test]
+fn test_token_that_left_the_battlefield_does_not_return() {
+    let mut game = setup_two_player_game();
+    let mut token = GameObject::new(creatures::grizzly_bears(), 0, Zone::Battlefield);
+    token.is_token = true;
+    let token = game.add_object(token);
+    game.place_on_battlefield(token, 0);
+
+    flicker(&mut game, token);
+    assert!(!game.battlefield.contains_key(&game.current_identity(token)));
+}
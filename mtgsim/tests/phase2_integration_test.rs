@@ -38,6 +38,7 @@ fn test_cast_and_resolve_lightning_bolt() {
     // Round 1: Cast (returns ActionTaken immediately)
     let result = game.run_priority_round(&decisions).unwrap();
     assert_eq!(result, PriorityResult::ActionTaken);
+    let bolt_id = game.current_identity(bolt_id);
     assert!(game.stack.contains(&bolt_id));
     assert_eq!(game.players[0].mana_pool.amount(ManaType::Red), 0);
 
@@ -50,6 +51,7 @@ fn test_cast_and_resolve_lightning_bolt() {
     assert!(game.stack.is_empty());
 
     // Bolt in graveyard
+    let bolt_id = game.current_identity(bolt_id);
     assert_eq!(game.get_object(bolt_id).unwrap().zone, Zone::Graveyard);
     assert!(game.players[0].graveyard.contains(&bolt_id));
 }
@@ -75,7 +77,7 @@ fn test_cast_and_resolve_ancestral_recall() {
     }, vec![0]);
     // Cast (returns ActionTaken immediately)
     game.run_priority_round(&decisions).unwrap();
-    assert!(game.stack.contains(&recall_id));
+    assert!(game.stack.contains(&game.current_identity(recall_id)));
 
     // Resolve: both pass
     decisions.expect_pick_n(ChoiceKind::PriorityAction, vec![0]);
@@ -109,6 +111,7 @@ fn test_counterspell_counters_bolt() {
     }, vec![1]);
     let result = game.run_priority_round(&decisions).unwrap();
     assert_eq!(result, PriorityResult::ActionTaken);
+    let bolt_id = game.current_identity(bolt_id);
     assert!(game.stack.contains(&bolt_id));
 
     // Player 1 responds with Counterspell targeting the bolt
@@ -124,7 +127,7 @@ fn test_counterspell_counters_bolt() {
 
     let result = game.run_priority_round(&decisions).unwrap();
     assert_eq!(result, PriorityResult::ActionTaken);
-    assert!(game.stack.contains(&cs_id)); // counterspell on stack
+    assert!(game.stack.contains(&game.current_identity(cs_id))); // counterspell on stack
     assert!(game.stack.contains(&bolt_id)); // bolt still on stack below it
 
     // Both pass — Counterspell resolves
@@ -135,10 +138,10 @@ fn test_counterspell_counters_bolt() {
 
     // Bolt should have been countered (removed from stack, in graveyard)
     assert!(!game.stack.contains(&bolt_id));
-    assert_eq!(game.get_object(bolt_id).unwrap().zone, Zone::Graveyard);
+    assert_eq!(game.get_object(game.current_identity(bolt_id)).unwrap().zone, Zone::Graveyard);
 
     // Counterspell itself should be in graveyard too
-    assert_eq!(game.get_object(cs_id).unwrap().zone, Zone::Graveyard);
+    assert_eq!(game.get_object(game.current_identity(cs_id)).unwrap().zone, Zone::Graveyard);
 
     // Player 1's life should be unchanged — bolt was countered
     assert_eq!(game.players[1].life_total, 20);
@@ -185,6 +188,7 @@ fn test_volcanic_upheaval_destroys_land() {
 
     // Land should be destroyed (in graveyard)
     assert!(!game.battlefield.contains_key(&target_land));
+    let target_land = game.current_identity(target_land);
     assert_eq!(game.get_object(target_land).unwrap().zone, Zone::Graveyard);
     assert!(game.players[1].graveyard.contains(&target_land));
 }
@@ -291,7 +295,7 @@ fn test_volcanic_upheaval_fizzles_when_target_destroyed() {
     assert_eq!(result, PriorityResult::StackResolved);
 
     // upheaval1 fizzled but still goes to graveyard
-    assert_eq!(game.get_object(upheaval1_id).unwrap().zone, Zone::Graveyard);
+    assert_eq!(game.get_object(game.current_identity(upheaval1_id)).unwrap().zone, Zone::Graveyard);
     // Stack should be empty
     assert!(game.stack.is_empty());
 }
@@ -353,7 +357,7 @@ fn test_burst_of_energy_fizzles_after_upheaval_destroys_target() {
     let result = game.run_priority_round(&decisions).unwrap();
     assert_eq!(result, PriorityResult::StackResolved);
     assert!(!game.battlefield.contains_key(&target_land));
-    assert_eq!(game.get_object(target_land).unwrap().zone, Zone::Graveyard);
+    assert_eq!(game.get_object(game.current_identity(target_land)).unwrap().zone, Zone::Graveyard);
 
     // Both pass — Burst of Energy resolves but fizzles (target gone)
     decisions.expect_pick_n(ChoiceKind::PriorityAction, vec![0]);
@@ -362,7 +366,7 @@ fn test_burst_of_energy_fizzles_after_upheaval_destroys_target() {
     assert_eq!(result, PriorityResult::StackResolved);
 
     // Burst fizzled — it's in the graveyard, stack is empty
-    assert_eq!(game.get_object(burst_id).unwrap().zone, Zone::Graveyard);
+    assert_eq!(game.get_object(game.current_identity(burst_id)).unwrap().zone, Zone::Graveyard);
     assert!(game.stack.is_empty());
 }
 
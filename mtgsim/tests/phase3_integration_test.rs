@@ -428,8 +428,8 @@ fn test_combat_damage_kills_player() {
     // Player 1 at 0 life
     assert_eq!(game.state.players[1].life_total, 0);
 
-    // Run priority loop — SBAs fire, flagging player 1 as lost
-    scripted.expect_pick_n(ChoiceKind::PriorityAction, vec![0]);
+    // Run priority loop — SBAs fire, flagging player 1 as lost; only
+    // player 0 is left to receive priority
     scripted.expect_pick_n(ChoiceKind::PriorityAction, vec![0]);
     game.state.run_priority_loop(&scripted).unwrap();
     assert!(game.state.player_lost[1]);
@@ -585,7 +585,7 @@ fn test_damage_persists_bolt_in_second_main_kills() {
 
     // Elemental now has 2 (combat) + 3 (bolt) = 5 damage ≥ 5 toughness → dead
     assert!(!game.state.battlefield.contains_key(&elemental));
-    assert_eq!(game.state.get_object(elemental).unwrap().zone, Zone::Graveyard);
+    assert_eq!(game.state.get_object(game.state.current_identity(elemental)).unwrap().zone, Zone::Graveyard);
 }
 
 // ---------------------------------------------------------------------------
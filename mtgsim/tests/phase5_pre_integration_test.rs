@@ -71,7 +71,7 @@ fn test_nights_whisper_draw_and_life_loss() {
     // Lost 2 life
     assert_eq!(game.players[0].life_total, 18);
     // Sorcery in graveyard
-    assert_eq!(game.get_object(whisper_id).unwrap().zone, Zone::Graveyard);
+    assert_eq!(game.get_object(game.current_identity(whisper_id)).unwrap().zone, Zone::Graveyard);
 }
 
 #[test]
@@ -121,7 +121,7 @@ fn test_angels_mercy_gains_life() {
     cast_and_resolve(&mut game, &decisions);
 
     assert_eq!(game.players[0].life_total, 27);
-    assert_eq!(game.get_object(mercy_id).unwrap().zone, Zone::Graveyard);
+    assert_eq!(game.get_object(game.current_identity(mercy_id)).unwrap().zone, Zone::Graveyard);
 }
 
 // ---------------------------------------------------------------------------
@@ -144,7 +144,7 @@ fn test_dark_ritual_adds_three_black_mana() {
 
     // Started with 1B, spent 1B to cast, gained 3B → 3B in pool
     assert_eq!(game.players[0].mana_pool.amount(ManaType::Black), 3);
-    assert_eq!(game.get_object(ritual_id).unwrap().zone, Zone::Graveyard);
+    assert_eq!(game.get_object(game.current_identity(ritual_id)).unwrap().zone, Zone::Graveyard);
 }
 
 #[test]
@@ -161,7 +161,7 @@ fn test_dark_ritual_uses_stack_not_mana_ability() {
     // Cast — it should go on the stack
     let result = game.run_priority_round(&decisions).unwrap();
     assert_eq!(result, PriorityResult::ActionTaken);
-    assert!(game.stack.contains(&ritual_id));
+    assert!(game.stack.contains(&game.current_identity(ritual_id)));
 
     // Mana hasn't been added yet — still on the stack
     assert_eq!(game.players[0].mana_pool.amount(ManaType::Black), 0);
@@ -216,10 +216,10 @@ fn test_doom_blade_destroys_nonblack_creature() {
     cast_and_resolve(&mut game, &decisions);
 
     // Creature destroyed
-    assert_eq!(game.get_object(target_id).unwrap().zone, Zone::Graveyard);
+    assert_eq!(game.get_object(game.current_identity(target_id)).unwrap().zone, Zone::Graveyard);
     assert!(!game.battlefield.contains_key(&target_id));
     // Spell in graveyard
-    assert_eq!(game.get_object(blade_id).unwrap().zone, Zone::Graveyard);
+    assert_eq!(game.get_object(game.current_identity(blade_id)).unwrap().zone, Zone::Graveyard);
 }
 
 #[test]
@@ -302,6 +302,7 @@ fn test_isamaru_legend_rule_second_copy_dies() {
     // ScriptedDecisionProvider keeps the first legendary (legendaries[0]).
     // One Isamaru should remain, one should be in the graveyard.
     let first_on_bf = game.battlefield.contains_key(&first_id);
+    let second_id = game.current_identity(second_id);
     let second_on_bf = game.battlefield.contains_key(&second_id);
     assert!(
         first_on_bf ^ second_on_bf,
@@ -312,5 +313,5 @@ fn test_isamaru_legend_rule_second_copy_dies() {
     let survivor = if first_on_bf { first_id } else { second_id };
     let deceased = if first_on_bf { second_id } else { first_id };
     assert!(game.battlefield.contains_key(&survivor));
-    assert_eq!(game.get_object(deceased).unwrap().zone, Zone::Graveyard);
+    assert_eq!(game.get_object(game.current_identity(deceased)).unwrap().zone, Zone::Graveyard);
 }
@@ -49,7 +49,7 @@ fn test_giant_growth_pumps_creature() {
     );
     let result = game.run_priority_round(&decisions).unwrap();
     assert_eq!(result, PriorityResult::ActionTaken);
-    assert!(game.stack.contains(&growth_id));
+    assert!(game.stack.contains(&game.current_identity(growth_id)));
 
     // Both players pass → resolve Giant Growth
     decisions.expect_pick_n(ChoiceKind::PriorityAction, vec![0]);
@@ -58,7 +58,7 @@ fn test_giant_growth_pumps_creature() {
     assert_eq!(result, PriorityResult::StackResolved);
 
     // Giant Growth is now in graveyard
-    assert_eq!(game.get_object(growth_id).unwrap().zone, Zone::Graveyard);
+    assert_eq!(game.get_object(game.current_identity(growth_id)).unwrap().zone, Zone::Graveyard);
 
     // Creature should be 5/5
     assert_eq!(get_effective_power(&game, bears_id), Some(5));
@@ -71,7 +71,7 @@ fn test_cerulean_wisps_sets_color_to_blue() {
     assert_eq!(colors.len(), 1);
 
     // Wisps is in graveyard
-    assert_eq!(game.get_object(wisps_id).unwrap().zone, Zone::Graveyard);
+    assert_eq!(game.get_object(game.current_identity(wisps_id)).unwrap().zone, Zone::Graveyard);
 
     // Drew a card (Sequence: ChangeColor + DrawCards)
     assert_eq!(game.players[0].hand.len(), 1);
@@ -104,7 +104,7 @@ fn test_liquimetal_adds_artifact_type() {
     assert!(types.contains(&CardType::Artifact));
 
     // Spell is in graveyard
-    assert_eq!(game.get_object(spell_id).unwrap().zone, Zone::Graveyard);
+    assert_eq!(game.get_object(game.current_identity(spell_id)).unwrap().zone, Zone::Graveyard);
 }
 
 // ===========================================================================
@@ -0,0 +1,207 @@
+//! Phasing integration tests — phasing out and in (rule 702.26), the untap
+//! step's phasing turn-based action (rule 502.1), indirect phasing of
+//! attached permanents (rule 702.26g), and phased-out permanents being
+//! treated as though they don't exist (rule 702.26b).
+
+mod common;
+
+use common::{fill_library, put_in_hand, put_on_battlefield, setup_two_player_game};
+
+use mtgsim::cards::alpha;
+use mtgsim::cards::creatures;
+use mtgsim::cards::equipment;
+use mtgsim::cards::phase5_pre_cards;
+use mtgsim::cards::phasing;
+use mtgsim::engine::actions::GameAction;
+use mtgsim::events::event::{DamageTarget, GameEvent};
+use mtgsim::oracle::board::permanents_controlled_by;
+use mtgsim::oracle::characteristics::get_effective_power;
+use mtgsim::oracle::legality::{legal_attackers, legal_blockers};
+use mtgsim::state::battlefield::{AttackTarget, AttackingInfo};
+use mtgsim::state::game_state::{GameState, StepType};
+use mtgsim::types::effects::{EffectRecipient, SelectionFilter, TargetCount};
+use mtgsim::types::mana::{ManaCost, ManaType};
+use mtgsim::types::zones::Zone;
+use mtgsim::ui::choice_types::ChoiceKind;
+use mtgsim::ui::decision::ScriptedDecisionProvider;
+
+/// Player 0 casts Teferi's Protection and it resolves.
+fn cast_teferis_protection(game: &mut GameState) {
+    let spell = put_in_hand(game, phasing::teferis_protection(), 0);
+    game.players[0].mana_pool.add(ManaType::White, 3);
+    let dp = ScriptedDecisionProvider::new();
+    dp.expect_allocation(ChoiceKind::GenericManaAllocation { mana_cost: ManaCost::zero() }, vec![2]);
+    game.cast_spell(0, spell, &dp).unwrap();
+    game.resolve_top_of_stack(&dp).unwrap();
+}
+
+/// Advance until the next untap step has been processed.
+fn advance_to_untap(game: &mut GameState) {
+    loop {
+        let (_, step) = game.advance_turn().unwrap();
+        if step == Some(StepType::Untap) {
+            return;
+        }
+    }
+}
+
+#[test]
+fn test_teferis_protection_phases_out_only_your_permanents() {
+    let mut game = setup_two_player_game();
+    let bears = put_on_battlefield(&mut game, creatures::grizzly_bears(), 0);
+    let giant = put_on_battlefield(&mut game, creatures::hill_giant(), 1);
+
+    cast_teferis_protection(&mut game);
+
+    assert!(game.battlefield.contains_key(&bears), "phasing isn't leaving the battlefield");
+    assert!(!game.is_phased_in(bears));
+    assert!(game.is_phased_in(giant));
+    assert!(permanents_controlled_by(&game, 0).is_empty());
+    assert!(legal_attackers(&game, 0).is_empty());
+    assert!(legal_blockers(&game, 0).is_empty());
+    assert!(game.events.events().iter().any(|e| matches!(
+        e,
+        GameEvent::PhasedOut { object_id } if *object_id == bears
+    )));
+}
+
+#[test]
+fn test_phased_out_permanents_phase_in_during_controllers_untap_step() {
+    let mut game = setup_two_player_game();
+    for pid in 0..2 {
+        fill_library(&mut game, pid, 5);
+    }
+    let bears = put_on_battlefield(&mut game, creatures::grizzly_bears(), 0);
+    game.battlefield.get_mut(&bears).unwrap().tapped = true;
+    cast_teferis_protection(&mut game);
+
+    // Player 1's untap step leaves them phased out.
+    advance_to_untap(&mut game);
+    assert_eq!(game.active_player, 1);
+    assert!(!game.is_phased_in(bears));
+
+    // Player 0's untap step phases them in, then untaps them. They've been
+    // under player 0's control all along, so they aren't summoning sick.
+    advance_to_untap(&mut game);
+    assert_eq!(game.active_player, 0);
+    assert!(game.is_phased_in(bears));
+    assert!(!game.battlefield[&bears].tapped);
+    assert_eq!(legal_attackers(&game, 0), vec![bears]);
+}
+
+#[test]
+fn test_phasing_keyword_phases_out_and_in_on_alternate_turns() {
+    let mut game = setup_two_player_game();
+    for pid in 0..2 {
+        fill_library(&mut game, pid, 10);
+    }
+    let djinn = put_on_battlefield(&mut game, phasing::breezekeeper(), 0);
+
+    advance_to_untap(&mut game); // player 1
+    assert!(game.is_phased_in(djinn));
+    advance_to_untap(&mut game); // player 0
+    assert!(!game.is_phased_in(djinn));
+    advance_to_untap(&mut game); // player 1
+    advance_to_untap(&mut game); // player 0
+    assert!(game.is_phased_in(djinn));
+}
+
+#[test]
+fn test_equipment_phases_out_and_in_with_its_creature() {
+    let mut game = setup_two_player_game();
+    for pid in 0..2 {
+        fill_library(&mut game, pid, 5);
+    }
+    let bears = put_on_battlefield(&mut game, creatures::grizzly_bears(), 0);
+    let opponent_bears = put_on_battlefield(&mut game, creatures::grizzly_bears(), 1);
+    let bonesplitter = put_on_battlefield(&mut game, equipment::bonesplitter(), 1);
+    game.execute_action(GameAction::Attach { attachment: bonesplitter, host: bears }).unwrap();
+
+    cast_teferis_protection(&mut game);
+    assert!(!game.is_phased_in(bonesplitter), "attached permanents phase out indirectly");
+    assert!(game.battlefield[&bonesplitter].phased_out_indirectly);
+
+    // The Equipment's controller untapping doesn't bring it back on its own.
+    advance_to_untap(&mut game);
+    assert_eq!(game.active_player, 1);
+    assert!(!game.is_phased_in(bonesplitter));
+    assert_eq!(get_effective_power(&game, opponent_bears), Some(2));
+
+    advance_to_untap(&mut game);
+    assert!(game.is_phased_in(bonesplitter));
+    assert_eq!(game.battlefield[&bonesplitter].attached_to, Some(bears));
+    assert_eq!(get_effective_power(&game, bears), Some(4));
+}
+
+#[test]
+fn test_phased_out_static_ability_stops_applying() {
+    let mut game = setup_two_player_game();
+    let anthem = put_on_battlefield(&mut game, phase5_pre_cards::glorious_anthem(), 0);
+    let bears = put_on_battlefield(&mut game, creatures::grizzly_bears(), 0);
+    assert_eq!(get_effective_power(&game, bears), Some(3));
+
+    game.phase_out(anthem);
+    assert_eq!(get_effective_power(&game, bears), Some(2));
+
+    game.phase_in(anthem);
+    assert_eq!(get_effective_power(&game, bears), Some(3));
+}
+
+#[test]
+fn test_phased_out_creature_ignored_by_sba() {
+    let mut game = setup_two_player_game();
+    let bears = put_on_battlefield(&mut game, creatures::grizzly_bears(), 0);
+    let bolt = put_in_hand(&mut game, alpha::lightning_bolt(), 1);
+    game.execute_action(GameAction::DealDamage {
+        source: bolt,
+        target: DamageTarget::Object(bears),
+        amount: 3,
+        is_combat: false,
+    }).unwrap();
+    game.phase_out(bears);
+
+    assert!(!game.check_state_based_actions(&ScriptedDecisionProvider::new()).unwrap());
+    assert_eq!(game.get_object(bears).unwrap().zone, Zone::Battlefield);
+}
+
+#[test]
+fn test_spell_targeting_phased_out_creature_fizzles() {
+    let mut game = setup_two_player_game();
+    let bears = put_on_battlefield(&mut game, creatures::grizzly_bears(), 1);
+    let bolt = put_in_hand(&mut game, alpha::lightning_bolt(), 0);
+    game.players[0].mana_pool.add(ManaType::Red, 1);
+
+    let dp = ScriptedDecisionProvider::new();
+    dp.expect_pick_n(
+        ChoiceKind::SelectRecipients {
+            recipient: EffectRecipient::Target(SelectionFilter::Any, TargetCount::Exactly(1)),
+            spell_id: bolt,
+        },
+        vec![2], // players 0 and 1 come first
+    );
+    let bolt = game.cast_spell(0, bolt, &dp).unwrap();
+    game.phase_out(bears);
+    game.resolve_top_of_stack(&dp).unwrap();
+
+    assert!(game.events.events().iter().any(|e| matches!(
+        e,
+        GameEvent::SpellFizzled { spell_id } if *spell_id == bolt
+    )));
+    game.phase_in(bears);
+    assert_eq!(game.battlefield[&bears].damage_marked, 0);
+}
+
+#[test]
+fn test_phasing_out_removes_attacker_from_combat() {
+    let mut game = setup_two_player_game();
+    let giant = put_on_battlefield(&mut game, creatures::hill_giant(), 0);
+    game.battlefield.get_mut(&giant).unwrap().attacking = Some(AttackingInfo {
+        target: AttackTarget::Player(1),
+        is_blocked: false,
+        blocked_by: Vec::new(),
+    });
+
+    game.phase_out(giant);
+    game.phase_in(giant);
+    assert!(game.battlefield[&giant].attacking.is_none());
+}
@@ -0,0 +1,227 @@
+//! Planeswalker integration tests — loyalty abilities (rule 606), damage to
+//! planeswalkers (rule 120.3c), and attacking planeswalkers (rule 508.1b).
+
+mod common;
+
+use common::{fill_library, put_on_battlefield, setup_two_player_game};
+
+use mtgsim::cards::alpha;
+use mtgsim::cards::creatures;
+use mtgsim::cards::planeswalkers;
+use mtgsim::engine::resolve::ResolvedTarget;
+use mtgsim::events::event::DamageTarget;
+use mtgsim::engine::actions::GameAction;
+use mtgsim::state::battlefield::AttackTarget;
+use mtgsim::state::game_state::{Phase, PhaseType, StepType};
+use mtgsim::types::effects::{CounterType, EffectRecipient, SelectionFilter, TargetCount};
+use mtgsim::types::zones::Zone;
+use mtgsim::ui::choice_types::ChoiceKind;
+use mtgsim::ui::decision::ScriptedDecisionProvider;
+
+fn loyalty(game: &mtgsim::state::game_state::GameState, id: mtgsim::types::ids::ObjectId) -> u32 {
+    game.battlefield.get(&id).unwrap().counter_count(CounterType::Loyalty)
+}
+
+#[test]
+fn test_plus_ability_adds_loyalty_and_resolves() {
+    let mut game = setup_two_player_game();
+    game.turn_number = 1;
+    fill_library(&mut game, 0, 5);
+    let ob = put_on_battlefield(&mut game, planeswalkers::ob_nixilis_reignited(), 0);
+    assert_eq!(loyalty(&game, ob), 5);
+
+    let dp = ScriptedDecisionProvider::new();
+    game.activate_ability(0, ob, 0, &dp).unwrap();
+    // Cost paid on activation (rule 602.2h / 606.4)
+    assert_eq!(loyalty(&game, ob), 6);
+
+    game.resolve_top_of_stack(&dp).unwrap();
+    assert_eq!(game.players[0].hand.len(), 1);
+    assert_eq!(game.players[0].life_total, 19);
+}
+
+#[test]
+fn test_loyalty_ability_once_per_turn() {
+    let mut game = setup_two_player_game();
+    game.turn_number = 1;
+    fill_library(&mut game, 0, 5);
+    let ob = put_on_battlefield(&mut game, planeswalkers::ob_nixilis_reignited(), 0);
+
+    let dp = ScriptedDecisionProvider::new();
+    game.activate_ability(0, ob, 0, &dp).unwrap();
+    game.resolve_top_of_stack(&dp).unwrap();
+
+    assert!(game.activate_ability(0, ob, 0, &dp).is_err());
+    assert_eq!(loyalty(&game, ob), 6);
+
+    // Next turn it's available again.
+    game.turn_number = 2;
+    assert!(game.activate_ability(0, ob, 0, &dp).is_ok());
+}
+
+#[test]
+fn test_loyalty_ability_sorcery_speed_only() {
+    let mut game = setup_two_player_game();
+    game.turn_number = 1;
+    let ob = put_on_battlefield(&mut game, planeswalkers::ob_nixilis_reignited(), 0);
+    let dp = ScriptedDecisionProvider::new();
+
+    // Not a main phase
+    game.phase = Phase { phase_type: PhaseType::Combat, step: Some(StepType::DeclareAttackers) };
+    assert!(game.activate_ability(0, ob, 0, &dp).is_err());
+
+    // Main phase, but not the active player
+    game.phase = Phase::new(PhaseType::Precombat);
+    game.active_player = 1;
+    assert!(game.activate_ability(0, ob, 0, &dp).is_err());
+    assert_eq!(loyalty(&game, ob), 5);
+}
+
+#[test]
+fn test_minus_ability_requires_enough_loyalty() {
+    let mut game = setup_two_player_game();
+    game.turn_number = 1;
+    let chandra = put_on_battlefield(&mut game, planeswalkers::chandra_torch_of_defiance(), 0);
+    let bears = put_on_battlefield(&mut game, creatures::grizzly_bears(), 1);
+    game.battlefield.get_mut(&chandra).unwrap().remove_counters(CounterType::Loyalty, 2);
+
+    let dp = ScriptedDecisionProvider::new();
+    dp.expect_pick_n(
+        ChoiceKind::SelectRecipients {
+            recipient: EffectRecipient::Target(SelectionFilter::Creature, TargetCount::Exactly(1)),
+            spell_id: chandra,
+        },
+        vec![0],
+    );
+    // −3 with only 2 loyalty can't be activated (rule 606.6).
+    assert!(game.activate_ability(0, chandra, 1, &dp).is_err());
+    assert!(game.stack.is_empty());
+    assert_eq!(loyalty(&game, chandra), 2);
+    assert!(game.battlefield.contains_key(&bears));
+}
+
+#[test]
+fn test_minus_ability_to_zero_puts_walker_in_graveyard() {
+    let mut game = setup_two_player_game();
+    game.turn_number = 1;
+    let chandra = put_on_battlefield(&mut game, planeswalkers::chandra_torch_of_defiance(), 0);
+    game.battlefield.get_mut(&chandra).unwrap().remove_counters(CounterType::Loyalty, 1);
+    let bears = put_on_battlefield(&mut game, creatures::grizzly_bears(), 1);
+
+    let dp = ScriptedDecisionProvider::new();
+    dp.expect_pick_n(
+        ChoiceKind::SelectRecipients {
+            recipient: EffectRecipient::Target(SelectionFilter::Creature, TargetCount::Exactly(1)),
+            spell_id: chandra,
+        },
+        vec![0],
+    );
+    game.activate_ability(0, chandra, 1, &dp).unwrap();
+    assert_eq!(loyalty(&game, chandra), 0);
+
+    // SBA 704.5i: zero loyalty → graveyard, even with the ability on the stack.
+    game.check_state_based_actions(&dp).unwrap();
+    assert_eq!(game.get_object(game.current_identity(chandra)).unwrap().zone, Zone::Graveyard);
+
+    // The ability still resolves independently of its source (rule 113.7a).
+    game.resolve_top_of_stack(&dp).unwrap();
+    assert_eq!(game.battlefield.get(&bears).unwrap().damage_marked, 4);
+}
+
+#[test]
+fn test_damage_to_planeswalker_removes_loyalty() {
+    let mut game = setup_two_player_game();
+    let garruk = put_on_battlefield(&mut game, planeswalkers::garruk_wildspeaker(), 1);
+    let bolt = mtgsim::objects::object::GameObject::new(alpha::lightning_bolt(), 0, Zone::Graveyard);
+    let bolt_id = bolt.id;
+    game.add_object(bolt);
+
+    game.execute_action(GameAction::DealDamage {
+        source: bolt_id,
+        target: DamageTarget::Object(garruk),
+        amount: 2,
+        is_combat: false,
+    }).unwrap();
+    assert_eq!(loyalty(&game, garruk), 1);
+    assert_eq!(game.battlefield.get(&garruk).unwrap().damage_marked, 0);
+
+    game.execute_action(GameAction::DealDamage {
+        source: bolt_id,
+        target: DamageTarget::Object(garruk),
+        amount: 3,
+        is_combat: false,
+    }).unwrap();
+    game.check_state_based_actions(&ScriptedDecisionProvider::new()).unwrap();
+    assert_eq!(game.get_object(game.current_identity(garruk)).unwrap().zone, Zone::Graveyard);
+}
+
+#[test]
+fn test_attack_planeswalker_and_deal_combat_damage() {
+    let mut game = setup_two_player_game();
+    game.turn_number = 2;
+    let bears = put_on_battlefield(&mut game, creatures::grizzly_bears(), 0);
+    let garruk = put_on_battlefield(&mut game, planeswalkers::garruk_wildspeaker(), 1);
+
+    // Legal pairs: [(bears, Player(1)), (bears, Planeswalker(garruk))]
+    let dp = ScriptedDecisionProvider::new();
+    dp.expect_pick_n(ChoiceKind::DeclareAttackers, vec![1]);
+    assert!(game.process_declare_attackers(&dp).unwrap());
+    assert_eq!(
+        game.battlefield.get(&bears).unwrap().attacking.as_ref().unwrap().target,
+        AttackTarget::Planeswalker(garruk),
+    );
+
+    // Player 1 defends the planeswalker but has no creatures to block with.
+    game.process_declare_blockers(&dp).unwrap();
+
+    game.process_combat_damage(&dp, false).unwrap();
+    assert_eq!(loyalty(&game, garruk), 1);
+    assert_eq!(game.players[1].life_total, 20);
+}
+
+#[test]
+fn test_planeswalker_controller_can_block_its_attacker() {
+    let mut game = setup_two_player_game();
+    game.turn_number = 2;
+    let bears = put_on_battlefield(&mut game, creatures::grizzly_bears(), 0);
+    let garruk = put_on_battlefield(&mut game, planeswalkers::garruk_wildspeaker(), 1);
+    let blocker = put_on_battlefield(&mut game, creatures::grizzly_bears(), 1);
+
+    let dp = ScriptedDecisionProvider::new();
+    dp.expect_pick_n(ChoiceKind::DeclareAttackers, vec![1]);
+    game.process_declare_attackers(&dp).unwrap();
+
+    dp.expect_pick_n(ChoiceKind::DeclareBlockers, vec![0]);
+    game.process_declare_blockers(&dp).unwrap();
+    assert!(game.battlefield.get(&blocker).unwrap().blocking.is_some());
+
+    game.process_combat_damage(&dp, false).unwrap();
+    assert_eq!(loyalty(&game, garruk), 3);
+    assert_eq!(game.battlefield.get(&bears).unwrap().damage_marked, 2);
+}
+
+#[test]
+fn test_attacker_of_departed_planeswalker_deals_no_damage() {
+    let mut game = setup_two_player_game();
+    game.turn_number = 2;
+    let bears = put_on_battlefield(&mut game, creatures::grizzly_bears(), 0);
+    let garruk = put_on_battlefield(&mut game, planeswalkers::garruk_wildspeaker(), 1);
+
+    let dp = ScriptedDecisionProvider::new();
+    dp.expect_pick_n(ChoiceKind::DeclareAttackers, vec![1]);
+    game.process_declare_attackers(&dp).unwrap();
+
+    game.change_zone(garruk, Zone::Graveyard).unwrap();
+    game.process_combat_damage(&dp, false).unwrap();
+    assert_eq!(game.players[1].life_total, 20);
+    assert!(game.battlefield.get(&bears).unwrap().attacking.is_some());
+}
+
+#[test]
+fn test_planeswalker_target_for_any_target_spell() {
+    // "Any target" includes planeswalkers (rule 115.4).
+    let mut game = setup_two_player_game();
+    let garruk = put_on_battlefield(&mut game, planeswalkers::garruk_wildspeaker(), 1);
+    let legal = mtgsim::oracle::legality::enumerate_legal_selections(&game, &SelectionFilter::Any, None);
+    assert!(legal.contains(&ResolvedTarget::Object(garruk)));
+}
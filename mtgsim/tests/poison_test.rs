@@ -0,0 +1,140 @@
+//! Infect, wither and toxic through combat — damage results (rule 120.3),
+//! toxic (rule 702.164c), and the ten-poison-counter SBA (rule 704.5c).
+
+mod common;
+
+use common::{put_on_battlefield, setup_two_player_game};
+
+use mtgsim::cards::creatures;
+use mtgsim::cards::keyword_creatures;
+use mtgsim::events::event::{GameEvent, LossReason};
+use mtgsim::objects::card_data::CardDataBuilder;
+use mtgsim::types::card_types::CardType;
+use mtgsim::types::effects::CounterType;
+use mtgsim::types::keywords::KeywordAbility;
+use mtgsim::types::zones::Zone;
+use mtgsim::ui::choice_types::ChoiceKind;
+use mtgsim::ui::decision::ScriptedDecisionProvider;
+
+#[test]
+fn test_unblocked_infect_attacker_gives_poison_not_life_loss() {
+    let mut game = setup_two_player_game();
+    game.turn_number = 2;
+    put_on_battlefield(&mut game, keyword_creatures::glistener_elf(), 0);
+
+    let dp = ScriptedDecisionProvider::new();
+    dp.expect_pick_n(ChoiceKind::DeclareAttackers, vec![0]);
+    game.process_declare_attackers(&dp).unwrap();
+    game.process_declare_blockers(&dp).unwrap();
+    game.process_combat_damage(&dp, false).unwrap();
+
+    assert_eq!(game.players[1].life_total, 20);
+    assert_eq!(game.players[1].poison_counters, 1);
+}
+
+#[test]
+fn test_blocked_infect_attacker_shrinks_blocker() {
+    let mut game = setup_two_player_game();
+    game.turn_number = 2;
+    put_on_battlefield(&mut game, keyword_creatures::glistener_elf(), 0);
+    let bears = put_on_battlefield(&mut game, creatures::grizzly_bears(), 1);
+
+    let dp = ScriptedDecisionProvider::new();
+    dp.expect_pick_n(ChoiceKind::DeclareAttackers, vec![0]);
+    game.process_declare_attackers(&dp).unwrap();
+    dp.expect_pick_n(ChoiceKind::DeclareBlockers, vec![0]);
+    game.process_declare_blockers(&dp).unwrap();
+    game.process_combat_damage(&dp, false).unwrap();
+
+    let entry = game.battlefield.get(&bears).unwrap();
+    assert_eq!(entry.damage_marked, 0);
+    assert_eq!(entry.counter_count(CounterType::MinusOneMinusOne), 1);
+}
+
+#[test]
+fn test_wither_blocker_kills_attacker_with_counters() {
+    let mut game = setup_two_player_game();
+    game.turn_number = 2;
+    let bears = put_on_battlefield(&mut game, creatures::grizzly_bears(), 0);
+    put_on_battlefield(&mut game, keyword_creatures::sickle_ripper(), 1);
+
+    let dp = ScriptedDecisionProvider::new();
+    dp.expect_pick_n(ChoiceKind::DeclareAttackers, vec![0]);
+    game.process_declare_attackers(&dp).unwrap();
+    dp.expect_pick_n(ChoiceKind::DeclareBlockers, vec![0]);
+    game.process_declare_blockers(&dp).unwrap();
+    game.process_combat_damage(&dp, false).unwrap();
+    assert_eq!(game.battlefield.get(&bears).unwrap().counter_count(CounterType::MinusOneMinusOne), 2);
+
+    // 0 toughness (704.5f), not lethal damage.
+    game.check_state_based_actions(&dp).unwrap();
+    assert_eq!(game.get_object(game.current_identity(bears)).unwrap().zone, Zone::Graveyard);
+}
+
+#[test]
+fn test_deathtouch_infect_damage_destroys_a_larger_blocker() {
+    let mut game = setup_two_player_game();
+    game.turn_number = 2;
+    let sting = CardDataBuilder::new("Deathtouch Infect Sting")
+        .card_type(CardType::Creature)
+        .power_toughness(1, 1)
+        .keyword(KeywordAbility::Deathtouch)
+        .keyword(KeywordAbility::Infect)
+        .build();
+    put_on_battlefield(&mut game, sting, 0);
+    let elemental = put_on_battlefield(&mut game, creatures::earth_elemental(), 1);
+
+    let dp = ScriptedDecisionProvider::new();
+    dp.expect_pick_n(ChoiceKind::DeclareAttackers, vec![0]);
+    game.process_declare_attackers(&dp).unwrap();
+    dp.expect_pick_n(ChoiceKind::DeclareBlockers, vec![0]);
+    game.process_declare_blockers(&dp).unwrap();
+    game.process_combat_damage(&dp, false).unwrap();
+
+    // One -1/-1 counter and no marked damage, but it was dealt damage by a
+    // deathtouch source (rule 704.5h).
+    assert_eq!(game.battlefield[&elemental].damage_marked, 0);
+    game.check_state_based_actions(&dp).unwrap();
+    assert!(!game.battlefield.contains_key(&elemental));
+}
+
+#[test]
+fn test_toxic_attacker_deals_damage_and_poison() {
+    let mut game = setup_two_player_game();
+    game.turn_number = 2;
+    let skulldweller = put_on_battlefield(&mut game, keyword_creatures::bilious_skulldweller(), 0);
+
+    let dp = ScriptedDecisionProvider::new();
+    dp.expect_pick_n(ChoiceKind::DeclareAttackers, vec![0]);
+    game.process_declare_attackers(&dp).unwrap();
+    game.process_declare_blockers(&dp).unwrap();
+    game.process_combat_damage(&dp, false).unwrap();
+
+    assert_eq!(game.players[1].life_total, 19);
+    assert_eq!(game.players[1].poison_counters, 1);
+    assert!(game.events.events().iter().any(|e| matches!(
+        e,
+        GameEvent::PoisonCountersAdded { player_id: 1, amount: 1, source } if *source == skulldweller
+    )));
+}
+
+#[test]
+fn test_tenth_poison_counter_loses_the_game() {
+    let mut game = setup_two_player_game();
+    game.turn_number = 2;
+    game.players[1].poison_counters = 9;
+    put_on_battlefield(&mut game, keyword_creatures::glistener_elf(), 0);
+
+    let dp = ScriptedDecisionProvider::new();
+    dp.expect_pick_n(ChoiceKind::DeclareAttackers, vec![0]);
+    game.process_declare_attackers(&dp).unwrap();
+    game.process_declare_blockers(&dp).unwrap();
+    game.process_combat_damage(&dp, false).unwrap();
+    game.check_state_based_actions(&dp).unwrap();
+
+    assert!(game.player_lost[1]);
+    assert!(game.events.events().iter().any(|e| matches!(
+        e,
+        GameEvent::PlayerLost { player_id: 1, reason: LossReason::PoisonCounters }
+    )));
+}
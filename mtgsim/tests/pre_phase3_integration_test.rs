@@ -104,8 +104,7 @@ fn test_game_over_bolt_to_zero() {
     // Both pass → resolve + SBA (player 1 at 0 life → loses)
     scripted.expect_pick_n(ChoiceKind::PriorityAction, vec![0]);
     scripted.expect_pick_n(ChoiceKind::PriorityAction, vec![0]);
-    // After resolve + SBA, both pass → phase ends
-    scripted.expect_pick_n(ChoiceKind::PriorityAction, vec![0]);
+    // After resolve + SBA, player 1 has lost: only player 0 passes → phase ends
     scripted.expect_pick_n(ChoiceKind::PriorityAction, vec![0]);
 
     // Cast and resolve via priority loop
@@ -220,7 +219,10 @@ fn test_first_player_draw_skip() {
         vec![make_test_decklist(60), make_test_decklist(60)],
     ).unwrap();
 
+    // Standard uses the London mulligan: both players keep.
     let decisions = ScriptedDecisionProvider::new();
+    decisions.expect_pick_n(ChoiceKind::Mulligan { mulligans_taken: 0 }, vec![]);
+    decisions.expect_pick_n(ChoiceKind::Mulligan { mulligans_taken: 0 }, vec![]);
     game.setup(&decisions).unwrap();
 
     // Both players drew 7 cards during setup
@@ -340,7 +342,7 @@ fn test_counterspell_cleans_up_stack_entries() {
         recipient: EffectRecipient::Target(SelectionFilter::Spell, TargetCount::Exactly(1)),
         spell_id: cs_id,
     }, vec![0]);
-    game.cast_spell(0, bolt_id, &decisions).unwrap();
+    let bolt_id = game.cast_spell(0, bolt_id, &decisions).unwrap();
     assert!(game.stack_entries.contains_key(&bolt_id));
 
     // Player 1 casts counterspell targeting bolt
@@ -353,7 +355,7 @@ fn test_counterspell_cleans_up_stack_entries() {
     // Bolt's stack entry should be cleaned up
     assert!(!game.stack_entries.contains_key(&bolt_id));
     // Bolt should be in graveyard
-    assert_eq!(game.get_object(bolt_id).unwrap().zone, Zone::Graveyard);
+    assert_eq!(game.get_object(game.current_identity(bolt_id)).unwrap().zone, Zone::Graveyard);
     // Stack should have just the counterspell's post-resolution state (empty after CS resolves)
     // Actually counterspell itself was resolved and removed too
     assert!(!game.stack.contains(&bolt_id));
@@ -0,0 +1,85 @@
+//! Pregame action integration tests (rule 103.6) — `Game::setup` offering
+//! opening-hand actions after mulligans, Leylines beginning the game on the
+//! battlefield, and a revealed card's delayed trigger firing at its
+//! controller's first upkeep.
+
+use std::sync::Arc;
+
+use mtgsim::cards::basic_lands;
+use mtgsim::cards::pregame::leyline_of_vitality;
+use mtgsim::objects::card_data::{CardData, CardDataBuilder, OpeningHandAction};
+use mtgsim::state::game::Game;
+use mtgsim::state::game_config::GameConfig;
+use mtgsim::state::game_state::StepType;
+use mtgsim::types::effects::{AmountExpr, Effect, EffectRecipient, Primitive};
+use mtgsim::types::zones::Zone;
+use mtgsim::ui::choice_types::ChoiceKind;
+use mtgsim::ui::decision::ScriptedDecisionProvider;
+
+fn copies(card: fn() -> Arc<CardData>, n: usize) -> Vec<Arc<CardData>> {
+    (0..n).map(|_| card()).collect()
+}
+
+/// A Chancellor-style card: reveal it from your opening hand to gain 3 life
+/// at the beginning of your first upkeep.
+fn test_chancellor() -> Arc<CardData> {
+    CardDataBuilder::new("Test Chancellor")
+        .opening_hand_action(OpeningHandAction::RevealForFirstUpkeep(Box::new(
+            Effect::Atom(Primitive::GainLife(AmountExpr::Fixed(3)), EffectRecipient::Controller),
+        )))
+        .build()
+}
+
+#[test]
+fn test_setup_puts_chosen_leylines_onto_battlefield() {
+    let mut game = Game::new(GameConfig::test(), vec![
+        copies(leyline_of_vitality, 20),
+        copies(basic_lands::forest, 20),
+    ]).unwrap();
+
+    // Player 0 holds seven Leylines and begins with two of them; player 1
+    // has nothing to take and isn't asked.
+    let dp = ScriptedDecisionProvider::new();
+    dp.expect_pick_n(ChoiceKind::OpeningHandActions, vec![0, 1]);
+    dp.expect_ordering(ChoiceKind::OpeningHandActions, vec![1, 0]);
+    game.setup(&dp).unwrap();
+
+    let on_battlefield: Vec<_> = game.state.battlefield.values()
+        .filter(|e| e.controller == 0)
+        .collect();
+    assert_eq!(on_battlefield.len(), 2);
+    assert!(on_battlefield.iter().all(|e| e.controller_since_turn == 0));
+    assert_eq!(game.state.players[0].hand.len(), 5);
+    assert_eq!(game.state.players[1].hand.len(), 7);
+}
+
+#[test]
+fn test_revealed_card_triggers_at_controllers_first_upkeep() {
+    let mut game = Game::new(GameConfig::test(), vec![
+        copies(basic_lands::forest, 20),
+        copies(test_chancellor, 20),
+    ]).unwrap();
+
+    let dp = ScriptedDecisionProvider::new();
+    dp.expect_pick_n(ChoiceKind::OpeningHandActions, vec![0]);
+    game.setup(&dp).unwrap();
+
+    // The revealed card stays in hand.
+    assert_eq!(game.state.players[1].hand.len(), 7);
+    assert!(game.state.players[1].hand.iter()
+        .all(|id| game.state.objects[id].zone == Zone::Hand));
+
+    // Nothing happens during player 0's turn; player 1's first upkeep puts
+    // the trigger on the stack.
+    while !(game.state.active_player == 1 && game.state.phase.step == Some(StepType::Upkeep)) {
+        game.state.advance_turn().unwrap();
+        if game.state.active_player == 0 {
+            assert!(game.state.stack.is_empty());
+        }
+    }
+    assert_eq!(game.state.stack.len(), 1);
+
+    game.state.resolve_top_of_stack(&ScriptedDecisionProvider::new()).unwrap();
+    assert_eq!(game.state.players[1].life_total, 23);
+    assert!(game.state.first_upkeep_triggers.is_empty());
+}
@@ -0,0 +1,177 @@
+//! Regeneration and indestructible integration tests — regeneration shields
+//! (rule 701.19), "can't be regenerated" (rule 701.19c), and indestructible
+//! (rule 702.12b) against destroy effects and the lethal-damage SBAs
+//! (rules 704.5f–h).
+
+mod common;
+
+use common::{bolt_source, deal_damage, put_in_hand, put_on_battlefield, setup_two_player_game};
+
+use mtgsim::cards::creatures;
+use mtgsim::cards::keyword_creatures;
+use mtgsim::cards::regeneration;
+use mtgsim::engine::actions::GameAction;
+use mtgsim::events::event::GameEvent;
+use mtgsim::state::battlefield::{AttackTarget, AttackingInfo, BlockingInfo};
+use mtgsim::state::game_state::GameState;
+use mtgsim::types::effects::{CounterType, EffectRecipient, SelectionFilter, TargetCount};
+use mtgsim::types::ids::ObjectId;
+use mtgsim::types::mana::{ManaCost, ManaType};
+use mtgsim::types::zones::Zone;
+use mtgsim::ui::choice_types::ChoiceKind;
+use mtgsim::ui::decision::ScriptedDecisionProvider;
+
+/// Activate Drudge Skeletons' "{B}: Regenerate" and resolve it.
+fn regenerate_skeletons(game: &mut GameState, skeletons: ObjectId) {
+    game.players[0].mana_pool.add(ManaType::Black, 1);
+    let dp = ScriptedDecisionProvider::new();
+    game.activate_ability(0, skeletons, 0, &dp).unwrap();
+    game.resolve_top_of_stack(&dp).unwrap();
+}
+
+#[test]
+fn test_regeneration_shield_replaces_lethal_damage_destruction() {
+    let mut game = setup_two_player_game();
+    let skeletons = put_on_battlefield(&mut game, regeneration::drudge_skeletons(), 0);
+    regenerate_skeletons(&mut game, skeletons);
+    assert_eq!(game.battlefield.get(&skeletons).unwrap().regeneration_shields, 1);
+
+    let bolt = bolt_source(&mut game, 1);
+    deal_damage(&mut game, bolt, skeletons, 3);
+    assert!(game.check_state_based_actions(&ScriptedDecisionProvider::new()).unwrap());
+
+    let entry = game.battlefield.get(&skeletons).unwrap();
+    assert!(entry.tapped);
+    assert_eq!(entry.damage_marked, 0);
+    assert_eq!(entry.regeneration_shields, 0);
+    assert!(game.events.events().iter().any(|e| matches!(
+        e,
+        GameEvent::Regenerated { object_id } if *object_id == skeletons
+    )));
+
+    // The shield is used up: the next lethal damage destroys it.
+    deal_damage(&mut game, bolt, skeletons, 3);
+    game.check_state_based_actions(&ScriptedDecisionProvider::new()).unwrap();
+    assert_eq!(game.get_object(game.current_identity(skeletons)).unwrap().zone, Zone::Graveyard);
+}
+
+#[test]
+fn test_regeneration_shield_replaces_destroy_effect() {
+    let mut game = setup_two_player_game();
+    let skeletons = put_on_battlefield(&mut game, regeneration::drudge_skeletons(), 0);
+    regenerate_skeletons(&mut game, skeletons);
+
+    game.execute_action(GameAction::Destroy { object: skeletons, can_regenerate: true }).unwrap();
+    assert!(game.battlefield.contains_key(&skeletons));
+    assert!(game.battlefield.get(&skeletons).unwrap().tapped);
+}
+
+#[test]
+fn test_regeneration_shields_stack() {
+    let mut game = setup_two_player_game();
+    let skeletons = put_on_battlefield(&mut game, regeneration::drudge_skeletons(), 0);
+    regenerate_skeletons(&mut game, skeletons);
+    regenerate_skeletons(&mut game, skeletons);
+
+    game.execute_action(GameAction::Destroy { object: skeletons, can_regenerate: true }).unwrap();
+    game.execute_action(GameAction::Destroy { object: skeletons, can_regenerate: true }).unwrap();
+    assert!(game.battlefield.contains_key(&skeletons));
+    game.execute_action(GameAction::Destroy { object: skeletons, can_regenerate: true }).unwrap();
+    assert_eq!(game.get_object(game.current_identity(skeletons)).unwrap().zone, Zone::Graveyard);
+}
+
+#[test]
+fn test_terror_ignores_regeneration_shield() {
+    let mut game = setup_two_player_game();
+    let bears = put_on_battlefield(&mut game, creatures::grizzly_bears(), 1);
+    game.execute_action(GameAction::Regenerate { object: bears }).unwrap();
+    let terror = put_in_hand(&mut game, regeneration::terror(), 0);
+    game.players[0].mana_pool.add(ManaType::Black, 2);
+
+    let dp = ScriptedDecisionProvider::new();
+    dp.expect_pick_n(
+        ChoiceKind::SelectRecipients {
+            recipient: EffectRecipient::Target(SelectionFilter::Creature, TargetCount::Exactly(1)),
+            spell_id: terror,
+        },
+        vec![0],
+    );
+    dp.expect_allocation(ChoiceKind::GenericManaAllocation { mana_cost: ManaCost::zero() }, vec![1]);
+    game.cast_spell(0, terror, &dp).unwrap();
+    game.resolve_top_of_stack(&dp).unwrap();
+
+    assert_eq!(game.get_object(game.current_identity(bears)).unwrap().zone, Zone::Graveyard);
+    assert!(game.events.events().iter().any(|e| matches!(
+        e,
+        GameEvent::CreatureDied { creature_id, owner: 1 } if *creature_id == bears
+    )));
+}
+
+#[test]
+fn test_regenerated_blocker_is_removed_from_combat() {
+    let mut game = setup_two_player_game();
+    let attacker = put_on_battlefield(&mut game, creatures::hill_giant(), 1);
+    let skeletons = put_on_battlefield(&mut game, regeneration::drudge_skeletons(), 0);
+    game.battlefield.get_mut(&attacker).unwrap().attacking = Some(AttackingInfo {
+        target: AttackTarget::Player(0),
+        is_blocked: true,
+        blocked_by: vec![skeletons],
+    });
+    game.battlefield.get_mut(&skeletons).unwrap().blocking = Some(BlockingInfo {
+        blocking: vec![attacker],
+    });
+    regenerate_skeletons(&mut game, skeletons);
+
+    game.execute_action(GameAction::Destroy { object: skeletons, can_regenerate: true }).unwrap();
+
+    assert!(game.battlefield.get(&skeletons).unwrap().blocking.is_none());
+    let info = game.battlefield.get(&attacker).unwrap().attacking.clone().unwrap();
+    // Still blocked (rule 509.1h), but by nothing.
+    assert!(info.is_blocked);
+    assert!(info.blocked_by.is_empty());
+}
+
+#[test]
+fn test_indestructible_survives_lethal_and_deathtouch_damage() {
+    let mut game = setup_two_player_game();
+    let myr = put_on_battlefield(&mut game, regeneration::darksteel_myr(), 0);
+    let bolt = bolt_source(&mut game, 1);
+    deal_damage(&mut game, bolt, myr, 5);
+    assert!(!game.check_state_based_actions(&ScriptedDecisionProvider::new()).unwrap());
+    assert!(game.battlefield.contains_key(&myr));
+
+    // Deathtouch damage (704.5h) doesn't destroy it either.
+    let archer = put_on_battlefield(&mut game, keyword_creatures::thornweald_archer(), 1);
+    game.battlefield.get_mut(&myr).unwrap().damage_marked = 0;
+    game.battlefield.get_mut(&myr).unwrap().add_counters(CounterType::PlusOnePlusOne, 3);
+    deal_damage(&mut game, archer, myr, 1);
+    assert!(game.battlefield.get(&myr).unwrap().damaged_by_deathtouch);
+    game.check_state_based_actions(&ScriptedDecisionProvider::new()).unwrap();
+    assert!(game.battlefield.contains_key(&myr));
+
+    // Nor does a destroy effect.
+    game.execute_action(GameAction::Destroy { object: myr, can_regenerate: false }).unwrap();
+    assert!(game.battlefield.contains_key(&myr));
+}
+
+#[test]
+fn test_indestructible_dies_to_zero_toughness() {
+    // 704.5f isn't destruction: indestructible doesn't help.
+    let mut game = setup_two_player_game();
+    let myr = put_on_battlefield(&mut game, regeneration::darksteel_myr(), 0);
+    game.battlefield.get_mut(&myr).unwrap().add_counters(CounterType::MinusOneMinusOne, 1);
+
+    game.check_state_based_actions(&ScriptedDecisionProvider::new()).unwrap();
+    assert_eq!(game.get_object(game.current_identity(myr)).unwrap().zone, Zone::Graveyard);
+}
+
+#[test]
+fn test_zero_toughness_ignores_regeneration_shield() {
+    let mut game = setup_two_player_game();
+    let skeletons = put_on_battlefield(&mut game, regeneration::drudge_skeletons(), 0);
+    regenerate_skeletons(&mut game, skeletons);
+    game.battlefield.get_mut(&skeletons).unwrap().add_counters(CounterType::MinusOneMinusOne, 1);
+
+    game.check_state_based_actions(&ScriptedDecisionProvider::new()).unwrap();
+    assert_eq!(game.get_object(game.current_identity(skeletons)).unwrap().zone, Zone::Graveyard);
+}
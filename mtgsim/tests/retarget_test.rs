@@ -0,0 +1,168 @@
+//! Changing targets integration tests — "change the target" to another
+//! legal one or leave it unchanged (rule 115.7a), redirecting to a specific
+//! object (Spellskite), and "choose new targets" (rule 115.7d).
+
+mod common;
+
+use common::{put_in_hand, put_on_battlefield, setup_two_player_game};
+
+use mtgsim::cards::alpha;
+use mtgsim::cards::creatures;
+use mtgsim::cards::redirection;
+use mtgsim::engine::resolve::ResolvedTarget;
+use mtgsim::events::event::GameEvent;
+use mtgsim::oracle::legality::enumerate_legal_selections;
+use mtgsim::state::game_state::GameState;
+use mtgsim::types::effects::{EffectRecipient, SelectionFilter};
+use mtgsim::types::ids::ObjectId;
+use mtgsim::types::mana::{ManaCost, ManaType};
+use mtgsim::ui::choice_types::ChoiceKind;
+use mtgsim::ui::decision::ScriptedDecisionProvider;
+
+/// Expectations are matched by kind only, so their ids are placeholders.
+const ANY: ObjectId = ObjectId::nil();
+
+fn pick_target(dp: &ScriptedDecisionProvider, index: usize) {
+    dp.expect_pick_n(
+        ChoiceKind::SelectRecipients { recipient: EffectRecipient::Implicit, spell_id: ANY },
+        vec![index],
+    );
+}
+
+fn pay_generic(dp: &ScriptedDecisionProvider, allocation: Vec<u64>) {
+    dp.expect_allocation(ChoiceKind::GenericManaAllocation { mana_cost: ManaCost::zero() }, allocation);
+}
+
+fn targets_changed(game: &GameState) -> bool {
+    game.events.events().iter().any(|e| matches!(e, GameEvent::TargetsChanged { .. }))
+}
+
+/// Player 0 casts Lightning Bolt at player 1.
+fn bolt_player_one(game: &mut GameState, dp: &ScriptedDecisionProvider) -> ObjectId {
+    let bolt = put_in_hand(game, alpha::lightning_bolt(), 0);
+    game.players[0].mana_pool.add(ManaType::Red, 1);
+    // Any target: [Player(0), Player(1), creatures...]
+    pick_target(dp, 1);
+    game.cast_spell(0, bolt, dp).unwrap()
+}
+
+/// Player 1 casts Deflection at the only spell on the stack.
+fn cast_deflection(game: &mut GameState, dp: &ScriptedDecisionProvider) {
+    let deflection = put_in_hand(game, redirection::deflection(), 1);
+    game.players[1].mana_pool.add(ManaType::Blue, 4);
+    pick_target(dp, 0);
+    pay_generic(dp, vec![3]);
+    game.cast_spell(1, deflection, dp).unwrap();
+}
+
+#[test]
+fn test_deflection_changes_the_target_of_a_spell() {
+    let mut game = setup_two_player_game();
+    let dp = ScriptedDecisionProvider::new();
+    let bolt = bolt_player_one(&mut game, &dp);
+    cast_deflection(&mut game, &dp);
+
+    // Alternatives to Player(1): [Player(0)]
+    dp.expect_pick_n(ChoiceKind::ChangeTarget { recipient: EffectRecipient::Implicit, spell_id: ANY }, vec![0]);
+    game.resolve_top_of_stack(&dp).unwrap();
+    assert_eq!(game.stack_entries[&bolt].chosen_targets, vec![ResolvedTarget::Player(0)]);
+    assert!(targets_changed(&game));
+
+    game.resolve_top_of_stack(&dp).unwrap();
+    assert_eq!(game.players[0].life_total, 17);
+    assert_eq!(game.players[1].life_total, 20);
+}
+
+#[test]
+fn test_target_is_unchanged_without_a_legal_alternative() {
+    let mut game = setup_two_player_game();
+    let bears = put_on_battlefield(&mut game, creatures::grizzly_bears(), 0);
+    let growth = put_in_hand(&mut game, alpha::giant_growth(), 0);
+    game.players[0].mana_pool.add(ManaType::Green, 1);
+    let dp = ScriptedDecisionProvider::new();
+    pick_target(&dp, 0);
+    let growth = game.cast_spell(0, growth, &dp).unwrap();
+    cast_deflection(&mut game, &dp);
+
+    game.resolve_top_of_stack(&dp).unwrap();
+    assert_eq!(game.stack_entries[&growth].chosen_targets, vec![ResolvedTarget::Object(bears)]);
+    assert!(!targets_changed(&game));
+}
+
+#[test]
+fn test_deflection_needs_a_spell_with_a_single_target() {
+    let mut game = setup_two_player_game();
+    let bears = put_in_hand(&mut game, creatures::grizzly_bears(), 0);
+    game.players[0].mana_pool.add(ManaType::Green, 2);
+    let dp = ScriptedDecisionProvider::new();
+    pay_generic(&dp, vec![1]);
+    game.cast_spell(0, bears, &dp).unwrap();
+
+    assert!(enumerate_legal_selections(&game, &SelectionFilter::SpellWithSingleTarget, None).is_empty());
+    let deflection = put_in_hand(&mut game, redirection::deflection(), 1);
+    game.players[1].mana_pool.add(ManaType::Blue, 4);
+    assert!(game.cast_spell(1, deflection, &dp).is_err());
+}
+
+#[test]
+fn test_spellskite_redirects_a_spell_to_itself() {
+    let mut game = setup_two_player_game();
+    let skite = put_on_battlefield(&mut game, redirection::spellskite(), 1);
+    let dp = ScriptedDecisionProvider::new();
+    let bolt = bolt_player_one(&mut game, &dp);
+
+    game.players[1].mana_pool.add(ManaType::Blue, 1);
+    pick_target(&dp, 0);
+    game.activate_ability(1, skite, 0, &dp).unwrap();
+    game.resolve_top_of_stack(&dp).unwrap();
+    assert_eq!(game.stack_entries[&bolt].chosen_targets, vec![ResolvedTarget::Object(skite)]);
+
+    game.resolve_top_of_stack(&dp).unwrap();
+    assert_eq!(game.players[1].life_total, 20);
+    assert_eq!(game.battlefield[&skite].damage_marked, 3);
+}
+
+#[test]
+fn test_spellskite_cannot_redirect_to_an_illegal_target() {
+    let mut game = setup_two_player_game();
+    let skite = put_on_battlefield(&mut game, redirection::spellskite(), 1);
+    let dp = ScriptedDecisionProvider::new();
+    let bolt = bolt_player_one(&mut game, &dp);
+    let counterspell = put_in_hand(&mut game, alpha::counterspell(), 1);
+    game.players[1].mana_pool.add(ManaType::Blue, 2);
+    pick_target(&dp, 0);
+    let counterspell = game.cast_spell(1, counterspell, &dp).unwrap();
+
+    // Spells and abilities on the stack: [bolt, Counterspell]
+    game.players[1].mana_pool.add(ManaType::Blue, 1);
+    pick_target(&dp, 1);
+    game.activate_ability(1, skite, 0, &dp).unwrap();
+    game.resolve_top_of_stack(&dp).unwrap();
+    assert_eq!(game.stack_entries[&counterspell].chosen_targets, vec![ResolvedTarget::Object(bolt)]);
+    assert!(!targets_changed(&game), "a creature isn't a legal target for \"target spell\"");
+}
+
+#[test]
+fn test_choose_new_targets_may_keep_or_change_them() {
+    let mut game = setup_two_player_game();
+    let dp = ScriptedDecisionProvider::new();
+    let bolt = bolt_player_one(&mut game, &dp);
+
+    let guess = put_in_hand(&mut game, redirection::second_guess(), 1);
+    game.players[1].mana_pool.add(ManaType::Blue, 2);
+    pick_target(&dp, 0);
+    game.cast_spell(1, guess, &dp).unwrap();
+    // Options: [Player(1) (current), Player(0)] — keep it.
+    dp.expect_pick_n(ChoiceKind::ChooseNewTargets { recipient: EffectRecipient::Implicit, spell_id: ANY }, vec![0]);
+    game.resolve_top_of_stack(&dp).unwrap();
+    assert_eq!(game.stack_entries[&bolt].chosen_targets, vec![ResolvedTarget::Player(1)]);
+    assert!(!targets_changed(&game));
+
+    let guess = put_in_hand(&mut game, redirection::second_guess(), 1);
+    pick_target(&dp, 0);
+    game.cast_spell(1, guess, &dp).unwrap();
+    dp.expect_pick_n(ChoiceKind::ChooseNewTargets { recipient: EffectRecipient::Implicit, spell_id: ANY }, vec![1]);
+    game.resolve_top_of_stack(&dp).unwrap();
+    assert_eq!(game.stack_entries[&bolt].chosen_targets, vec![ResolvedTarget::Player(0)]);
+    assert!(targets_changed(&game));
+}
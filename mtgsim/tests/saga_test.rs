@@ -0,0 +1,128 @@
+//! Saga integration tests — entering with a lore counter, a lore counter
+//! each precombat main phase, chapter abilities triggering as the count
+//! reaches them, and the final-chapter sacrifice (rule 714, SBA 704.5s).
+
+mod common;
+
+use common::{fill_library, put_in_hand, setup_two_player_game};
+
+use mtgsim::cards::sagas;
+use mtgsim::engine::priority::PriorityResult;
+use mtgsim::events::event::GameEvent;
+use mtgsim::state::game_state::{GameState, PhaseType};
+use mtgsim::types::effects::{CounterType, EffectRecipient};
+use mtgsim::types::ids::ObjectId;
+use mtgsim::types::mana::{ManaCost, ManaType};
+use mtgsim::types::zones::Zone;
+use mtgsim::ui::choice_types::ChoiceKind;
+use mtgsim::ui::decision::ScriptedDecisionProvider;
+use mtgsim::ui::display::format_permanent;
+
+/// Cast Chronicle of Embers for player 0 and resolve it.
+fn cast_chronicle(game: &mut GameState) -> ObjectId {
+    let saga = put_in_hand(game, sagas::chronicle_of_embers(), 0);
+    game.players[0].mana_pool.add(ManaType::Red, 3);
+    let dp = ScriptedDecisionProvider::new();
+    dp.expect_allocation(ChoiceKind::GenericManaAllocation { mana_cost: ManaCost::zero() }, vec![2]);
+    let saga = game.cast_spell(0, saga, &dp).unwrap();
+    game.resolve_top_of_stack(&dp).unwrap();
+    game.current_identity(saga)
+}
+
+/// A decision provider that targets player 1 with the next chapter ability.
+fn target_opponent(saga: ObjectId) -> ScriptedDecisionProvider {
+    let dp = ScriptedDecisionProvider::new();
+    dp.expect_pick_n(
+        ChoiceKind::SelectRecipients { recipient: EffectRecipient::Implicit, spell_id: saga },
+        vec![1],
+    );
+    dp
+}
+
+fn lore(game: &GameState, saga: ObjectId) -> u32 {
+    game.battlefield[&saga].counter_count(CounterType::Lore)
+}
+
+/// Advance to player 0's next precombat main phase.
+fn advance_to_next_main_phase(game: &mut GameState) {
+    let turn = game.turn_number;
+    while !(game.turn_number > turn
+        && game.active_player == 0
+        && game.phase.phase_type == PhaseType::Precombat)
+    {
+        game.advance_turn().unwrap();
+    }
+}
+
+#[test]
+fn test_saga_enters_with_lore_counter_and_chapter_one_triggers() {
+    let mut game = setup_two_player_game();
+    let saga = cast_chronicle(&mut game);
+
+    assert_eq!(lore(&game, saga), 1);
+    assert_eq!(game.pending_triggers.len(), 1);
+    assert!(format_permanent(&game, saga, 1).contains("lore 1"));
+
+    // Before anyone gets priority, chapter I goes on the stack.
+    let dp = target_opponent(saga);
+    dp.expect_pick_n(ChoiceKind::PriorityAction, vec![0]);
+    dp.expect_pick_n(ChoiceKind::PriorityAction, vec![0]);
+    assert_eq!(game.run_priority_round(&dp).unwrap(), PriorityResult::StackResolved);
+    assert_eq!(game.players[1].life_total, 18);
+    assert!(game.stack.is_empty());
+    assert!(game.battlefield.contains_key(&saga));
+}
+
+#[test]
+fn test_lore_counter_added_as_precombat_main_phase_begins() {
+    let mut game = setup_two_player_game();
+    fill_library(&mut game, 0, 5);
+    fill_library(&mut game, 1, 5);
+    let saga = cast_chronicle(&mut game);
+    game.pending_triggers.clear();
+
+    advance_to_next_main_phase(&mut game);
+    assert_eq!(lore(&game, saga), 2);
+    assert_eq!(game.pending_triggers.len(), 1, "chapter II triggered");
+}
+
+#[test]
+fn test_opponents_main_phase_adds_no_lore_counter() {
+    let mut game = setup_two_player_game();
+    fill_library(&mut game, 0, 5);
+    fill_library(&mut game, 1, 5);
+    let saga = cast_chronicle(&mut game);
+
+    while game.active_player == 0 {
+        game.advance_turn().unwrap();
+    }
+    while game.phase.phase_type != PhaseType::Precombat {
+        game.advance_turn().unwrap();
+    }
+    assert_eq!(lore(&game, saga), 1);
+}
+
+#[test]
+fn test_saga_is_sacrificed_after_final_chapter_leaves_the_stack() {
+    let mut game = setup_two_player_game();
+    fill_library(&mut game, 0, 5);
+    let saga = cast_chronicle(&mut game);
+    game.pending_triggers.clear();
+    game.add_lore_counters(saga, 2);
+    game.pending_triggers.remove(0);
+
+    let dp = ScriptedDecisionProvider::new();
+    assert!(game.put_pending_triggers_on_stack(&dp).unwrap());
+    game.check_state_based_actions_loop(&dp).unwrap();
+    assert!(game.battlefield.contains_key(&saga), "chapter III is still on the stack");
+
+    let hand_before = game.players[0].hand.len();
+    game.resolve_top_of_stack(&dp).unwrap();
+    assert_eq!(game.players[0].hand.len(), hand_before + 1);
+
+    game.check_state_based_actions_loop(&dp).unwrap();
+    assert_eq!(game.objects[&game.current_identity(saga)].zone, Zone::Graveyard);
+    assert!(game.events.events().iter().any(|e| matches!(
+        e, GameEvent::SagaSacrificed { object_id, .. } if *object_id == saga
+    )));
+}
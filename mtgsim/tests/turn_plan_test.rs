@@ -0,0 +1,210 @@
+//! Turn plan integration tests — extra turns (rule 500.7), additional
+//! combat and main phases (rule 500.8), skipped steps (rule 614.10) and
+//! ending the turn (rule 723), cast through priority and walked by
+//! `advance_turn`.
+
+mod common;
+
+use common::{fill_library, put_in_hand, setup_multiplayer_game, setup_two_player_game};
+use mtgsim::cards::alpha;
+use mtgsim::cards::basic_lands;
+use mtgsim::cards::turn_structure::{fatigue, relentless_assault, time_stop, time_walk};
+use mtgsim::events::event::GameEvent;
+use mtgsim::state::game_state::{GameState, PhaseType, StepType};
+use mtgsim::types::effects::{EffectRecipient, SelectionFilter, SkipTarget, TargetCount};
+use mtgsim::types::mana::{ManaCost, ManaType};
+use mtgsim::types::zones::Zone;
+use mtgsim::ui::choice_types::ChoiceKind;
+use mtgsim::ui::decision::ScriptedDecisionProvider;
+
+/// Player 0 casts the only spell in their hand, paying `generic` from a
+/// one-color pool, then both players pass.
+fn cast_and_resolve(game: &mut GameState, dp: &ScriptedDecisionProvider, generic: u64) {
+    dp.expect_pick_n(ChoiceKind::PriorityAction, vec![1]);
+    pay_generic(dp, generic);
+    resolve_after_cast(game, dp);
+}
+
+fn pay_generic(dp: &ScriptedDecisionProvider, generic: u64) {
+    dp.expect_allocation(ChoiceKind::GenericManaAllocation { mana_cost: ManaCost::zero() }, vec![generic]);
+}
+
+/// The rest of `cast_and_resolve` once the cast's own choices are queued.
+fn resolve_after_cast(game: &mut GameState, dp: &ScriptedDecisionProvider) {
+    dp.expect_pick_n(ChoiceKind::PriorityAction, vec![0]);
+    dp.expect_pick_n(ChoiceKind::PriorityAction, vec![0]);
+    game.run_priority_round(dp).unwrap();
+    game.run_priority_round(dp).unwrap();
+}
+
+/// Advance until a new turn begins; returns its active player.
+fn finish_turn(game: &mut GameState) -> usize {
+    let turn = game.turn_number;
+    while game.turn_number == turn {
+        game.advance_turn().unwrap();
+    }
+    game.active_player
+}
+
+fn phases_in_rest_of_turn(game: &mut GameState) -> Vec<PhaseType> {
+    let turn = game.turn_number;
+    let mut phases = Vec::new();
+    loop {
+        let (phase, step) = game.advance_turn().unwrap();
+        if game.turn_number != turn {
+            return phases;
+        }
+        if step.is_none() || phases.last() != Some(&phase) {
+            phases.push(phase);
+        }
+    }
+}
+
+#[test]
+fn test_time_walk_gives_caster_the_next_turn() {
+    let mut game = setup_two_player_game();
+    for pid in 0..2 {
+        fill_library(&mut game, pid, 5);
+    }
+    put_in_hand(&mut game, time_walk(), 0);
+    game.players[0].mana_pool.add(ManaType::Blue, 2);
+
+    let dp = ScriptedDecisionProvider::new();
+    cast_and_resolve(&mut game, &dp, 1);
+    assert!(game.events.events().iter().any(|e| matches!(e, GameEvent::ExtraTurnAdded { player: 0 })));
+
+    assert_eq!(finish_turn(&mut game), 0);
+    assert_eq!(game.turn_number, 2);
+    assert_eq!(finish_turn(&mut game), 1);
+}
+
+#[test]
+fn test_turn_order_resumes_after_extra_turn() {
+    // Player 1 gives player 0 an extra turn: after it, player 2 is next.
+    let mut game = setup_multiplayer_game(3);
+    for pid in 0..3 {
+        fill_library(&mut game, pid, 5);
+    }
+    game.active_player = 1;
+    game.add_extra_turn(0);
+    assert_eq!(finish_turn(&mut game), 0);
+    assert_eq!(finish_turn(&mut game), 2);
+    assert_eq!(finish_turn(&mut game), 0);
+}
+
+#[test]
+fn test_extra_turns_are_taken_most_recent_first() {
+    let mut game = setup_multiplayer_game(3);
+    for pid in 0..3 {
+        fill_library(&mut game, pid, 5);
+    }
+    game.add_extra_turn(1);
+    game.add_extra_turn(2);
+    assert_eq!(finish_turn(&mut game), 2);
+    assert_eq!(finish_turn(&mut game), 1);
+    assert_eq!(finish_turn(&mut game), 1);
+}
+
+#[test]
+fn test_relentless_assault_adds_combat_and_main_phase() {
+    let mut game = setup_two_player_game();
+    put_in_hand(&mut game, relentless_assault(), 0);
+    game.players[0].mana_pool.add(ManaType::Red, 4);
+
+    let dp = ScriptedDecisionProvider::new();
+    cast_and_resolve(&mut game, &dp, 2);
+    assert!(game.events.events().iter().any(|e| matches!(
+        e,
+        GameEvent::PhasesAdded { phases } if phases == &vec![PhaseType::Combat, PhaseType::Postcombat]
+    )));
+
+    fill_library(&mut game, 1, 5);
+    assert_eq!(phases_in_rest_of_turn(&mut game), vec![
+        PhaseType::Combat, PhaseType::Postcombat,
+        PhaseType::Combat, PhaseType::Postcombat,
+        PhaseType::Ending,
+    ]);
+}
+
+#[test]
+fn test_fatigue_skips_targets_next_draw_step() {
+    let mut game = setup_two_player_game();
+    fill_library(&mut game, 1, 5);
+    let spell = put_in_hand(&mut game, fatigue(), 0);
+    game.players[0].mana_pool.add(ManaType::Blue, 2);
+
+    let dp = ScriptedDecisionProvider::new();
+    dp.expect_pick_n(ChoiceKind::PriorityAction, vec![1]);
+    dp.expect_pick_n(ChoiceKind::SelectRecipients {
+        recipient: EffectRecipient::Target(SelectionFilter::Player, TargetCount::Exactly(1)),
+        spell_id: spell,
+    }, vec![1]);
+    pay_generic(&dp, 1);
+    resolve_after_cast(&mut game, &dp);
+    assert_eq!(game.turn_plan.skips(), &[(1, SkipTarget::DrawStep)]);
+
+    // Player 1's turn: untap, upkeep, then straight to the main phase.
+    finish_turn(&mut game);
+    assert_eq!(game.advance_turn().unwrap(), (PhaseType::Beginning, Some(StepType::Upkeep)));
+    assert_eq!(game.advance_turn().unwrap(), (PhaseType::Precombat, None));
+    assert!(game.players[1].hand.is_empty());
+    assert!(game.turn_plan.skips().is_empty());
+    assert!(game.events.events().iter().any(|e| matches!(
+        e,
+        GameEvent::Skipped { player: 1, what: SkipTarget::DrawStep }
+    )));
+}
+
+#[test]
+fn test_time_stop_exiles_stack_and_skips_to_cleanup() {
+    let mut game = setup_two_player_game();
+    fill_library(&mut game, 1, 5);
+    let bolt = put_in_hand(&mut game, alpha::lightning_bolt(), 0);
+    game.players[0].mana_pool.add(ManaType::Red, 1);
+    let stop = put_in_hand(&mut game, time_stop(), 1);
+    game.players[1].mana_pool.add(ManaType::Blue, 6);
+
+    // Player 0 bolts player 1, who responds with Time Stop.
+    let dp = ScriptedDecisionProvider::new();
+    dp.expect_pick_n(ChoiceKind::PriorityAction, vec![1]);
+    dp.expect_pick_n(ChoiceKind::SelectRecipients {
+        recipient: EffectRecipient::Target(SelectionFilter::Any, TargetCount::Exactly(1)),
+        spell_id: bolt,
+    }, vec![1]);
+    dp.expect_pick_n(ChoiceKind::PriorityAction, vec![0]);
+    dp.expect_pick_n(ChoiceKind::PriorityAction, vec![1]);
+    pay_generic(&dp, 4);
+    dp.expect_pick_n(ChoiceKind::PriorityAction, vec![0]);
+    dp.expect_pick_n(ChoiceKind::PriorityAction, vec![0]);
+    game.run_priority_loop(&dp).unwrap();
+
+    // Both spells are exiled and the bolt never resolved (rule 723.1a).
+    assert!(game.stack.is_empty());
+    assert_eq!(game.objects[&game.current_identity(bolt)].zone, Zone::Exile);
+    assert_eq!(game.objects[&game.current_identity(stop)].zone, Zone::Exile);
+    assert_eq!(game.players[1].life_total, 20);
+    assert!(game.turn_plan.is_ending());
+
+    assert_eq!(game.advance_turn().unwrap(), (PhaseType::Ending, Some(StepType::Cleanup)));
+    assert!(!game.turn_plan.is_ending());
+    assert_eq!(finish_turn(&mut game), 1);
+}
+
+#[test]
+fn test_skipped_untap_step_still_gives_a_land_drop() {
+    let mut game = setup_two_player_game();
+    fill_library(&mut game, 0, 5);
+    fill_library(&mut game, 1, 5);
+    let forest = put_in_hand(&mut game, basic_lands::forest(), 0);
+    game.play_land(0, forest, Zone::Hand).unwrap();
+    game.add_skip(0, SkipTarget::UntapStep);
+
+    finish_turn(&mut game);
+    assert_eq!(finish_turn(&mut game), 0);
+    assert_eq!(game.phase.step, Some(StepType::Upkeep));
+    while game.phase.phase_type != PhaseType::Precombat {
+        game.advance_turn().unwrap();
+    }
+    let forest = put_in_hand(&mut game, basic_lands::forest(), 0);
+    assert!(game.play_land(0, forest, Zone::Hand).is_ok());
+}
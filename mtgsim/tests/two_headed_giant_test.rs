@@ -0,0 +1,184 @@
+//! Two-Headed Giant integration tests (rule 810) — shared turns, the shared
+//! life total, the fifteen-poison team threshold, team losses, and team
+//! attack and block declarations.
+
+mod common;
+
+use common::{fill_library, put_in_hand, put_on_battlefield, setup_multiplayer_game};
+
+use mtgsim::cards::basic_lands;
+use mtgsim::cards::creatures;
+use mtgsim::cards::planeswalkers;
+use mtgsim::engine::actions::GameAction;
+use mtgsim::events::event::{GameEvent, LossReason};
+use mtgsim::oracle::legality::legal_attack_targets;
+use mtgsim::state::battlefield::AttackTarget;
+use mtgsim::state::game::{Game, GameResult};
+use mtgsim::state::game_config::GameConfig;
+use mtgsim::state::game_state::{GameState, StepType};
+use mtgsim::types::zones::Zone;
+use mtgsim::ui::choice_types::ChoiceKind;
+use mtgsim::ui::decision::ScriptedDecisionProvider;
+
+/// Players 0 and 1 (active team) against players 2 and 3, at 30 life.
+fn setup_two_headed_giant() -> GameState {
+    let mut game = setup_multiplayer_game(4);
+    game.set_teams(2).unwrap();
+    for player in &mut game.players {
+        player.life_total = 30;
+    }
+    game
+}
+
+#[test]
+fn test_game_new_seats_two_teams_at_thirty_life() {
+    let decks = (0..4)
+        .map(|_| vec![basic_lands::forest(); 60])
+        .collect();
+    let game = Game::new(GameConfig::two_headed_giant(), decks).unwrap();
+
+    assert_eq!(game.state.teams, vec![vec![0, 1], vec![2, 3]]);
+    assert!(game.state.players.iter().all(|p| p.life_total == 30));
+    assert!(game.state.skip_first_draw);
+}
+
+#[test]
+fn test_teammates_share_life_total() {
+    let mut game = setup_two_headed_giant();
+    game.execute_action(GameAction::LoseLife { player: 3, amount: 5 }).unwrap();
+    assert_eq!(game.players[2].life_total, 25);
+    assert_eq!(game.players[3].life_total, 25);
+    assert_eq!(game.players[0].life_total, 30);
+
+    let source = put_on_battlefield(&mut game, creatures::grizzly_bears(), 2);
+    game.execute_action(GameAction::GainLife { player: 2, amount: 2, source }).unwrap();
+    assert_eq!(game.players[3].life_total, 27);
+}
+
+#[test]
+fn test_team_at_zero_life_loses_together() {
+    let decks = (0..4)
+        .map(|_| vec![basic_lands::forest(); 60])
+        .collect();
+    let mut game = Game::new(GameConfig::two_headed_giant(), decks).unwrap();
+    game.state.execute_action(GameAction::LoseLife { player: 2, amount: 30 }).unwrap();
+    game.state.check_state_based_actions(&ScriptedDecisionProvider::new()).unwrap();
+
+    assert!(game.state.player_lost[2]);
+    assert!(game.state.player_lost[3]);
+    assert_eq!(game.check_game_over(), Some(GameResult::TeamWinner(vec![0, 1])));
+}
+
+#[test]
+fn test_fifteen_team_poison_counters_lose() {
+    let mut game = setup_two_headed_giant();
+    let dp = ScriptedDecisionProvider::new();
+
+    // Ten on one player is not enough in Two-Headed Giant.
+    game.players[2].poison_counters = 10;
+    game.check_state_based_actions(&dp).unwrap();
+    assert!(!game.player_lost[2]);
+
+    game.players[3].poison_counters = 5;
+    game.check_state_based_actions(&dp).unwrap();
+    assert!(game.player_lost[2]);
+    assert!(game.player_lost[3]);
+}
+
+#[test]
+fn test_one_player_losing_loses_for_the_team() {
+    let mut game = setup_two_headed_giant();
+    game.players[3].has_drawn_from_empty_library = true;
+    game.check_state_based_actions(&ScriptedDecisionProvider::new()).unwrap();
+
+    assert!(game.player_lost[3]);
+    assert!(game.player_lost[2]);
+    assert!(game.events.events().iter().any(|e| matches!(
+        e,
+        GameEvent::PlayerLost { player_id: 2, reason: LossReason::TeamLost }
+    )));
+    // The game is over: nobody's objects leave.
+    assert!(!game.events.events().iter().any(|e| matches!(e, GameEvent::PlayerLeftGame { .. })));
+}
+
+#[test]
+fn test_teams_share_turns() {
+    let mut game = setup_two_headed_giant();
+    for pid in 0..4 {
+        fill_library(&mut game, pid, 5);
+    }
+    let mine = put_on_battlefield(&mut game, creatures::grizzly_bears(), 1);
+    let theirs = put_on_battlefield(&mut game, creatures::grizzly_bears(), 3);
+    game.battlefield.get_mut(&mine).unwrap().tapped = true;
+    game.battlefield.get_mut(&theirs).unwrap().tapped = true;
+
+    // Finish team A's turn: team B is next, and both its players untap and draw.
+    let turn = game.turn_number;
+    while game.turn_number == turn {
+        game.advance_turn().unwrap();
+    }
+    assert_eq!(game.active_player, 2);
+    assert!(game.is_active_player(3));
+    while game.phase.step != Some(StepType::Upkeep) {
+        game.advance_turn().unwrap();
+    }
+    assert!(!game.battlefield.get(&theirs).unwrap().tapped);
+    assert!(game.battlefield.get(&mine).unwrap().tapped);
+    game.advance_turn().unwrap();
+    assert_eq!(game.players[2].hand.len(), 1);
+    assert_eq!(game.players[3].hand.len(), 1);
+
+    // And back to team A.
+    let turn = game.turn_number;
+    while game.turn_number == turn {
+        game.advance_turn().unwrap();
+    }
+    assert_eq!(game.active_player, 0);
+}
+
+#[test]
+fn test_both_active_players_act_at_sorcery_speed() {
+    let mut game = setup_two_headed_giant();
+    let forest = put_in_hand(&mut game, basic_lands::forest(), 1);
+    let forest = game.play_land(1, forest, Zone::Hand).unwrap();
+    assert!(game.battlefield.contains_key(&forest));
+
+    let other = put_in_hand(&mut game, basic_lands::forest(), 2);
+    assert!(game.play_land(2, other, Zone::Hand).is_err());
+}
+
+#[test]
+fn test_team_attacks_and_blocks_together() {
+    let mut game = setup_two_headed_giant();
+    game.turn_number = 2;
+    let attacker = put_on_battlefield(&mut game, creatures::grizzly_bears(), 1);
+    let blocker = put_on_battlefield(&mut game, creatures::grizzly_bears(), 3);
+
+    // Player 1's creature is offered in the team's declaration, and can
+    // attack only the opposing team: [(bears, P2), (bears, P3)].
+    let dp = ScriptedDecisionProvider::new();
+    dp.expect_pick_n(ChoiceKind::DeclareAttackers, vec![0]);
+    assert!(game.process_declare_attackers(&dp).unwrap());
+    assert_eq!(
+        game.battlefield.get(&attacker).unwrap().attacking.as_ref().unwrap().target,
+        AttackTarget::Player(2),
+    );
+
+    // Player 3 blocks the attacker aimed at their teammate.
+    dp.expect_pick_n(ChoiceKind::DeclareBlockers, vec![0]);
+    game.process_declare_blockers(&dp).unwrap();
+    assert!(game.battlefield.get(&blocker).unwrap().blocking.is_some());
+
+    game.process_combat_damage(&dp, false).unwrap();
+    assert_eq!(game.players[2].life_total, 30);
+    assert_eq!(game.battlefield.get(&attacker).unwrap().damage_marked, 2);
+}
+
+#[test]
+fn test_cannot_attack_teammate_or_their_planeswalker() {
+    let mut game = setup_two_headed_giant();
+    put_on_battlefield(&mut game, planeswalkers::garruk_wildspeaker(), 1);
+
+    let targets = legal_attack_targets(&game, 0);
+    assert_eq!(targets, vec![AttackTarget::Player(2), AttackTarget::Player(3)]);
+}